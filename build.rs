@@ -0,0 +1,124 @@
+//! Generates the colour lookup tables from the constants in `src/clr.rs`.
+//!
+//! `src/clr.rs` is itself generated by `get_colors_from_matplotlib.py`, so instead of keeping a second
+//! list of names in sync by hand, this script scans the constant definitions and emits
+//! - `NAMED`: a `NamedColor` record for every constant, indexed by its colour number
+//! - `SET_RANGES`: the range of colour numbers covered by each `ColorSet`
+//! - `LOOKUP`: a perfect hash map from the normalised name to the colour number
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+include!("src/names.rs");
+
+/// The `ColorSet` variant for the set names used in the generated doc comments, in declaration
+/// order.
+const SETS: [(&str, &str); 4] = [
+    ("BASE_COLORS", "Base"),
+    ("CSS4_COLORS", "Css4"),
+    ("XKCD_COLORS", "Xkcd"),
+    ("TABLEAU_COLORS", "Tableau"),
+];
+
+struct Entry {
+    constant: String,
+    name: String,
+    set: usize,
+    number: usize,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/clr.rs");
+    println!("cargo:rerun-if-changed=src/names.rs");
+
+    let source = fs::read_to_string("src/clr.rs").expect("could not read src/clr.rs");
+
+    // every constant is preceded by a line of the form
+    // /// Colour `CONSTANT` from the set `SET`. (Colour number `N`, name `NAME`)
+    // where `NAME` is the original matplotlib name, e.g. `xkcd:robin's egg`
+    let mut entries: Vec<Entry> = Vec::new();
+    for line in source.lines() {
+        let rest = match line.strip_prefix("/// Colour `") {
+            Some(rest) if rest.contains("from the set") => rest,
+            _ => continue,
+        };
+        let fields: Vec<&str> = rest.split('`').collect();
+        let set = SETS
+            .iter()
+            .position(|(name, _)| *name == fields[2])
+            .unwrap_or_else(|| panic!("unknown colour set {}", fields[2]));
+        let number: usize = fields[4].parse().expect("invalid colour number");
+        assert_eq!(number, entries.len(), "colour numbers are not consecutive");
+        entries.push(Entry {
+            constant: fields[0].to_string(),
+            name: fields[6].to_string(),
+            set,
+            number,
+        });
+    }
+
+    let mut lookup = phf_codegen::Map::new();
+    let keys: Vec<String> = entries
+        .iter()
+        .map(|e| normalize_name(&e.constant))
+        .collect();
+    for (key, entry) in keys.iter().zip(entries.iter()) {
+        lookup.entry(key.as_str(), &entry.number.to_string());
+    }
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("color_table.rs");
+    let mut out = fs::File::create(out_path).expect("could not create color_table.rs");
+
+    writeln!(
+        out,
+        "/// All named colours, indexed by their colour number."
+    )
+    .unwrap();
+    writeln!(out, "static NAMED: [NamedColor; {}] = [", entries.len()).unwrap();
+    for entry in entries.iter() {
+        writeln!(
+            out,
+            "    NamedColor {{ name: {:?}, set: ColorSet::{}, index: {}, color: {} }},",
+            entry.name, SETS[entry.set].1, entry.number, entry.constant
+        )
+        .unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+
+    writeln!(out, "/// The colour numbers covered by each `ColorSet`.").unwrap();
+    writeln!(
+        out,
+        "static SET_RANGES: [(usize, usize); {}] = [",
+        SETS.len()
+    )
+    .unwrap();
+    for (set, (set_name, _)) in SETS.iter().enumerate() {
+        let numbers: Vec<usize> = entries
+            .iter()
+            .filter(|e| e.set == set)
+            .map(|e| e.number)
+            .collect();
+        let (start, end) = (numbers[0], numbers[numbers.len() - 1] + 1);
+        assert!(
+            entries[start..end].iter().all(|e| e.set == set),
+            "colour set {} is not contiguous",
+            set_name
+        );
+        writeln!(out, "    ({}, {}),", start, end).unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+
+    writeln!(
+        out,
+        "/// Maps normalised colour names to their colour number."
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static LOOKUP: phf::Map<&'static str, u16> = {};",
+        lookup.build()
+    )
+    .unwrap();
+}
@@ -8,10 +8,10 @@ from matplotlib import colors
 import re
 
 
-def color_to_struct(name, col, i, which_set="base"):
-    name = re.sub("/", "_slash_", re.sub("[: ']", "_", name)).upper()
-    message = "Colour `{}` from the set `{}`. (Colour number `{}`)".format(
-        name, which_set, i)
+def color_to_struct(key, col, i, which_set="base"):
+    name = re.sub("/", "_slash_", re.sub("[: ']", "_", key)).upper()
+    message = "Colour `{}` from the set `{}`. (Colour number `{}`, name `{}`)".format(
+        name, which_set, i, key)
     return """
 /// Colour `{}`
 ///
@@ -102,6 +102,9 @@ def main():
         for k, col in colors.XKCD_COLORS.items():
             outfile.write(color_to_struct(k, color(col), i, 'XKCD_COLORS'))
             i += 1
+        for k, col in colors.TABLEAU_COLORS.items():
+            outfile.write(color_to_struct(k, color(col), i, 'TABLEAU_COLORS'))
+            i += 1
 
 
 if __name__ == "__main__":
@@ -0,0 +1,238 @@
+//! Operations deriving colours from a base colour, e.g. the hover and active states of a theme
+//! colour: tints, shades and tones, lighten/darken, saturate/desaturate, greyscale, inversion and
+//! hue rotation.
+//!
+//! The methods on `Color` work in Oklch, so that lightening or rotating the hue changes only what
+//! it says, and map results outside the sRGB gamut back into it with the CSS gamut mapping.
+//! Lightness changes are given in Oklch lightness (`0` to `1`), chroma changes in Oklch chroma,
+//! which is below about `0.37` for sRGB colours. The `sass` module has the functions of the same
+//! names from Sass, which work in HSL and give the same results as the Sass compiler.
+//!
+//! # Examples
+//! ```
+//! use colors::adjust::sass;
+//! use colors::clr::{Color, ROYALBLUE};
+//!
+//! assert_eq!(ROYALBLUE.lighten(0.1), Color(92, 137, 255));
+//! assert_eq!(ROYALBLUE.darken(0.1), Color(40, 73, 191));
+//! assert_eq!(ROYALBLUE.tint(0.5), Color(156, 182, 244));
+//!
+//! // lighten($color, 10%) of Sass
+//! assert_eq!(sass::lighten(ROYALBLUE, 10.0).to_hex(), "#6D8CE8");
+//! ```
+use crate::clr::{Color, BLACK, WHITE};
+use crate::gamut::GamutMapping;
+use crate::mix::{mix, MixSpace};
+use crate::oklab::Oklch;
+
+impl Color {
+    /// Applies `f` to the Oklch coordinates of the colour and maps the result into the sRGB gamut.
+    fn adjust_oklch(&self, f: impl FnOnce(Oklch) -> Oklch) -> Color {
+        GamutMapping::Css.map(f(Oklch::from(*self)))
+    }
+
+    /// The colour mixed in Oklab with white by `amount` in `[0, 1]`: `0` is the colour itself, `1`
+    /// white.
+    pub fn tint(&self, amount: f32) -> Color {
+        mix(*self, WHITE, amount.clamp(0.0, 1.0), MixSpace::Oklab)
+    }
+
+    /// The colour mixed in Oklab with black by `amount` in `[0, 1]`: `0` is the colour itself, `1`
+    /// black.
+    pub fn shade(&self, amount: f32) -> Color {
+        mix(*self, BLACK, amount.clamp(0.0, 1.0), MixSpace::Oklab)
+    }
+
+    /// The colour mixed in Oklab with the grey of the same lightness by `amount` in `[0, 1]`: `0`
+    /// is the colour itself, `1` the grey.
+    pub fn tone(&self, amount: f32) -> Color {
+        mix(
+            *self,
+            self.grayscale(),
+            amount.clamp(0.0, 1.0),
+            MixSpace::Oklab,
+        )
+    }
+
+    /// The colour with its Oklch lightness increased by `amount`.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::{Color, BLACK, WHITE};
+    /// # fn main() {
+    /// assert_eq!(BLACK.lighten(0.5), Color(99, 99, 99));
+    /// assert_eq!(BLACK.lighten(2.0), WHITE);
+    /// # }
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Color {
+        self.adjust_oklch(|lch| Oklch {
+            l: (lch.l + amount).clamp(0.0, 1.0),
+            ..lch
+        })
+    }
+
+    /// The colour with its Oklch lightness decreased by `amount`.
+    pub fn darken(&self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// The colour with its Oklch chroma increased by `amount`. The chroma is limited by the sRGB
+    /// gamut.
+    pub fn saturate(&self, amount: f32) -> Color {
+        self.adjust_oklch(|lch| Oklch {
+            c: (lch.c + amount).max(0.0),
+            ..lch
+        })
+    }
+
+    /// The colour with its Oklch chroma decreased by `amount`, down to a grey.
+    pub fn desaturate(&self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// The grey with the same Oklch lightness.
+    pub fn grayscale(&self) -> Color {
+        self.adjust_oklch(|lch| Oklch { c: 0.0, ..lch })
+    }
+
+    /// The colour with each RGB component inverted.
+    pub fn invert(&self) -> Color {
+        Color(255 - self.0, 255 - self.1, 255 - self.2)
+    }
+
+    /// The colour with its Oklch hue rotated by `degrees`.
+    pub fn rotate_hue(&self, degrees: f32) -> Color {
+        self.adjust_oklch(|lch| Oklch {
+            h: (lch.h + degrees).rem_euclid(360.0),
+            ..lch
+        })
+    }
+
+    /// The colour on the opposite side of the Oklch hue circle, with the same lightness and, as far
+    /// as the gamut allows, the same chroma.
+    pub fn complement(&self) -> Color {
+        self.rotate_hue(180.0)
+    }
+}
+
+/// The colour functions of Sass, which work on HSL. Amounts are in percent and hues in degrees,
+/// as in Sass: `lighten($color, 10%)` is `sass::lighten(color, 10.0)`.
+///
+/// The computation is done in `f64` and components are rounded half up, as the Sass compiler does.
+pub mod sass {
+    use crate::clr::Color;
+
+    /// HSL with the hue in degrees and saturation and lightness in percent.
+    fn to_hsl(color: Color) -> (f64, f64, f64) {
+        let [r, g, b] = [color.0, color.1, color.2].map(|c| f64::from(c) / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * (g - b) / delta
+        } else if max == g {
+            60.0 * (b - r) / delta + 120.0
+        } else {
+            60.0 * (r - g) / delta + 240.0
+        };
+        let lightness = 50.0 * (max + min);
+        let saturation = if delta == 0.0 {
+            0.0
+        } else if lightness < 50.0 {
+            100.0 * delta / (max + min)
+        } else {
+            100.0 * delta / (2.0 - max - min)
+        };
+        (hue.rem_euclid(360.0), saturation, lightness)
+    }
+
+    /// The colour with the given HSL coordinates, using the algorithm of the Sass compiler.
+    fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Color {
+        let h = hue.rem_euclid(360.0) / 360.0;
+        let s = saturation.clamp(0.0, 100.0) / 100.0;
+        let l = lightness.clamp(0.0, 100.0) / 100.0;
+        let m2 = if l <= 0.5 {
+            l * (s + 1.0)
+        } else {
+            l + s - l * s
+        };
+        let m1 = l * 2.0 - m2;
+        let channel = |h: f64| {
+            let h = h.rem_euclid(1.0);
+            let value = if h < 1.0 / 6.0 {
+                m1 + (m2 - m1) * h * 6.0
+            } else if h < 1.0 / 2.0 {
+                m2
+            } else if h < 2.0 / 3.0 {
+                m1 + (m2 - m1) * (2.0 / 3.0 - h) * 6.0
+            } else {
+                m1
+            };
+            round(value * 255.0)
+        };
+        Color(channel(h + 1.0 / 3.0), channel(h), channel(h - 1.0 / 3.0))
+    }
+
+    /// Rounds half up, ignoring the error of floating point arithmetic near halves.
+    fn round(value: f64) -> u8 {
+        (value + 0.5 + 1e-9).floor().clamp(0.0, 255.0) as u8
+    }
+
+    fn adjust(color: Color, f: impl FnOnce(f64, f64, f64) -> (f64, f64, f64)) -> Color {
+        let (h, s, l) = to_hsl(color);
+        let (h, s, l) = f(h, s, l);
+        from_hsl(h, s, l)
+    }
+
+    /// `lighten($color, $amount)`: increases the HSL lightness by `amount` percent.
+    pub fn lighten(color: Color, amount: f32) -> Color {
+        adjust(color, |h, s, l| (h, s, l + f64::from(amount)))
+    }
+
+    /// `darken($color, $amount)`: decreases the HSL lightness by `amount` percent.
+    pub fn darken(color: Color, amount: f32) -> Color {
+        adjust(color, |h, s, l| (h, s, l - f64::from(amount)))
+    }
+
+    /// `saturate($color, $amount)`: increases the HSL saturation by `amount` percent.
+    pub fn saturate(color: Color, amount: f32) -> Color {
+        adjust(color, |h, s, l| (h, s + f64::from(amount), l))
+    }
+
+    /// `desaturate($color, $amount)`: decreases the HSL saturation by `amount` percent.
+    pub fn desaturate(color: Color, amount: f32) -> Color {
+        adjust(color, |h, s, l| (h, s - f64::from(amount), l))
+    }
+
+    /// `grayscale($color)`: the colour with an HSL saturation of `0`.
+    pub fn grayscale(color: Color) -> Color {
+        adjust(color, |h, _, l| (h, 0.0, l))
+    }
+
+    /// `adjust-hue($color, $degrees)`: rotates the HSL hue by `degrees`.
+    pub fn adjust_hue(color: Color, degrees: f32) -> Color {
+        adjust(color, |h, s, l| (h + f64::from(degrees), s, l))
+    }
+
+    /// `complement($color)`: rotates the HSL hue by 180°.
+    pub fn complement(color: Color) -> Color {
+        adjust_hue(color, 180.0)
+    }
+
+    /// `mix($color1, $color2, $weight)`: mixes the RGB components of two opaque colours, with
+    /// `weight` percent of `a`.
+    pub fn mix(a: Color, b: Color, weight: f32) -> Color {
+        let weight = f64::from(weight.clamp(0.0, 100.0)) / 100.0;
+        let channel = |a: u8, b: u8| round(f64::from(a) * weight + f64::from(b) * (1.0 - weight));
+        Color(channel(a.0, b.0), channel(a.1, b.1), channel(a.2, b.2))
+    }
+
+    /// `invert($color, $weight)`: the colour with its RGB components inverted, mixed with the
+    /// original with `weight` percent of the inverse. Sass uses a weight of `100`.
+    pub fn invert(color: Color, weight: f32) -> Color {
+        let inverse = Color(255 - color.0, 255 - color.1, 255 - color.2);
+        mix(inverse, color, weight)
+    }
+}
@@ -0,0 +1,174 @@
+//! The [Accessible Perceptual Contrast Algorithm](https://github.com/Myndex/apca-w3) (APCA), the
+//! contrast method proposed for WCAG 3, in version 0.0.98G-4g.
+//!
+//! Unlike the WCAG 2 contrast ratio, APCA takes the polarity into account: the lightness contrast
+//! `Lc` of dark text on a light background is positive, that of light text on a dark background
+//! negative, and swapping text and background changes the value. `Lc` ranges from about `106` for
+//! black on white to `-108` for white on black; values below `15` in magnitude count as no contrast.
+//!
+//! # Examples
+//! ```
+//! use colors::apca::{contrast, min_font_size};
+//! use colors::clr::{Color, WHITE};
+//!
+//! let grey = Color(0x88, 0x88, 0x88);
+//! assert_eq!(format!("{:.3}", contrast(grey, WHITE)), "63.056");
+//! assert_eq!(format!("{:.3}", contrast(WHITE, grey)), "-68.541");
+//! // 400 weight text needs to be at least 24px at Lc 60
+//! assert_eq!(min_font_size(60.0, 400), Some(24.0));
+//! ```
+use crate::clr::{Color, BLACK, WHITE};
+use crate::gamut::GamutMapping;
+use crate::oklab::Oklch;
+
+const MAIN_TRC: f32 = 2.4;
+const NORMAL_BACKGROUND: f32 = 0.56;
+const NORMAL_TEXT: f32 = 0.57;
+const REVERSE_TEXT: f32 = 0.62;
+const REVERSE_BACKGROUND: f32 = 0.65;
+const BLACK_THRESHOLD: f32 = 0.022;
+const BLACK_CLAMP: f32 = 1.414;
+const SCALE: f32 = 1.14;
+const OFFSET: f32 = 0.027;
+const DELTA_Y_MIN: f32 = 0.0005;
+const LOW_CLIP: f32 = 0.1;
+
+/// The estimated screen luminance APCA works with, including the soft clamp near black.
+fn screen_luminance(color: Color) -> f32 {
+    let channel = |value: u8| (f32::from(value) / 255.0).powf(MAIN_TRC);
+    let y = 0.212_672_9 * channel(color.0)
+        + 0.715_152_2 * channel(color.1)
+        + 0.072_175 * channel(color.2);
+    if y > BLACK_THRESHOLD {
+        y
+    } else {
+        y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+    }
+}
+
+/// The APCA lightness contrast `Lc` of `text` on `background`: positive for dark text on a light
+/// background, negative for light text on a dark one.
+pub fn contrast(text: Color, background: Color) -> f32 {
+    let (text, background) = (screen_luminance(text), screen_luminance(background));
+    if (background - text).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+    let lc = if background > text {
+        let sapc = (background.powf(NORMAL_BACKGROUND) - text.powf(NORMAL_TEXT)) * SCALE;
+        if sapc < LOW_CLIP {
+            0.0
+        } else {
+            sapc - OFFSET
+        }
+    } else {
+        let sapc = (background.powf(REVERSE_BACKGROUND) - text.powf(REVERSE_TEXT)) * SCALE;
+        if sapc > -LOW_CLIP {
+            0.0
+        } else {
+            sapc + OFFSET
+        }
+    };
+    lc * 100.0
+}
+
+/// A size meaning no text may be used at the given contrast.
+const PROHIBITED: f32 = 999.0;
+/// A size meaning only non-text elements (e.g. dividers) may be used at the given contrast.
+const NON_TEXT: f32 = 777.0;
+
+/// The minimum font sizes in px for the font weights 100 to 900, in steps of Lc 5 starting at 0.
+#[rustfmt::skip]
+const FONT_SIZES: [[f32; 9]; 26] = [
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0],
+    [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0],
+    [777.0, 777.0, 777.0, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0],
+    [777.0, 777.0, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0],
+    [777.0, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0],
+    [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0],
+    [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0],
+    [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0],
+    [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0],
+    [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0],
+    [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0],
+    [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0],
+    [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0],
+    [56.0, 38.25, 23.0, 17.25, 15.81, 14.81, 14.0, 16.0, 18.0],
+    [52.0, 34.5, 22.0, 16.5, 15.625, 14.625, 14.0, 16.0, 18.0],
+    [48.0, 32.0, 21.0, 16.0, 15.5, 14.5, 14.0, 16.0, 18.0],
+    [45.0, 28.0, 19.5, 15.5, 15.0, 14.0, 13.5, 16.0, 18.0],
+    [42.0, 26.5, 18.5, 15.0, 14.5, 13.5, 13.0, 16.0, 18.0],
+    [39.0, 25.0, 18.0, 14.5, 14.0, 13.0, 12.0, 16.0, 18.0],
+    [36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 11.0, 16.0, 18.0],
+    [34.5, 22.5, 17.25, 12.5, 11.875, 11.25, 10.625, 14.5, 16.5],
+    [33.0, 21.0, 16.5, 11.0, 11.0, 11.0, 10.0, 13.0, 15.0],
+    [32.0, 20.0, 16.0, 10.0, 10.0, 10.0, 9.0, 12.0, 14.0],
+];
+
+/// The minimum font size in px for text of the given weight (`100` to `900`) at a contrast of `lc`,
+/// following the APCA font lookup table. The polarity does not matter. Between the rows of the
+/// table the lower contrast is used, weights are rounded down to the next hundred. Returns `None`
+/// if the contrast is too low for text of any size.
+pub fn min_font_size(lc: f32, weight: u16) -> Option<f32> {
+    let row = ((lc.abs() / 5.0) as usize).min(FONT_SIZES.len() - 1);
+    let column = usize::from(weight.clamp(100, 900) / 100 - 1);
+    let size = FONT_SIZES[row][column];
+    if size == PROHIBITED || size == NON_TEXT {
+        None
+    } else {
+        Some(size)
+    }
+}
+
+/// Adjusts the Oklch lightness of `text`, keeping its hue and, where possible, its chroma, until
+/// its contrast on `background` reaches `target` in magnitude. The text keeps its polarity if
+/// that can reach the target, otherwise it is flipped. Returns `text` unchanged if it already has
+/// enough contrast and `None` if not even black or white reach the target.
+///
+/// Example
+/// ```
+/// # use colors::apca::{adjust_lightness, contrast};
+/// # use colors::clr::{Color, GOLDENROD};
+/// # fn main() {
+/// let background = Color(30, 30, 30);
+/// let text = adjust_lightness(Color(100, 80, 30), background, 75.0).unwrap();
+/// assert!(contrast(text, background) <= -75.0);
+/// assert_eq!(adjust_lightness(GOLDENROD, background, 110.0), None);
+/// # }
+/// ```
+pub fn adjust_lightness(text: Color, background: Color, target: f32) -> Option<Color> {
+    let target = target.abs();
+    if contrast(text, background).abs() >= target {
+        return Some(text);
+    }
+    let origin = Oklch::from(text);
+    let lighter = screen_luminance(text) >= screen_luminance(background);
+    let directions = if lighter {
+        [(WHITE, 1.0), (BLACK, 0.0)]
+    } else {
+        [(BLACK, 0.0), (WHITE, 1.0)]
+    };
+    for (extreme, bound) in directions {
+        if contrast(extreme, background).abs() < target {
+            continue;
+        }
+        // binary search for the smallest change in lightness that reaches the target
+        let at = |l: f32| GamutMapping::Css.map(Oklch { l, ..origin });
+        let (mut near, mut far) = (origin.l, bound);
+        let mut best = extreme;
+        for _ in 0..24 {
+            let middle = (near + far) / 2.0;
+            let candidate = at(middle);
+            if contrast(candidate, background).abs() >= target {
+                best = candidate;
+                far = middle;
+            } else {
+                near = middle;
+            }
+        }
+        return Some(best);
+    }
+    None
+}
@@ -0,0 +1,582 @@
+//! The CIE colour spaces XYZ, L\*a\*b\*, L\*u\*v\* and their cylindrical forms LCh(ab) and LCh(uv).
+//!
+//! `Color` is an sRGB colour and therefore relative to the D65 white point. The plain `From<Color>`
+//! conversions stay at D65. To get values relative to another reference white, e.g. D50 as used by
+//! ICC profiles and CSS `lab()`, use the `from_color`/`to_color` methods, which adapt the colour with
+//! the chosen chromatic adaptation transform.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, R};
+//! use colors::cie::{Adaptation, Lab, WhitePoint};
+//!
+//! let screen = Lab::from(R);
+//! assert_eq!(format!("{:.2}", screen), "(53.24, 80.09, 67.20)");
+//!
+//! let print = Lab::from_color(R, WhitePoint::D50, Adaptation::Bradford);
+//! assert_eq!(format!("{:.2}", print), "(54.29, 80.80, 69.89)");
+//! assert_eq!(print.to_color(WhitePoint::D50, Adaptation::Bradford), R);
+//! ```
+use crate::clr::Color;
+use crate::matrix::{invert, multiply, transform, Matrix};
+use crate::rgb::{color_to_linear, linear_to_color, Float, LinearRgb};
+
+/// ε of the CIE standard, `(6/29)³`.
+const EPSILON: f32 = 216.0 / 24389.0;
+/// κ of the CIE standard, `(29/3)³`.
+const KAPPA: f32 = 24389.0 / 27.0;
+
+/// Linear sRGB to XYZ (D65).
+pub(crate) const SRGB_TO_XYZ: Matrix = [
+    [0.412_390_8, 0.357_584_33, 0.180_480_8],
+    [0.212_639, 0.715_168_7, 0.072_192_32],
+    [0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+
+/// XYZ (D65) to linear sRGB.
+const XYZ_TO_SRGB: Matrix = [
+    [3.240_97, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_65, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+/// A reference white, given as its XYZ tristimulus values normalised to `y = 1`.
+#[derive(Copy, Clone, PartialEq)]
+pub struct WhitePoint {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl_fmt!(WhitePoint { x, y, z });
+
+impl WhitePoint {
+    /// CIE standard illuminant D65 (noon daylight), the white point of sRGB.
+    pub const D65: WhitePoint = WhitePoint {
+        x: 0.950_455_9,
+        y: 1.0,
+        z: 1.089_057_8,
+    };
+    /// CIE standard illuminant D50 (horizon light), the white point of ICC profiles and CSS `lab()`.
+    pub const D50: WhitePoint = WhitePoint {
+        x: 0.964_295_7,
+        y: 1.0,
+        z: 0.825_104_6,
+    };
+    /// CIE standard illuminant A (incandescent light).
+    pub const A: WhitePoint = WhitePoint {
+        x: 1.098_466,
+        y: 1.0,
+        z: 0.355_820_4,
+    };
+    /// CIE standard illuminant E (equal energy).
+    pub const E: WhitePoint = WhitePoint {
+        x: 1.0,
+        y: 1.0,
+        z: 1.0,
+    };
+
+    /// A custom white point from its tristimulus values, normalised to `y = 1`.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        WhitePoint {
+            x: x / y,
+            y: 1.0,
+            z: z / y,
+        }
+    }
+
+    /// A custom white point from its `(x, y)` chromaticity coordinates.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::cie::WhitePoint;
+    /// # fn main() {
+    /// let d65 = WhitePoint::from_chromaticity(0.3127, 0.3290);
+    /// assert!((d65.x - WhitePoint::D65.x).abs() < 1e-6);
+    /// # }
+    /// ```
+    pub fn from_chromaticity(x: f32, y: f32) -> Self {
+        WhitePoint {
+            x: x / y,
+            y: 1.0,
+            z: (1.0 - x - y) / y,
+        }
+    }
+
+    fn xyz(&self) -> Xyz {
+        Xyz::new(self.x, self.y, self.z)
+    }
+}
+
+/// A chromatic adaptation transform, used to convert colours between white points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Adaptation {
+    /// The Bradford transform, used by ICC profiles and CSS
+    Bradford,
+    /// The transform of the CIECAM02 colour appearance model
+    Cat02,
+    /// The von Kries transform with the Hunt-Pointer-Estevez cone responses
+    VonKries,
+}
+
+impl Adaptation {
+    /// The matrix from XYZ to the cone response domain of this transform.
+    fn cone_response(self) -> Matrix {
+        match self {
+            Adaptation::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            Adaptation::Cat02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.0030, 0.0136, 0.9834],
+            ],
+            Adaptation::VonKries => [
+                [0.40024, 0.7076, -0.08081],
+                [-0.2263, 1.16532, 0.0457],
+                [0.0, 0.0, 0.91822],
+            ],
+        }
+    }
+
+    /// The matrix adapting XYZ values relative to `from` to XYZ values relative to `to`.
+    pub(crate) fn matrix(self, from: WhitePoint, to: WhitePoint) -> Matrix {
+        let cone = self.cone_response();
+        let source = transform(&cone, [from.x, from.y, from.z]);
+        let destination = transform(&cone, [to.x, to.y, to.z]);
+        let mut scale = [[0.0; 3]; 3];
+        for i in 0..3 {
+            scale[i][i] = destination[i] / source[i];
+        }
+        multiply(&invert(&cone), &multiply(&scale, &cone))
+    }
+}
+
+/// A colour in the CIE 1931 XYZ space, scaled so that the reference white has `y = 1`.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Xyz {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl_fmt!(Xyz { x, y, z });
+
+impl Xyz {
+    /// Creates a new XYZ colour.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Xyz { x, y, z }
+    }
+
+    /// Converts linear light sRGB components to XYZ (D65).
+    pub(crate) fn from_linear_srgb(rgb: [f32; 3]) -> Self {
+        let [x, y, z] = transform(&SRGB_TO_XYZ, rgb);
+        Xyz { x, y, z }
+    }
+
+    /// Converts to linear light sRGB components, which may lie outside `[0, 1]`.
+    pub(crate) fn to_linear_srgb(self) -> [f32; 3] {
+        transform(&XYZ_TO_SRGB, [self.x, self.y, self.z])
+    }
+
+    /// Adapts a colour relative to the white point `from` to the white point `to`.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::cie::{Adaptation, WhitePoint, Xyz};
+    /// # fn main() {
+    /// let white = Xyz::new(0.9504559, 1.0, 1.0890578);
+    /// let adapted = white.adapt(WhitePoint::D65, WhitePoint::D50, Adaptation::Bradford);
+    /// assert!((adapted.x - WhitePoint::D50.x).abs() < 1e-5);
+    /// assert!((adapted.z - WhitePoint::D50.z).abs() < 1e-5);
+    /// # }
+    /// ```
+    pub fn adapt(&self, from: WhitePoint, to: WhitePoint, method: Adaptation) -> Xyz {
+        let [x, y, z] = transform(&method.matrix(from, to), [self.x, self.y, self.z]);
+        Xyz { x, y, z }
+    }
+
+    /// The `(x, y)` chromaticity coordinates of the colour.
+    pub fn chromaticity(&self) -> (f32, f32) {
+        let sum = self.x + self.y + self.z;
+        if sum == 0.0 {
+            return (0.0, 0.0);
+        }
+        (self.x / sum, self.y / sum)
+    }
+
+    /// The `(u′, v′)` coordinates of the CIE 1976 UCS diagram.
+    pub fn uv_prime(&self) -> (f32, f32) {
+        let denominator = self.x + 15.0 * self.y + 3.0 * self.z;
+        if denominator == 0.0 {
+            return (0.0, 0.0);
+        }
+        (4.0 * self.x / denominator, 9.0 * self.y / denominator)
+    }
+}
+
+impl From<Color> for Xyz {
+    /// The XYZ values of an sRGB colour, relative to D65.
+    fn from(color: Color) -> Self {
+        Xyz::from_linear_srgb(color_to_linear(color))
+    }
+}
+
+impl From<Xyz> for Color {
+    /// Converts XYZ values relative to D65 to sRGB, clipping colours outside the gamut.
+    fn from(xyz: Xyz) -> Self {
+        linear_to_color(xyz.to_linear_srgb())
+    }
+}
+
+impl<T: Float> From<LinearRgb<T>> for Xyz {
+    /// The XYZ values of a linear light sRGB colour, relative to D65.
+    fn from(linear: LinearRgb<T>) -> Self {
+        Xyz::from_linear_srgb(linear.cast::<f32>().to_array())
+    }
+}
+
+impl<T: Float> From<Xyz> for LinearRgb<T> {
+    /// Converts XYZ values relative to D65 to linear light sRGB without clipping.
+    fn from(xyz: Xyz) -> Self {
+        LinearRgb::from_array(xyz.to_linear_srgb()).cast()
+    }
+}
+
+/// Converts a colour to XYZ relative to `white`.
+fn color_to_xyz(color: Color, white: WhitePoint, method: Adaptation) -> Xyz {
+    Xyz::from(color).adapt(WhitePoint::D65, white, method)
+}
+
+/// Converts XYZ relative to `white` to a colour.
+fn xyz_to_color(xyz: Xyz, white: WhitePoint, method: Adaptation) -> Color {
+    Color::from(xyz.adapt(white, WhitePoint::D65, method))
+}
+
+/// Converts rectangular `(a, b)` coordinates to chroma and hue in degrees. Greys get a hue of `0`.
+pub(crate) fn to_polar(a: f32, b: f32) -> (f32, f32) {
+    let chroma = a.hypot(b);
+    let hue = if chroma < 1e-6 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    };
+    (chroma, hue)
+}
+
+/// Converts chroma and hue in degrees to rectangular `(a, b)` coordinates.
+pub(crate) fn from_polar(chroma: f32, hue: f32) -> (f32, f32) {
+    let (sin, cos) = hue.to_radians().sin_cos();
+    (chroma * cos, chroma * sin)
+}
+
+/// A colour in the CIE 1976 L\*a\*b\* space. `l` is the lightness in `[0, 100]`, `a` and `b` are
+/// the green/red and blue/yellow axes.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl_fmt!(Lab { l, a, b });
+
+impl Lab {
+    /// Creates a new Lab colour.
+    pub fn new(l: f32, a: f32, b: f32) -> Self {
+        Lab { l, a, b }
+    }
+
+    /// Converts XYZ values relative to `white` to Lab.
+    pub fn from_xyz(xyz: Xyz, white: WhitePoint) -> Self {
+        let f = |t: f32| {
+            if t > EPSILON {
+                t.cbrt()
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(xyz.x / white.x), f(xyz.y / white.y), f(xyz.z / white.z));
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Converts to XYZ values relative to `white`.
+    pub fn to_xyz(&self, white: WhitePoint) -> Xyz {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = self.a / 500.0 + fy;
+        let fz = fy - self.b / 200.0;
+        let inverse = |f: f32| {
+            let cube = f * f * f;
+            if cube > EPSILON {
+                cube
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+        let y = if self.l > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            self.l / KAPPA
+        };
+        Xyz {
+            x: inverse(fx) * white.x,
+            y: y * white.y,
+            z: inverse(fz) * white.z,
+        }
+    }
+
+    /// Converts a colour to Lab relative to `white`, adapting it from D65 with `method`.
+    pub fn from_color(color: Color, white: WhitePoint, method: Adaptation) -> Self {
+        Lab::from_xyz(color_to_xyz(color, white, method), white)
+    }
+
+    /// Converts a Lab colour relative to `white` to a `Color`, clipping it to the sRGB gamut.
+    pub fn to_color(&self, white: WhitePoint, method: Adaptation) -> Color {
+        xyz_to_color(self.to_xyz(white), white, method)
+    }
+}
+
+impl From<Color> for Lab {
+    /// Lab relative to D65.
+    fn from(color: Color) -> Self {
+        Lab::from_xyz(Xyz::from(color), WhitePoint::D65)
+    }
+}
+
+impl From<Lab> for Color {
+    /// Converts Lab relative to D65, clipping it to the sRGB gamut.
+    fn from(lab: Lab) -> Self {
+        Color::from(Xyz::from(lab))
+    }
+}
+
+impl From<Lab> for Xyz {
+    /// Converts Lab relative to D65.
+    fn from(lab: Lab) -> Self {
+        lab.to_xyz(WhitePoint::D65)
+    }
+}
+
+/// A colour in the CIE 1976 L\*u\*v\* space. `l` is the lightness in `[0, 100]`.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Luv {
+    pub l: f32,
+    pub u: f32,
+    pub v: f32,
+}
+
+impl_fmt!(Luv { l, u, v });
+
+impl Luv {
+    /// Creates a new Luv colour.
+    pub fn new(l: f32, u: f32, v: f32) -> Self {
+        Luv { l, u, v }
+    }
+
+    /// Converts XYZ values relative to `white` to Luv.
+    pub fn from_xyz(xyz: Xyz, white: WhitePoint) -> Self {
+        let y = xyz.y / white.y;
+        let l = if y > EPSILON {
+            116.0 * y.cbrt() - 16.0
+        } else {
+            KAPPA * y
+        };
+        if xyz.x + 15.0 * xyz.y + 3.0 * xyz.z == 0.0 {
+            return Luv { l, u: 0.0, v: 0.0 };
+        }
+        let (u, v) = xyz.uv_prime();
+        let (un, vn) = white.xyz().uv_prime();
+        Luv {
+            l,
+            u: 13.0 * l * (u - un),
+            v: 13.0 * l * (v - vn),
+        }
+    }
+
+    /// Converts to XYZ values relative to `white`. Colours with a chromaticity `v′` that is not
+    /// positive have no XYZ values and give black, like a lightness of `0`.
+    pub fn to_xyz(&self, white: WhitePoint) -> Xyz {
+        if self.l <= 0.0 {
+            return Xyz::new(0.0, 0.0, 0.0);
+        }
+        let (un, vn) = white.xyz().uv_prime();
+        let u = self.u / (13.0 * self.l) + un;
+        let v = self.v / (13.0 * self.l) + vn;
+        if v <= 0.0 {
+            return Xyz::new(0.0, 0.0, 0.0);
+        }
+        let y = if self.l > KAPPA * EPSILON {
+            ((self.l + 16.0) / 116.0).powi(3)
+        } else {
+            self.l / KAPPA
+        } * white.y;
+        Xyz {
+            x: y * 9.0 * u / (4.0 * v),
+            y,
+            z: y * (12.0 - 3.0 * u - 20.0 * v) / (4.0 * v),
+        }
+    }
+
+    /// Converts a colour to Luv relative to `white`, adapting it from D65 with `method`.
+    pub fn from_color(color: Color, white: WhitePoint, method: Adaptation) -> Self {
+        Luv::from_xyz(color_to_xyz(color, white, method), white)
+    }
+
+    /// Converts a Luv colour relative to `white` to a `Color`, clipping it to the sRGB gamut.
+    pub fn to_color(&self, white: WhitePoint, method: Adaptation) -> Color {
+        xyz_to_color(self.to_xyz(white), white, method)
+    }
+}
+
+impl From<Color> for Luv {
+    /// Luv relative to D65.
+    fn from(color: Color) -> Self {
+        Luv::from_xyz(Xyz::from(color), WhitePoint::D65)
+    }
+}
+
+impl From<Luv> for Color {
+    /// Converts Luv relative to D65, clipping it to the sRGB gamut.
+    fn from(luv: Luv) -> Self {
+        Color::from(Xyz::from(luv))
+    }
+}
+
+impl From<Luv> for Xyz {
+    /// Converts Luv relative to D65.
+    fn from(luv: Luv) -> Self {
+        luv.to_xyz(WhitePoint::D65)
+    }
+}
+
+/// A colour in the LCh(ab) space, the cylindrical form of `Lab`: lightness, chroma and hue in
+/// degrees.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Lch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl_fmt!(Lch { l, c, h });
+
+impl Lch {
+    /// Creates a new LCh(ab) colour.
+    pub fn new(l: f32, c: f32, h: f32) -> Self {
+        Lch { l, c, h }
+    }
+
+    /// Converts a colour to LCh(ab) relative to `white`, adapting it from D65 with `method`.
+    pub fn from_color(color: Color, white: WhitePoint, method: Adaptation) -> Self {
+        Lch::from(Lab::from_color(color, white, method))
+    }
+
+    /// Converts an LCh(ab) colour relative to `white` to a `Color`, clipping it to the sRGB gamut.
+    pub fn to_color(&self, white: WhitePoint, method: Adaptation) -> Color {
+        Lab::from(*self).to_color(white, method)
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        let (c, h) = to_polar(lab.a, lab.b);
+        Lch { l: lab.l, c, h }
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        let (a, b) = from_polar(lch.c, lch.h);
+        Lab { l: lch.l, a, b }
+    }
+}
+
+impl From<Color> for Lch {
+    /// LCh(ab) relative to D65.
+    fn from(color: Color) -> Self {
+        Lch::from(Lab::from(color))
+    }
+}
+
+impl From<Lch> for Color {
+    /// Converts LCh(ab) relative to D65, clipping it to the sRGB gamut.
+    fn from(lch: Lch) -> Self {
+        Color::from(Lab::from(lch))
+    }
+}
+
+impl From<Lch> for Xyz {
+    /// Converts LCh(ab) relative to D65.
+    fn from(lch: Lch) -> Self {
+        Xyz::from(Lab::from(lch))
+    }
+}
+
+/// A colour in the LCh(uv) space, the cylindrical form of `Luv`: lightness, chroma and hue in
+/// degrees.
+#[derive(Copy, Clone, PartialEq)]
+pub struct LchUv {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl_fmt!(LchUv { l, c, h });
+
+impl LchUv {
+    /// Creates a new LCh(uv) colour.
+    pub fn new(l: f32, c: f32, h: f32) -> Self {
+        LchUv { l, c, h }
+    }
+
+    /// Converts a colour to LCh(uv) relative to `white`, adapting it from D65 with `method`.
+    pub fn from_color(color: Color, white: WhitePoint, method: Adaptation) -> Self {
+        LchUv::from(Luv::from_color(color, white, method))
+    }
+
+    /// Converts an LCh(uv) colour relative to `white` to a `Color`, clipping it to the sRGB gamut.
+    pub fn to_color(&self, white: WhitePoint, method: Adaptation) -> Color {
+        Luv::from(*self).to_color(white, method)
+    }
+}
+
+impl From<Luv> for LchUv {
+    fn from(luv: Luv) -> Self {
+        let (c, h) = to_polar(luv.u, luv.v);
+        LchUv { l: luv.l, c, h }
+    }
+}
+
+impl From<LchUv> for Luv {
+    fn from(lch: LchUv) -> Self {
+        let (u, v) = from_polar(lch.c, lch.h);
+        Luv { l: lch.l, u, v }
+    }
+}
+
+impl From<Color> for LchUv {
+    /// LCh(uv) relative to D65.
+    fn from(color: Color) -> Self {
+        LchUv::from(Luv::from(color))
+    }
+}
+
+impl From<LchUv> for Color {
+    /// Converts LCh(uv) relative to D65, clipping it to the sRGB gamut.
+    fn from(lch: LchUv) -> Self {
+        Color::from(Luv::from(lch))
+    }
+}
+
+impl From<LchUv> for Xyz {
+    /// Converts LCh(uv) relative to D65.
+    fn from(lch: LchUv) -> Self {
+        Xyz::from(Luv::from(lch))
+    }
+}
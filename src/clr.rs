@@ -3,7 +3,7 @@ use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
 
 /// An RGB24 color representation. Each channel has a depth of 8 bit.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Color(pub u8, pub u8, pub u8);
 
 impl Color {
@@ -36,10 +36,168 @@ impl Display for Color {
     }
 }
 
+/// The sets the named colours are taken from, following matplotlib's colour module.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ColorSet {
+    /// The single letter colours `b`, `g`, `r`, `c`, `m`, `y`, `k` and `w` (`BASE_COLORS`)
+    Base,
+    /// The CSS colour names (`CSS4_COLORS`)
+    Css4,
+    /// The colours from the [XKCD colour survey](https://xkcd.com/color/rgb/) (`XKCD_COLORS`)
+    Xkcd,
+    /// The Tableau palette, matplotlib's default colour cycle (`TABLEAU_COLORS`)
+    Tableau,
+}
+
+impl ColorSet {
+    /// All colour sets, in the order of their colour numbers.
+    pub const ALL: [ColorSet; 4] = [
+        ColorSet::Base,
+        ColorSet::Css4,
+        ColorSet::Xkcd,
+        ColorSet::Tableau,
+    ];
+
+    /// The matplotlib name of the set, e.g. `"CSS4_COLORS"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorSet::Base => "BASE_COLORS",
+            ColorSet::Css4 => "CSS4_COLORS",
+            ColorSet::Xkcd => "XKCD_COLORS",
+            ColorSet::Tableau => "TABLEAU_COLORS",
+        }
+    }
+
+    /// The named colours belonging to this set, ordered by their colour number.
+    pub fn as_slice(self) -> &'static [NamedColor] {
+        let (start, end) = SET_RANGES[self as usize];
+        &NAMED[start..end]
+    }
+
+    /// Iterates over the colours of this set.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::ColorSet;
+    /// # fn main() {
+    /// for named in ColorSet::Base.iter() {
+    ///     println!("{}: {}", named.name, named.color.to_hex());
+    /// }
+    /// assert_eq!(ColorSet::Base.iter().count(), 8);
+    /// # }
+    /// ```
+    pub fn iter(self) -> std::slice::Iter<'static, NamedColor> {
+        self.as_slice().iter()
+    }
+
+    /// The number of colours in this set.
+    pub fn len(self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Whether the set contains no colours (never the case for the built-in sets).
+    pub fn is_empty(self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// Returns the colour at position `index` within this set. Note that this is not the colour
+    /// number (`NamedColor::index`), which counts across all sets; use `named_colors()` for that.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::{self, ColorSet};
+    /// # fn main() {
+    /// let named = ColorSet::Css4.get(0).unwrap();
+    /// assert_eq!(named.name, "aliceblue");
+    /// assert_eq!(named.index, 8);
+    /// assert_eq!(clr::named_colors()[named.index], named);
+    /// assert!(ColorSet::Css4.get(ColorSet::Css4.len()).is_none());
+    /// # }
+    /// ```
+    pub fn get(self, index: usize) -> Option<NamedColor> {
+        self.as_slice().get(index).copied()
+    }
+}
+
+impl Display for ColorSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A colour from one of the sets together with its name and colour number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NamedColor {
+    /// The matplotlib name of the colour, e.g. `"goldenrod"` or `"xkcd:cloudy blue"`
+    pub name: &'static str,
+    /// The set the colour is taken from
+    pub set: ColorSet,
+    /// The colour number, as given in the documentation of the constant
+    pub index: usize,
+    /// The colour itself
+    pub color: Color,
+}
+
+/// Display formatting for NamedColor, prints the name followed by the colour, e.g.
+/// `goldenrod (218, 165, 32)`.
+impl Display for NamedColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.color)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/color_table.rs"));
+
+/// All named colours of all sets, indexed by their colour number.
+pub fn named_colors() -> &'static [NamedColor] {
+    &NAMED
+}
+
+include!("names.rs");
+
+/// Looks up a colour by name at runtime.
+///
+/// Accepts the matplotlib names (`"goldenrod"`, `"xkcd:dusty teal"`, `"tab:blue"`, single letters
+/// like `"r"`) as well as the names of the constants in this module (`"XKCD_DUSTY_TEAL"`). The
+/// lookup ignores case and treats spaces, underscores and dashes alike; it is backed by a perfect
+/// hash generated at compile time.
+///
+/// Example
+/// ```
+/// # use colors::clr;
+/// # fn main() {
+/// assert_eq!(clr::by_name("goldenrod"), Some(clr::GOLDENROD));
+/// assert_eq!(clr::by_name("xkcd:Dusty Teal"), Some(clr::XKCD_DUSTY_TEAL));
+/// assert_eq!(clr::by_name("tab:blue"), Some(clr::TAB_BLUE));
+/// assert_eq!(clr::by_name("r"), Some(clr::R));
+/// assert_eq!(clr::by_name("not a colour"), None);
+/// # }
+/// ```
+pub fn by_name(name: &str) -> Option<Color> {
+    lookup(name).map(|named| named.color)
+}
+
+/// Looks up a colour by name like `by_name`, but returns the full record including the set and the
+/// matplotlib name.
+///
+/// Example
+/// ```
+/// # use colors::clr::{self, ColorSet};
+/// # fn main() {
+/// let named = clr::lookup("XKCD_ROBIN_S_EGG").unwrap();
+/// assert_eq!(named.name, "xkcd:robin's egg");
+/// assert_eq!(named.set, ColorSet::Xkcd);
+/// # }
+/// ```
+pub fn lookup(name: &str) -> Option<NamedColor> {
+    LOOKUP
+        .get(normalize_name(name).as_str())
+        .map(|&index| NAMED[index as usize])
+}
 
 /// Colour `(r = 0, g = 0, b = 255)`
 ///
-/// Colour `B` from the set `BASE_COLORS`. (Colour number `0`)
+/// Colour `B` from the set `BASE_COLORS`. (Colour number `0`, name `b`)
 /// ## Representations:
 /// - int tuple `(0, 0, 255)`
 /// - float tuple `(0.0, 0.0, 1.0)`
@@ -48,7 +206,7 @@ pub const B: Color = Color(0, 0, 255);
 
 /// Colour `(r = 0, g = 127, b = 0)`
 ///
-/// Colour `G` from the set `BASE_COLORS`. (Colour number `1`)
+/// Colour `G` from the set `BASE_COLORS`. (Colour number `1`, name `g`)
 /// ## Representations:
 /// - int tuple `(0, 127, 0)`
 /// - float tuple `(0.0, 0.5, 0.0)`
@@ -57,7 +215,7 @@ pub const G: Color = Color(0, 127, 0);
 
 /// Colour `(r = 255, g = 0, b = 0)`
 ///
-/// Colour `R` from the set `BASE_COLORS`. (Colour number `2`)
+/// Colour `R` from the set `BASE_COLORS`. (Colour number `2`, name `r`)
 /// ## Representations:
 /// - int tuple `(255, 0, 0)`
 /// - float tuple `(1.0, 0.0, 0.0)`
@@ -66,7 +224,7 @@ pub const R: Color = Color(255, 0, 0);
 
 /// Colour `(r = 0, g = 191, b = 191)`
 ///
-/// Colour `C` from the set `BASE_COLORS`. (Colour number `3`)
+/// Colour `C` from the set `BASE_COLORS`. (Colour number `3`, name `c`)
 /// ## Representations:
 /// - int tuple `(0, 191, 191)`
 /// - float tuple `(0.0, 0.75, 0.75)`
@@ -75,7 +233,7 @@ pub const C: Color = Color(0, 191, 191);
 
 /// Colour `(r = 191, g = 0, b = 191)`
 ///
-/// Colour `M` from the set `BASE_COLORS`. (Colour number `4`)
+/// Colour `M` from the set `BASE_COLORS`. (Colour number `4`, name `m`)
 /// ## Representations:
 /// - int tuple `(191, 0, 191)`
 /// - float tuple `(0.75, 0.0, 0.75)`
@@ -84,7 +242,7 @@ pub const M: Color = Color(191, 0, 191);
 
 /// Colour `(r = 191, g = 191, b = 0)`
 ///
-/// Colour `Y` from the set `BASE_COLORS`. (Colour number `5`)
+/// Colour `Y` from the set `BASE_COLORS`. (Colour number `5`, name `y`)
 /// ## Representations:
 /// - int tuple `(191, 191, 0)`
 /// - float tuple `(0.75, 0.75, 0.0)`
@@ -93,7 +251,7 @@ pub const Y: Color = Color(191, 191, 0);
 
 /// Colour `(r = 0, g = 0, b = 0)`
 ///
-/// Colour `K` from the set `BASE_COLORS`. (Colour number `6`)
+/// Colour `K` from the set `BASE_COLORS`. (Colour number `6`, name `k`)
 /// ## Representations:
 /// - int tuple `(0, 0, 0)`
 /// - float tuple `(0.0, 0.0, 0.0)`
@@ -102,7 +260,7 @@ pub const K: Color = Color(0, 0, 0);
 
 /// Colour `(r = 255, g = 255, b = 255)`
 ///
-/// Colour `W` from the set `BASE_COLORS`. (Colour number `7`)
+/// Colour `W` from the set `BASE_COLORS`. (Colour number `7`, name `w`)
 /// ## Representations:
 /// - int tuple `(255, 255, 255)`
 /// - float tuple `(1.0, 1.0, 1.0)`
@@ -111,7 +269,7 @@ pub const W: Color = Color(255, 255, 255);
 
 /// Colour `(r = 240, g = 248, b = 255)`
 ///
-/// Colour `ALICEBLUE` from the set `CSS4_COLORS`. (Colour number `8`)
+/// Colour `ALICEBLUE` from the set `CSS4_COLORS`. (Colour number `8`, name `aliceblue`)
 /// ## Representations:
 /// - int tuple `(240, 248, 255)`
 /// - float tuple `(0.94, 0.97, 1.0)`
@@ -120,7 +278,7 @@ pub const ALICEBLUE: Color = Color(240, 248, 255);
 
 /// Colour `(r = 250, g = 235, b = 215)`
 ///
-/// Colour `ANTIQUEWHITE` from the set `CSS4_COLORS`. (Colour number `9`)
+/// Colour `ANTIQUEWHITE` from the set `CSS4_COLORS`. (Colour number `9`, name `antiquewhite`)
 /// ## Representations:
 /// - int tuple `(250, 235, 215)`
 /// - float tuple `(0.98, 0.92, 0.84)`
@@ -129,7 +287,7 @@ pub const ANTIQUEWHITE: Color = Color(250, 235, 215);
 
 /// Colour `(r = 0, g = 255, b = 255)`
 ///
-/// Colour `AQUA` from the set `CSS4_COLORS`. (Colour number `10`)
+/// Colour `AQUA` from the set `CSS4_COLORS`. (Colour number `10`, name `aqua`)
 /// ## Representations:
 /// - int tuple `(0, 255, 255)`
 /// - float tuple `(0.0, 1.0, 1.0)`
@@ -138,7 +296,7 @@ pub const AQUA: Color = Color(0, 255, 255);
 
 /// Colour `(r = 127, g = 255, b = 212)`
 ///
-/// Colour `AQUAMARINE` from the set `CSS4_COLORS`. (Colour number `11`)
+/// Colour `AQUAMARINE` from the set `CSS4_COLORS`. (Colour number `11`, name `aquamarine`)
 /// ## Representations:
 /// - int tuple `(127, 255, 212)`
 /// - float tuple `(0.5, 1.0, 0.83)`
@@ -147,7 +305,7 @@ pub const AQUAMARINE: Color = Color(127, 255, 212);
 
 /// Colour `(r = 240, g = 255, b = 255)`
 ///
-/// Colour `AZURE` from the set `CSS4_COLORS`. (Colour number `12`)
+/// Colour `AZURE` from the set `CSS4_COLORS`. (Colour number `12`, name `azure`)
 /// ## Representations:
 /// - int tuple `(240, 255, 255)`
 /// - float tuple `(0.94, 1.0, 1.0)`
@@ -156,7 +314,7 @@ pub const AZURE: Color = Color(240, 255, 255);
 
 /// Colour `(r = 245, g = 245, b = 220)`
 ///
-/// Colour `BEIGE` from the set `CSS4_COLORS`. (Colour number `13`)
+/// Colour `BEIGE` from the set `CSS4_COLORS`. (Colour number `13`, name `beige`)
 /// ## Representations:
 /// - int tuple `(245, 245, 220)`
 /// - float tuple `(0.96, 0.96, 0.86)`
@@ -165,7 +323,7 @@ pub const BEIGE: Color = Color(245, 245, 220);
 
 /// Colour `(r = 255, g = 228, b = 196)`
 ///
-/// Colour `BISQUE` from the set `CSS4_COLORS`. (Colour number `14`)
+/// Colour `BISQUE` from the set `CSS4_COLORS`. (Colour number `14`, name `bisque`)
 /// ## Representations:
 /// - int tuple `(255, 228, 196)`
 /// - float tuple `(1.0, 0.89, 0.77)`
@@ -174,7 +332,7 @@ pub const BISQUE: Color = Color(255, 228, 196);
 
 /// Colour `(r = 0, g = 0, b = 0)`
 ///
-/// Colour `BLACK` from the set `CSS4_COLORS`. (Colour number `15`)
+/// Colour `BLACK` from the set `CSS4_COLORS`. (Colour number `15`, name `black`)
 /// ## Representations:
 /// - int tuple `(0, 0, 0)`
 /// - float tuple `(0.0, 0.0, 0.0)`
@@ -183,7 +341,7 @@ pub const BLACK: Color = Color(0, 0, 0);
 
 /// Colour `(r = 255, g = 235, b = 205)`
 ///
-/// Colour `BLANCHEDALMOND` from the set `CSS4_COLORS`. (Colour number `16`)
+/// Colour `BLANCHEDALMOND` from the set `CSS4_COLORS`. (Colour number `16`, name `blanchedalmond`)
 /// ## Representations:
 /// - int tuple `(255, 235, 205)`
 /// - float tuple `(1.0, 0.92, 0.8)`
@@ -192,7 +350,7 @@ pub const BLANCHEDALMOND: Color = Color(255, 235, 205);
 
 /// Colour `(r = 0, g = 0, b = 255)`
 ///
-/// Colour `BLUE` from the set `CSS4_COLORS`. (Colour number `17`)
+/// Colour `BLUE` from the set `CSS4_COLORS`. (Colour number `17`, name `blue`)
 /// ## Representations:
 /// - int tuple `(0, 0, 255)`
 /// - float tuple `(0.0, 0.0, 1.0)`
@@ -201,7 +359,7 @@ pub const BLUE: Color = Color(0, 0, 255);
 
 /// Colour `(r = 138, g = 43, b = 226)`
 ///
-/// Colour `BLUEVIOLET` from the set `CSS4_COLORS`. (Colour number `18`)
+/// Colour `BLUEVIOLET` from the set `CSS4_COLORS`. (Colour number `18`, name `blueviolet`)
 /// ## Representations:
 /// - int tuple `(138, 43, 226)`
 /// - float tuple `(0.54, 0.17, 0.89)`
@@ -210,7 +368,7 @@ pub const BLUEVIOLET: Color = Color(138, 43, 226);
 
 /// Colour `(r = 165, g = 42, b = 42)`
 ///
-/// Colour `BROWN` from the set `CSS4_COLORS`. (Colour number `19`)
+/// Colour `BROWN` from the set `CSS4_COLORS`. (Colour number `19`, name `brown`)
 /// ## Representations:
 /// - int tuple `(165, 42, 42)`
 /// - float tuple `(0.65, 0.16, 0.16)`
@@ -219,7 +377,7 @@ pub const BROWN: Color = Color(165, 42, 42);
 
 /// Colour `(r = 222, g = 184, b = 135)`
 ///
-/// Colour `BURLYWOOD` from the set `CSS4_COLORS`. (Colour number `20`)
+/// Colour `BURLYWOOD` from the set `CSS4_COLORS`. (Colour number `20`, name `burlywood`)
 /// ## Representations:
 /// - int tuple `(222, 184, 135)`
 /// - float tuple `(0.87, 0.72, 0.53)`
@@ -228,7 +386,7 @@ pub const BURLYWOOD: Color = Color(222, 184, 135);
 
 /// Colour `(r = 95, g = 158, b = 160)`
 ///
-/// Colour `CADETBLUE` from the set `CSS4_COLORS`. (Colour number `21`)
+/// Colour `CADETBLUE` from the set `CSS4_COLORS`. (Colour number `21`, name `cadetblue`)
 /// ## Representations:
 /// - int tuple `(95, 158, 160)`
 /// - float tuple `(0.37, 0.62, 0.63)`
@@ -237,7 +395,7 @@ pub const CADETBLUE: Color = Color(95, 158, 160);
 
 /// Colour `(r = 127, g = 255, b = 0)`
 ///
-/// Colour `CHARTREUSE` from the set `CSS4_COLORS`. (Colour number `22`)
+/// Colour `CHARTREUSE` from the set `CSS4_COLORS`. (Colour number `22`, name `chartreuse`)
 /// ## Representations:
 /// - int tuple `(127, 255, 0)`
 /// - float tuple `(0.5, 1.0, 0.0)`
@@ -246,7 +404,7 @@ pub const CHARTREUSE: Color = Color(127, 255, 0);
 
 /// Colour `(r = 210, g = 105, b = 30)`
 ///
-/// Colour `CHOCOLATE` from the set `CSS4_COLORS`. (Colour number `23`)
+/// Colour `CHOCOLATE` from the set `CSS4_COLORS`. (Colour number `23`, name `chocolate`)
 /// ## Representations:
 /// - int tuple `(210, 105, 30)`
 /// - float tuple `(0.82, 0.41, 0.12)`
@@ -255,7 +413,7 @@ pub const CHOCOLATE: Color = Color(210, 105, 30);
 
 /// Colour `(r = 255, g = 127, b = 80)`
 ///
-/// Colour `CORAL` from the set `CSS4_COLORS`. (Colour number `24`)
+/// Colour `CORAL` from the set `CSS4_COLORS`. (Colour number `24`, name `coral`)
 /// ## Representations:
 /// - int tuple `(255, 127, 80)`
 /// - float tuple `(1.0, 0.5, 0.31)`
@@ -264,7 +422,7 @@ pub const CORAL: Color = Color(255, 127, 80);
 
 /// Colour `(r = 100, g = 149, b = 237)`
 ///
-/// Colour `CORNFLOWERBLUE` from the set `CSS4_COLORS`. (Colour number `25`)
+/// Colour `CORNFLOWERBLUE` from the set `CSS4_COLORS`. (Colour number `25`, name `cornflowerblue`)
 /// ## Representations:
 /// - int tuple `(100, 149, 237)`
 /// - float tuple `(0.39, 0.58, 0.93)`
@@ -273,7 +431,7 @@ pub const CORNFLOWERBLUE: Color = Color(100, 149, 237);
 
 /// Colour `(r = 255, g = 248, b = 220)`
 ///
-/// Colour `CORNSILK` from the set `CSS4_COLORS`. (Colour number `26`)
+/// Colour `CORNSILK` from the set `CSS4_COLORS`. (Colour number `26`, name `cornsilk`)
 /// ## Representations:
 /// - int tuple `(255, 248, 220)`
 /// - float tuple `(1.0, 0.97, 0.86)`
@@ -282,7 +440,7 @@ pub const CORNSILK: Color = Color(255, 248, 220);
 
 /// Colour `(r = 220, g = 20, b = 60)`
 ///
-/// Colour `CRIMSON` from the set `CSS4_COLORS`. (Colour number `27`)
+/// Colour `CRIMSON` from the set `CSS4_COLORS`. (Colour number `27`, name `crimson`)
 /// ## Representations:
 /// - int tuple `(220, 20, 60)`
 /// - float tuple `(0.86, 0.08, 0.24)`
@@ -291,7 +449,7 @@ pub const CRIMSON: Color = Color(220, 20, 60);
 
 /// Colour `(r = 0, g = 255, b = 255)`
 ///
-/// Colour `CYAN` from the set `CSS4_COLORS`. (Colour number `28`)
+/// Colour `CYAN` from the set `CSS4_COLORS`. (Colour number `28`, name `cyan`)
 /// ## Representations:
 /// - int tuple `(0, 255, 255)`
 /// - float tuple `(0.0, 1.0, 1.0)`
@@ -300,7 +458,7 @@ pub const CYAN: Color = Color(0, 255, 255);
 
 /// Colour `(r = 0, g = 0, b = 139)`
 ///
-/// Colour `DARKBLUE` from the set `CSS4_COLORS`. (Colour number `29`)
+/// Colour `DARKBLUE` from the set `CSS4_COLORS`. (Colour number `29`, name `darkblue`)
 /// ## Representations:
 /// - int tuple `(0, 0, 139)`
 /// - float tuple `(0.0, 0.0, 0.55)`
@@ -309,7 +467,7 @@ pub const DARKBLUE: Color = Color(0, 0, 139);
 
 /// Colour `(r = 0, g = 139, b = 139)`
 ///
-/// Colour `DARKCYAN` from the set `CSS4_COLORS`. (Colour number `30`)
+/// Colour `DARKCYAN` from the set `CSS4_COLORS`. (Colour number `30`, name `darkcyan`)
 /// ## Representations:
 /// - int tuple `(0, 139, 139)`
 /// - float tuple `(0.0, 0.55, 0.55)`
@@ -318,7 +476,7 @@ pub const DARKCYAN: Color = Color(0, 139, 139);
 
 /// Colour `(r = 184, g = 134, b = 11)`
 ///
-/// Colour `DARKGOLDENROD` from the set `CSS4_COLORS`. (Colour number `31`)
+/// Colour `DARKGOLDENROD` from the set `CSS4_COLORS`. (Colour number `31`, name `darkgoldenrod`)
 /// ## Representations:
 /// - int tuple `(184, 134, 11)`
 /// - float tuple `(0.72, 0.53, 0.04)`
@@ -327,7 +485,7 @@ pub const DARKGOLDENROD: Color = Color(184, 134, 11);
 
 /// Colour `(r = 169, g = 169, b = 169)`
 ///
-/// Colour `DARKGRAY` from the set `CSS4_COLORS`. (Colour number `32`)
+/// Colour `DARKGRAY` from the set `CSS4_COLORS`. (Colour number `32`, name `darkgray`)
 /// ## Representations:
 /// - int tuple `(169, 169, 169)`
 /// - float tuple `(0.66, 0.66, 0.66)`
@@ -336,7 +494,7 @@ pub const DARKGRAY: Color = Color(169, 169, 169);
 
 /// Colour `(r = 0, g = 100, b = 0)`
 ///
-/// Colour `DARKGREEN` from the set `CSS4_COLORS`. (Colour number `33`)
+/// Colour `DARKGREEN` from the set `CSS4_COLORS`. (Colour number `33`, name `darkgreen`)
 /// ## Representations:
 /// - int tuple `(0, 100, 0)`
 /// - float tuple `(0.0, 0.39, 0.0)`
@@ -345,7 +503,7 @@ pub const DARKGREEN: Color = Color(0, 100, 0);
 
 /// Colour `(r = 169, g = 169, b = 169)`
 ///
-/// Colour `DARKGREY` from the set `CSS4_COLORS`. (Colour number `34`)
+/// Colour `DARKGREY` from the set `CSS4_COLORS`. (Colour number `34`, name `darkgrey`)
 /// ## Representations:
 /// - int tuple `(169, 169, 169)`
 /// - float tuple `(0.66, 0.66, 0.66)`
@@ -354,7 +512,7 @@ pub const DARKGREY: Color = Color(169, 169, 169);
 
 /// Colour `(r = 189, g = 183, b = 107)`
 ///
-/// Colour `DARKKHAKI` from the set `CSS4_COLORS`. (Colour number `35`)
+/// Colour `DARKKHAKI` from the set `CSS4_COLORS`. (Colour number `35`, name `darkkhaki`)
 /// ## Representations:
 /// - int tuple `(189, 183, 107)`
 /// - float tuple `(0.74, 0.72, 0.42)`
@@ -363,7 +521,7 @@ pub const DARKKHAKI: Color = Color(189, 183, 107);
 
 /// Colour `(r = 139, g = 0, b = 139)`
 ///
-/// Colour `DARKMAGENTA` from the set `CSS4_COLORS`. (Colour number `36`)
+/// Colour `DARKMAGENTA` from the set `CSS4_COLORS`. (Colour number `36`, name `darkmagenta`)
 /// ## Representations:
 /// - int tuple `(139, 0, 139)`
 /// - float tuple `(0.55, 0.0, 0.55)`
@@ -372,7 +530,7 @@ pub const DARKMAGENTA: Color = Color(139, 0, 139);
 
 /// Colour `(r = 85, g = 107, b = 47)`
 ///
-/// Colour `DARKOLIVEGREEN` from the set `CSS4_COLORS`. (Colour number `37`)
+/// Colour `DARKOLIVEGREEN` from the set `CSS4_COLORS`. (Colour number `37`, name `darkolivegreen`)
 /// ## Representations:
 /// - int tuple `(85, 107, 47)`
 /// - float tuple `(0.33, 0.42, 0.18)`
@@ -381,7 +539,7 @@ pub const DARKOLIVEGREEN: Color = Color(85, 107, 47);
 
 /// Colour `(r = 255, g = 140, b = 0)`
 ///
-/// Colour `DARKORANGE` from the set `CSS4_COLORS`. (Colour number `38`)
+/// Colour `DARKORANGE` from the set `CSS4_COLORS`. (Colour number `38`, name `darkorange`)
 /// ## Representations:
 /// - int tuple `(255, 140, 0)`
 /// - float tuple `(1.0, 0.55, 0.0)`
@@ -390,7 +548,7 @@ pub const DARKORANGE: Color = Color(255, 140, 0);
 
 /// Colour `(r = 153, g = 50, b = 204)`
 ///
-/// Colour `DARKORCHID` from the set `CSS4_COLORS`. (Colour number `39`)
+/// Colour `DARKORCHID` from the set `CSS4_COLORS`. (Colour number `39`, name `darkorchid`)
 /// ## Representations:
 /// - int tuple `(153, 50, 204)`
 /// - float tuple `(0.6, 0.2, 0.8)`
@@ -399,7 +557,7 @@ pub const DARKORCHID: Color = Color(153, 50, 204);
 
 /// Colour `(r = 139, g = 0, b = 0)`
 ///
-/// Colour `DARKRED` from the set `CSS4_COLORS`. (Colour number `40`)
+/// Colour `DARKRED` from the set `CSS4_COLORS`. (Colour number `40`, name `darkred`)
 /// ## Representations:
 /// - int tuple `(139, 0, 0)`
 /// - float tuple `(0.55, 0.0, 0.0)`
@@ -408,7 +566,7 @@ pub const DARKRED: Color = Color(139, 0, 0);
 
 /// Colour `(r = 233, g = 150, b = 122)`
 ///
-/// Colour `DARKSALMON` from the set `CSS4_COLORS`. (Colour number `41`)
+/// Colour `DARKSALMON` from the set `CSS4_COLORS`. (Colour number `41`, name `darksalmon`)
 /// ## Representations:
 /// - int tuple `(233, 150, 122)`
 /// - float tuple `(0.91, 0.59, 0.48)`
@@ -417,7 +575,7 @@ pub const DARKSALMON: Color = Color(233, 150, 122);
 
 /// Colour `(r = 143, g = 188, b = 143)`
 ///
-/// Colour `DARKSEAGREEN` from the set `CSS4_COLORS`. (Colour number `42`)
+/// Colour `DARKSEAGREEN` from the set `CSS4_COLORS`. (Colour number `42`, name `darkseagreen`)
 /// ## Representations:
 /// - int tuple `(143, 188, 143)`
 /// - float tuple `(0.56, 0.74, 0.56)`
@@ -426,7 +584,7 @@ pub const DARKSEAGREEN: Color = Color(143, 188, 143);
 
 /// Colour `(r = 72, g = 61, b = 139)`
 ///
-/// Colour `DARKSLATEBLUE` from the set `CSS4_COLORS`. (Colour number `43`)
+/// Colour `DARKSLATEBLUE` from the set `CSS4_COLORS`. (Colour number `43`, name `darkslateblue`)
 /// ## Representations:
 /// - int tuple `(72, 61, 139)`
 /// - float tuple `(0.28, 0.24, 0.55)`
@@ -435,7 +593,7 @@ pub const DARKSLATEBLUE: Color = Color(72, 61, 139);
 
 /// Colour `(r = 47, g = 79, b = 79)`
 ///
-/// Colour `DARKSLATEGRAY` from the set `CSS4_COLORS`. (Colour number `44`)
+/// Colour `DARKSLATEGRAY` from the set `CSS4_COLORS`. (Colour number `44`, name `darkslategray`)
 /// ## Representations:
 /// - int tuple `(47, 79, 79)`
 /// - float tuple `(0.18, 0.31, 0.31)`
@@ -444,7 +602,7 @@ pub const DARKSLATEGRAY: Color = Color(47, 79, 79);
 
 /// Colour `(r = 47, g = 79, b = 79)`
 ///
-/// Colour `DARKSLATEGREY` from the set `CSS4_COLORS`. (Colour number `45`)
+/// Colour `DARKSLATEGREY` from the set `CSS4_COLORS`. (Colour number `45`, name `darkslategrey`)
 /// ## Representations:
 /// - int tuple `(47, 79, 79)`
 /// - float tuple `(0.18, 0.31, 0.31)`
@@ -453,7 +611,7 @@ pub const DARKSLATEGREY: Color = Color(47, 79, 79);
 
 /// Colour `(r = 0, g = 206, b = 209)`
 ///
-/// Colour `DARKTURQUOISE` from the set `CSS4_COLORS`. (Colour number `46`)
+/// Colour `DARKTURQUOISE` from the set `CSS4_COLORS`. (Colour number `46`, name `darkturquoise`)
 /// ## Representations:
 /// - int tuple `(0, 206, 209)`
 /// - float tuple `(0.0, 0.81, 0.82)`
@@ -462,7 +620,7 @@ pub const DARKTURQUOISE: Color = Color(0, 206, 209);
 
 /// Colour `(r = 148, g = 0, b = 211)`
 ///
-/// Colour `DARKVIOLET` from the set `CSS4_COLORS`. (Colour number `47`)
+/// Colour `DARKVIOLET` from the set `CSS4_COLORS`. (Colour number `47`, name `darkviolet`)
 /// ## Representations:
 /// - int tuple `(148, 0, 211)`
 /// - float tuple `(0.58, 0.0, 0.83)`
@@ -471,7 +629,7 @@ pub const DARKVIOLET: Color = Color(148, 0, 211);
 
 /// Colour `(r = 255, g = 20, b = 147)`
 ///
-/// Colour `DEEPPINK` from the set `CSS4_COLORS`. (Colour number `48`)
+/// Colour `DEEPPINK` from the set `CSS4_COLORS`. (Colour number `48`, name `deeppink`)
 /// ## Representations:
 /// - int tuple `(255, 20, 147)`
 /// - float tuple `(1.0, 0.08, 0.58)`
@@ -480,7 +638,7 @@ pub const DEEPPINK: Color = Color(255, 20, 147);
 
 /// Colour `(r = 0, g = 191, b = 255)`
 ///
-/// Colour `DEEPSKYBLUE` from the set `CSS4_COLORS`. (Colour number `49`)
+/// Colour `DEEPSKYBLUE` from the set `CSS4_COLORS`. (Colour number `49`, name `deepskyblue`)
 /// ## Representations:
 /// - int tuple `(0, 191, 255)`
 /// - float tuple `(0.0, 0.75, 1.0)`
@@ -489,7 +647,7 @@ pub const DEEPSKYBLUE: Color = Color(0, 191, 255);
 
 /// Colour `(r = 105, g = 105, b = 105)`
 ///
-/// Colour `DIMGRAY` from the set `CSS4_COLORS`. (Colour number `50`)
+/// Colour `DIMGRAY` from the set `CSS4_COLORS`. (Colour number `50`, name `dimgray`)
 /// ## Representations:
 /// - int tuple `(105, 105, 105)`
 /// - float tuple `(0.41, 0.41, 0.41)`
@@ -498,7 +656,7 @@ pub const DIMGRAY: Color = Color(105, 105, 105);
 
 /// Colour `(r = 105, g = 105, b = 105)`
 ///
-/// Colour `DIMGREY` from the set `CSS4_COLORS`. (Colour number `51`)
+/// Colour `DIMGREY` from the set `CSS4_COLORS`. (Colour number `51`, name `dimgrey`)
 /// ## Representations:
 /// - int tuple `(105, 105, 105)`
 /// - float tuple `(0.41, 0.41, 0.41)`
@@ -507,7 +665,7 @@ pub const DIMGREY: Color = Color(105, 105, 105);
 
 /// Colour `(r = 30, g = 144, b = 255)`
 ///
-/// Colour `DODGERBLUE` from the set `CSS4_COLORS`. (Colour number `52`)
+/// Colour `DODGERBLUE` from the set `CSS4_COLORS`. (Colour number `52`, name `dodgerblue`)
 /// ## Representations:
 /// - int tuple `(30, 144, 255)`
 /// - float tuple `(0.12, 0.56, 1.0)`
@@ -516,7 +674,7 @@ pub const DODGERBLUE: Color = Color(30, 144, 255);
 
 /// Colour `(r = 178, g = 34, b = 34)`
 ///
-/// Colour `FIREBRICK` from the set `CSS4_COLORS`. (Colour number `53`)
+/// Colour `FIREBRICK` from the set `CSS4_COLORS`. (Colour number `53`, name `firebrick`)
 /// ## Representations:
 /// - int tuple `(178, 34, 34)`
 /// - float tuple `(0.7, 0.13, 0.13)`
@@ -525,7 +683,7 @@ pub const FIREBRICK: Color = Color(178, 34, 34);
 
 /// Colour `(r = 255, g = 250, b = 240)`
 ///
-/// Colour `FLORALWHITE` from the set `CSS4_COLORS`. (Colour number `54`)
+/// Colour `FLORALWHITE` from the set `CSS4_COLORS`. (Colour number `54`, name `floralwhite`)
 /// ## Representations:
 /// - int tuple `(255, 250, 240)`
 /// - float tuple `(1.0, 0.98, 0.94)`
@@ -534,7 +692,7 @@ pub const FLORALWHITE: Color = Color(255, 250, 240);
 
 /// Colour `(r = 34, g = 139, b = 34)`
 ///
-/// Colour `FORESTGREEN` from the set `CSS4_COLORS`. (Colour number `55`)
+/// Colour `FORESTGREEN` from the set `CSS4_COLORS`. (Colour number `55`, name `forestgreen`)
 /// ## Representations:
 /// - int tuple `(34, 139, 34)`
 /// - float tuple `(0.13, 0.55, 0.13)`
@@ -543,7 +701,7 @@ pub const FORESTGREEN: Color = Color(34, 139, 34);
 
 /// Colour `(r = 255, g = 0, b = 255)`
 ///
-/// Colour `FUCHSIA` from the set `CSS4_COLORS`. (Colour number `56`)
+/// Colour `FUCHSIA` from the set `CSS4_COLORS`. (Colour number `56`, name `fuchsia`)
 /// ## Representations:
 /// - int tuple `(255, 0, 255)`
 /// - float tuple `(1.0, 0.0, 1.0)`
@@ -552,7 +710,7 @@ pub const FUCHSIA: Color = Color(255, 0, 255);
 
 /// Colour `(r = 220, g = 220, b = 220)`
 ///
-/// Colour `GAINSBORO` from the set `CSS4_COLORS`. (Colour number `57`)
+/// Colour `GAINSBORO` from the set `CSS4_COLORS`. (Colour number `57`, name `gainsboro`)
 /// ## Representations:
 /// - int tuple `(220, 220, 220)`
 /// - float tuple `(0.86, 0.86, 0.86)`
@@ -561,7 +719,7 @@ pub const GAINSBORO: Color = Color(220, 220, 220);
 
 /// Colour `(r = 248, g = 248, b = 255)`
 ///
-/// Colour `GHOSTWHITE` from the set `CSS4_COLORS`. (Colour number `58`)
+/// Colour `GHOSTWHITE` from the set `CSS4_COLORS`. (Colour number `58`, name `ghostwhite`)
 /// ## Representations:
 /// - int tuple `(248, 248, 255)`
 /// - float tuple `(0.97, 0.97, 1.0)`
@@ -570,7 +728,7 @@ pub const GHOSTWHITE: Color = Color(248, 248, 255);
 
 /// Colour `(r = 255, g = 215, b = 0)`
 ///
-/// Colour `GOLD` from the set `CSS4_COLORS`. (Colour number `59`)
+/// Colour `GOLD` from the set `CSS4_COLORS`. (Colour number `59`, name `gold`)
 /// ## Representations:
 /// - int tuple `(255, 215, 0)`
 /// - float tuple `(1.0, 0.84, 0.0)`
@@ -579,7 +737,7 @@ pub const GOLD: Color = Color(255, 215, 0);
 
 /// Colour `(r = 218, g = 165, b = 32)`
 ///
-/// Colour `GOLDENROD` from the set `CSS4_COLORS`. (Colour number `60`)
+/// Colour `GOLDENROD` from the set `CSS4_COLORS`. (Colour number `60`, name `goldenrod`)
 /// ## Representations:
 /// - int tuple `(218, 165, 32)`
 /// - float tuple `(0.85, 0.65, 0.13)`
@@ -588,7 +746,7 @@ pub const GOLDENROD: Color = Color(218, 165, 32);
 
 /// Colour `(r = 128, g = 128, b = 128)`
 ///
-/// Colour `GRAY` from the set `CSS4_COLORS`. (Colour number `61`)
+/// Colour `GRAY` from the set `CSS4_COLORS`. (Colour number `61`, name `gray`)
 /// ## Representations:
 /// - int tuple `(128, 128, 128)`
 /// - float tuple `(0.5, 0.5, 0.5)`
@@ -597,7 +755,7 @@ pub const GRAY: Color = Color(128, 128, 128);
 
 /// Colour `(r = 0, g = 128, b = 0)`
 ///
-/// Colour `GREEN` from the set `CSS4_COLORS`. (Colour number `62`)
+/// Colour `GREEN` from the set `CSS4_COLORS`. (Colour number `62`, name `green`)
 /// ## Representations:
 /// - int tuple `(0, 128, 0)`
 /// - float tuple `(0.0, 0.5, 0.0)`
@@ -606,7 +764,7 @@ pub const GREEN: Color = Color(0, 128, 0);
 
 /// Colour `(r = 173, g = 255, b = 47)`
 ///
-/// Colour `GREENYELLOW` from the set `CSS4_COLORS`. (Colour number `63`)
+/// Colour `GREENYELLOW` from the set `CSS4_COLORS`. (Colour number `63`, name `greenyellow`)
 /// ## Representations:
 /// - int tuple `(173, 255, 47)`
 /// - float tuple `(0.68, 1.0, 0.18)`
@@ -615,7 +773,7 @@ pub const GREENYELLOW: Color = Color(173, 255, 47);
 
 /// Colour `(r = 128, g = 128, b = 128)`
 ///
-/// Colour `GREY` from the set `CSS4_COLORS`. (Colour number `64`)
+/// Colour `GREY` from the set `CSS4_COLORS`. (Colour number `64`, name `grey`)
 /// ## Representations:
 /// - int tuple `(128, 128, 128)`
 /// - float tuple `(0.5, 0.5, 0.5)`
@@ -624,7 +782,7 @@ pub const GREY: Color = Color(128, 128, 128);
 
 /// Colour `(r = 240, g = 255, b = 240)`
 ///
-/// Colour `HONEYDEW` from the set `CSS4_COLORS`. (Colour number `65`)
+/// Colour `HONEYDEW` from the set `CSS4_COLORS`. (Colour number `65`, name `honeydew`)
 /// ## Representations:
 /// - int tuple `(240, 255, 240)`
 /// - float tuple `(0.94, 1.0, 0.94)`
@@ -633,7 +791,7 @@ pub const HONEYDEW: Color = Color(240, 255, 240);
 
 /// Colour `(r = 255, g = 105, b = 180)`
 ///
-/// Colour `HOTPINK` from the set `CSS4_COLORS`. (Colour number `66`)
+/// Colour `HOTPINK` from the set `CSS4_COLORS`. (Colour number `66`, name `hotpink`)
 /// ## Representations:
 /// - int tuple `(255, 105, 180)`
 /// - float tuple `(1.0, 0.41, 0.71)`
@@ -642,7 +800,7 @@ pub const HOTPINK: Color = Color(255, 105, 180);
 
 /// Colour `(r = 205, g = 92, b = 92)`
 ///
-/// Colour `INDIANRED` from the set `CSS4_COLORS`. (Colour number `67`)
+/// Colour `INDIANRED` from the set `CSS4_COLORS`. (Colour number `67`, name `indianred`)
 /// ## Representations:
 /// - int tuple `(205, 92, 92)`
 /// - float tuple `(0.8, 0.36, 0.36)`
@@ -651,7 +809,7 @@ pub const INDIANRED: Color = Color(205, 92, 92);
 
 /// Colour `(r = 75, g = 0, b = 130)`
 ///
-/// Colour `INDIGO` from the set `CSS4_COLORS`. (Colour number `68`)
+/// Colour `INDIGO` from the set `CSS4_COLORS`. (Colour number `68`, name `indigo`)
 /// ## Representations:
 /// - int tuple `(75, 0, 130)`
 /// - float tuple `(0.29, 0.0, 0.51)`
@@ -660,7 +818,7 @@ pub const INDIGO: Color = Color(75, 0, 130);
 
 /// Colour `(r = 255, g = 255, b = 240)`
 ///
-/// Colour `IVORY` from the set `CSS4_COLORS`. (Colour number `69`)
+/// Colour `IVORY` from the set `CSS4_COLORS`. (Colour number `69`, name `ivory`)
 /// ## Representations:
 /// - int tuple `(255, 255, 240)`
 /// - float tuple `(1.0, 1.0, 0.94)`
@@ -669,7 +827,7 @@ pub const IVORY: Color = Color(255, 255, 240);
 
 /// Colour `(r = 240, g = 230, b = 140)`
 ///
-/// Colour `KHAKI` from the set `CSS4_COLORS`. (Colour number `70`)
+/// Colour `KHAKI` from the set `CSS4_COLORS`. (Colour number `70`, name `khaki`)
 /// ## Representations:
 /// - int tuple `(240, 230, 140)`
 /// - float tuple `(0.94, 0.9, 0.55)`
@@ -678,7 +836,7 @@ pub const KHAKI: Color = Color(240, 230, 140);
 
 /// Colour `(r = 230, g = 230, b = 250)`
 ///
-/// Colour `LAVENDER` from the set `CSS4_COLORS`. (Colour number `71`)
+/// Colour `LAVENDER` from the set `CSS4_COLORS`. (Colour number `71`, name `lavender`)
 /// ## Representations:
 /// - int tuple `(230, 230, 250)`
 /// - float tuple `(0.9, 0.9, 0.98)`
@@ -687,7 +845,7 @@ pub const LAVENDER: Color = Color(230, 230, 250);
 
 /// Colour `(r = 255, g = 240, b = 245)`
 ///
-/// Colour `LAVENDERBLUSH` from the set `CSS4_COLORS`. (Colour number `72`)
+/// Colour `LAVENDERBLUSH` from the set `CSS4_COLORS`. (Colour number `72`, name `lavenderblush`)
 /// ## Representations:
 /// - int tuple `(255, 240, 245)`
 /// - float tuple `(1.0, 0.94, 0.96)`
@@ -696,7 +854,7 @@ pub const LAVENDERBLUSH: Color = Color(255, 240, 245);
 
 /// Colour `(r = 124, g = 252, b = 0)`
 ///
-/// Colour `LAWNGREEN` from the set `CSS4_COLORS`. (Colour number `73`)
+/// Colour `LAWNGREEN` from the set `CSS4_COLORS`. (Colour number `73`, name `lawngreen`)
 /// ## Representations:
 /// - int tuple `(124, 252, 0)`
 /// - float tuple `(0.49, 0.99, 0.0)`
@@ -705,7 +863,7 @@ pub const LAWNGREEN: Color = Color(124, 252, 0);
 
 /// Colour `(r = 255, g = 250, b = 205)`
 ///
-/// Colour `LEMONCHIFFON` from the set `CSS4_COLORS`. (Colour number `74`)
+/// Colour `LEMONCHIFFON` from the set `CSS4_COLORS`. (Colour number `74`, name `lemonchiffon`)
 /// ## Representations:
 /// - int tuple `(255, 250, 205)`
 /// - float tuple `(1.0, 0.98, 0.8)`
@@ -714,7 +872,7 @@ pub const LEMONCHIFFON: Color = Color(255, 250, 205);
 
 /// Colour `(r = 173, g = 216, b = 230)`
 ///
-/// Colour `LIGHTBLUE` from the set `CSS4_COLORS`. (Colour number `75`)
+/// Colour `LIGHTBLUE` from the set `CSS4_COLORS`. (Colour number `75`, name `lightblue`)
 /// ## Representations:
 /// - int tuple `(173, 216, 230)`
 /// - float tuple `(0.68, 0.85, 0.9)`
@@ -723,7 +881,7 @@ pub const LIGHTBLUE: Color = Color(173, 216, 230);
 
 /// Colour `(r = 240, g = 128, b = 128)`
 ///
-/// Colour `LIGHTCORAL` from the set `CSS4_COLORS`. (Colour number `76`)
+/// Colour `LIGHTCORAL` from the set `CSS4_COLORS`. (Colour number `76`, name `lightcoral`)
 /// ## Representations:
 /// - int tuple `(240, 128, 128)`
 /// - float tuple `(0.94, 0.5, 0.5)`
@@ -732,7 +890,7 @@ pub const LIGHTCORAL: Color = Color(240, 128, 128);
 
 /// Colour `(r = 224, g = 255, b = 255)`
 ///
-/// Colour `LIGHTCYAN` from the set `CSS4_COLORS`. (Colour number `77`)
+/// Colour `LIGHTCYAN` from the set `CSS4_COLORS`. (Colour number `77`, name `lightcyan`)
 /// ## Representations:
 /// - int tuple `(224, 255, 255)`
 /// - float tuple `(0.88, 1.0, 1.0)`
@@ -741,7 +899,7 @@ pub const LIGHTCYAN: Color = Color(224, 255, 255);
 
 /// Colour `(r = 250, g = 250, b = 210)`
 ///
-/// Colour `LIGHTGOLDENRODYELLOW` from the set `CSS4_COLORS`. (Colour number `78`)
+/// Colour `LIGHTGOLDENRODYELLOW` from the set `CSS4_COLORS`. (Colour number `78`, name `lightgoldenrodyellow`)
 /// ## Representations:
 /// - int tuple `(250, 250, 210)`
 /// - float tuple `(0.98, 0.98, 0.82)`
@@ -750,7 +908,7 @@ pub const LIGHTGOLDENRODYELLOW: Color = Color(250, 250, 210);
 
 /// Colour `(r = 211, g = 211, b = 211)`
 ///
-/// Colour `LIGHTGRAY` from the set `CSS4_COLORS`. (Colour number `79`)
+/// Colour `LIGHTGRAY` from the set `CSS4_COLORS`. (Colour number `79`, name `lightgray`)
 /// ## Representations:
 /// - int tuple `(211, 211, 211)`
 /// - float tuple `(0.83, 0.83, 0.83)`
@@ -759,7 +917,7 @@ pub const LIGHTGRAY: Color = Color(211, 211, 211);
 
 /// Colour `(r = 144, g = 238, b = 144)`
 ///
-/// Colour `LIGHTGREEN` from the set `CSS4_COLORS`. (Colour number `80`)
+/// Colour `LIGHTGREEN` from the set `CSS4_COLORS`. (Colour number `80`, name `lightgreen`)
 /// ## Representations:
 /// - int tuple `(144, 238, 144)`
 /// - float tuple `(0.56, 0.93, 0.56)`
@@ -768,7 +926,7 @@ pub const LIGHTGREEN: Color = Color(144, 238, 144);
 
 /// Colour `(r = 211, g = 211, b = 211)`
 ///
-/// Colour `LIGHTGREY` from the set `CSS4_COLORS`. (Colour number `81`)
+/// Colour `LIGHTGREY` from the set `CSS4_COLORS`. (Colour number `81`, name `lightgrey`)
 /// ## Representations:
 /// - int tuple `(211, 211, 211)`
 /// - float tuple `(0.83, 0.83, 0.83)`
@@ -777,7 +935,7 @@ pub const LIGHTGREY: Color = Color(211, 211, 211);
 
 /// Colour `(r = 255, g = 182, b = 193)`
 ///
-/// Colour `LIGHTPINK` from the set `CSS4_COLORS`. (Colour number `82`)
+/// Colour `LIGHTPINK` from the set `CSS4_COLORS`. (Colour number `82`, name `lightpink`)
 /// ## Representations:
 /// - int tuple `(255, 182, 193)`
 /// - float tuple `(1.0, 0.71, 0.76)`
@@ -786,7 +944,7 @@ pub const LIGHTPINK: Color = Color(255, 182, 193);
 
 /// Colour `(r = 255, g = 160, b = 122)`
 ///
-/// Colour `LIGHTSALMON` from the set `CSS4_COLORS`. (Colour number `83`)
+/// Colour `LIGHTSALMON` from the set `CSS4_COLORS`. (Colour number `83`, name `lightsalmon`)
 /// ## Representations:
 /// - int tuple `(255, 160, 122)`
 /// - float tuple `(1.0, 0.63, 0.48)`
@@ -795,7 +953,7 @@ pub const LIGHTSALMON: Color = Color(255, 160, 122);
 
 /// Colour `(r = 32, g = 178, b = 170)`
 ///
-/// Colour `LIGHTSEAGREEN` from the set `CSS4_COLORS`. (Colour number `84`)
+/// Colour `LIGHTSEAGREEN` from the set `CSS4_COLORS`. (Colour number `84`, name `lightseagreen`)
 /// ## Representations:
 /// - int tuple `(32, 178, 170)`
 /// - float tuple `(0.13, 0.7, 0.67)`
@@ -804,7 +962,7 @@ pub const LIGHTSEAGREEN: Color = Color(32, 178, 170);
 
 /// Colour `(r = 135, g = 206, b = 250)`
 ///
-/// Colour `LIGHTSKYBLUE` from the set `CSS4_COLORS`. (Colour number `85`)
+/// Colour `LIGHTSKYBLUE` from the set `CSS4_COLORS`. (Colour number `85`, name `lightskyblue`)
 /// ## Representations:
 /// - int tuple `(135, 206, 250)`
 /// - float tuple `(0.53, 0.81, 0.98)`
@@ -813,7 +971,7 @@ pub const LIGHTSKYBLUE: Color = Color(135, 206, 250);
 
 /// Colour `(r = 119, g = 136, b = 153)`
 ///
-/// Colour `LIGHTSLATEGRAY` from the set `CSS4_COLORS`. (Colour number `86`)
+/// Colour `LIGHTSLATEGRAY` from the set `CSS4_COLORS`. (Colour number `86`, name `lightslategray`)
 /// ## Representations:
 /// - int tuple `(119, 136, 153)`
 /// - float tuple `(0.47, 0.53, 0.6)`
@@ -822,7 +980,7 @@ pub const LIGHTSLATEGRAY: Color = Color(119, 136, 153);
 
 /// Colour `(r = 119, g = 136, b = 153)`
 ///
-/// Colour `LIGHTSLATEGREY` from the set `CSS4_COLORS`. (Colour number `87`)
+/// Colour `LIGHTSLATEGREY` from the set `CSS4_COLORS`. (Colour number `87`, name `lightslategrey`)
 /// ## Representations:
 /// - int tuple `(119, 136, 153)`
 /// - float tuple `(0.47, 0.53, 0.6)`
@@ -831,7 +989,7 @@ pub const LIGHTSLATEGREY: Color = Color(119, 136, 153);
 
 /// Colour `(r = 176, g = 196, b = 222)`
 ///
-/// Colour `LIGHTSTEELBLUE` from the set `CSS4_COLORS`. (Colour number `88`)
+/// Colour `LIGHTSTEELBLUE` from the set `CSS4_COLORS`. (Colour number `88`, name `lightsteelblue`)
 /// ## Representations:
 /// - int tuple `(176, 196, 222)`
 /// - float tuple `(0.69, 0.77, 0.87)`
@@ -840,7 +998,7 @@ pub const LIGHTSTEELBLUE: Color = Color(176, 196, 222);
 
 /// Colour `(r = 255, g = 255, b = 224)`
 ///
-/// Colour `LIGHTYELLOW` from the set `CSS4_COLORS`. (Colour number `89`)
+/// Colour `LIGHTYELLOW` from the set `CSS4_COLORS`. (Colour number `89`, name `lightyellow`)
 /// ## Representations:
 /// - int tuple `(255, 255, 224)`
 /// - float tuple `(1.0, 1.0, 0.88)`
@@ -849,7 +1007,7 @@ pub const LIGHTYELLOW: Color = Color(255, 255, 224);
 
 /// Colour `(r = 0, g = 255, b = 0)`
 ///
-/// Colour `LIME` from the set `CSS4_COLORS`. (Colour number `90`)
+/// Colour `LIME` from the set `CSS4_COLORS`. (Colour number `90`, name `lime`)
 /// ## Representations:
 /// - int tuple `(0, 255, 0)`
 /// - float tuple `(0.0, 1.0, 0.0)`
@@ -858,7 +1016,7 @@ pub const LIME: Color = Color(0, 255, 0);
 
 /// Colour `(r = 50, g = 205, b = 50)`
 ///
-/// Colour `LIMEGREEN` from the set `CSS4_COLORS`. (Colour number `91`)
+/// Colour `LIMEGREEN` from the set `CSS4_COLORS`. (Colour number `91`, name `limegreen`)
 /// ## Representations:
 /// - int tuple `(50, 205, 50)`
 /// - float tuple `(0.2, 0.8, 0.2)`
@@ -867,7 +1025,7 @@ pub const LIMEGREEN: Color = Color(50, 205, 50);
 
 /// Colour `(r = 250, g = 240, b = 230)`
 ///
-/// Colour `LINEN` from the set `CSS4_COLORS`. (Colour number `92`)
+/// Colour `LINEN` from the set `CSS4_COLORS`. (Colour number `92`, name `linen`)
 /// ## Representations:
 /// - int tuple `(250, 240, 230)`
 /// - float tuple `(0.98, 0.94, 0.9)`
@@ -876,7 +1034,7 @@ pub const LINEN: Color = Color(250, 240, 230);
 
 /// Colour `(r = 255, g = 0, b = 255)`
 ///
-/// Colour `MAGENTA` from the set `CSS4_COLORS`. (Colour number `93`)
+/// Colour `MAGENTA` from the set `CSS4_COLORS`. (Colour number `93`, name `magenta`)
 /// ## Representations:
 /// - int tuple `(255, 0, 255)`
 /// - float tuple `(1.0, 0.0, 1.0)`
@@ -885,7 +1043,7 @@ pub const MAGENTA: Color = Color(255, 0, 255);
 
 /// Colour `(r = 128, g = 0, b = 0)`
 ///
-/// Colour `MAROON` from the set `CSS4_COLORS`. (Colour number `94`)
+/// Colour `MAROON` from the set `CSS4_COLORS`. (Colour number `94`, name `maroon`)
 /// ## Representations:
 /// - int tuple `(128, 0, 0)`
 /// - float tuple `(0.5, 0.0, 0.0)`
@@ -894,7 +1052,7 @@ pub const MAROON: Color = Color(128, 0, 0);
 
 /// Colour `(r = 102, g = 205, b = 170)`
 ///
-/// Colour `MEDIUMAQUAMARINE` from the set `CSS4_COLORS`. (Colour number `95`)
+/// Colour `MEDIUMAQUAMARINE` from the set `CSS4_COLORS`. (Colour number `95`, name `mediumaquamarine`)
 /// ## Representations:
 /// - int tuple `(102, 205, 170)`
 /// - float tuple `(0.4, 0.8, 0.67)`
@@ -903,7 +1061,7 @@ pub const MEDIUMAQUAMARINE: Color = Color(102, 205, 170);
 
 /// Colour `(r = 0, g = 0, b = 205)`
 ///
-/// Colour `MEDIUMBLUE` from the set `CSS4_COLORS`. (Colour number `96`)
+/// Colour `MEDIUMBLUE` from the set `CSS4_COLORS`. (Colour number `96`, name `mediumblue`)
 /// ## Representations:
 /// - int tuple `(0, 0, 205)`
 /// - float tuple `(0.0, 0.0, 0.8)`
@@ -912,7 +1070,7 @@ pub const MEDIUMBLUE: Color = Color(0, 0, 205);
 
 /// Colour `(r = 186, g = 85, b = 211)`
 ///
-/// Colour `MEDIUMORCHID` from the set `CSS4_COLORS`. (Colour number `97`)
+/// Colour `MEDIUMORCHID` from the set `CSS4_COLORS`. (Colour number `97`, name `mediumorchid`)
 /// ## Representations:
 /// - int tuple `(186, 85, 211)`
 /// - float tuple `(0.73, 0.33, 0.83)`
@@ -921,7 +1079,7 @@ pub const MEDIUMORCHID: Color = Color(186, 85, 211);
 
 /// Colour `(r = 147, g = 112, b = 219)`
 ///
-/// Colour `MEDIUMPURPLE` from the set `CSS4_COLORS`. (Colour number `98`)
+/// Colour `MEDIUMPURPLE` from the set `CSS4_COLORS`. (Colour number `98`, name `mediumpurple`)
 /// ## Representations:
 /// - int tuple `(147, 112, 219)`
 /// - float tuple `(0.58, 0.44, 0.86)`
@@ -930,7 +1088,7 @@ pub const MEDIUMPURPLE: Color = Color(147, 112, 219);
 
 /// Colour `(r = 60, g = 179, b = 113)`
 ///
-/// Colour `MEDIUMSEAGREEN` from the set `CSS4_COLORS`. (Colour number `99`)
+/// Colour `MEDIUMSEAGREEN` from the set `CSS4_COLORS`. (Colour number `99`, name `mediumseagreen`)
 /// ## Representations:
 /// - int tuple `(60, 179, 113)`
 /// - float tuple `(0.24, 0.7, 0.44)`
@@ -939,7 +1097,7 @@ pub const MEDIUMSEAGREEN: Color = Color(60, 179, 113);
 
 /// Colour `(r = 123, g = 104, b = 238)`
 ///
-/// Colour `MEDIUMSLATEBLUE` from the set `CSS4_COLORS`. (Colour number `100`)
+/// Colour `MEDIUMSLATEBLUE` from the set `CSS4_COLORS`. (Colour number `100`, name `mediumslateblue`)
 /// ## Representations:
 /// - int tuple `(123, 104, 238)`
 /// - float tuple `(0.48, 0.41, 0.93)`
@@ -948,7 +1106,7 @@ pub const MEDIUMSLATEBLUE: Color = Color(123, 104, 238);
 
 /// Colour `(r = 0, g = 250, b = 154)`
 ///
-/// Colour `MEDIUMSPRINGGREEN` from the set `CSS4_COLORS`. (Colour number `101`)
+/// Colour `MEDIUMSPRINGGREEN` from the set `CSS4_COLORS`. (Colour number `101`, name `mediumspringgreen`)
 /// ## Representations:
 /// - int tuple `(0, 250, 154)`
 /// - float tuple `(0.0, 0.98, 0.6)`
@@ -957,7 +1115,7 @@ pub const MEDIUMSPRINGGREEN: Color = Color(0, 250, 154);
 
 /// Colour `(r = 72, g = 209, b = 204)`
 ///
-/// Colour `MEDIUMTURQUOISE` from the set `CSS4_COLORS`. (Colour number `102`)
+/// Colour `MEDIUMTURQUOISE` from the set `CSS4_COLORS`. (Colour number `102`, name `mediumturquoise`)
 /// ## Representations:
 /// - int tuple `(72, 209, 204)`
 /// - float tuple `(0.28, 0.82, 0.8)`
@@ -966,7 +1124,7 @@ pub const MEDIUMTURQUOISE: Color = Color(72, 209, 204);
 
 /// Colour `(r = 199, g = 21, b = 133)`
 ///
-/// Colour `MEDIUMVIOLETRED` from the set `CSS4_COLORS`. (Colour number `103`)
+/// Colour `MEDIUMVIOLETRED` from the set `CSS4_COLORS`. (Colour number `103`, name `mediumvioletred`)
 /// ## Representations:
 /// - int tuple `(199, 21, 133)`
 /// - float tuple `(0.78, 0.08, 0.52)`
@@ -975,7 +1133,7 @@ pub const MEDIUMVIOLETRED: Color = Color(199, 21, 133);
 
 /// Colour `(r = 25, g = 25, b = 112)`
 ///
-/// Colour `MIDNIGHTBLUE` from the set `CSS4_COLORS`. (Colour number `104`)
+/// Colour `MIDNIGHTBLUE` from the set `CSS4_COLORS`. (Colour number `104`, name `midnightblue`)
 /// ## Representations:
 /// - int tuple `(25, 25, 112)`
 /// - float tuple `(0.1, 0.1, 0.44)`
@@ -984,7 +1142,7 @@ pub const MIDNIGHTBLUE: Color = Color(25, 25, 112);
 
 /// Colour `(r = 245, g = 255, b = 250)`
 ///
-/// Colour `MINTCREAM` from the set `CSS4_COLORS`. (Colour number `105`)
+/// Colour `MINTCREAM` from the set `CSS4_COLORS`. (Colour number `105`, name `mintcream`)
 /// ## Representations:
 /// - int tuple `(245, 255, 250)`
 /// - float tuple `(0.96, 1.0, 0.98)`
@@ -993,7 +1151,7 @@ pub const MINTCREAM: Color = Color(245, 255, 250);
 
 /// Colour `(r = 255, g = 228, b = 225)`
 ///
-/// Colour `MISTYROSE` from the set `CSS4_COLORS`. (Colour number `106`)
+/// Colour `MISTYROSE` from the set `CSS4_COLORS`. (Colour number `106`, name `mistyrose`)
 /// ## Representations:
 /// - int tuple `(255, 228, 225)`
 /// - float tuple `(1.0, 0.89, 0.88)`
@@ -1002,7 +1160,7 @@ pub const MISTYROSE: Color = Color(255, 228, 225);
 
 /// Colour `(r = 255, g = 228, b = 181)`
 ///
-/// Colour `MOCCASIN` from the set `CSS4_COLORS`. (Colour number `107`)
+/// Colour `MOCCASIN` from the set `CSS4_COLORS`. (Colour number `107`, name `moccasin`)
 /// ## Representations:
 /// - int tuple `(255, 228, 181)`
 /// - float tuple `(1.0, 0.89, 0.71)`
@@ -1011,7 +1169,7 @@ pub const MOCCASIN: Color = Color(255, 228, 181);
 
 /// Colour `(r = 255, g = 222, b = 173)`
 ///
-/// Colour `NAVAJOWHITE` from the set `CSS4_COLORS`. (Colour number `108`)
+/// Colour `NAVAJOWHITE` from the set `CSS4_COLORS`. (Colour number `108`, name `navajowhite`)
 /// ## Representations:
 /// - int tuple `(255, 222, 173)`
 /// - float tuple `(1.0, 0.87, 0.68)`
@@ -1020,7 +1178,7 @@ pub const NAVAJOWHITE: Color = Color(255, 222, 173);
 
 /// Colour `(r = 0, g = 0, b = 128)`
 ///
-/// Colour `NAVY` from the set `CSS4_COLORS`. (Colour number `109`)
+/// Colour `NAVY` from the set `CSS4_COLORS`. (Colour number `109`, name `navy`)
 /// ## Representations:
 /// - int tuple `(0, 0, 128)`
 /// - float tuple `(0.0, 0.0, 0.5)`
@@ -1029,7 +1187,7 @@ pub const NAVY: Color = Color(0, 0, 128);
 
 /// Colour `(r = 253, g = 245, b = 230)`
 ///
-/// Colour `OLDLACE` from the set `CSS4_COLORS`. (Colour number `110`)
+/// Colour `OLDLACE` from the set `CSS4_COLORS`. (Colour number `110`, name `oldlace`)
 /// ## Representations:
 /// - int tuple `(253, 245, 230)`
 /// - float tuple `(0.99, 0.96, 0.9)`
@@ -1038,7 +1196,7 @@ pub const OLDLACE: Color = Color(253, 245, 230);
 
 /// Colour `(r = 128, g = 128, b = 0)`
 ///
-/// Colour `OLIVE` from the set `CSS4_COLORS`. (Colour number `111`)
+/// Colour `OLIVE` from the set `CSS4_COLORS`. (Colour number `111`, name `olive`)
 /// ## Representations:
 /// - int tuple `(128, 128, 0)`
 /// - float tuple `(0.5, 0.5, 0.0)`
@@ -1047,7 +1205,7 @@ pub const OLIVE: Color = Color(128, 128, 0);
 
 /// Colour `(r = 107, g = 142, b = 35)`
 ///
-/// Colour `OLIVEDRAB` from the set `CSS4_COLORS`. (Colour number `112`)
+/// Colour `OLIVEDRAB` from the set `CSS4_COLORS`. (Colour number `112`, name `olivedrab`)
 /// ## Representations:
 /// - int tuple `(107, 142, 35)`
 /// - float tuple `(0.42, 0.56, 0.14)`
@@ -1056,7 +1214,7 @@ pub const OLIVEDRAB: Color = Color(107, 142, 35);
 
 /// Colour `(r = 255, g = 165, b = 0)`
 ///
-/// Colour `ORANGE` from the set `CSS4_COLORS`. (Colour number `113`)
+/// Colour `ORANGE` from the set `CSS4_COLORS`. (Colour number `113`, name `orange`)
 /// ## Representations:
 /// - int tuple `(255, 165, 0)`
 /// - float tuple `(1.0, 0.65, 0.0)`
@@ -1065,7 +1223,7 @@ pub const ORANGE: Color = Color(255, 165, 0);
 
 /// Colour `(r = 255, g = 69, b = 0)`
 ///
-/// Colour `ORANGERED` from the set `CSS4_COLORS`. (Colour number `114`)
+/// Colour `ORANGERED` from the set `CSS4_COLORS`. (Colour number `114`, name `orangered`)
 /// ## Representations:
 /// - int tuple `(255, 69, 0)`
 /// - float tuple `(1.0, 0.27, 0.0)`
@@ -1074,7 +1232,7 @@ pub const ORANGERED: Color = Color(255, 69, 0);
 
 /// Colour `(r = 218, g = 112, b = 214)`
 ///
-/// Colour `ORCHID` from the set `CSS4_COLORS`. (Colour number `115`)
+/// Colour `ORCHID` from the set `CSS4_COLORS`. (Colour number `115`, name `orchid`)
 /// ## Representations:
 /// - int tuple `(218, 112, 214)`
 /// - float tuple `(0.85, 0.44, 0.84)`
@@ -1083,7 +1241,7 @@ pub const ORCHID: Color = Color(218, 112, 214);
 
 /// Colour `(r = 238, g = 232, b = 170)`
 ///
-/// Colour `PALEGOLDENROD` from the set `CSS4_COLORS`. (Colour number `116`)
+/// Colour `PALEGOLDENROD` from the set `CSS4_COLORS`. (Colour number `116`, name `palegoldenrod`)
 /// ## Representations:
 /// - int tuple `(238, 232, 170)`
 /// - float tuple `(0.93, 0.91, 0.67)`
@@ -1092,7 +1250,7 @@ pub const PALEGOLDENROD: Color = Color(238, 232, 170);
 
 /// Colour `(r = 152, g = 251, b = 152)`
 ///
-/// Colour `PALEGREEN` from the set `CSS4_COLORS`. (Colour number `117`)
+/// Colour `PALEGREEN` from the set `CSS4_COLORS`. (Colour number `117`, name `palegreen`)
 /// ## Representations:
 /// - int tuple `(152, 251, 152)`
 /// - float tuple `(0.6, 0.98, 0.6)`
@@ -1101,7 +1259,7 @@ pub const PALEGREEN: Color = Color(152, 251, 152);
 
 /// Colour `(r = 175, g = 238, b = 238)`
 ///
-/// Colour `PALETURQUOISE` from the set `CSS4_COLORS`. (Colour number `118`)
+/// Colour `PALETURQUOISE` from the set `CSS4_COLORS`. (Colour number `118`, name `paleturquoise`)
 /// ## Representations:
 /// - int tuple `(175, 238, 238)`
 /// - float tuple `(0.69, 0.93, 0.93)`
@@ -1110,7 +1268,7 @@ pub const PALETURQUOISE: Color = Color(175, 238, 238);
 
 /// Colour `(r = 219, g = 112, b = 147)`
 ///
-/// Colour `PALEVIOLETRED` from the set `CSS4_COLORS`. (Colour number `119`)
+/// Colour `PALEVIOLETRED` from the set `CSS4_COLORS`. (Colour number `119`, name `palevioletred`)
 /// ## Representations:
 /// - int tuple `(219, 112, 147)`
 /// - float tuple `(0.86, 0.44, 0.58)`
@@ -1119,7 +1277,7 @@ pub const PALEVIOLETRED: Color = Color(219, 112, 147);
 
 /// Colour `(r = 255, g = 239, b = 213)`
 ///
-/// Colour `PAPAYAWHIP` from the set `CSS4_COLORS`. (Colour number `120`)
+/// Colour `PAPAYAWHIP` from the set `CSS4_COLORS`. (Colour number `120`, name `papayawhip`)
 /// ## Representations:
 /// - int tuple `(255, 239, 213)`
 /// - float tuple `(1.0, 0.94, 0.84)`
@@ -1128,7 +1286,7 @@ pub const PAPAYAWHIP: Color = Color(255, 239, 213);
 
 /// Colour `(r = 255, g = 218, b = 185)`
 ///
-/// Colour `PEACHPUFF` from the set `CSS4_COLORS`. (Colour number `121`)
+/// Colour `PEACHPUFF` from the set `CSS4_COLORS`. (Colour number `121`, name `peachpuff`)
 /// ## Representations:
 /// - int tuple `(255, 218, 185)`
 /// - float tuple `(1.0, 0.85, 0.73)`
@@ -1137,7 +1295,7 @@ pub const PEACHPUFF: Color = Color(255, 218, 185);
 
 /// Colour `(r = 205, g = 133, b = 63)`
 ///
-/// Colour `PERU` from the set `CSS4_COLORS`. (Colour number `122`)
+/// Colour `PERU` from the set `CSS4_COLORS`. (Colour number `122`, name `peru`)
 /// ## Representations:
 /// - int tuple `(205, 133, 63)`
 /// - float tuple `(0.8, 0.52, 0.25)`
@@ -1146,7 +1304,7 @@ pub const PERU: Color = Color(205, 133, 63);
 
 /// Colour `(r = 255, g = 192, b = 203)`
 ///
-/// Colour `PINK` from the set `CSS4_COLORS`. (Colour number `123`)
+/// Colour `PINK` from the set `CSS4_COLORS`. (Colour number `123`, name `pink`)
 /// ## Representations:
 /// - int tuple `(255, 192, 203)`
 /// - float tuple `(1.0, 0.75, 0.8)`
@@ -1155,7 +1313,7 @@ pub const PINK: Color = Color(255, 192, 203);
 
 /// Colour `(r = 221, g = 160, b = 221)`
 ///
-/// Colour `PLUM` from the set `CSS4_COLORS`. (Colour number `124`)
+/// Colour `PLUM` from the set `CSS4_COLORS`. (Colour number `124`, name `plum`)
 /// ## Representations:
 /// - int tuple `(221, 160, 221)`
 /// - float tuple `(0.87, 0.63, 0.87)`
@@ -1164,7 +1322,7 @@ pub const PLUM: Color = Color(221, 160, 221);
 
 /// Colour `(r = 176, g = 224, b = 230)`
 ///
-/// Colour `POWDERBLUE` from the set `CSS4_COLORS`. (Colour number `125`)
+/// Colour `POWDERBLUE` from the set `CSS4_COLORS`. (Colour number `125`, name `powderblue`)
 /// ## Representations:
 /// - int tuple `(176, 224, 230)`
 /// - float tuple `(0.69, 0.88, 0.9)`
@@ -1173,7 +1331,7 @@ pub const POWDERBLUE: Color = Color(176, 224, 230);
 
 /// Colour `(r = 128, g = 0, b = 128)`
 ///
-/// Colour `PURPLE` from the set `CSS4_COLORS`. (Colour number `126`)
+/// Colour `PURPLE` from the set `CSS4_COLORS`. (Colour number `126`, name `purple`)
 /// ## Representations:
 /// - int tuple `(128, 0, 128)`
 /// - float tuple `(0.5, 0.0, 0.5)`
@@ -1182,7 +1340,7 @@ pub const PURPLE: Color = Color(128, 0, 128);
 
 /// Colour `(r = 102, g = 51, b = 153)`
 ///
-/// Colour `REBECCAPURPLE` from the set `CSS4_COLORS`. (Colour number `127`)
+/// Colour `REBECCAPURPLE` from the set `CSS4_COLORS`. (Colour number `127`, name `rebeccapurple`)
 /// ## Representations:
 /// - int tuple `(102, 51, 153)`
 /// - float tuple `(0.4, 0.2, 0.6)`
@@ -1191,7 +1349,7 @@ pub const REBECCAPURPLE: Color = Color(102, 51, 153);
 
 /// Colour `(r = 255, g = 0, b = 0)`
 ///
-/// Colour `RED` from the set `CSS4_COLORS`. (Colour number `128`)
+/// Colour `RED` from the set `CSS4_COLORS`. (Colour number `128`, name `red`)
 /// ## Representations:
 /// - int tuple `(255, 0, 0)`
 /// - float tuple `(1.0, 0.0, 0.0)`
@@ -1200,7 +1358,7 @@ pub const RED: Color = Color(255, 0, 0);
 
 /// Colour `(r = 188, g = 143, b = 143)`
 ///
-/// Colour `ROSYBROWN` from the set `CSS4_COLORS`. (Colour number `129`)
+/// Colour `ROSYBROWN` from the set `CSS4_COLORS`. (Colour number `129`, name `rosybrown`)
 /// ## Representations:
 /// - int tuple `(188, 143, 143)`
 /// - float tuple `(0.74, 0.56, 0.56)`
@@ -1209,7 +1367,7 @@ pub const ROSYBROWN: Color = Color(188, 143, 143);
 
 /// Colour `(r = 65, g = 105, b = 225)`
 ///
-/// Colour `ROYALBLUE` from the set `CSS4_COLORS`. (Colour number `130`)
+/// Colour `ROYALBLUE` from the set `CSS4_COLORS`. (Colour number `130`, name `royalblue`)
 /// ## Representations:
 /// - int tuple `(65, 105, 225)`
 /// - float tuple `(0.25, 0.41, 0.88)`
@@ -1218,7 +1376,7 @@ pub const ROYALBLUE: Color = Color(65, 105, 225);
 
 /// Colour `(r = 139, g = 69, b = 19)`
 ///
-/// Colour `SADDLEBROWN` from the set `CSS4_COLORS`. (Colour number `131`)
+/// Colour `SADDLEBROWN` from the set `CSS4_COLORS`. (Colour number `131`, name `saddlebrown`)
 /// ## Representations:
 /// - int tuple `(139, 69, 19)`
 /// - float tuple `(0.55, 0.27, 0.07)`
@@ -1227,7 +1385,7 @@ pub const SADDLEBROWN: Color = Color(139, 69, 19);
 
 /// Colour `(r = 250, g = 128, b = 114)`
 ///
-/// Colour `SALMON` from the set `CSS4_COLORS`. (Colour number `132`)
+/// Colour `SALMON` from the set `CSS4_COLORS`. (Colour number `132`, name `salmon`)
 /// ## Representations:
 /// - int tuple `(250, 128, 114)`
 /// - float tuple `(0.98, 0.5, 0.45)`
@@ -1236,7 +1394,7 @@ pub const SALMON: Color = Color(250, 128, 114);
 
 /// Colour `(r = 244, g = 164, b = 96)`
 ///
-/// Colour `SANDYBROWN` from the set `CSS4_COLORS`. (Colour number `133`)
+/// Colour `SANDYBROWN` from the set `CSS4_COLORS`. (Colour number `133`, name `sandybrown`)
 /// ## Representations:
 /// - int tuple `(244, 164, 96)`
 /// - float tuple `(0.96, 0.64, 0.38)`
@@ -1245,7 +1403,7 @@ pub const SANDYBROWN: Color = Color(244, 164, 96);
 
 /// Colour `(r = 46, g = 139, b = 87)`
 ///
-/// Colour `SEAGREEN` from the set `CSS4_COLORS`. (Colour number `134`)
+/// Colour `SEAGREEN` from the set `CSS4_COLORS`. (Colour number `134`, name `seagreen`)
 /// ## Representations:
 /// - int tuple `(46, 139, 87)`
 /// - float tuple `(0.18, 0.55, 0.34)`
@@ -1254,7 +1412,7 @@ pub const SEAGREEN: Color = Color(46, 139, 87);
 
 /// Colour `(r = 255, g = 245, b = 238)`
 ///
-/// Colour `SEASHELL` from the set `CSS4_COLORS`. (Colour number `135`)
+/// Colour `SEASHELL` from the set `CSS4_COLORS`. (Colour number `135`, name `seashell`)
 /// ## Representations:
 /// - int tuple `(255, 245, 238)`
 /// - float tuple `(1.0, 0.96, 0.93)`
@@ -1263,7 +1421,7 @@ pub const SEASHELL: Color = Color(255, 245, 238);
 
 /// Colour `(r = 160, g = 82, b = 45)`
 ///
-/// Colour `SIENNA` from the set `CSS4_COLORS`. (Colour number `136`)
+/// Colour `SIENNA` from the set `CSS4_COLORS`. (Colour number `136`, name `sienna`)
 /// ## Representations:
 /// - int tuple `(160, 82, 45)`
 /// - float tuple `(0.63, 0.32, 0.18)`
@@ -1272,7 +1430,7 @@ pub const SIENNA: Color = Color(160, 82, 45);
 
 /// Colour `(r = 192, g = 192, b = 192)`
 ///
-/// Colour `SILVER` from the set `CSS4_COLORS`. (Colour number `137`)
+/// Colour `SILVER` from the set `CSS4_COLORS`. (Colour number `137`, name `silver`)
 /// ## Representations:
 /// - int tuple `(192, 192, 192)`
 /// - float tuple `(0.75, 0.75, 0.75)`
@@ -1281,7 +1439,7 @@ pub const SILVER: Color = Color(192, 192, 192);
 
 /// Colour `(r = 135, g = 206, b = 235)`
 ///
-/// Colour `SKYBLUE` from the set `CSS4_COLORS`. (Colour number `138`)
+/// Colour `SKYBLUE` from the set `CSS4_COLORS`. (Colour number `138`, name `skyblue`)
 /// ## Representations:
 /// - int tuple `(135, 206, 235)`
 /// - float tuple `(0.53, 0.81, 0.92)`
@@ -1290,7 +1448,7 @@ pub const SKYBLUE: Color = Color(135, 206, 235);
 
 /// Colour `(r = 106, g = 90, b = 205)`
 ///
-/// Colour `SLATEBLUE` from the set `CSS4_COLORS`. (Colour number `139`)
+/// Colour `SLATEBLUE` from the set `CSS4_COLORS`. (Colour number `139`, name `slateblue`)
 /// ## Representations:
 /// - int tuple `(106, 90, 205)`
 /// - float tuple `(0.42, 0.35, 0.8)`
@@ -1299,7 +1457,7 @@ pub const SLATEBLUE: Color = Color(106, 90, 205);
 
 /// Colour `(r = 112, g = 128, b = 144)`
 ///
-/// Colour `SLATEGRAY` from the set `CSS4_COLORS`. (Colour number `140`)
+/// Colour `SLATEGRAY` from the set `CSS4_COLORS`. (Colour number `140`, name `slategray`)
 /// ## Representations:
 /// - int tuple `(112, 128, 144)`
 /// - float tuple `(0.44, 0.5, 0.56)`
@@ -1308,7 +1466,7 @@ pub const SLATEGRAY: Color = Color(112, 128, 144);
 
 /// Colour `(r = 112, g = 128, b = 144)`
 ///
-/// Colour `SLATEGREY` from the set `CSS4_COLORS`. (Colour number `141`)
+/// Colour `SLATEGREY` from the set `CSS4_COLORS`. (Colour number `141`, name `slategrey`)
 /// ## Representations:
 /// - int tuple `(112, 128, 144)`
 /// - float tuple `(0.44, 0.5, 0.56)`
@@ -1317,7 +1475,7 @@ pub const SLATEGREY: Color = Color(112, 128, 144);
 
 /// Colour `(r = 255, g = 250, b = 250)`
 ///
-/// Colour `SNOW` from the set `CSS4_COLORS`. (Colour number `142`)
+/// Colour `SNOW` from the set `CSS4_COLORS`. (Colour number `142`, name `snow`)
 /// ## Representations:
 /// - int tuple `(255, 250, 250)`
 /// - float tuple `(1.0, 0.98, 0.98)`
@@ -1326,7 +1484,7 @@ pub const SNOW: Color = Color(255, 250, 250);
 
 /// Colour `(r = 0, g = 255, b = 127)`
 ///
-/// Colour `SPRINGGREEN` from the set `CSS4_COLORS`. (Colour number `143`)
+/// Colour `SPRINGGREEN` from the set `CSS4_COLORS`. (Colour number `143`, name `springgreen`)
 /// ## Representations:
 /// - int tuple `(0, 255, 127)`
 /// - float tuple `(0.0, 1.0, 0.5)`
@@ -1335,7 +1493,7 @@ pub const SPRINGGREEN: Color = Color(0, 255, 127);
 
 /// Colour `(r = 70, g = 130, b = 180)`
 ///
-/// Colour `STEELBLUE` from the set `CSS4_COLORS`. (Colour number `144`)
+/// Colour `STEELBLUE` from the set `CSS4_COLORS`. (Colour number `144`, name `steelblue`)
 /// ## Representations:
 /// - int tuple `(70, 130, 180)`
 /// - float tuple `(0.27, 0.51, 0.71)`
@@ -1344,7 +1502,7 @@ pub const STEELBLUE: Color = Color(70, 130, 180);
 
 /// Colour `(r = 210, g = 180, b = 140)`
 ///
-/// Colour `TAN` from the set `CSS4_COLORS`. (Colour number `145`)
+/// Colour `TAN` from the set `CSS4_COLORS`. (Colour number `145`, name `tan`)
 /// ## Representations:
 /// - int tuple `(210, 180, 140)`
 /// - float tuple `(0.82, 0.71, 0.55)`
@@ -1353,7 +1511,7 @@ pub const TAN: Color = Color(210, 180, 140);
 
 /// Colour `(r = 0, g = 128, b = 128)`
 ///
-/// Colour `TEAL` from the set `CSS4_COLORS`. (Colour number `146`)
+/// Colour `TEAL` from the set `CSS4_COLORS`. (Colour number `146`, name `teal`)
 /// ## Representations:
 /// - int tuple `(0, 128, 128)`
 /// - float tuple `(0.0, 0.5, 0.5)`
@@ -1362,7 +1520,7 @@ pub const TEAL: Color = Color(0, 128, 128);
 
 /// Colour `(r = 216, g = 191, b = 216)`
 ///
-/// Colour `THISTLE` from the set `CSS4_COLORS`. (Colour number `147`)
+/// Colour `THISTLE` from the set `CSS4_COLORS`. (Colour number `147`, name `thistle`)
 /// ## Representations:
 /// - int tuple `(216, 191, 216)`
 /// - float tuple `(0.85, 0.75, 0.85)`
@@ -1371,7 +1529,7 @@ pub const THISTLE: Color = Color(216, 191, 216);
 
 /// Colour `(r = 255, g = 99, b = 71)`
 ///
-/// Colour `TOMATO` from the set `CSS4_COLORS`. (Colour number `148`)
+/// Colour `TOMATO` from the set `CSS4_COLORS`. (Colour number `148`, name `tomato`)
 /// ## Representations:
 /// - int tuple `(255, 99, 71)`
 /// - float tuple `(1.0, 0.39, 0.28)`
@@ -1380,7 +1538,7 @@ pub const TOMATO: Color = Color(255, 99, 71);
 
 /// Colour `(r = 64, g = 224, b = 208)`
 ///
-/// Colour `TURQUOISE` from the set `CSS4_COLORS`. (Colour number `149`)
+/// Colour `TURQUOISE` from the set `CSS4_COLORS`. (Colour number `149`, name `turquoise`)
 /// ## Representations:
 /// - int tuple `(64, 224, 208)`
 /// - float tuple `(0.25, 0.88, 0.82)`
@@ -1389,7 +1547,7 @@ pub const TURQUOISE: Color = Color(64, 224, 208);
 
 /// Colour `(r = 238, g = 130, b = 238)`
 ///
-/// Colour `VIOLET` from the set `CSS4_COLORS`. (Colour number `150`)
+/// Colour `VIOLET` from the set `CSS4_COLORS`. (Colour number `150`, name `violet`)
 /// ## Representations:
 /// - int tuple `(238, 130, 238)`
 /// - float tuple `(0.93, 0.51, 0.93)`
@@ -1398,7 +1556,7 @@ pub const VIOLET: Color = Color(238, 130, 238);
 
 /// Colour `(r = 245, g = 222, b = 179)`
 ///
-/// Colour `WHEAT` from the set `CSS4_COLORS`. (Colour number `151`)
+/// Colour `WHEAT` from the set `CSS4_COLORS`. (Colour number `151`, name `wheat`)
 /// ## Representations:
 /// - int tuple `(245, 222, 179)`
 /// - float tuple `(0.96, 0.87, 0.7)`
@@ -1407,7 +1565,7 @@ pub const WHEAT: Color = Color(245, 222, 179);
 
 /// Colour `(r = 255, g = 255, b = 255)`
 ///
-/// Colour `WHITE` from the set `CSS4_COLORS`. (Colour number `152`)
+/// Colour `WHITE` from the set `CSS4_COLORS`. (Colour number `152`, name `white`)
 /// ## Representations:
 /// - int tuple `(255, 255, 255)`
 /// - float tuple `(1.0, 1.0, 1.0)`
@@ -1416,7 +1574,7 @@ pub const WHITE: Color = Color(255, 255, 255);
 
 /// Colour `(r = 245, g = 245, b = 245)`
 ///
-/// Colour `WHITESMOKE` from the set `CSS4_COLORS`. (Colour number `153`)
+/// Colour `WHITESMOKE` from the set `CSS4_COLORS`. (Colour number `153`, name `whitesmoke`)
 /// ## Representations:
 /// - int tuple `(245, 245, 245)`
 /// - float tuple `(0.96, 0.96, 0.96)`
@@ -1425,7 +1583,7 @@ pub const WHITESMOKE: Color = Color(245, 245, 245);
 
 /// Colour `(r = 255, g = 255, b = 0)`
 ///
-/// Colour `YELLOW` from the set `CSS4_COLORS`. (Colour number `154`)
+/// Colour `YELLOW` from the set `CSS4_COLORS`. (Colour number `154`, name `yellow`)
 /// ## Representations:
 /// - int tuple `(255, 255, 0)`
 /// - float tuple `(1.0, 1.0, 0.0)`
@@ -1434,7 +1592,7 @@ pub const YELLOW: Color = Color(255, 255, 0);
 
 /// Colour `(r = 154, g = 205, b = 50)`
 ///
-/// Colour `YELLOWGREEN` from the set `CSS4_COLORS`. (Colour number `155`)
+/// Colour `YELLOWGREEN` from the set `CSS4_COLORS`. (Colour number `155`, name `yellowgreen`)
 /// ## Representations:
 /// - int tuple `(154, 205, 50)`
 /// - float tuple `(0.6, 0.8, 0.2)`
@@ -1443,7 +1601,7 @@ pub const YELLOWGREEN: Color = Color(154, 205, 50);
 
 /// Colour `(r = 172, g = 194, b = 217)`
 ///
-/// Colour `XKCD_CLOUDY_BLUE` from the set `XKCD_COLORS`. (Colour number `156`)
+/// Colour `XKCD_CLOUDY_BLUE` from the set `XKCD_COLORS`. (Colour number `156`, name `xkcd:cloudy blue`)
 /// ## Representations:
 /// - int tuple `(172, 194, 217)`
 /// - float tuple `(0.67, 0.76, 0.85)`
@@ -1452,7 +1610,7 @@ pub const XKCD_CLOUDY_BLUE: Color = Color(172, 194, 217);
 
 /// Colour `(r = 86, g = 174, b = 87)`
 ///
-/// Colour `XKCD_DARK_PASTEL_GREEN` from the set `XKCD_COLORS`. (Colour number `157`)
+/// Colour `XKCD_DARK_PASTEL_GREEN` from the set `XKCD_COLORS`. (Colour number `157`, name `xkcd:dark pastel green`)
 /// ## Representations:
 /// - int tuple `(86, 174, 87)`
 /// - float tuple `(0.34, 0.68, 0.34)`
@@ -1461,7 +1619,7 @@ pub const XKCD_DARK_PASTEL_GREEN: Color = Color(86, 174, 87);
 
 /// Colour `(r = 178, g = 153, b = 110)`
 ///
-/// Colour `XKCD_DUST` from the set `XKCD_COLORS`. (Colour number `158`)
+/// Colour `XKCD_DUST` from the set `XKCD_COLORS`. (Colour number `158`, name `xkcd:dust`)
 /// ## Representations:
 /// - int tuple `(178, 153, 110)`
 /// - float tuple `(0.7, 0.6, 0.43)`
@@ -1470,7 +1628,7 @@ pub const XKCD_DUST: Color = Color(178, 153, 110);
 
 /// Colour `(r = 168, g = 255, b = 4)`
 ///
-/// Colour `XKCD_ELECTRIC_LIME` from the set `XKCD_COLORS`. (Colour number `159`)
+/// Colour `XKCD_ELECTRIC_LIME` from the set `XKCD_COLORS`. (Colour number `159`, name `xkcd:electric lime`)
 /// ## Representations:
 /// - int tuple `(168, 255, 4)`
 /// - float tuple `(0.66, 1.0, 0.02)`
@@ -1479,7 +1637,7 @@ pub const XKCD_ELECTRIC_LIME: Color = Color(168, 255, 4);
 
 /// Colour `(r = 105, g = 216, b = 79)`
 ///
-/// Colour `XKCD_FRESH_GREEN` from the set `XKCD_COLORS`. (Colour number `160`)
+/// Colour `XKCD_FRESH_GREEN` from the set `XKCD_COLORS`. (Colour number `160`, name `xkcd:fresh green`)
 /// ## Representations:
 /// - int tuple `(105, 216, 79)`
 /// - float tuple `(0.41, 0.85, 0.31)`
@@ -1488,7 +1646,7 @@ pub const XKCD_FRESH_GREEN: Color = Color(105, 216, 79);
 
 /// Colour `(r = 137, g = 69, b = 133)`
 ///
-/// Colour `XKCD_LIGHT_EGGPLANT` from the set `XKCD_COLORS`. (Colour number `161`)
+/// Colour `XKCD_LIGHT_EGGPLANT` from the set `XKCD_COLORS`. (Colour number `161`, name `xkcd:light eggplant`)
 /// ## Representations:
 /// - int tuple `(137, 69, 133)`
 /// - float tuple `(0.54, 0.27, 0.52)`
@@ -1497,7 +1655,7 @@ pub const XKCD_LIGHT_EGGPLANT: Color = Color(137, 69, 133);
 
 /// Colour `(r = 112, g = 178, b = 63)`
 ///
-/// Colour `XKCD_NASTY_GREEN` from the set `XKCD_COLORS`. (Colour number `162`)
+/// Colour `XKCD_NASTY_GREEN` from the set `XKCD_COLORS`. (Colour number `162`, name `xkcd:nasty green`)
 /// ## Representations:
 /// - int tuple `(112, 178, 63)`
 /// - float tuple `(0.44, 0.7, 0.25)`
@@ -1506,7 +1664,7 @@ pub const XKCD_NASTY_GREEN: Color = Color(112, 178, 63);
 
 /// Colour `(r = 212, g = 255, b = 255)`
 ///
-/// Colour `XKCD_REALLY_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `163`)
+/// Colour `XKCD_REALLY_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `163`, name `xkcd:really light blue`)
 /// ## Representations:
 /// - int tuple `(212, 255, 255)`
 /// - float tuple `(0.83, 1.0, 1.0)`
@@ -1515,7 +1673,7 @@ pub const XKCD_REALLY_LIGHT_BLUE: Color = Color(212, 255, 255);
 
 /// Colour `(r = 101, g = 171, b = 124)`
 ///
-/// Colour `XKCD_TEA` from the set `XKCD_COLORS`. (Colour number `164`)
+/// Colour `XKCD_TEA` from the set `XKCD_COLORS`. (Colour number `164`, name `xkcd:tea`)
 /// ## Representations:
 /// - int tuple `(101, 171, 124)`
 /// - float tuple `(0.4, 0.67, 0.49)`
@@ -1524,7 +1682,7 @@ pub const XKCD_TEA: Color = Color(101, 171, 124);
 
 /// Colour `(r = 149, g = 46, b = 143)`
 ///
-/// Colour `XKCD_WARM_PURPLE` from the set `XKCD_COLORS`. (Colour number `165`)
+/// Colour `XKCD_WARM_PURPLE` from the set `XKCD_COLORS`. (Colour number `165`, name `xkcd:warm purple`)
 /// ## Representations:
 /// - int tuple `(149, 46, 143)`
 /// - float tuple `(0.58, 0.18, 0.56)`
@@ -1533,7 +1691,7 @@ pub const XKCD_WARM_PURPLE: Color = Color(149, 46, 143);
 
 /// Colour `(r = 252, g = 252, b = 129)`
 ///
-/// Colour `XKCD_YELLOWISH_TAN` from the set `XKCD_COLORS`. (Colour number `166`)
+/// Colour `XKCD_YELLOWISH_TAN` from the set `XKCD_COLORS`. (Colour number `166`, name `xkcd:yellowish tan`)
 /// ## Representations:
 /// - int tuple `(252, 252, 129)`
 /// - float tuple `(0.99, 0.99, 0.51)`
@@ -1542,7 +1700,7 @@ pub const XKCD_YELLOWISH_TAN: Color = Color(252, 252, 129);
 
 /// Colour `(r = 165, g = 163, b = 145)`
 ///
-/// Colour `XKCD_CEMENT` from the set `XKCD_COLORS`. (Colour number `167`)
+/// Colour `XKCD_CEMENT` from the set `XKCD_COLORS`. (Colour number `167`, name `xkcd:cement`)
 /// ## Representations:
 /// - int tuple `(165, 163, 145)`
 /// - float tuple `(0.65, 0.64, 0.57)`
@@ -1551,7 +1709,7 @@ pub const XKCD_CEMENT: Color = Color(165, 163, 145);
 
 /// Colour `(r = 56, g = 128, b = 4)`
 ///
-/// Colour `XKCD_DARK_GRASS_GREEN` from the set `XKCD_COLORS`. (Colour number `168`)
+/// Colour `XKCD_DARK_GRASS_GREEN` from the set `XKCD_COLORS`. (Colour number `168`, name `xkcd:dark grass green`)
 /// ## Representations:
 /// - int tuple `(56, 128, 4)`
 /// - float tuple `(0.22, 0.5, 0.02)`
@@ -1560,7 +1718,7 @@ pub const XKCD_DARK_GRASS_GREEN: Color = Color(56, 128, 4);
 
 /// Colour `(r = 76, g = 144, b = 133)`
 ///
-/// Colour `XKCD_DUSTY_TEAL` from the set `XKCD_COLORS`. (Colour number `169`)
+/// Colour `XKCD_DUSTY_TEAL` from the set `XKCD_COLORS`. (Colour number `169`, name `xkcd:dusty teal`)
 /// ## Representations:
 /// - int tuple `(76, 144, 133)`
 /// - float tuple `(0.3, 0.56, 0.52)`
@@ -1569,7 +1727,7 @@ pub const XKCD_DUSTY_TEAL: Color = Color(76, 144, 133);
 
 /// Colour `(r = 94, g = 155, b = 138)`
 ///
-/// Colour `XKCD_GREY_TEAL` from the set `XKCD_COLORS`. (Colour number `170`)
+/// Colour `XKCD_GREY_TEAL` from the set `XKCD_COLORS`. (Colour number `170`, name `xkcd:grey teal`)
 /// ## Representations:
 /// - int tuple `(94, 155, 138)`
 /// - float tuple `(0.37, 0.61, 0.54)`
@@ -1578,7 +1736,7 @@ pub const XKCD_GREY_TEAL: Color = Color(94, 155, 138);
 
 /// Colour `(r = 239, g = 180, b = 53)`
 ///
-/// Colour `XKCD_MACARONI_AND_CHEESE` from the set `XKCD_COLORS`. (Colour number `171`)
+/// Colour `XKCD_MACARONI_AND_CHEESE` from the set `XKCD_COLORS`. (Colour number `171`, name `xkcd:macaroni and cheese`)
 /// ## Representations:
 /// - int tuple `(239, 180, 53)`
 /// - float tuple `(0.94, 0.71, 0.21)`
@@ -1587,7 +1745,7 @@ pub const XKCD_MACARONI_AND_CHEESE: Color = Color(239, 180, 53);
 
 /// Colour `(r = 217, g = 155, b = 130)`
 ///
-/// Colour `XKCD_PINKISH_TAN` from the set `XKCD_COLORS`. (Colour number `172`)
+/// Colour `XKCD_PINKISH_TAN` from the set `XKCD_COLORS`. (Colour number `172`, name `xkcd:pinkish tan`)
 /// ## Representations:
 /// - int tuple `(217, 155, 130)`
 /// - float tuple `(0.85, 0.61, 0.51)`
@@ -1596,7 +1754,7 @@ pub const XKCD_PINKISH_TAN: Color = Color(217, 155, 130);
 
 /// Colour `(r = 10, g = 95, b = 56)`
 ///
-/// Colour `XKCD_SPRUCE` from the set `XKCD_COLORS`. (Colour number `173`)
+/// Colour `XKCD_SPRUCE` from the set `XKCD_COLORS`. (Colour number `173`, name `xkcd:spruce`)
 /// ## Representations:
 /// - int tuple `(10, 95, 56)`
 /// - float tuple `(0.04, 0.37, 0.22)`
@@ -1605,7 +1763,7 @@ pub const XKCD_SPRUCE: Color = Color(10, 95, 56);
 
 /// Colour `(r = 12, g = 6, b = 247)`
 ///
-/// Colour `XKCD_STRONG_BLUE` from the set `XKCD_COLORS`. (Colour number `174`)
+/// Colour `XKCD_STRONG_BLUE` from the set `XKCD_COLORS`. (Colour number `174`, name `xkcd:strong blue`)
 /// ## Representations:
 /// - int tuple `(12, 6, 247)`
 /// - float tuple `(0.05, 0.02, 0.97)`
@@ -1614,7 +1772,7 @@ pub const XKCD_STRONG_BLUE: Color = Color(12, 6, 247);
 
 /// Colour `(r = 97, g = 222, b = 42)`
 ///
-/// Colour `XKCD_TOXIC_GREEN` from the set `XKCD_COLORS`. (Colour number `175`)
+/// Colour `XKCD_TOXIC_GREEN` from the set `XKCD_COLORS`. (Colour number `175`, name `xkcd:toxic green`)
 /// ## Representations:
 /// - int tuple `(97, 222, 42)`
 /// - float tuple `(0.38, 0.87, 0.16)`
@@ -1623,7 +1781,7 @@ pub const XKCD_TOXIC_GREEN: Color = Color(97, 222, 42);
 
 /// Colour `(r = 55, g = 120, b = 191)`
 ///
-/// Colour `XKCD_WINDOWS_BLUE` from the set `XKCD_COLORS`. (Colour number `176`)
+/// Colour `XKCD_WINDOWS_BLUE` from the set `XKCD_COLORS`. (Colour number `176`, name `xkcd:windows blue`)
 /// ## Representations:
 /// - int tuple `(55, 120, 191)`
 /// - float tuple `(0.22, 0.47, 0.75)`
@@ -1632,7 +1790,7 @@ pub const XKCD_WINDOWS_BLUE: Color = Color(55, 120, 191);
 
 /// Colour `(r = 34, g = 66, b = 199)`
 ///
-/// Colour `XKCD_BLUE_BLUE` from the set `XKCD_COLORS`. (Colour number `177`)
+/// Colour `XKCD_BLUE_BLUE` from the set `XKCD_COLORS`. (Colour number `177`, name `xkcd:blue blue`)
 /// ## Representations:
 /// - int tuple `(34, 66, 199)`
 /// - float tuple `(0.13, 0.26, 0.78)`
@@ -1641,7 +1799,7 @@ pub const XKCD_BLUE_BLUE: Color = Color(34, 66, 199);
 
 /// Colour `(r = 83, g = 60, b = 198)`
 ///
-/// Colour `XKCD_BLUE_WITH_A_HINT_OF_PURPLE` from the set `XKCD_COLORS`. (Colour number `178`)
+/// Colour `XKCD_BLUE_WITH_A_HINT_OF_PURPLE` from the set `XKCD_COLORS`. (Colour number `178`, name `xkcd:blue with a hint of purple`)
 /// ## Representations:
 /// - int tuple `(83, 60, 198)`
 /// - float tuple `(0.33, 0.24, 0.78)`
@@ -1650,7 +1808,7 @@ pub const XKCD_BLUE_WITH_A_HINT_OF_PURPLE: Color = Color(83, 60, 198);
 
 /// Colour `(r = 155, g = 181, b = 60)`
 ///
-/// Colour `XKCD_BOOGER` from the set `XKCD_COLORS`. (Colour number `179`)
+/// Colour `XKCD_BOOGER` from the set `XKCD_COLORS`. (Colour number `179`, name `xkcd:booger`)
 /// ## Representations:
 /// - int tuple `(155, 181, 60)`
 /// - float tuple `(0.61, 0.71, 0.24)`
@@ -1659,7 +1817,7 @@ pub const XKCD_BOOGER: Color = Color(155, 181, 60);
 
 /// Colour `(r = 5, g = 255, b = 166)`
 ///
-/// Colour `XKCD_BRIGHT_SEA_GREEN` from the set `XKCD_COLORS`. (Colour number `180`)
+/// Colour `XKCD_BRIGHT_SEA_GREEN` from the set `XKCD_COLORS`. (Colour number `180`, name `xkcd:bright sea green`)
 /// ## Representations:
 /// - int tuple `(5, 255, 166)`
 /// - float tuple `(0.02, 1.0, 0.65)`
@@ -1668,7 +1826,7 @@ pub const XKCD_BRIGHT_SEA_GREEN: Color = Color(5, 255, 166);
 
 /// Colour `(r = 31, g = 99, b = 87)`
 ///
-/// Colour `XKCD_DARK_GREEN_BLUE` from the set `XKCD_COLORS`. (Colour number `181`)
+/// Colour `XKCD_DARK_GREEN_BLUE` from the set `XKCD_COLORS`. (Colour number `181`, name `xkcd:dark green blue`)
 /// ## Representations:
 /// - int tuple `(31, 99, 87)`
 /// - float tuple `(0.12, 0.39, 0.34)`
@@ -1677,7 +1835,7 @@ pub const XKCD_DARK_GREEN_BLUE: Color = Color(31, 99, 87);
 
 /// Colour `(r = 1, g = 115, b = 116)`
 ///
-/// Colour `XKCD_DEEP_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `182`)
+/// Colour `XKCD_DEEP_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `182`, name `xkcd:deep turquoise`)
 /// ## Representations:
 /// - int tuple `(1, 115, 116)`
 /// - float tuple `(0.0, 0.45, 0.45)`
@@ -1686,7 +1844,7 @@ pub const XKCD_DEEP_TURQUOISE: Color = Color(1, 115, 116);
 
 /// Colour `(r = 12, g = 181, b = 119)`
 ///
-/// Colour `XKCD_GREEN_TEAL` from the set `XKCD_COLORS`. (Colour number `183`)
+/// Colour `XKCD_GREEN_TEAL` from the set `XKCD_COLORS`. (Colour number `183`, name `xkcd:green teal`)
 /// ## Representations:
 /// - int tuple `(12, 181, 119)`
 /// - float tuple `(0.05, 0.71, 0.47)`
@@ -1695,7 +1853,7 @@ pub const XKCD_GREEN_TEAL: Color = Color(12, 181, 119);
 
 /// Colour `(r = 255, g = 7, b = 137)`
 ///
-/// Colour `XKCD_STRONG_PINK` from the set `XKCD_COLORS`. (Colour number `184`)
+/// Colour `XKCD_STRONG_PINK` from the set `XKCD_COLORS`. (Colour number `184`, name `xkcd:strong pink`)
 /// ## Representations:
 /// - int tuple `(255, 7, 137)`
 /// - float tuple `(1.0, 0.03, 0.54)`
@@ -1704,7 +1862,7 @@ pub const XKCD_STRONG_PINK: Color = Color(255, 7, 137);
 
 /// Colour `(r = 175, g = 168, b = 139)`
 ///
-/// Colour `XKCD_BLAND` from the set `XKCD_COLORS`. (Colour number `185`)
+/// Colour `XKCD_BLAND` from the set `XKCD_COLORS`. (Colour number `185`, name `xkcd:bland`)
 /// ## Representations:
 /// - int tuple `(175, 168, 139)`
 /// - float tuple `(0.69, 0.66, 0.55)`
@@ -1713,7 +1871,7 @@ pub const XKCD_BLAND: Color = Color(175, 168, 139);
 
 /// Colour `(r = 8, g = 120, b = 127)`
 ///
-/// Colour `XKCD_DEEP_AQUA` from the set `XKCD_COLORS`. (Colour number `186`)
+/// Colour `XKCD_DEEP_AQUA` from the set `XKCD_COLORS`. (Colour number `186`, name `xkcd:deep aqua`)
 /// ## Representations:
 /// - int tuple `(8, 120, 127)`
 /// - float tuple `(0.03, 0.47, 0.5)`
@@ -1722,7 +1880,7 @@ pub const XKCD_DEEP_AQUA: Color = Color(8, 120, 127);
 
 /// Colour `(r = 221, g = 133, b = 215)`
 ///
-/// Colour `XKCD_LAVENDER_PINK` from the set `XKCD_COLORS`. (Colour number `187`)
+/// Colour `XKCD_LAVENDER_PINK` from the set `XKCD_COLORS`. (Colour number `187`, name `xkcd:lavender pink`)
 /// ## Representations:
 /// - int tuple `(221, 133, 215)`
 /// - float tuple `(0.87, 0.52, 0.84)`
@@ -1731,7 +1889,7 @@ pub const XKCD_LAVENDER_PINK: Color = Color(221, 133, 215);
 
 /// Colour `(r = 166, g = 200, b = 117)`
 ///
-/// Colour `XKCD_LIGHT_MOSS_GREEN` from the set `XKCD_COLORS`. (Colour number `188`)
+/// Colour `XKCD_LIGHT_MOSS_GREEN` from the set `XKCD_COLORS`. (Colour number `188`, name `xkcd:light moss green`)
 /// ## Representations:
 /// - int tuple `(166, 200, 117)`
 /// - float tuple `(0.65, 0.78, 0.46)`
@@ -1740,7 +1898,7 @@ pub const XKCD_LIGHT_MOSS_GREEN: Color = Color(166, 200, 117);
 
 /// Colour `(r = 167, g = 255, b = 181)`
 ///
-/// Colour `XKCD_LIGHT_SEAFOAM_GREEN` from the set `XKCD_COLORS`. (Colour number `189`)
+/// Colour `XKCD_LIGHT_SEAFOAM_GREEN` from the set `XKCD_COLORS`. (Colour number `189`, name `xkcd:light seafoam green`)
 /// ## Representations:
 /// - int tuple `(167, 255, 181)`
 /// - float tuple `(0.65, 1.0, 0.71)`
@@ -1749,7 +1907,7 @@ pub const XKCD_LIGHT_SEAFOAM_GREEN: Color = Color(167, 255, 181);
 
 /// Colour `(r = 194, g = 183, b = 9)`
 ///
-/// Colour `XKCD_OLIVE_YELLOW` from the set `XKCD_COLORS`. (Colour number `190`)
+/// Colour `XKCD_OLIVE_YELLOW` from the set `XKCD_COLORS`. (Colour number `190`, name `xkcd:olive yellow`)
 /// ## Representations:
 /// - int tuple `(194, 183, 9)`
 /// - float tuple `(0.76, 0.72, 0.04)`
@@ -1758,7 +1916,7 @@ pub const XKCD_OLIVE_YELLOW: Color = Color(194, 183, 9);
 
 /// Colour `(r = 231, g = 142, b = 165)`
 ///
-/// Colour `XKCD_PIG_PINK` from the set `XKCD_COLORS`. (Colour number `191`)
+/// Colour `XKCD_PIG_PINK` from the set `XKCD_COLORS`. (Colour number `191`, name `xkcd:pig pink`)
 /// ## Representations:
 /// - int tuple `(231, 142, 165)`
 /// - float tuple `(0.91, 0.56, 0.65)`
@@ -1767,7 +1925,7 @@ pub const XKCD_PIG_PINK: Color = Color(231, 142, 165);
 
 /// Colour `(r = 150, g = 110, b = 189)`
 ///
-/// Colour `XKCD_DEEP_LILAC` from the set `XKCD_COLORS`. (Colour number `192`)
+/// Colour `XKCD_DEEP_LILAC` from the set `XKCD_COLORS`. (Colour number `192`, name `xkcd:deep lilac`)
 /// ## Representations:
 /// - int tuple `(150, 110, 189)`
 /// - float tuple `(0.59, 0.43, 0.74)`
@@ -1776,7 +1934,7 @@ pub const XKCD_DEEP_LILAC: Color = Color(150, 110, 189);
 
 /// Colour `(r = 204, g = 173, b = 96)`
 ///
-/// Colour `XKCD_DESERT` from the set `XKCD_COLORS`. (Colour number `193`)
+/// Colour `XKCD_DESERT` from the set `XKCD_COLORS`. (Colour number `193`, name `xkcd:desert`)
 /// ## Representations:
 /// - int tuple `(204, 173, 96)`
 /// - float tuple `(0.8, 0.68, 0.38)`
@@ -1785,7 +1943,7 @@ pub const XKCD_DESERT: Color = Color(204, 173, 96);
 
 /// Colour `(r = 172, g = 134, b = 168)`
 ///
-/// Colour `XKCD_DUSTY_LAVENDER` from the set `XKCD_COLORS`. (Colour number `194`)
+/// Colour `XKCD_DUSTY_LAVENDER` from the set `XKCD_COLORS`. (Colour number `194`, name `xkcd:dusty lavender`)
 /// ## Representations:
 /// - int tuple `(172, 134, 168)`
 /// - float tuple `(0.67, 0.53, 0.66)`
@@ -1794,7 +1952,7 @@ pub const XKCD_DUSTY_LAVENDER: Color = Color(172, 134, 168);
 
 /// Colour `(r = 148, g = 126, b = 148)`
 ///
-/// Colour `XKCD_PURPLEY_GREY` from the set `XKCD_COLORS`. (Colour number `195`)
+/// Colour `XKCD_PURPLEY_GREY` from the set `XKCD_COLORS`. (Colour number `195`, name `xkcd:purpley grey`)
 /// ## Representations:
 /// - int tuple `(148, 126, 148)`
 /// - float tuple `(0.58, 0.49, 0.58)`
@@ -1803,7 +1961,7 @@ pub const XKCD_PURPLEY_GREY: Color = Color(148, 126, 148);
 
 /// Colour `(r = 152, g = 63, b = 178)`
 ///
-/// Colour `XKCD_PURPLY` from the set `XKCD_COLORS`. (Colour number `196`)
+/// Colour `XKCD_PURPLY` from the set `XKCD_COLORS`. (Colour number `196`, name `xkcd:purply`)
 /// ## Representations:
 /// - int tuple `(152, 63, 178)`
 /// - float tuple `(0.6, 0.25, 0.7)`
@@ -1812,7 +1970,7 @@ pub const XKCD_PURPLY: Color = Color(152, 63, 178);
 
 /// Colour `(r = 255, g = 99, b = 233)`
 ///
-/// Colour `XKCD_CANDY_PINK` from the set `XKCD_COLORS`. (Colour number `197`)
+/// Colour `XKCD_CANDY_PINK` from the set `XKCD_COLORS`. (Colour number `197`, name `xkcd:candy pink`)
 /// ## Representations:
 /// - int tuple `(255, 99, 233)`
 /// - float tuple `(1.0, 0.39, 0.91)`
@@ -1821,7 +1979,7 @@ pub const XKCD_CANDY_PINK: Color = Color(255, 99, 233);
 
 /// Colour `(r = 178, g = 251, b = 165)`
 ///
-/// Colour `XKCD_LIGHT_PASTEL_GREEN` from the set `XKCD_COLORS`. (Colour number `198`)
+/// Colour `XKCD_LIGHT_PASTEL_GREEN` from the set `XKCD_COLORS`. (Colour number `198`, name `xkcd:light pastel green`)
 /// ## Representations:
 /// - int tuple `(178, 251, 165)`
 /// - float tuple `(0.7, 0.98, 0.65)`
@@ -1830,7 +1988,7 @@ pub const XKCD_LIGHT_PASTEL_GREEN: Color = Color(178, 251, 165);
 
 /// Colour `(r = 99, g = 179, b = 101)`
 ///
-/// Colour `XKCD_BORING_GREEN` from the set `XKCD_COLORS`. (Colour number `199`)
+/// Colour `XKCD_BORING_GREEN` from the set `XKCD_COLORS`. (Colour number `199`, name `xkcd:boring green`)
 /// ## Representations:
 /// - int tuple `(99, 179, 101)`
 /// - float tuple `(0.39, 0.7, 0.4)`
@@ -1839,7 +1997,7 @@ pub const XKCD_BORING_GREEN: Color = Color(99, 179, 101);
 
 /// Colour `(r = 142, g = 229, b = 63)`
 ///
-/// Colour `XKCD_KIWI_GREEN` from the set `XKCD_COLORS`. (Colour number `200`)
+/// Colour `XKCD_KIWI_GREEN` from the set `XKCD_COLORS`. (Colour number `200`, name `xkcd:kiwi green`)
 /// ## Representations:
 /// - int tuple `(142, 229, 63)`
 /// - float tuple `(0.56, 0.9, 0.25)`
@@ -1848,7 +2006,7 @@ pub const XKCD_KIWI_GREEN: Color = Color(142, 229, 63);
 
 /// Colour `(r = 183, g = 225, b = 161)`
 ///
-/// Colour `XKCD_LIGHT_GREY_GREEN` from the set `XKCD_COLORS`. (Colour number `201`)
+/// Colour `XKCD_LIGHT_GREY_GREEN` from the set `XKCD_COLORS`. (Colour number `201`, name `xkcd:light grey green`)
 /// ## Representations:
 /// - int tuple `(183, 225, 161)`
 /// - float tuple `(0.72, 0.88, 0.63)`
@@ -1857,7 +2015,7 @@ pub const XKCD_LIGHT_GREY_GREEN: Color = Color(183, 225, 161);
 
 /// Colour `(r = 255, g = 111, b = 82)`
 ///
-/// Colour `XKCD_ORANGE_PINK` from the set `XKCD_COLORS`. (Colour number `202`)
+/// Colour `XKCD_ORANGE_PINK` from the set `XKCD_COLORS`. (Colour number `202`, name `xkcd:orange pink`)
 /// ## Representations:
 /// - int tuple `(255, 111, 82)`
 /// - float tuple `(1.0, 0.44, 0.32)`
@@ -1866,7 +2024,7 @@ pub const XKCD_ORANGE_PINK: Color = Color(255, 111, 82);
 
 /// Colour `(r = 189, g = 248, b = 163)`
 ///
-/// Colour `XKCD_TEA_GREEN` from the set `XKCD_COLORS`. (Colour number `203`)
+/// Colour `XKCD_TEA_GREEN` from the set `XKCD_COLORS`. (Colour number `203`, name `xkcd:tea green`)
 /// ## Representations:
 /// - int tuple `(189, 248, 163)`
 /// - float tuple `(0.74, 0.97, 0.64)`
@@ -1875,7 +2033,7 @@ pub const XKCD_TEA_GREEN: Color = Color(189, 248, 163);
 
 /// Colour `(r = 211, g = 182, b = 131)`
 ///
-/// Colour `XKCD_VERY_LIGHT_BROWN` from the set `XKCD_COLORS`. (Colour number `204`)
+/// Colour `XKCD_VERY_LIGHT_BROWN` from the set `XKCD_COLORS`. (Colour number `204`, name `xkcd:very light brown`)
 /// ## Representations:
 /// - int tuple `(211, 182, 131)`
 /// - float tuple `(0.83, 0.71, 0.51)`
@@ -1884,7 +2042,7 @@ pub const XKCD_VERY_LIGHT_BROWN: Color = Color(211, 182, 131);
 
 /// Colour `(r = 255, g = 252, b = 196)`
 ///
-/// Colour `XKCD_EGG_SHELL` from the set `XKCD_COLORS`. (Colour number `205`)
+/// Colour `XKCD_EGG_SHELL` from the set `XKCD_COLORS`. (Colour number `205`, name `xkcd:egg shell`)
 /// ## Representations:
 /// - int tuple `(255, 252, 196)`
 /// - float tuple `(1.0, 0.99, 0.77)`
@@ -1893,7 +2051,7 @@ pub const XKCD_EGG_SHELL: Color = Color(255, 252, 196);
 
 /// Colour `(r = 67, g = 5, b = 65)`
 ///
-/// Colour `XKCD_EGGPLANT_PURPLE` from the set `XKCD_COLORS`. (Colour number `206`)
+/// Colour `XKCD_EGGPLANT_PURPLE` from the set `XKCD_COLORS`. (Colour number `206`, name `xkcd:eggplant purple`)
 /// ## Representations:
 /// - int tuple `(67, 5, 65)`
 /// - float tuple `(0.26, 0.02, 0.25)`
@@ -1902,7 +2060,7 @@ pub const XKCD_EGGPLANT_PURPLE: Color = Color(67, 5, 65);
 
 /// Colour `(r = 255, g = 178, b = 208)`
 ///
-/// Colour `XKCD_POWDER_PINK` from the set `XKCD_COLORS`. (Colour number `207`)
+/// Colour `XKCD_POWDER_PINK` from the set `XKCD_COLORS`. (Colour number `207`, name `xkcd:powder pink`)
 /// ## Representations:
 /// - int tuple `(255, 178, 208)`
 /// - float tuple `(1.0, 0.7, 0.82)`
@@ -1911,7 +2069,7 @@ pub const XKCD_POWDER_PINK: Color = Color(255, 178, 208);
 
 /// Colour `(r = 153, g = 117, b = 112)`
 ///
-/// Colour `XKCD_REDDISH_GREY` from the set `XKCD_COLORS`. (Colour number `208`)
+/// Colour `XKCD_REDDISH_GREY` from the set `XKCD_COLORS`. (Colour number `208`, name `xkcd:reddish grey`)
 /// ## Representations:
 /// - int tuple `(153, 117, 112)`
 /// - float tuple `(0.6, 0.46, 0.44)`
@@ -1920,7 +2078,7 @@ pub const XKCD_REDDISH_GREY: Color = Color(153, 117, 112);
 
 /// Colour `(r = 173, g = 144, b = 13)`
 ///
-/// Colour `XKCD_BABY_SHIT_BROWN` from the set `XKCD_COLORS`. (Colour number `209`)
+/// Colour `XKCD_BABY_SHIT_BROWN` from the set `XKCD_COLORS`. (Colour number `209`, name `xkcd:baby shit brown`)
 /// ## Representations:
 /// - int tuple `(173, 144, 13)`
 /// - float tuple `(0.68, 0.56, 0.05)`
@@ -1929,7 +2087,7 @@ pub const XKCD_BABY_SHIT_BROWN: Color = Color(173, 144, 13);
 
 /// Colour `(r = 196, g = 142, b = 253)`
 ///
-/// Colour `XKCD_LILIAC` from the set `XKCD_COLORS`. (Colour number `210`)
+/// Colour `XKCD_LILIAC` from the set `XKCD_COLORS`. (Colour number `210`, name `xkcd:liliac`)
 /// ## Representations:
 /// - int tuple `(196, 142, 253)`
 /// - float tuple `(0.77, 0.56, 0.99)`
@@ -1938,7 +2096,7 @@ pub const XKCD_LILIAC: Color = Color(196, 142, 253);
 
 /// Colour `(r = 80, g = 123, b = 156)`
 ///
-/// Colour `XKCD_STORMY_BLUE` from the set `XKCD_COLORS`. (Colour number `211`)
+/// Colour `XKCD_STORMY_BLUE` from the set `XKCD_COLORS`. (Colour number `211`, name `xkcd:stormy blue`)
 /// ## Representations:
 /// - int tuple `(80, 123, 156)`
 /// - float tuple `(0.31, 0.48, 0.61)`
@@ -1947,7 +2105,7 @@ pub const XKCD_STORMY_BLUE: Color = Color(80, 123, 156);
 
 /// Colour `(r = 125, g = 113, b = 3)`
 ///
-/// Colour `XKCD_UGLY_BROWN` from the set `XKCD_COLORS`. (Colour number `212`)
+/// Colour `XKCD_UGLY_BROWN` from the set `XKCD_COLORS`. (Colour number `212`, name `xkcd:ugly brown`)
 /// ## Representations:
 /// - int tuple `(125, 113, 3)`
 /// - float tuple `(0.49, 0.44, 0.01)`
@@ -1956,7 +2114,7 @@ pub const XKCD_UGLY_BROWN: Color = Color(125, 113, 3);
 
 /// Colour `(r = 255, g = 253, b = 120)`
 ///
-/// Colour `XKCD_CUSTARD` from the set `XKCD_COLORS`. (Colour number `213`)
+/// Colour `XKCD_CUSTARD` from the set `XKCD_COLORS`. (Colour number `213`, name `xkcd:custard`)
 /// ## Representations:
 /// - int tuple `(255, 253, 120)`
 /// - float tuple `(1.0, 0.99, 0.47)`
@@ -1965,7 +2123,7 @@ pub const XKCD_CUSTARD: Color = Color(255, 253, 120);
 
 /// Colour `(r = 218, g = 70, b = 125)`
 ///
-/// Colour `XKCD_DARKISH_PINK` from the set `XKCD_COLORS`. (Colour number `214`)
+/// Colour `XKCD_DARKISH_PINK` from the set `XKCD_COLORS`. (Colour number `214`, name `xkcd:darkish pink`)
 /// ## Representations:
 /// - int tuple `(218, 70, 125)`
 /// - float tuple `(0.85, 0.27, 0.49)`
@@ -1974,7 +2132,7 @@ pub const XKCD_DARKISH_PINK: Color = Color(218, 70, 125);
 
 /// Colour `(r = 65, g = 2, b = 0)`
 ///
-/// Colour `XKCD_DEEP_BROWN` from the set `XKCD_COLORS`. (Colour number `215`)
+/// Colour `XKCD_DEEP_BROWN` from the set `XKCD_COLORS`. (Colour number `215`, name `xkcd:deep brown`)
 /// ## Representations:
 /// - int tuple `(65, 2, 0)`
 /// - float tuple `(0.25, 0.01, 0.0)`
@@ -1983,7 +2141,7 @@ pub const XKCD_DEEP_BROWN: Color = Color(65, 2, 0);
 
 /// Colour `(r = 201, g = 209, b = 121)`
 ///
-/// Colour `XKCD_GREENISH_BEIGE` from the set `XKCD_COLORS`. (Colour number `216`)
+/// Colour `XKCD_GREENISH_BEIGE` from the set `XKCD_COLORS`. (Colour number `216`, name `xkcd:greenish beige`)
 /// ## Representations:
 /// - int tuple `(201, 209, 121)`
 /// - float tuple `(0.79, 0.82, 0.47)`
@@ -1992,7 +2150,7 @@ pub const XKCD_GREENISH_BEIGE: Color = Color(201, 209, 121);
 
 /// Colour `(r = 255, g = 250, b = 134)`
 ///
-/// Colour `XKCD_MANILLA` from the set `XKCD_COLORS`. (Colour number `217`)
+/// Colour `XKCD_MANILLA` from the set `XKCD_COLORS`. (Colour number `217`, name `xkcd:manilla`)
 /// ## Representations:
 /// - int tuple `(255, 250, 134)`
 /// - float tuple `(1.0, 0.98, 0.53)`
@@ -2001,7 +2159,7 @@ pub const XKCD_MANILLA: Color = Color(255, 250, 134);
 
 /// Colour `(r = 86, g = 132, b = 174)`
 ///
-/// Colour `XKCD_OFF_BLUE` from the set `XKCD_COLORS`. (Colour number `218`)
+/// Colour `XKCD_OFF_BLUE` from the set `XKCD_COLORS`. (Colour number `218`, name `xkcd:off blue`)
 /// ## Representations:
 /// - int tuple `(86, 132, 174)`
 /// - float tuple `(0.34, 0.52, 0.68)`
@@ -2010,7 +2168,7 @@ pub const XKCD_OFF_BLUE: Color = Color(86, 132, 174);
 
 /// Colour `(r = 107, g = 124, b = 133)`
 ///
-/// Colour `XKCD_BATTLESHIP_GREY` from the set `XKCD_COLORS`. (Colour number `219`)
+/// Colour `XKCD_BATTLESHIP_GREY` from the set `XKCD_COLORS`. (Colour number `219`, name `xkcd:battleship grey`)
 /// ## Representations:
 /// - int tuple `(107, 124, 133)`
 /// - float tuple `(0.42, 0.49, 0.52)`
@@ -2019,7 +2177,7 @@ pub const XKCD_BATTLESHIP_GREY: Color = Color(107, 124, 133);
 
 /// Colour `(r = 111, g = 108, b = 10)`
 ///
-/// Colour `XKCD_BROWNY_GREEN` from the set `XKCD_COLORS`. (Colour number `220`)
+/// Colour `XKCD_BROWNY_GREEN` from the set `XKCD_COLORS`. (Colour number `220`, name `xkcd:browny green`)
 /// ## Representations:
 /// - int tuple `(111, 108, 10)`
 /// - float tuple `(0.44, 0.42, 0.04)`
@@ -2028,7 +2186,7 @@ pub const XKCD_BROWNY_GREEN: Color = Color(111, 108, 10);
 
 /// Colour `(r = 126, g = 64, b = 113)`
 ///
-/// Colour `XKCD_BRUISE` from the set `XKCD_COLORS`. (Colour number `221`)
+/// Colour `XKCD_BRUISE` from the set `XKCD_COLORS`. (Colour number `221`, name `xkcd:bruise`)
 /// ## Representations:
 /// - int tuple `(126, 64, 113)`
 /// - float tuple `(0.49, 0.25, 0.44)`
@@ -2037,7 +2195,7 @@ pub const XKCD_BRUISE: Color = Color(126, 64, 113);
 
 /// Colour `(r = 0, g = 147, b = 55)`
 ///
-/// Colour `XKCD_KELLEY_GREEN` from the set `XKCD_COLORS`. (Colour number `222`)
+/// Colour `XKCD_KELLEY_GREEN` from the set `XKCD_COLORS`. (Colour number `222`, name `xkcd:kelley green`)
 /// ## Representations:
 /// - int tuple `(0, 147, 55)`
 /// - float tuple `(0.0, 0.58, 0.22)`
@@ -2046,7 +2204,7 @@ pub const XKCD_KELLEY_GREEN: Color = Color(0, 147, 55);
 
 /// Colour `(r = 208, g = 228, b = 41)`
 ///
-/// Colour `XKCD_SICKLY_YELLOW` from the set `XKCD_COLORS`. (Colour number `223`)
+/// Colour `XKCD_SICKLY_YELLOW` from the set `XKCD_COLORS`. (Colour number `223`, name `xkcd:sickly yellow`)
 /// ## Representations:
 /// - int tuple `(208, 228, 41)`
 /// - float tuple `(0.82, 0.89, 0.16)`
@@ -2055,7 +2213,7 @@ pub const XKCD_SICKLY_YELLOW: Color = Color(208, 228, 41);
 
 /// Colour `(r = 255, g = 249, b = 23)`
 ///
-/// Colour `XKCD_SUNNY_YELLOW` from the set `XKCD_COLORS`. (Colour number `224`)
+/// Colour `XKCD_SUNNY_YELLOW` from the set `XKCD_COLORS`. (Colour number `224`, name `xkcd:sunny yellow`)
 /// ## Representations:
 /// - int tuple `(255, 249, 23)`
 /// - float tuple `(1.0, 0.98, 0.09)`
@@ -2064,7 +2222,7 @@ pub const XKCD_SUNNY_YELLOW: Color = Color(255, 249, 23);
 
 /// Colour `(r = 29, g = 93, b = 236)`
 ///
-/// Colour `XKCD_AZUL` from the set `XKCD_COLORS`. (Colour number `225`)
+/// Colour `XKCD_AZUL` from the set `XKCD_COLORS`. (Colour number `225`, name `xkcd:azul`)
 /// ## Representations:
 /// - int tuple `(29, 93, 236)`
 /// - float tuple `(0.11, 0.36, 0.93)`
@@ -2073,7 +2231,7 @@ pub const XKCD_AZUL: Color = Color(29, 93, 236);
 
 /// Colour `(r = 5, g = 73, b = 7)`
 ///
-/// Colour `XKCD_DARKGREEN` from the set `XKCD_COLORS`. (Colour number `226`)
+/// Colour `XKCD_DARKGREEN` from the set `XKCD_COLORS`. (Colour number `226`, name `xkcd:darkgreen`)
 /// ## Representations:
 /// - int tuple `(5, 73, 7)`
 /// - float tuple `(0.02, 0.29, 0.03)`
@@ -2082,7 +2240,7 @@ pub const XKCD_DARKGREEN: Color = Color(5, 73, 7);
 
 /// Colour `(r = 181, g = 206, b = 8)`
 ///
-/// Colour `XKCD_GREEN_SLASH_YELLOW` from the set `XKCD_COLORS`. (Colour number `227`)
+/// Colour `XKCD_GREEN_SLASH_YELLOW` from the set `XKCD_COLORS`. (Colour number `227`, name `xkcd:green/yellow`)
 /// ## Representations:
 /// - int tuple `(181, 206, 8)`
 /// - float tuple `(0.71, 0.81, 0.03)`
@@ -2091,7 +2249,7 @@ pub const XKCD_GREEN_SLASH_YELLOW: Color = Color(181, 206, 8);
 
 /// Colour `(r = 143, g = 182, b = 123)`
 ///
-/// Colour `XKCD_LICHEN` from the set `XKCD_COLORS`. (Colour number `228`)
+/// Colour `XKCD_LICHEN` from the set `XKCD_COLORS`. (Colour number `228`, name `xkcd:lichen`)
 /// ## Representations:
 /// - int tuple `(143, 182, 123)`
 /// - float tuple `(0.56, 0.71, 0.48)`
@@ -2100,7 +2258,7 @@ pub const XKCD_LICHEN: Color = Color(143, 182, 123);
 
 /// Colour `(r = 200, g = 255, b = 176)`
 ///
-/// Colour `XKCD_LIGHT_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `229`)
+/// Colour `XKCD_LIGHT_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `229`, name `xkcd:light light green`)
 /// ## Representations:
 /// - int tuple `(200, 255, 176)`
 /// - float tuple `(0.78, 1.0, 0.69)`
@@ -2109,7 +2267,7 @@ pub const XKCD_LIGHT_LIGHT_GREEN: Color = Color(200, 255, 176);
 
 /// Colour `(r = 253, g = 222, b = 108)`
 ///
-/// Colour `XKCD_PALE_GOLD` from the set `XKCD_COLORS`. (Colour number `230`)
+/// Colour `XKCD_PALE_GOLD` from the set `XKCD_COLORS`. (Colour number `230`, name `xkcd:pale gold`)
 /// ## Representations:
 /// - int tuple `(253, 222, 108)`
 /// - float tuple `(0.99, 0.87, 0.42)`
@@ -2118,7 +2276,7 @@ pub const XKCD_PALE_GOLD: Color = Color(253, 222, 108);
 
 /// Colour `(r = 255, g = 223, b = 34)`
 ///
-/// Colour `XKCD_SUN_YELLOW` from the set `XKCD_COLORS`. (Colour number `231`)
+/// Colour `XKCD_SUN_YELLOW` from the set `XKCD_COLORS`. (Colour number `231`, name `xkcd:sun yellow`)
 /// ## Representations:
 /// - int tuple `(255, 223, 34)`
 /// - float tuple `(1.0, 0.87, 0.13)`
@@ -2127,7 +2285,7 @@ pub const XKCD_SUN_YELLOW: Color = Color(255, 223, 34);
 
 /// Colour `(r = 169, g = 190, b = 112)`
 ///
-/// Colour `XKCD_TAN_GREEN` from the set `XKCD_COLORS`. (Colour number `232`)
+/// Colour `XKCD_TAN_GREEN` from the set `XKCD_COLORS`. (Colour number `232`, name `xkcd:tan green`)
 /// ## Representations:
 /// - int tuple `(169, 190, 112)`
 /// - float tuple `(0.66, 0.75, 0.44)`
@@ -2136,7 +2294,7 @@ pub const XKCD_TAN_GREEN: Color = Color(169, 190, 112);
 
 /// Colour `(r = 104, g = 50, b = 227)`
 ///
-/// Colour `XKCD_BURPLE` from the set `XKCD_COLORS`. (Colour number `233`)
+/// Colour `XKCD_BURPLE` from the set `XKCD_COLORS`. (Colour number `233`, name `xkcd:burple`)
 /// ## Representations:
 /// - int tuple `(104, 50, 227)`
 /// - float tuple `(0.41, 0.2, 0.89)`
@@ -2145,7 +2303,7 @@ pub const XKCD_BURPLE: Color = Color(104, 50, 227);
 
 /// Colour `(r = 253, g = 177, b = 71)`
 ///
-/// Colour `XKCD_BUTTERSCOTCH` from the set `XKCD_COLORS`. (Colour number `234`)
+/// Colour `XKCD_BUTTERSCOTCH` from the set `XKCD_COLORS`. (Colour number `234`, name `xkcd:butterscotch`)
 /// ## Representations:
 /// - int tuple `(253, 177, 71)`
 /// - float tuple `(0.99, 0.69, 0.28)`
@@ -2154,7 +2312,7 @@ pub const XKCD_BUTTERSCOTCH: Color = Color(253, 177, 71);
 
 /// Colour `(r = 199, g = 172, b = 125)`
 ///
-/// Colour `XKCD_TOUPE` from the set `XKCD_COLORS`. (Colour number `235`)
+/// Colour `XKCD_TOUPE` from the set `XKCD_COLORS`. (Colour number `235`, name `xkcd:toupe`)
 /// ## Representations:
 /// - int tuple `(199, 172, 125)`
 /// - float tuple `(0.78, 0.67, 0.49)`
@@ -2163,7 +2321,7 @@ pub const XKCD_TOUPE: Color = Color(199, 172, 125);
 
 /// Colour `(r = 255, g = 243, b = 154)`
 ///
-/// Colour `XKCD_DARK_CREAM` from the set `XKCD_COLORS`. (Colour number `236`)
+/// Colour `XKCD_DARK_CREAM` from the set `XKCD_COLORS`. (Colour number `236`, name `xkcd:dark cream`)
 /// ## Representations:
 /// - int tuple `(255, 243, 154)`
 /// - float tuple `(1.0, 0.95, 0.6)`
@@ -2172,7 +2330,7 @@ pub const XKCD_DARK_CREAM: Color = Color(255, 243, 154);
 
 /// Colour `(r = 133, g = 14, b = 4)`
 ///
-/// Colour `XKCD_INDIAN_RED` from the set `XKCD_COLORS`. (Colour number `237`)
+/// Colour `XKCD_INDIAN_RED` from the set `XKCD_COLORS`. (Colour number `237`, name `xkcd:indian red`)
 /// ## Representations:
 /// - int tuple `(133, 14, 4)`
 /// - float tuple `(0.52, 0.05, 0.02)`
@@ -2181,7 +2339,7 @@ pub const XKCD_INDIAN_RED: Color = Color(133, 14, 4);
 
 /// Colour `(r = 239, g = 192, b = 254)`
 ///
-/// Colour `XKCD_LIGHT_LAVENDAR` from the set `XKCD_COLORS`. (Colour number `238`)
+/// Colour `XKCD_LIGHT_LAVENDAR` from the set `XKCD_COLORS`. (Colour number `238`, name `xkcd:light lavendar`)
 /// ## Representations:
 /// - int tuple `(239, 192, 254)`
 /// - float tuple `(0.94, 0.75, 1.0)`
@@ -2190,7 +2348,7 @@ pub const XKCD_LIGHT_LAVENDAR: Color = Color(239, 192, 254);
 
 /// Colour `(r = 64, g = 253, b = 20)`
 ///
-/// Colour `XKCD_POISON_GREEN` from the set `XKCD_COLORS`. (Colour number `239`)
+/// Colour `XKCD_POISON_GREEN` from the set `XKCD_COLORS`. (Colour number `239`, name `xkcd:poison green`)
 /// ## Representations:
 /// - int tuple `(64, 253, 20)`
 /// - float tuple `(0.25, 0.99, 0.08)`
@@ -2199,7 +2357,7 @@ pub const XKCD_POISON_GREEN: Color = Color(64, 253, 20);
 
 /// Colour `(r = 182, g = 196, b = 6)`
 ///
-/// Colour `XKCD_BABY_PUKE_GREEN` from the set `XKCD_COLORS`. (Colour number `240`)
+/// Colour `XKCD_BABY_PUKE_GREEN` from the set `XKCD_COLORS`. (Colour number `240`, name `xkcd:baby puke green`)
 /// ## Representations:
 /// - int tuple `(182, 196, 6)`
 /// - float tuple `(0.71, 0.77, 0.02)`
@@ -2208,7 +2366,7 @@ pub const XKCD_BABY_PUKE_GREEN: Color = Color(182, 196, 6);
 
 /// Colour `(r = 157, g = 255, b = 0)`
 ///
-/// Colour `XKCD_BRIGHT_YELLOW_GREEN` from the set `XKCD_COLORS`. (Colour number `241`)
+/// Colour `XKCD_BRIGHT_YELLOW_GREEN` from the set `XKCD_COLORS`. (Colour number `241`, name `xkcd:bright yellow green`)
 /// ## Representations:
 /// - int tuple `(157, 255, 0)`
 /// - float tuple `(0.62, 1.0, 0.0)`
@@ -2217,7 +2375,7 @@ pub const XKCD_BRIGHT_YELLOW_GREEN: Color = Color(157, 255, 0);
 
 /// Colour `(r = 60, g = 65, b = 66)`
 ///
-/// Colour `XKCD_CHARCOAL_GREY` from the set `XKCD_COLORS`. (Colour number `242`)
+/// Colour `XKCD_CHARCOAL_GREY` from the set `XKCD_COLORS`. (Colour number `242`, name `xkcd:charcoal grey`)
 /// ## Representations:
 /// - int tuple `(60, 65, 66)`
 /// - float tuple `(0.24, 0.25, 0.26)`
@@ -2226,7 +2384,7 @@ pub const XKCD_CHARCOAL_GREY: Color = Color(60, 65, 66);
 
 /// Colour `(r = 242, g = 171, b = 21)`
 ///
-/// Colour `XKCD_SQUASH` from the set `XKCD_COLORS`. (Colour number `243`)
+/// Colour `XKCD_SQUASH` from the set `XKCD_COLORS`. (Colour number `243`, name `xkcd:squash`)
 /// ## Representations:
 /// - int tuple `(242, 171, 21)`
 /// - float tuple `(0.95, 0.67, 0.08)`
@@ -2235,7 +2393,7 @@ pub const XKCD_SQUASH: Color = Color(242, 171, 21);
 
 /// Colour `(r = 172, g = 79, b = 6)`
 ///
-/// Colour `XKCD_CINNAMON` from the set `XKCD_COLORS`. (Colour number `244`)
+/// Colour `XKCD_CINNAMON` from the set `XKCD_COLORS`. (Colour number `244`, name `xkcd:cinnamon`)
 /// ## Representations:
 /// - int tuple `(172, 79, 6)`
 /// - float tuple `(0.67, 0.31, 0.02)`
@@ -2244,7 +2402,7 @@ pub const XKCD_CINNAMON: Color = Color(172, 79, 6);
 
 /// Colour `(r = 196, g = 254, b = 130)`
 ///
-/// Colour `XKCD_LIGHT_PEA_GREEN` from the set `XKCD_COLORS`. (Colour number `245`)
+/// Colour `XKCD_LIGHT_PEA_GREEN` from the set `XKCD_COLORS`. (Colour number `245`, name `xkcd:light pea green`)
 /// ## Representations:
 /// - int tuple `(196, 254, 130)`
 /// - float tuple `(0.77, 1.0, 0.51)`
@@ -2253,7 +2411,7 @@ pub const XKCD_LIGHT_PEA_GREEN: Color = Color(196, 254, 130);
 
 /// Colour `(r = 44, g = 250, b = 31)`
 ///
-/// Colour `XKCD_RADIOACTIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `246`)
+/// Colour `XKCD_RADIOACTIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `246`, name `xkcd:radioactive green`)
 /// ## Representations:
 /// - int tuple `(44, 250, 31)`
 /// - float tuple `(0.17, 0.98, 0.12)`
@@ -2262,7 +2420,7 @@ pub const XKCD_RADIOACTIVE_GREEN: Color = Color(44, 250, 31);
 
 /// Colour `(r = 154, g = 98, b = 0)`
 ///
-/// Colour `XKCD_RAW_SIENNA` from the set `XKCD_COLORS`. (Colour number `247`)
+/// Colour `XKCD_RAW_SIENNA` from the set `XKCD_COLORS`. (Colour number `247`, name `xkcd:raw sienna`)
 /// ## Representations:
 /// - int tuple `(154, 98, 0)`
 /// - float tuple `(0.6, 0.38, 0.0)`
@@ -2271,7 +2429,7 @@ pub const XKCD_RAW_SIENNA: Color = Color(154, 98, 0);
 
 /// Colour `(r = 202, g = 155, b = 247)`
 ///
-/// Colour `XKCD_BABY_PURPLE` from the set `XKCD_COLORS`. (Colour number `248`)
+/// Colour `XKCD_BABY_PURPLE` from the set `XKCD_COLORS`. (Colour number `248`, name `xkcd:baby purple`)
 /// ## Representations:
 /// - int tuple `(202, 155, 247)`
 /// - float tuple `(0.79, 0.61, 0.97)`
@@ -2280,7 +2438,7 @@ pub const XKCD_BABY_PURPLE: Color = Color(202, 155, 247);
 
 /// Colour `(r = 135, g = 95, b = 66)`
 ///
-/// Colour `XKCD_COCOA` from the set `XKCD_COLORS`. (Colour number `249`)
+/// Colour `XKCD_COCOA` from the set `XKCD_COLORS`. (Colour number `249`, name `xkcd:cocoa`)
 /// ## Representations:
 /// - int tuple `(135, 95, 66)`
 /// - float tuple `(0.53, 0.37, 0.26)`
@@ -2289,7 +2447,7 @@ pub const XKCD_COCOA: Color = Color(135, 95, 66);
 
 /// Colour `(r = 58, g = 46, b = 254)`
 ///
-/// Colour `XKCD_LIGHT_ROYAL_BLUE` from the set `XKCD_COLORS`. (Colour number `250`)
+/// Colour `XKCD_LIGHT_ROYAL_BLUE` from the set `XKCD_COLORS`. (Colour number `250`, name `xkcd:light royal blue`)
 /// ## Representations:
 /// - int tuple `(58, 46, 254)`
 /// - float tuple `(0.23, 0.18, 1.0)`
@@ -2298,7 +2456,7 @@ pub const XKCD_LIGHT_ROYAL_BLUE: Color = Color(58, 46, 254);
 
 /// Colour `(r = 253, g = 141, b = 73)`
 ///
-/// Colour `XKCD_ORANGEISH` from the set `XKCD_COLORS`. (Colour number `251`)
+/// Colour `XKCD_ORANGEISH` from the set `XKCD_COLORS`. (Colour number `251`, name `xkcd:orangeish`)
 /// ## Representations:
 /// - int tuple `(253, 141, 73)`
 /// - float tuple `(0.99, 0.55, 0.29)`
@@ -2307,7 +2465,7 @@ pub const XKCD_ORANGEISH: Color = Color(253, 141, 73);
 
 /// Colour `(r = 139, g = 49, b = 3)`
 ///
-/// Colour `XKCD_RUST_BROWN` from the set `XKCD_COLORS`. (Colour number `252`)
+/// Colour `XKCD_RUST_BROWN` from the set `XKCD_COLORS`. (Colour number `252`, name `xkcd:rust brown`)
 /// ## Representations:
 /// - int tuple `(139, 49, 3)`
 /// - float tuple `(0.55, 0.19, 0.01)`
@@ -2316,7 +2474,7 @@ pub const XKCD_RUST_BROWN: Color = Color(139, 49, 3);
 
 /// Colour `(r = 203, g = 165, b = 96)`
 ///
-/// Colour `XKCD_SAND_BROWN` from the set `XKCD_COLORS`. (Colour number `253`)
+/// Colour `XKCD_SAND_BROWN` from the set `XKCD_COLORS`. (Colour number `253`, name `xkcd:sand brown`)
 /// ## Representations:
 /// - int tuple `(203, 165, 96)`
 /// - float tuple `(0.8, 0.65, 0.38)`
@@ -2325,7 +2483,7 @@ pub const XKCD_SAND_BROWN: Color = Color(203, 165, 96);
 
 /// Colour `(r = 105, g = 131, b = 57)`
 ///
-/// Colour `XKCD_SWAMP` from the set `XKCD_COLORS`. (Colour number `254`)
+/// Colour `XKCD_SWAMP` from the set `XKCD_COLORS`. (Colour number `254`, name `xkcd:swamp`)
 /// ## Representations:
 /// - int tuple `(105, 131, 57)`
 /// - float tuple `(0.41, 0.51, 0.22)`
@@ -2334,7 +2492,7 @@ pub const XKCD_SWAMP: Color = Color(105, 131, 57);
 
 /// Colour `(r = 12, g = 220, b = 115)`
 ///
-/// Colour `XKCD_TEALISH_GREEN` from the set `XKCD_COLORS`. (Colour number `255`)
+/// Colour `XKCD_TEALISH_GREEN` from the set `XKCD_COLORS`. (Colour number `255`, name `xkcd:tealish green`)
 /// ## Representations:
 /// - int tuple `(12, 220, 115)`
 /// - float tuple `(0.05, 0.86, 0.45)`
@@ -2343,7 +2501,7 @@ pub const XKCD_TEALISH_GREEN: Color = Color(12, 220, 115);
 
 /// Colour `(r = 183, g = 82, b = 3)`
 ///
-/// Colour `XKCD_BURNT_SIENA` from the set `XKCD_COLORS`. (Colour number `256`)
+/// Colour `XKCD_BURNT_SIENA` from the set `XKCD_COLORS`. (Colour number `256`, name `xkcd:burnt siena`)
 /// ## Representations:
 /// - int tuple `(183, 82, 3)`
 /// - float tuple `(0.72, 0.32, 0.01)`
@@ -2352,7 +2510,7 @@ pub const XKCD_BURNT_SIENA: Color = Color(183, 82, 3);
 
 /// Colour `(r = 127, g = 143, b = 78)`
 ///
-/// Colour `XKCD_CAMO` from the set `XKCD_COLORS`. (Colour number `257`)
+/// Colour `XKCD_CAMO` from the set `XKCD_COLORS`. (Colour number `257`, name `xkcd:camo`)
 /// ## Representations:
 /// - int tuple `(127, 143, 78)`
 /// - float tuple `(0.5, 0.56, 0.31)`
@@ -2361,7 +2519,7 @@ pub const XKCD_CAMO: Color = Color(127, 143, 78);
 
 /// Colour `(r = 38, g = 83, b = 141)`
 ///
-/// Colour `XKCD_DUSK_BLUE` from the set `XKCD_COLORS`. (Colour number `258`)
+/// Colour `XKCD_DUSK_BLUE` from the set `XKCD_COLORS`. (Colour number `258`, name `xkcd:dusk blue`)
 /// ## Representations:
 /// - int tuple `(38, 83, 141)`
 /// - float tuple `(0.15, 0.33, 0.55)`
@@ -2370,7 +2528,7 @@ pub const XKCD_DUSK_BLUE: Color = Color(38, 83, 141);
 
 /// Colour `(r = 99, g = 169, b = 80)`
 ///
-/// Colour `XKCD_FERN` from the set `XKCD_COLORS`. (Colour number `259`)
+/// Colour `XKCD_FERN` from the set `XKCD_COLORS`. (Colour number `259`, name `xkcd:fern`)
 /// ## Representations:
 /// - int tuple `(99, 169, 80)`
 /// - float tuple `(0.39, 0.66, 0.31)`
@@ -2379,7 +2537,7 @@ pub const XKCD_FERN: Color = Color(99, 169, 80);
 
 /// Colour `(r = 200, g = 127, b = 137)`
 ///
-/// Colour `XKCD_OLD_ROSE` from the set `XKCD_COLORS`. (Colour number `260`)
+/// Colour `XKCD_OLD_ROSE` from the set `XKCD_COLORS`. (Colour number `260`, name `xkcd:old rose`)
 /// ## Representations:
 /// - int tuple `(200, 127, 137)`
 /// - float tuple `(0.78, 0.5, 0.54)`
@@ -2388,7 +2546,7 @@ pub const XKCD_OLD_ROSE: Color = Color(200, 127, 137);
 
 /// Colour `(r = 177, g = 252, b = 153)`
 ///
-/// Colour `XKCD_PALE_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `261`)
+/// Colour `XKCD_PALE_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `261`, name `xkcd:pale light green`)
 /// ## Representations:
 /// - int tuple `(177, 252, 153)`
 /// - float tuple `(0.69, 0.99, 0.6)`
@@ -2397,7 +2555,7 @@ pub const XKCD_PALE_LIGHT_GREEN: Color = Color(177, 252, 153);
 
 /// Colour `(r = 255, g = 154, b = 138)`
 ///
-/// Colour `XKCD_PEACHY_PINK` from the set `XKCD_COLORS`. (Colour number `262`)
+/// Colour `XKCD_PEACHY_PINK` from the set `XKCD_COLORS`. (Colour number `262`, name `xkcd:peachy pink`)
 /// ## Representations:
 /// - int tuple `(255, 154, 138)`
 /// - float tuple `(1.0, 0.6, 0.54)`
@@ -2406,7 +2564,7 @@ pub const XKCD_PEACHY_PINK: Color = Color(255, 154, 138);
 
 /// Colour `(r = 246, g = 104, b = 142)`
 ///
-/// Colour `XKCD_ROSY_PINK` from the set `XKCD_COLORS`. (Colour number `263`)
+/// Colour `XKCD_ROSY_PINK` from the set `XKCD_COLORS`. (Colour number `263`, name `xkcd:rosy pink`)
 /// ## Representations:
 /// - int tuple `(246, 104, 142)`
 /// - float tuple `(0.96, 0.41, 0.56)`
@@ -2415,7 +2573,7 @@ pub const XKCD_ROSY_PINK: Color = Color(246, 104, 142);
 
 /// Colour `(r = 118, g = 253, b = 168)`
 ///
-/// Colour `XKCD_LIGHT_BLUISH_GREEN` from the set `XKCD_COLORS`. (Colour number `264`)
+/// Colour `XKCD_LIGHT_BLUISH_GREEN` from the set `XKCD_COLORS`. (Colour number `264`, name `xkcd:light bluish green`)
 /// ## Representations:
 /// - int tuple `(118, 253, 168)`
 /// - float tuple `(0.46, 0.99, 0.66)`
@@ -2424,7 +2582,7 @@ pub const XKCD_LIGHT_BLUISH_GREEN: Color = Color(118, 253, 168);
 
 /// Colour `(r = 83, g = 254, b = 92)`
 ///
-/// Colour `XKCD_LIGHT_BRIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `265`)
+/// Colour `XKCD_LIGHT_BRIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `265`, name `xkcd:light bright green`)
 /// ## Representations:
 /// - int tuple `(83, 254, 92)`
 /// - float tuple `(0.33, 1.0, 0.36)`
@@ -2433,7 +2591,7 @@ pub const XKCD_LIGHT_BRIGHT_GREEN: Color = Color(83, 254, 92);
 
 /// Colour `(r = 78, g = 253, b = 84)`
 ///
-/// Colour `XKCD_LIGHT_NEON_GREEN` from the set `XKCD_COLORS`. (Colour number `266`)
+/// Colour `XKCD_LIGHT_NEON_GREEN` from the set `XKCD_COLORS`. (Colour number `266`, name `xkcd:light neon green`)
 /// ## Representations:
 /// - int tuple `(78, 253, 84)`
 /// - float tuple `(0.31, 0.99, 0.33)`
@@ -2442,7 +2600,7 @@ pub const XKCD_LIGHT_NEON_GREEN: Color = Color(78, 253, 84);
 
 /// Colour `(r = 160, g = 254, b = 191)`
 ///
-/// Colour `XKCD_LIGHT_SEAFOAM` from the set `XKCD_COLORS`. (Colour number `267`)
+/// Colour `XKCD_LIGHT_SEAFOAM` from the set `XKCD_COLORS`. (Colour number `267`, name `xkcd:light seafoam`)
 /// ## Representations:
 /// - int tuple `(160, 254, 191)`
 /// - float tuple `(0.63, 1.0, 0.75)`
@@ -2451,7 +2609,7 @@ pub const XKCD_LIGHT_SEAFOAM: Color = Color(160, 254, 191);
 
 /// Colour `(r = 123, g = 242, b = 218)`
 ///
-/// Colour `XKCD_TIFFANY_BLUE` from the set `XKCD_COLORS`. (Colour number `268`)
+/// Colour `XKCD_TIFFANY_BLUE` from the set `XKCD_COLORS`. (Colour number `268`, name `xkcd:tiffany blue`)
 /// ## Representations:
 /// - int tuple `(123, 242, 218)`
 /// - float tuple `(0.48, 0.95, 0.85)`
@@ -2460,7 +2618,7 @@ pub const XKCD_TIFFANY_BLUE: Color = Color(123, 242, 218);
 
 /// Colour `(r = 188, g = 245, b = 166)`
 ///
-/// Colour `XKCD_WASHED_OUT_GREEN` from the set `XKCD_COLORS`. (Colour number `269`)
+/// Colour `XKCD_WASHED_OUT_GREEN` from the set `XKCD_COLORS`. (Colour number `269`, name `xkcd:washed out green`)
 /// ## Representations:
 /// - int tuple `(188, 245, 166)`
 /// - float tuple `(0.74, 0.96, 0.65)`
@@ -2469,7 +2627,7 @@ pub const XKCD_WASHED_OUT_GREEN: Color = Color(188, 245, 166);
 
 /// Colour `(r = 202, g = 107, b = 2)`
 ///
-/// Colour `XKCD_BROWNY_ORANGE` from the set `XKCD_COLORS`. (Colour number `270`)
+/// Colour `XKCD_BROWNY_ORANGE` from the set `XKCD_COLORS`. (Colour number `270`, name `xkcd:browny orange`)
 /// ## Representations:
 /// - int tuple `(202, 107, 2)`
 /// - float tuple `(0.79, 0.42, 0.01)`
@@ -2478,7 +2636,7 @@ pub const XKCD_BROWNY_ORANGE: Color = Color(202, 107, 2);
 
 /// Colour `(r = 16, g = 122, b = 176)`
 ///
-/// Colour `XKCD_NICE_BLUE` from the set `XKCD_COLORS`. (Colour number `271`)
+/// Colour `XKCD_NICE_BLUE` from the set `XKCD_COLORS`. (Colour number `271`, name `xkcd:nice blue`)
 /// ## Representations:
 /// - int tuple `(16, 122, 176)`
 /// - float tuple `(0.06, 0.48, 0.69)`
@@ -2487,7 +2645,7 @@ pub const XKCD_NICE_BLUE: Color = Color(16, 122, 176);
 
 /// Colour `(r = 33, g = 56, b = 171)`
 ///
-/// Colour `XKCD_SAPPHIRE` from the set `XKCD_COLORS`. (Colour number `272`)
+/// Colour `XKCD_SAPPHIRE` from the set `XKCD_COLORS`. (Colour number `272`, name `xkcd:sapphire`)
 /// ## Representations:
 /// - int tuple `(33, 56, 171)`
 /// - float tuple `(0.13, 0.22, 0.67)`
@@ -2496,7 +2654,7 @@ pub const XKCD_SAPPHIRE: Color = Color(33, 56, 171);
 
 /// Colour `(r = 113, g = 159, b = 145)`
 ///
-/// Colour `XKCD_GREYISH_TEAL` from the set `XKCD_COLORS`. (Colour number `273`)
+/// Colour `XKCD_GREYISH_TEAL` from the set `XKCD_COLORS`. (Colour number `273`, name `xkcd:greyish teal`)
 /// ## Representations:
 /// - int tuple `(113, 159, 145)`
 /// - float tuple `(0.44, 0.62, 0.57)`
@@ -2505,7 +2663,7 @@ pub const XKCD_GREYISH_TEAL: Color = Color(113, 159, 145);
 
 /// Colour `(r = 253, g = 185, b = 21)`
 ///
-/// Colour `XKCD_ORANGEY_YELLOW` from the set `XKCD_COLORS`. (Colour number `274`)
+/// Colour `XKCD_ORANGEY_YELLOW` from the set `XKCD_COLORS`. (Colour number `274`, name `xkcd:orangey yellow`)
 /// ## Representations:
 /// - int tuple `(253, 185, 21)`
 /// - float tuple `(0.99, 0.73, 0.08)`
@@ -2514,7 +2672,7 @@ pub const XKCD_ORANGEY_YELLOW: Color = Color(253, 185, 21);
 
 /// Colour `(r = 254, g = 252, b = 175)`
 ///
-/// Colour `XKCD_PARCHMENT` from the set `XKCD_COLORS`. (Colour number `275`)
+/// Colour `XKCD_PARCHMENT` from the set `XKCD_COLORS`. (Colour number `275`, name `xkcd:parchment`)
 /// ## Representations:
 /// - int tuple `(254, 252, 175)`
 /// - float tuple `(1.0, 0.99, 0.69)`
@@ -2523,7 +2681,7 @@ pub const XKCD_PARCHMENT: Color = Color(254, 252, 175);
 
 /// Colour `(r = 252, g = 246, b = 121)`
 ///
-/// Colour `XKCD_STRAW` from the set `XKCD_COLORS`. (Colour number `276`)
+/// Colour `XKCD_STRAW` from the set `XKCD_COLORS`. (Colour number `276`, name `xkcd:straw`)
 /// ## Representations:
 /// - int tuple `(252, 246, 121)`
 /// - float tuple `(0.99, 0.96, 0.47)`
@@ -2532,7 +2690,7 @@ pub const XKCD_STRAW: Color = Color(252, 246, 121);
 
 /// Colour `(r = 29, g = 2, b = 0)`
 ///
-/// Colour `XKCD_VERY_DARK_BROWN` from the set `XKCD_COLORS`. (Colour number `277`)
+/// Colour `XKCD_VERY_DARK_BROWN` from the set `XKCD_COLORS`. (Colour number `277`, name `xkcd:very dark brown`)
 /// ## Representations:
 /// - int tuple `(29, 2, 0)`
 /// - float tuple `(0.11, 0.01, 0.0)`
@@ -2541,7 +2699,7 @@ pub const XKCD_VERY_DARK_BROWN: Color = Color(29, 2, 0);
 
 /// Colour `(r = 203, g = 104, b = 67)`
 ///
-/// Colour `XKCD_TERRACOTA` from the set `XKCD_COLORS`. (Colour number `278`)
+/// Colour `XKCD_TERRACOTA` from the set `XKCD_COLORS`. (Colour number `278`, name `xkcd:terracota`)
 /// ## Representations:
 /// - int tuple `(203, 104, 67)`
 /// - float tuple `(0.8, 0.41, 0.26)`
@@ -2550,7 +2708,7 @@ pub const XKCD_TERRACOTA: Color = Color(203, 104, 67);
 
 /// Colour `(r = 49, g = 102, b = 138)`
 ///
-/// Colour `XKCD_UGLY_BLUE` from the set `XKCD_COLORS`. (Colour number `279`)
+/// Colour `XKCD_UGLY_BLUE` from the set `XKCD_COLORS`. (Colour number `279`, name `xkcd:ugly blue`)
 /// ## Representations:
 /// - int tuple `(49, 102, 138)`
 /// - float tuple `(0.19, 0.4, 0.54)`
@@ -2559,7 +2717,7 @@ pub const XKCD_UGLY_BLUE: Color = Color(49, 102, 138);
 
 /// Colour `(r = 36, g = 122, b = 253)`
 ///
-/// Colour `XKCD_CLEAR_BLUE` from the set `XKCD_COLORS`. (Colour number `280`)
+/// Colour `XKCD_CLEAR_BLUE` from the set `XKCD_COLORS`. (Colour number `280`, name `xkcd:clear blue`)
 /// ## Representations:
 /// - int tuple `(36, 122, 253)`
 /// - float tuple `(0.14, 0.48, 0.99)`
@@ -2568,7 +2726,7 @@ pub const XKCD_CLEAR_BLUE: Color = Color(36, 122, 253);
 
 /// Colour `(r = 255, g = 255, b = 182)`
 ///
-/// Colour `XKCD_CREME` from the set `XKCD_COLORS`. (Colour number `281`)
+/// Colour `XKCD_CREME` from the set `XKCD_COLORS`. (Colour number `281`, name `xkcd:creme`)
 /// ## Representations:
 /// - int tuple `(255, 255, 182)`
 /// - float tuple `(1.0, 1.0, 0.71)`
@@ -2577,7 +2735,7 @@ pub const XKCD_CREME: Color = Color(255, 255, 182);
 
 /// Colour `(r = 144, g = 253, b = 169)`
 ///
-/// Colour `XKCD_FOAM_GREEN` from the set `XKCD_COLORS`. (Colour number `282`)
+/// Colour `XKCD_FOAM_GREEN` from the set `XKCD_COLORS`. (Colour number `282`, name `xkcd:foam green`)
 /// ## Representations:
 /// - int tuple `(144, 253, 169)`
 /// - float tuple `(0.56, 0.99, 0.66)`
@@ -2586,7 +2744,7 @@ pub const XKCD_FOAM_GREEN: Color = Color(144, 253, 169);
 
 /// Colour `(r = 134, g = 161, b = 125)`
 ///
-/// Colour `XKCD_GREY_SLASH_GREEN` from the set `XKCD_COLORS`. (Colour number `283`)
+/// Colour `XKCD_GREY_SLASH_GREEN` from the set `XKCD_COLORS`. (Colour number `283`, name `xkcd:grey/green`)
 /// ## Representations:
 /// - int tuple `(134, 161, 125)`
 /// - float tuple `(0.53, 0.63, 0.49)`
@@ -2595,7 +2753,7 @@ pub const XKCD_GREY_SLASH_GREEN: Color = Color(134, 161, 125);
 
 /// Colour `(r = 253, g = 220, b = 92)`
 ///
-/// Colour `XKCD_LIGHT_GOLD` from the set `XKCD_COLORS`. (Colour number `284`)
+/// Colour `XKCD_LIGHT_GOLD` from the set `XKCD_COLORS`. (Colour number `284`, name `xkcd:light gold`)
 /// ## Representations:
 /// - int tuple `(253, 220, 92)`
 /// - float tuple `(0.99, 0.86, 0.36)`
@@ -2604,7 +2762,7 @@ pub const XKCD_LIGHT_GOLD: Color = Color(253, 220, 92);
 
 /// Colour `(r = 120, g = 209, b = 182)`
 ///
-/// Colour `XKCD_SEAFOAM_BLUE` from the set `XKCD_COLORS`. (Colour number `285`)
+/// Colour `XKCD_SEAFOAM_BLUE` from the set `XKCD_COLORS`. (Colour number `285`, name `xkcd:seafoam blue`)
 /// ## Representations:
 /// - int tuple `(120, 209, 182)`
 /// - float tuple `(0.47, 0.82, 0.71)`
@@ -2613,7 +2771,7 @@ pub const XKCD_SEAFOAM_BLUE: Color = Color(120, 209, 182);
 
 /// Colour `(r = 19, g = 187, b = 175)`
 ///
-/// Colour `XKCD_TOPAZ` from the set `XKCD_COLORS`. (Colour number `286`)
+/// Colour `XKCD_TOPAZ` from the set `XKCD_COLORS`. (Colour number `286`, name `xkcd:topaz`)
 /// ## Representations:
 /// - int tuple `(19, 187, 175)`
 /// - float tuple `(0.07, 0.73, 0.69)`
@@ -2622,7 +2780,7 @@ pub const XKCD_TOPAZ: Color = Color(19, 187, 175);
 
 /// Colour `(r = 251, g = 95, b = 252)`
 ///
-/// Colour `XKCD_VIOLET_PINK` from the set `XKCD_COLORS`. (Colour number `287`)
+/// Colour `XKCD_VIOLET_PINK` from the set `XKCD_COLORS`. (Colour number `287`, name `xkcd:violet pink`)
 /// ## Representations:
 /// - int tuple `(251, 95, 252)`
 /// - float tuple `(0.98, 0.37, 0.99)`
@@ -2631,7 +2789,7 @@ pub const XKCD_VIOLET_PINK: Color = Color(251, 95, 252);
 
 /// Colour `(r = 32, g = 249, b = 134)`
 ///
-/// Colour `XKCD_WINTERGREEN` from the set `XKCD_COLORS`. (Colour number `288`)
+/// Colour `XKCD_WINTERGREEN` from the set `XKCD_COLORS`. (Colour number `288`, name `xkcd:wintergreen`)
 /// ## Representations:
 /// - int tuple `(32, 249, 134)`
 /// - float tuple `(0.13, 0.98, 0.53)`
@@ -2640,7 +2798,7 @@ pub const XKCD_WINTERGREEN: Color = Color(32, 249, 134);
 
 /// Colour `(r = 255, g = 227, b = 110)`
 ///
-/// Colour `XKCD_YELLOW_TAN` from the set `XKCD_COLORS`. (Colour number `289`)
+/// Colour `XKCD_YELLOW_TAN` from the set `XKCD_COLORS`. (Colour number `289`, name `xkcd:yellow tan`)
 /// ## Representations:
 /// - int tuple `(255, 227, 110)`
 /// - float tuple `(1.0, 0.89, 0.43)`
@@ -2649,7 +2807,7 @@ pub const XKCD_YELLOW_TAN: Color = Color(255, 227, 110);
 
 /// Colour `(r = 157, g = 7, b = 89)`
 ///
-/// Colour `XKCD_DARK_FUCHSIA` from the set `XKCD_COLORS`. (Colour number `290`)
+/// Colour `XKCD_DARK_FUCHSIA` from the set `XKCD_COLORS`. (Colour number `290`, name `xkcd:dark fuchsia`)
 /// ## Representations:
 /// - int tuple `(157, 7, 89)`
 /// - float tuple `(0.62, 0.03, 0.35)`
@@ -2658,7 +2816,7 @@ pub const XKCD_DARK_FUCHSIA: Color = Color(157, 7, 89);
 
 /// Colour `(r = 58, g = 24, b = 177)`
 ///
-/// Colour `XKCD_INDIGO_BLUE` from the set `XKCD_COLORS`. (Colour number `291`)
+/// Colour `XKCD_INDIGO_BLUE` from the set `XKCD_COLORS`. (Colour number `291`, name `xkcd:indigo blue`)
 /// ## Representations:
 /// - int tuple `(58, 24, 177)`
 /// - float tuple `(0.23, 0.09, 0.69)`
@@ -2667,7 +2825,7 @@ pub const XKCD_INDIGO_BLUE: Color = Color(58, 24, 177);
 
 /// Colour `(r = 194, g = 255, b = 137)`
 ///
-/// Colour `XKCD_LIGHT_YELLOWISH_GREEN` from the set `XKCD_COLORS`. (Colour number `292`)
+/// Colour `XKCD_LIGHT_YELLOWISH_GREEN` from the set `XKCD_COLORS`. (Colour number `292`, name `xkcd:light yellowish green`)
 /// ## Representations:
 /// - int tuple `(194, 255, 137)`
 /// - float tuple `(0.76, 1.0, 0.54)`
@@ -2676,7 +2834,7 @@ pub const XKCD_LIGHT_YELLOWISH_GREEN: Color = Color(194, 255, 137);
 
 /// Colour `(r = 215, g = 103, b = 173)`
 ///
-/// Colour `XKCD_PALE_MAGENTA` from the set `XKCD_COLORS`. (Colour number `293`)
+/// Colour `XKCD_PALE_MAGENTA` from the set `XKCD_COLORS`. (Colour number `293`, name `xkcd:pale magenta`)
 /// ## Representations:
 /// - int tuple `(215, 103, 173)`
 /// - float tuple `(0.84, 0.4, 0.68)`
@@ -2685,7 +2843,7 @@ pub const XKCD_PALE_MAGENTA: Color = Color(215, 103, 173);
 
 /// Colour `(r = 114, g = 0, b = 88)`
 ///
-/// Colour `XKCD_RICH_PURPLE` from the set `XKCD_COLORS`. (Colour number `294`)
+/// Colour `XKCD_RICH_PURPLE` from the set `XKCD_COLORS`. (Colour number `294`, name `xkcd:rich purple`)
 /// ## Representations:
 /// - int tuple `(114, 0, 88)`
 /// - float tuple `(0.45, 0.0, 0.35)`
@@ -2694,7 +2852,7 @@ pub const XKCD_RICH_PURPLE: Color = Color(114, 0, 88);
 
 /// Colour `(r = 255, g = 218, b = 3)`
 ///
-/// Colour `XKCD_SUNFLOWER_YELLOW` from the set `XKCD_COLORS`. (Colour number `295`)
+/// Colour `XKCD_SUNFLOWER_YELLOW` from the set `XKCD_COLORS`. (Colour number `295`, name `xkcd:sunflower yellow`)
 /// ## Representations:
 /// - int tuple `(255, 218, 3)`
 /// - float tuple `(1.0, 0.85, 0.01)`
@@ -2703,7 +2861,7 @@ pub const XKCD_SUNFLOWER_YELLOW: Color = Color(255, 218, 3);
 
 /// Colour `(r = 1, g = 192, b = 141)`
 ///
-/// Colour `XKCD_GREEN_SLASH_BLUE` from the set `XKCD_COLORS`. (Colour number `296`)
+/// Colour `XKCD_GREEN_SLASH_BLUE` from the set `XKCD_COLORS`. (Colour number `296`, name `xkcd:green/blue`)
 /// ## Representations:
 /// - int tuple `(1, 192, 141)`
 /// - float tuple `(0.0, 0.75, 0.55)`
@@ -2712,7 +2870,7 @@ pub const XKCD_GREEN_SLASH_BLUE: Color = Color(1, 192, 141);
 
 /// Colour `(r = 172, g = 116, b = 52)`
 ///
-/// Colour `XKCD_LEATHER` from the set `XKCD_COLORS`. (Colour number `297`)
+/// Colour `XKCD_LEATHER` from the set `XKCD_COLORS`. (Colour number `297`, name `xkcd:leather`)
 /// ## Representations:
 /// - int tuple `(172, 116, 52)`
 /// - float tuple `(0.67, 0.45, 0.2)`
@@ -2721,7 +2879,7 @@ pub const XKCD_LEATHER: Color = Color(172, 116, 52);
 
 /// Colour `(r = 1, g = 70, b = 0)`
 ///
-/// Colour `XKCD_RACING_GREEN` from the set `XKCD_COLORS`. (Colour number `298`)
+/// Colour `XKCD_RACING_GREEN` from the set `XKCD_COLORS`. (Colour number `298`, name `xkcd:racing green`)
 /// ## Representations:
 /// - int tuple `(1, 70, 0)`
 /// - float tuple `(0.0, 0.27, 0.0)`
@@ -2730,7 +2888,7 @@ pub const XKCD_RACING_GREEN: Color = Color(1, 70, 0);
 
 /// Colour `(r = 153, g = 0, b = 250)`
 ///
-/// Colour `XKCD_VIVID_PURPLE` from the set `XKCD_COLORS`. (Colour number `299`)
+/// Colour `XKCD_VIVID_PURPLE` from the set `XKCD_COLORS`. (Colour number `299`, name `xkcd:vivid purple`)
 /// ## Representations:
 /// - int tuple `(153, 0, 250)`
 /// - float tuple `(0.6, 0.0, 0.98)`
@@ -2739,7 +2897,7 @@ pub const XKCD_VIVID_PURPLE: Color = Color(153, 0, 250);
 
 /// Colour `(r = 2, g = 6, b = 111)`
 ///
-/// Colour `XKCD_DARK_ROYAL_BLUE` from the set `XKCD_COLORS`. (Colour number `300`)
+/// Colour `XKCD_DARK_ROYAL_BLUE` from the set `XKCD_COLORS`. (Colour number `300`, name `xkcd:dark royal blue`)
 /// ## Representations:
 /// - int tuple `(2, 6, 111)`
 /// - float tuple `(0.01, 0.02, 0.44)`
@@ -2748,7 +2906,7 @@ pub const XKCD_DARK_ROYAL_BLUE: Color = Color(2, 6, 111);
 
 /// Colour `(r = 142, g = 118, b = 24)`
 ///
-/// Colour `XKCD_HAZEL` from the set `XKCD_COLORS`. (Colour number `301`)
+/// Colour `XKCD_HAZEL` from the set `XKCD_COLORS`. (Colour number `301`, name `xkcd:hazel`)
 /// ## Representations:
 /// - int tuple `(142, 118, 24)`
 /// - float tuple `(0.56, 0.46, 0.09)`
@@ -2757,7 +2915,7 @@ pub const XKCD_HAZEL: Color = Color(142, 118, 24);
 
 /// Colour `(r = 209, g = 118, b = 143)`
 ///
-/// Colour `XKCD_MUTED_PINK` from the set `XKCD_COLORS`. (Colour number `302`)
+/// Colour `XKCD_MUTED_PINK` from the set `XKCD_COLORS`. (Colour number `302`, name `xkcd:muted pink`)
 /// ## Representations:
 /// - int tuple `(209, 118, 143)`
 /// - float tuple `(0.82, 0.46, 0.56)`
@@ -2766,7 +2924,7 @@ pub const XKCD_MUTED_PINK: Color = Color(209, 118, 143);
 
 /// Colour `(r = 150, g = 180, b = 3)`
 ///
-/// Colour `XKCD_BOOGER_GREEN` from the set `XKCD_COLORS`. (Colour number `303`)
+/// Colour `XKCD_BOOGER_GREEN` from the set `XKCD_COLORS`. (Colour number `303`, name `xkcd:booger green`)
 /// ## Representations:
 /// - int tuple `(150, 180, 3)`
 /// - float tuple `(0.59, 0.71, 0.01)`
@@ -2775,7 +2933,7 @@ pub const XKCD_BOOGER_GREEN: Color = Color(150, 180, 3);
 
 /// Colour `(r = 253, g = 255, b = 99)`
 ///
-/// Colour `XKCD_CANARY` from the set `XKCD_COLORS`. (Colour number `304`)
+/// Colour `XKCD_CANARY` from the set `XKCD_COLORS`. (Colour number `304`, name `xkcd:canary`)
 /// ## Representations:
 /// - int tuple `(253, 255, 99)`
 /// - float tuple `(0.99, 1.0, 0.39)`
@@ -2784,7 +2942,7 @@ pub const XKCD_CANARY: Color = Color(253, 255, 99);
 
 /// Colour `(r = 149, g = 163, b = 166)`
 ///
-/// Colour `XKCD_COOL_GREY` from the set `XKCD_COLORS`. (Colour number `305`)
+/// Colour `XKCD_COOL_GREY` from the set `XKCD_COLORS`. (Colour number `305`, name `xkcd:cool grey`)
 /// ## Representations:
 /// - int tuple `(149, 163, 166)`
 /// - float tuple `(0.58, 0.64, 0.65)`
@@ -2793,7 +2951,7 @@ pub const XKCD_COOL_GREY: Color = Color(149, 163, 166);
 
 /// Colour `(r = 127, g = 104, b = 78)`
 ///
-/// Colour `XKCD_DARK_TAUPE` from the set `XKCD_COLORS`. (Colour number `306`)
+/// Colour `XKCD_DARK_TAUPE` from the set `XKCD_COLORS`. (Colour number `306`, name `xkcd:dark taupe`)
 /// ## Representations:
 /// - int tuple `(127, 104, 78)`
 /// - float tuple `(0.5, 0.41, 0.31)`
@@ -2802,7 +2960,7 @@ pub const XKCD_DARK_TAUPE: Color = Color(127, 104, 78);
 
 /// Colour `(r = 117, g = 25, b = 115)`
 ///
-/// Colour `XKCD_DARKISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `307`)
+/// Colour `XKCD_DARKISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `307`, name `xkcd:darkish purple`)
 /// ## Representations:
 /// - int tuple `(117, 25, 115)`
 /// - float tuple `(0.46, 0.1, 0.45)`
@@ -2811,7 +2969,7 @@ pub const XKCD_DARKISH_PURPLE: Color = Color(117, 25, 115);
 
 /// Colour `(r = 8, g = 148, b = 4)`
 ///
-/// Colour `XKCD_TRUE_GREEN` from the set `XKCD_COLORS`. (Colour number `308`)
+/// Colour `XKCD_TRUE_GREEN` from the set `XKCD_COLORS`. (Colour number `308`, name `xkcd:true green`)
 /// ## Representations:
 /// - int tuple `(8, 148, 4)`
 /// - float tuple `(0.03, 0.58, 0.02)`
@@ -2820,7 +2978,7 @@ pub const XKCD_TRUE_GREEN: Color = Color(8, 148, 4);
 
 /// Colour `(r = 255, g = 97, b = 99)`
 ///
-/// Colour `XKCD_CORAL_PINK` from the set `XKCD_COLORS`. (Colour number `309`)
+/// Colour `XKCD_CORAL_PINK` from the set `XKCD_COLORS`. (Colour number `309`, name `xkcd:coral pink`)
 /// ## Representations:
 /// - int tuple `(255, 97, 99)`
 /// - float tuple `(1.0, 0.38, 0.39)`
@@ -2829,7 +2987,7 @@ pub const XKCD_CORAL_PINK: Color = Color(255, 97, 99);
 
 /// Colour `(r = 89, g = 133, b = 86)`
 ///
-/// Colour `XKCD_DARK_SAGE` from the set `XKCD_COLORS`. (Colour number `310`)
+/// Colour `XKCD_DARK_SAGE` from the set `XKCD_COLORS`. (Colour number `310`, name `xkcd:dark sage`)
 /// ## Representations:
 /// - int tuple `(89, 133, 86)`
 /// - float tuple `(0.35, 0.52, 0.34)`
@@ -2838,7 +2996,7 @@ pub const XKCD_DARK_SAGE: Color = Color(89, 133, 86);
 
 /// Colour `(r = 33, g = 71, b = 97)`
 ///
-/// Colour `XKCD_DARK_SLATE_BLUE` from the set `XKCD_COLORS`. (Colour number `311`)
+/// Colour `XKCD_DARK_SLATE_BLUE` from the set `XKCD_COLORS`. (Colour number `311`, name `xkcd:dark slate blue`)
 /// ## Representations:
 /// - int tuple `(33, 71, 97)`
 /// - float tuple `(0.13, 0.28, 0.38)`
@@ -2847,7 +3005,7 @@ pub const XKCD_DARK_SLATE_BLUE: Color = Color(33, 71, 97);
 
 /// Colour `(r = 60, g = 115, b = 168)`
 ///
-/// Colour `XKCD_FLAT_BLUE` from the set `XKCD_COLORS`. (Colour number `312`)
+/// Colour `XKCD_FLAT_BLUE` from the set `XKCD_COLORS`. (Colour number `312`, name `xkcd:flat blue`)
 /// ## Representations:
 /// - int tuple `(60, 115, 168)`
 /// - float tuple `(0.24, 0.45, 0.66)`
@@ -2856,7 +3014,7 @@ pub const XKCD_FLAT_BLUE: Color = Color(60, 115, 168);
 
 /// Colour `(r = 186, g = 158, b = 136)`
 ///
-/// Colour `XKCD_MUSHROOM` from the set `XKCD_COLORS`. (Colour number `313`)
+/// Colour `XKCD_MUSHROOM` from the set `XKCD_COLORS`. (Colour number `313`, name `xkcd:mushroom`)
 /// ## Representations:
 /// - int tuple `(186, 158, 136)`
 /// - float tuple `(0.73, 0.62, 0.53)`
@@ -2865,7 +3023,7 @@ pub const XKCD_MUSHROOM: Color = Color(186, 158, 136);
 
 /// Colour `(r = 2, g = 27, b = 249)`
 ///
-/// Colour `XKCD_RICH_BLUE` from the set `XKCD_COLORS`. (Colour number `314`)
+/// Colour `XKCD_RICH_BLUE` from the set `XKCD_COLORS`. (Colour number `314`, name `xkcd:rich blue`)
 /// ## Representations:
 /// - int tuple `(2, 27, 249)`
 /// - float tuple `(0.01, 0.11, 0.98)`
@@ -2874,7 +3032,7 @@ pub const XKCD_RICH_BLUE: Color = Color(2, 27, 249);
 
 /// Colour `(r = 115, g = 74, b = 101)`
 ///
-/// Colour `XKCD_DIRTY_PURPLE` from the set `XKCD_COLORS`. (Colour number `315`)
+/// Colour `XKCD_DIRTY_PURPLE` from the set `XKCD_COLORS`. (Colour number `315`, name `xkcd:dirty purple`)
 /// ## Representations:
 /// - int tuple `(115, 74, 101)`
 /// - float tuple `(0.45, 0.29, 0.4)`
@@ -2883,7 +3041,7 @@ pub const XKCD_DIRTY_PURPLE: Color = Color(115, 74, 101);
 
 /// Colour `(r = 35, g = 196, b = 139)`
 ///
-/// Colour `XKCD_GREENBLUE` from the set `XKCD_COLORS`. (Colour number `316`)
+/// Colour `XKCD_GREENBLUE` from the set `XKCD_COLORS`. (Colour number `316`, name `xkcd:greenblue`)
 /// ## Representations:
 /// - int tuple `(35, 196, 139)`
 /// - float tuple `(0.14, 0.77, 0.55)`
@@ -2892,7 +3050,7 @@ pub const XKCD_GREENBLUE: Color = Color(35, 196, 139);
 
 /// Colour `(r = 143, g = 174, b = 34)`
 ///
-/// Colour `XKCD_ICKY_GREEN` from the set `XKCD_COLORS`. (Colour number `317`)
+/// Colour `XKCD_ICKY_GREEN` from the set `XKCD_COLORS`. (Colour number `317`, name `xkcd:icky green`)
 /// ## Representations:
 /// - int tuple `(143, 174, 34)`
 /// - float tuple `(0.56, 0.68, 0.13)`
@@ -2901,7 +3059,7 @@ pub const XKCD_ICKY_GREEN: Color = Color(143, 174, 34);
 
 /// Colour `(r = 230, g = 242, b = 162)`
 ///
-/// Colour `XKCD_LIGHT_KHAKI` from the set `XKCD_COLORS`. (Colour number `318`)
+/// Colour `XKCD_LIGHT_KHAKI` from the set `XKCD_COLORS`. (Colour number `318`, name `xkcd:light khaki`)
 /// ## Representations:
 /// - int tuple `(230, 242, 162)`
 /// - float tuple `(0.9, 0.95, 0.64)`
@@ -2910,7 +3068,7 @@ pub const XKCD_LIGHT_KHAKI: Color = Color(230, 242, 162);
 
 /// Colour `(r = 75, g = 87, b = 219)`
 ///
-/// Colour `XKCD_WARM_BLUE` from the set `XKCD_COLORS`. (Colour number `319`)
+/// Colour `XKCD_WARM_BLUE` from the set `XKCD_COLORS`. (Colour number `319`, name `xkcd:warm blue`)
 /// ## Representations:
 /// - int tuple `(75, 87, 219)`
 /// - float tuple `(0.29, 0.34, 0.86)`
@@ -2919,7 +3077,7 @@ pub const XKCD_WARM_BLUE: Color = Color(75, 87, 219);
 
 /// Colour `(r = 217, g = 1, b = 102)`
 ///
-/// Colour `XKCD_DARK_HOT_PINK` from the set `XKCD_COLORS`. (Colour number `320`)
+/// Colour `XKCD_DARK_HOT_PINK` from the set `XKCD_COLORS`. (Colour number `320`, name `xkcd:dark hot pink`)
 /// ## Representations:
 /// - int tuple `(217, 1, 102)`
 /// - float tuple `(0.85, 0.0, 0.4)`
@@ -2928,7 +3086,7 @@ pub const XKCD_DARK_HOT_PINK: Color = Color(217, 1, 102);
 
 /// Colour `(r = 1, g = 84, b = 130)`
 ///
-/// Colour `XKCD_DEEP_SEA_BLUE` from the set `XKCD_COLORS`. (Colour number `321`)
+/// Colour `XKCD_DEEP_SEA_BLUE` from the set `XKCD_COLORS`. (Colour number `321`, name `xkcd:deep sea blue`)
 /// ## Representations:
 /// - int tuple `(1, 84, 130)`
 /// - float tuple `(0.0, 0.33, 0.51)`
@@ -2937,7 +3095,7 @@ pub const XKCD_DEEP_SEA_BLUE: Color = Color(1, 84, 130);
 
 /// Colour `(r = 157, g = 2, b = 22)`
 ///
-/// Colour `XKCD_CARMINE` from the set `XKCD_COLORS`. (Colour number `322`)
+/// Colour `XKCD_CARMINE` from the set `XKCD_COLORS`. (Colour number `322`, name `xkcd:carmine`)
 /// ## Representations:
 /// - int tuple `(157, 2, 22)`
 /// - float tuple `(0.62, 0.01, 0.09)`
@@ -2946,7 +3104,7 @@ pub const XKCD_CARMINE: Color = Color(157, 2, 22);
 
 /// Colour `(r = 114, g = 143, b = 2)`
 ///
-/// Colour `XKCD_DARK_YELLOW_GREEN` from the set `XKCD_COLORS`. (Colour number `323`)
+/// Colour `XKCD_DARK_YELLOW_GREEN` from the set `XKCD_COLORS`. (Colour number `323`, name `xkcd:dark yellow green`)
 /// ## Representations:
 /// - int tuple `(114, 143, 2)`
 /// - float tuple `(0.45, 0.56, 0.01)`
@@ -2955,7 +3113,7 @@ pub const XKCD_DARK_YELLOW_GREEN: Color = Color(114, 143, 2);
 
 /// Colour `(r = 255, g = 229, b = 173)`
 ///
-/// Colour `XKCD_PALE_PEACH` from the set `XKCD_COLORS`. (Colour number `324`)
+/// Colour `XKCD_PALE_PEACH` from the set `XKCD_COLORS`. (Colour number `324`, name `xkcd:pale peach`)
 /// ## Representations:
 /// - int tuple `(255, 229, 173)`
 /// - float tuple `(1.0, 0.9, 0.68)`
@@ -2964,7 +3122,7 @@ pub const XKCD_PALE_PEACH: Color = Color(255, 229, 173);
 
 /// Colour `(r = 78, g = 5, b = 80)`
 ///
-/// Colour `XKCD_PLUM_PURPLE` from the set `XKCD_COLORS`. (Colour number `325`)
+/// Colour `XKCD_PLUM_PURPLE` from the set `XKCD_COLORS`. (Colour number `325`, name `xkcd:plum purple`)
 /// ## Representations:
 /// - int tuple `(78, 5, 80)`
 /// - float tuple `(0.31, 0.02, 0.31)`
@@ -2973,7 +3131,7 @@ pub const XKCD_PLUM_PURPLE: Color = Color(78, 5, 80);
 
 /// Colour `(r = 249, g = 188, b = 8)`
 ///
-/// Colour `XKCD_GOLDEN_ROD` from the set `XKCD_COLORS`. (Colour number `326`)
+/// Colour `XKCD_GOLDEN_ROD` from the set `XKCD_COLORS`. (Colour number `326`, name `xkcd:golden rod`)
 /// ## Representations:
 /// - int tuple `(249, 188, 8)`
 /// - float tuple `(0.98, 0.74, 0.03)`
@@ -2982,7 +3140,7 @@ pub const XKCD_GOLDEN_ROD: Color = Color(249, 188, 8);
 
 /// Colour `(r = 255, g = 7, b = 58)`
 ///
-/// Colour `XKCD_NEON_RED` from the set `XKCD_COLORS`. (Colour number `327`)
+/// Colour `XKCD_NEON_RED` from the set `XKCD_COLORS`. (Colour number `327`, name `xkcd:neon red`)
 /// ## Representations:
 /// - int tuple `(255, 7, 58)`
 /// - float tuple `(1.0, 0.03, 0.23)`
@@ -2991,7 +3149,7 @@ pub const XKCD_NEON_RED: Color = Color(255, 7, 58);
 
 /// Colour `(r = 199, g = 121, b = 134)`
 ///
-/// Colour `XKCD_OLD_PINK` from the set `XKCD_COLORS`. (Colour number `328`)
+/// Colour `XKCD_OLD_PINK` from the set `XKCD_COLORS`. (Colour number `328`, name `xkcd:old pink`)
 /// ## Representations:
 /// - int tuple `(199, 121, 134)`
 /// - float tuple `(0.78, 0.47, 0.53)`
@@ -3000,7 +3158,7 @@ pub const XKCD_OLD_PINK: Color = Color(199, 121, 134);
 
 /// Colour `(r = 214, g = 255, b = 254)`
 ///
-/// Colour `XKCD_VERY_PALE_BLUE` from the set `XKCD_COLORS`. (Colour number `329`)
+/// Colour `XKCD_VERY_PALE_BLUE` from the set `XKCD_COLORS`. (Colour number `329`, name `xkcd:very pale blue`)
 /// ## Representations:
 /// - int tuple `(214, 255, 254)`
 /// - float tuple `(0.84, 1.0, 1.0)`
@@ -3009,7 +3167,7 @@ pub const XKCD_VERY_PALE_BLUE: Color = Color(214, 255, 254);
 
 /// Colour `(r = 254, g = 75, b = 3)`
 ///
-/// Colour `XKCD_BLOOD_ORANGE` from the set `XKCD_COLORS`. (Colour number `330`)
+/// Colour `XKCD_BLOOD_ORANGE` from the set `XKCD_COLORS`. (Colour number `330`, name `xkcd:blood orange`)
 /// ## Representations:
 /// - int tuple `(254, 75, 3)`
 /// - float tuple `(1.0, 0.29, 0.01)`
@@ -3018,7 +3176,7 @@ pub const XKCD_BLOOD_ORANGE: Color = Color(254, 75, 3);
 
 /// Colour `(r = 253, g = 89, b = 86)`
 ///
-/// Colour `XKCD_GRAPEFRUIT` from the set `XKCD_COLORS`. (Colour number `331`)
+/// Colour `XKCD_GRAPEFRUIT` from the set `XKCD_COLORS`. (Colour number `331`, name `xkcd:grapefruit`)
 /// ## Representations:
 /// - int tuple `(253, 89, 86)`
 /// - float tuple `(0.99, 0.35, 0.34)`
@@ -3027,7 +3185,7 @@ pub const XKCD_GRAPEFRUIT: Color = Color(253, 89, 86);
 
 /// Colour `(r = 252, g = 225, b = 102)`
 ///
-/// Colour `XKCD_SAND_YELLOW` from the set `XKCD_COLORS`. (Colour number `332`)
+/// Colour `XKCD_SAND_YELLOW` from the set `XKCD_COLORS`. (Colour number `332`, name `xkcd:sand yellow`)
 /// ## Representations:
 /// - int tuple `(252, 225, 102)`
 /// - float tuple `(0.99, 0.88, 0.4)`
@@ -3036,7 +3194,7 @@ pub const XKCD_SAND_YELLOW: Color = Color(252, 225, 102);
 
 /// Colour `(r = 178, g = 113, b = 61)`
 ///
-/// Colour `XKCD_CLAY_BROWN` from the set `XKCD_COLORS`. (Colour number `333`)
+/// Colour `XKCD_CLAY_BROWN` from the set `XKCD_COLORS`. (Colour number `333`, name `xkcd:clay brown`)
 /// ## Representations:
 /// - int tuple `(178, 113, 61)`
 /// - float tuple `(0.7, 0.44, 0.24)`
@@ -3045,7 +3203,7 @@ pub const XKCD_CLAY_BROWN: Color = Color(178, 113, 61);
 
 /// Colour `(r = 31, g = 59, b = 77)`
 ///
-/// Colour `XKCD_DARK_BLUE_GREY` from the set `XKCD_COLORS`. (Colour number `334`)
+/// Colour `XKCD_DARK_BLUE_GREY` from the set `XKCD_COLORS`. (Colour number `334`, name `xkcd:dark blue grey`)
 /// ## Representations:
 /// - int tuple `(31, 59, 77)`
 /// - float tuple `(0.12, 0.23, 0.3)`
@@ -3054,7 +3212,7 @@ pub const XKCD_DARK_BLUE_GREY: Color = Color(31, 59, 77);
 
 /// Colour `(r = 105, g = 157, b = 76)`
 ///
-/// Colour `XKCD_FLAT_GREEN` from the set `XKCD_COLORS`. (Colour number `335`)
+/// Colour `XKCD_FLAT_GREEN` from the set `XKCD_COLORS`. (Colour number `335`, name `xkcd:flat green`)
 /// ## Representations:
 /// - int tuple `(105, 157, 76)`
 /// - float tuple `(0.41, 0.62, 0.3)`
@@ -3063,7 +3221,7 @@ pub const XKCD_FLAT_GREEN: Color = Color(105, 157, 76);
 
 /// Colour `(r = 86, g = 252, b = 162)`
 ///
-/// Colour `XKCD_LIGHT_GREEN_BLUE` from the set `XKCD_COLORS`. (Colour number `336`)
+/// Colour `XKCD_LIGHT_GREEN_BLUE` from the set `XKCD_COLORS`. (Colour number `336`, name `xkcd:light green blue`)
 /// ## Representations:
 /// - int tuple `(86, 252, 162)`
 /// - float tuple `(0.34, 0.99, 0.64)`
@@ -3072,7 +3230,7 @@ pub const XKCD_LIGHT_GREEN_BLUE: Color = Color(86, 252, 162);
 
 /// Colour `(r = 251, g = 85, b = 129)`
 ///
-/// Colour `XKCD_WARM_PINK` from the set `XKCD_COLORS`. (Colour number `337`)
+/// Colour `XKCD_WARM_PINK` from the set `XKCD_COLORS`. (Colour number `337`, name `xkcd:warm pink`)
 /// ## Representations:
 /// - int tuple `(251, 85, 129)`
 /// - float tuple `(0.98, 0.33, 0.51)`
@@ -3081,7 +3239,7 @@ pub const XKCD_WARM_PINK: Color = Color(251, 85, 129);
 
 /// Colour `(r = 62, g = 130, b = 252)`
 ///
-/// Colour `XKCD_DODGER_BLUE` from the set `XKCD_COLORS`. (Colour number `338`)
+/// Colour `XKCD_DODGER_BLUE` from the set `XKCD_COLORS`. (Colour number `338`, name `xkcd:dodger blue`)
 /// ## Representations:
 /// - int tuple `(62, 130, 252)`
 /// - float tuple `(0.24, 0.51, 0.99)`
@@ -3090,7 +3248,7 @@ pub const XKCD_DODGER_BLUE: Color = Color(62, 130, 252);
 
 /// Colour `(r = 160, g = 191, b = 22)`
 ///
-/// Colour `XKCD_GROSS_GREEN` from the set `XKCD_COLORS`. (Colour number `339`)
+/// Colour `XKCD_GROSS_GREEN` from the set `XKCD_COLORS`. (Colour number `339`, name `xkcd:gross green`)
 /// ## Representations:
 /// - int tuple `(160, 191, 22)`
 /// - float tuple `(0.63, 0.75, 0.09)`
@@ -3099,7 +3257,7 @@ pub const XKCD_GROSS_GREEN: Color = Color(160, 191, 22);
 
 /// Colour `(r = 214, g = 255, b = 250)`
 ///
-/// Colour `XKCD_ICE` from the set `XKCD_COLORS`. (Colour number `340`)
+/// Colour `XKCD_ICE` from the set `XKCD_COLORS`. (Colour number `340`, name `xkcd:ice`)
 /// ## Representations:
 /// - int tuple `(214, 255, 250)`
 /// - float tuple `(0.84, 1.0, 0.98)`
@@ -3108,7 +3266,7 @@ pub const XKCD_ICE: Color = Color(214, 255, 250);
 
 /// Colour `(r = 79, g = 115, b = 142)`
 ///
-/// Colour `XKCD_METALLIC_BLUE` from the set `XKCD_COLORS`. (Colour number `341`)
+/// Colour `XKCD_METALLIC_BLUE` from the set `XKCD_COLORS`. (Colour number `341`, name `xkcd:metallic blue`)
 /// ## Representations:
 /// - int tuple `(79, 115, 142)`
 /// - float tuple `(0.31, 0.45, 0.56)`
@@ -3117,7 +3275,7 @@ pub const XKCD_METALLIC_BLUE: Color = Color(79, 115, 142);
 
 /// Colour `(r = 255, g = 177, b = 154)`
 ///
-/// Colour `XKCD_PALE_SALMON` from the set `XKCD_COLORS`. (Colour number `342`)
+/// Colour `XKCD_PALE_SALMON` from the set `XKCD_COLORS`. (Colour number `342`, name `xkcd:pale salmon`)
 /// ## Representations:
 /// - int tuple `(255, 177, 154)`
 /// - float tuple `(1.0, 0.69, 0.6)`
@@ -3126,7 +3284,7 @@ pub const XKCD_PALE_SALMON: Color = Color(255, 177, 154);
 
 /// Colour `(r = 92, g = 139, b = 21)`
 ///
-/// Colour `XKCD_SAP_GREEN` from the set `XKCD_COLORS`. (Colour number `343`)
+/// Colour `XKCD_SAP_GREEN` from the set `XKCD_COLORS`. (Colour number `343`, name `xkcd:sap green`)
 /// ## Representations:
 /// - int tuple `(92, 139, 21)`
 /// - float tuple `(0.36, 0.55, 0.08)`
@@ -3135,7 +3293,7 @@ pub const XKCD_SAP_GREEN: Color = Color(92, 139, 21);
 
 /// Colour `(r = 84, g = 172, b = 104)`
 ///
-/// Colour `XKCD_ALGAE` from the set `XKCD_COLORS`. (Colour number `344`)
+/// Colour `XKCD_ALGAE` from the set `XKCD_COLORS`. (Colour number `344`, name `xkcd:algae`)
 /// ## Representations:
 /// - int tuple `(84, 172, 104)`
 /// - float tuple `(0.33, 0.67, 0.41)`
@@ -3144,7 +3302,7 @@ pub const XKCD_ALGAE: Color = Color(84, 172, 104);
 
 /// Colour `(r = 137, g = 160, b = 176)`
 ///
-/// Colour `XKCD_BLUEY_GREY` from the set `XKCD_COLORS`. (Colour number `345`)
+/// Colour `XKCD_BLUEY_GREY` from the set `XKCD_COLORS`. (Colour number `345`, name `xkcd:bluey grey`)
 /// ## Representations:
 /// - int tuple `(137, 160, 176)`
 /// - float tuple `(0.54, 0.63, 0.69)`
@@ -3153,7 +3311,7 @@ pub const XKCD_BLUEY_GREY: Color = Color(137, 160, 176);
 
 /// Colour `(r = 126, g = 160, b = 122)`
 ///
-/// Colour `XKCD_GREENY_GREY` from the set `XKCD_COLORS`. (Colour number `346`)
+/// Colour `XKCD_GREENY_GREY` from the set `XKCD_COLORS`. (Colour number `346`, name `xkcd:greeny grey`)
 /// ## Representations:
 /// - int tuple `(126, 160, 122)`
 /// - float tuple `(0.49, 0.63, 0.48)`
@@ -3162,7 +3320,7 @@ pub const XKCD_GREENY_GREY: Color = Color(126, 160, 122);
 
 /// Colour `(r = 27, g = 252, b = 6)`
 ///
-/// Colour `XKCD_HIGHLIGHTER_GREEN` from the set `XKCD_COLORS`. (Colour number `347`)
+/// Colour `XKCD_HIGHLIGHTER_GREEN` from the set `XKCD_COLORS`. (Colour number `347`, name `xkcd:highlighter green`)
 /// ## Representations:
 /// - int tuple `(27, 252, 6)`
 /// - float tuple `(0.11, 0.99, 0.02)`
@@ -3171,7 +3329,7 @@ pub const XKCD_HIGHLIGHTER_GREEN: Color = Color(27, 252, 6);
 
 /// Colour `(r = 202, g = 255, b = 251)`
 ///
-/// Colour `XKCD_LIGHT_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `348`)
+/// Colour `XKCD_LIGHT_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `348`, name `xkcd:light light blue`)
 /// ## Representations:
 /// - int tuple `(202, 255, 251)`
 /// - float tuple `(0.79, 1.0, 0.98)`
@@ -3180,7 +3338,7 @@ pub const XKCD_LIGHT_LIGHT_BLUE: Color = Color(202, 255, 251);
 
 /// Colour `(r = 182, g = 255, b = 187)`
 ///
-/// Colour `XKCD_LIGHT_MINT` from the set `XKCD_COLORS`. (Colour number `349`)
+/// Colour `XKCD_LIGHT_MINT` from the set `XKCD_COLORS`. (Colour number `349`, name `xkcd:light mint`)
 /// ## Representations:
 /// - int tuple `(182, 255, 187)`
 /// - float tuple `(0.71, 1.0, 0.73)`
@@ -3189,7 +3347,7 @@ pub const XKCD_LIGHT_MINT: Color = Color(182, 255, 187);
 
 /// Colour `(r = 167, g = 94, b = 9)`
 ///
-/// Colour `XKCD_RAW_UMBER` from the set `XKCD_COLORS`. (Colour number `350`)
+/// Colour `XKCD_RAW_UMBER` from the set `XKCD_COLORS`. (Colour number `350`, name `xkcd:raw umber`)
 /// ## Representations:
 /// - int tuple `(167, 94, 9)`
 /// - float tuple `(0.65, 0.37, 0.04)`
@@ -3198,7 +3356,7 @@ pub const XKCD_RAW_UMBER: Color = Color(167, 94, 9);
 
 /// Colour `(r = 21, g = 46, b = 255)`
 ///
-/// Colour `XKCD_VIVID_BLUE` from the set `XKCD_COLORS`. (Colour number `351`)
+/// Colour `XKCD_VIVID_BLUE` from the set `XKCD_COLORS`. (Colour number `351`, name `xkcd:vivid blue`)
 /// ## Representations:
 /// - int tuple `(21, 46, 255)`
 /// - float tuple `(0.08, 0.18, 1.0)`
@@ -3207,7 +3365,7 @@ pub const XKCD_VIVID_BLUE: Color = Color(21, 46, 255);
 
 /// Colour `(r = 141, g = 94, b = 183)`
 ///
-/// Colour `XKCD_DEEP_LAVENDER` from the set `XKCD_COLORS`. (Colour number `352`)
+/// Colour `XKCD_DEEP_LAVENDER` from the set `XKCD_COLORS`. (Colour number `352`, name `xkcd:deep lavender`)
 /// ## Representations:
 /// - int tuple `(141, 94, 183)`
 /// - float tuple `(0.55, 0.37, 0.72)`
@@ -3216,7 +3374,7 @@ pub const XKCD_DEEP_LAVENDER: Color = Color(141, 94, 183);
 
 /// Colour `(r = 95, g = 158, b = 143)`
 ///
-/// Colour `XKCD_DULL_TEAL` from the set `XKCD_COLORS`. (Colour number `353`)
+/// Colour `XKCD_DULL_TEAL` from the set `XKCD_COLORS`. (Colour number `353`, name `xkcd:dull teal`)
 /// ## Representations:
 /// - int tuple `(95, 158, 143)`
 /// - float tuple `(0.37, 0.62, 0.56)`
@@ -3225,7 +3383,7 @@ pub const XKCD_DULL_TEAL: Color = Color(95, 158, 143);
 
 /// Colour `(r = 99, g = 247, b = 180)`
 ///
-/// Colour `XKCD_LIGHT_GREENISH_BLUE` from the set `XKCD_COLORS`. (Colour number `354`)
+/// Colour `XKCD_LIGHT_GREENISH_BLUE` from the set `XKCD_COLORS`. (Colour number `354`, name `xkcd:light greenish blue`)
 /// ## Representations:
 /// - int tuple `(99, 247, 180)`
 /// - float tuple `(0.39, 0.97, 0.71)`
@@ -3234,7 +3392,7 @@ pub const XKCD_LIGHT_GREENISH_BLUE: Color = Color(99, 247, 180);
 
 /// Colour `(r = 96, g = 102, b = 2)`
 ///
-/// Colour `XKCD_MUD_GREEN` from the set `XKCD_COLORS`. (Colour number `355`)
+/// Colour `XKCD_MUD_GREEN` from the set `XKCD_COLORS`. (Colour number `355`, name `xkcd:mud green`)
 /// ## Representations:
 /// - int tuple `(96, 102, 2)`
 /// - float tuple `(0.38, 0.4, 0.01)`
@@ -3243,7 +3401,7 @@ pub const XKCD_MUD_GREEN: Color = Color(96, 102, 2);
 
 /// Colour `(r = 252, g = 134, b = 170)`
 ///
-/// Colour `XKCD_PINKY` from the set `XKCD_COLORS`. (Colour number `356`)
+/// Colour `XKCD_PINKY` from the set `XKCD_COLORS`. (Colour number `356`, name `xkcd:pinky`)
 /// ## Representations:
 /// - int tuple `(252, 134, 170)`
 /// - float tuple `(0.99, 0.53, 0.67)`
@@ -3252,7 +3410,7 @@ pub const XKCD_PINKY: Color = Color(252, 134, 170);
 
 /// Colour `(r = 140, g = 0, b = 52)`
 ///
-/// Colour `XKCD_RED_WINE` from the set `XKCD_COLORS`. (Colour number `357`)
+/// Colour `XKCD_RED_WINE` from the set `XKCD_COLORS`. (Colour number `357`, name `xkcd:red wine`)
 /// ## Representations:
 /// - int tuple `(140, 0, 52)`
 /// - float tuple `(0.55, 0.0, 0.2)`
@@ -3261,7 +3419,7 @@ pub const XKCD_RED_WINE: Color = Color(140, 0, 52);
 
 /// Colour `(r = 117, g = 128, b = 0)`
 ///
-/// Colour `XKCD_SHIT_GREEN` from the set `XKCD_COLORS`. (Colour number `358`)
+/// Colour `XKCD_SHIT_GREEN` from the set `XKCD_COLORS`. (Colour number `358`, name `xkcd:shit green`)
 /// ## Representations:
 /// - int tuple `(117, 128, 0)`
 /// - float tuple `(0.46, 0.5, 0.0)`
@@ -3270,7 +3428,7 @@ pub const XKCD_SHIT_GREEN: Color = Color(117, 128, 0);
 
 /// Colour `(r = 171, g = 126, b = 76)`
 ///
-/// Colour `XKCD_TAN_BROWN` from the set `XKCD_COLORS`. (Colour number `359`)
+/// Colour `XKCD_TAN_BROWN` from the set `XKCD_COLORS`. (Colour number `359`, name `xkcd:tan brown`)
 /// ## Representations:
 /// - int tuple `(171, 126, 76)`
 /// - float tuple `(0.67, 0.49, 0.3)`
@@ -3279,7 +3437,7 @@ pub const XKCD_TAN_BROWN: Color = Color(171, 126, 76);
 
 /// Colour `(r = 3, g = 7, b = 100)`
 ///
-/// Colour `XKCD_DARKBLUE` from the set `XKCD_COLORS`. (Colour number `360`)
+/// Colour `XKCD_DARKBLUE` from the set `XKCD_COLORS`. (Colour number `360`, name `xkcd:darkblue`)
 /// ## Representations:
 /// - int tuple `(3, 7, 100)`
 /// - float tuple `(0.01, 0.03, 0.39)`
@@ -3288,7 +3446,7 @@ pub const XKCD_DARKBLUE: Color = Color(3, 7, 100);
 
 /// Colour `(r = 254, g = 134, b = 164)`
 ///
-/// Colour `XKCD_ROSA` from the set `XKCD_COLORS`. (Colour number `361`)
+/// Colour `XKCD_ROSA` from the set `XKCD_COLORS`. (Colour number `361`, name `xkcd:rosa`)
 /// ## Representations:
 /// - int tuple `(254, 134, 164)`
 /// - float tuple `(1.0, 0.53, 0.64)`
@@ -3297,7 +3455,7 @@ pub const XKCD_ROSA: Color = Color(254, 134, 164);
 
 /// Colour `(r = 213, g = 23, b = 78)`
 ///
-/// Colour `XKCD_LIPSTICK` from the set `XKCD_COLORS`. (Colour number `362`)
+/// Colour `XKCD_LIPSTICK` from the set `XKCD_COLORS`. (Colour number `362`, name `xkcd:lipstick`)
 /// ## Representations:
 /// - int tuple `(213, 23, 78)`
 /// - float tuple `(0.84, 0.09, 0.31)`
@@ -3306,7 +3464,7 @@ pub const XKCD_LIPSTICK: Color = Color(213, 23, 78);
 
 /// Colour `(r = 254, g = 208, b = 252)`
 ///
-/// Colour `XKCD_PALE_MAUVE` from the set `XKCD_COLORS`. (Colour number `363`)
+/// Colour `XKCD_PALE_MAUVE` from the set `XKCD_COLORS`. (Colour number `363`, name `xkcd:pale mauve`)
 /// ## Representations:
 /// - int tuple `(254, 208, 252)`
 /// - float tuple `(1.0, 0.82, 0.99)`
@@ -3315,7 +3473,7 @@ pub const XKCD_PALE_MAUVE: Color = Color(254, 208, 252);
 
 /// Colour `(r = 104, g = 0, b = 24)`
 ///
-/// Colour `XKCD_CLARET` from the set `XKCD_COLORS`. (Colour number `364`)
+/// Colour `XKCD_CLARET` from the set `XKCD_COLORS`. (Colour number `364`, name `xkcd:claret`)
 /// ## Representations:
 /// - int tuple `(104, 0, 24)`
 /// - float tuple `(0.41, 0.0, 0.09)`
@@ -3324,7 +3482,7 @@ pub const XKCD_CLARET: Color = Color(104, 0, 24);
 
 /// Colour `(r = 254, g = 223, b = 8)`
 ///
-/// Colour `XKCD_DANDELION` from the set `XKCD_COLORS`. (Colour number `365`)
+/// Colour `XKCD_DANDELION` from the set `XKCD_COLORS`. (Colour number `365`, name `xkcd:dandelion`)
 /// ## Representations:
 /// - int tuple `(254, 223, 8)`
 /// - float tuple `(1.0, 0.87, 0.03)`
@@ -3333,7 +3491,7 @@ pub const XKCD_DANDELION: Color = Color(254, 223, 8);
 
 /// Colour `(r = 254, g = 66, b = 15)`
 ///
-/// Colour `XKCD_ORANGERED` from the set `XKCD_COLORS`. (Colour number `366`)
+/// Colour `XKCD_ORANGERED` from the set `XKCD_COLORS`. (Colour number `366`, name `xkcd:orangered`)
 /// ## Representations:
 /// - int tuple `(254, 66, 15)`
 /// - float tuple `(1.0, 0.26, 0.06)`
@@ -3342,7 +3500,7 @@ pub const XKCD_ORANGERED: Color = Color(254, 66, 15);
 
 /// Colour `(r = 111, g = 124, b = 0)`
 ///
-/// Colour `XKCD_POOP_GREEN` from the set `XKCD_COLORS`. (Colour number `367`)
+/// Colour `XKCD_POOP_GREEN` from the set `XKCD_COLORS`. (Colour number `367`, name `xkcd:poop green`)
 /// ## Representations:
 /// - int tuple `(111, 124, 0)`
 /// - float tuple `(0.44, 0.49, 0.0)`
@@ -3351,7 +3509,7 @@ pub const XKCD_POOP_GREEN: Color = Color(111, 124, 0);
 
 /// Colour `(r = 202, g = 1, b = 71)`
 ///
-/// Colour `XKCD_RUBY` from the set `XKCD_COLORS`. (Colour number `368`)
+/// Colour `XKCD_RUBY` from the set `XKCD_COLORS`. (Colour number `368`, name `xkcd:ruby`)
 /// ## Representations:
 /// - int tuple `(202, 1, 71)`
 /// - float tuple `(0.79, 0.0, 0.28)`
@@ -3360,7 +3518,7 @@ pub const XKCD_RUBY: Color = Color(202, 1, 71);
 
 /// Colour `(r = 27, g = 36, b = 49)`
 ///
-/// Colour `XKCD_DARK` from the set `XKCD_COLORS`. (Colour number `369`)
+/// Colour `XKCD_DARK` from the set `XKCD_COLORS`. (Colour number `369`, name `xkcd:dark`)
 /// ## Representations:
 /// - int tuple `(27, 36, 49)`
 /// - float tuple `(0.11, 0.14, 0.19)`
@@ -3369,7 +3527,7 @@ pub const XKCD_DARK: Color = Color(27, 36, 49);
 
 /// Colour `(r = 0, g = 251, b = 176)`
 ///
-/// Colour `XKCD_GREENISH_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `370`)
+/// Colour `XKCD_GREENISH_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `370`, name `xkcd:greenish turquoise`)
 /// ## Representations:
 /// - int tuple `(0, 251, 176)`
 /// - float tuple `(0.0, 0.98, 0.69)`
@@ -3378,7 +3536,7 @@ pub const XKCD_GREENISH_TURQUOISE: Color = Color(0, 251, 176);
 
 /// Colour `(r = 219, g = 88, b = 86)`
 ///
-/// Colour `XKCD_PASTEL_RED` from the set `XKCD_COLORS`. (Colour number `371`)
+/// Colour `XKCD_PASTEL_RED` from the set `XKCD_COLORS`. (Colour number `371`, name `xkcd:pastel red`)
 /// ## Representations:
 /// - int tuple `(219, 88, 86)`
 /// - float tuple `(0.86, 0.35, 0.34)`
@@ -3387,7 +3545,7 @@ pub const XKCD_PASTEL_RED: Color = Color(219, 88, 86);
 
 /// Colour `(r = 221, g = 214, b = 24)`
 ///
-/// Colour `XKCD_PISS_YELLOW` from the set `XKCD_COLORS`. (Colour number `372`)
+/// Colour `XKCD_PISS_YELLOW` from the set `XKCD_COLORS`. (Colour number `372`, name `xkcd:piss yellow`)
 /// ## Representations:
 /// - int tuple `(221, 214, 24)`
 /// - float tuple `(0.87, 0.84, 0.09)`
@@ -3396,7 +3554,7 @@ pub const XKCD_PISS_YELLOW: Color = Color(221, 214, 24);
 
 /// Colour `(r = 65, g = 253, b = 254)`
 ///
-/// Colour `XKCD_BRIGHT_CYAN` from the set `XKCD_COLORS`. (Colour number `373`)
+/// Colour `XKCD_BRIGHT_CYAN` from the set `XKCD_COLORS`. (Colour number `373`, name `xkcd:bright cyan`)
 /// ## Representations:
 /// - int tuple `(65, 253, 254)`
 /// - float tuple `(0.25, 0.99, 1.0)`
@@ -3405,7 +3563,7 @@ pub const XKCD_BRIGHT_CYAN: Color = Color(65, 253, 254);
 
 /// Colour `(r = 207, g = 82, b = 78)`
 ///
-/// Colour `XKCD_DARK_CORAL` from the set `XKCD_COLORS`. (Colour number `374`)
+/// Colour `XKCD_DARK_CORAL` from the set `XKCD_COLORS`. (Colour number `374`, name `xkcd:dark coral`)
 /// ## Representations:
 /// - int tuple `(207, 82, 78)`
 /// - float tuple `(0.81, 0.32, 0.31)`
@@ -3414,7 +3572,7 @@ pub const XKCD_DARK_CORAL: Color = Color(207, 82, 78);
 
 /// Colour `(r = 33, g = 195, b = 111)`
 ///
-/// Colour `XKCD_ALGAE_GREEN` from the set `XKCD_COLORS`. (Colour number `375`)
+/// Colour `XKCD_ALGAE_GREEN` from the set `XKCD_COLORS`. (Colour number `375`, name `xkcd:algae green`)
 /// ## Representations:
 /// - int tuple `(33, 195, 111)`
 /// - float tuple `(0.13, 0.76, 0.44)`
@@ -3423,7 +3581,7 @@ pub const XKCD_ALGAE_GREEN: Color = Color(33, 195, 111);
 
 /// Colour `(r = 169, g = 3, b = 8)`
 ///
-/// Colour `XKCD_DARKISH_RED` from the set `XKCD_COLORS`. (Colour number `376`)
+/// Colour `XKCD_DARKISH_RED` from the set `XKCD_COLORS`. (Colour number `376`, name `xkcd:darkish red`)
 /// ## Representations:
 /// - int tuple `(169, 3, 8)`
 /// - float tuple `(0.66, 0.01, 0.03)`
@@ -3432,7 +3590,7 @@ pub const XKCD_DARKISH_RED: Color = Color(169, 3, 8);
 
 /// Colour `(r = 110, g = 16, b = 5)`
 ///
-/// Colour `XKCD_REDDY_BROWN` from the set `XKCD_COLORS`. (Colour number `377`)
+/// Colour `XKCD_REDDY_BROWN` from the set `XKCD_COLORS`. (Colour number `377`, name `xkcd:reddy brown`)
 /// ## Representations:
 /// - int tuple `(110, 16, 5)`
 /// - float tuple `(0.43, 0.06, 0.02)`
@@ -3441,7 +3599,7 @@ pub const XKCD_REDDY_BROWN: Color = Color(110, 16, 5);
 
 /// Colour `(r = 254, g = 130, b = 140)`
 ///
-/// Colour `XKCD_BLUSH_PINK` from the set `XKCD_COLORS`. (Colour number `378`)
+/// Colour `XKCD_BLUSH_PINK` from the set `XKCD_COLORS`. (Colour number `378`, name `xkcd:blush pink`)
 /// ## Representations:
 /// - int tuple `(254, 130, 140)`
 /// - float tuple `(1.0, 0.51, 0.55)`
@@ -3450,7 +3608,7 @@ pub const XKCD_BLUSH_PINK: Color = Color(254, 130, 140);
 
 /// Colour `(r = 75, g = 97, b = 19)`
 ///
-/// Colour `XKCD_CAMOUFLAGE_GREEN` from the set `XKCD_COLORS`. (Colour number `379`)
+/// Colour `XKCD_CAMOUFLAGE_GREEN` from the set `XKCD_COLORS`. (Colour number `379`, name `xkcd:camouflage green`)
 /// ## Representations:
 /// - int tuple `(75, 97, 19)`
 /// - float tuple `(0.29, 0.38, 0.07)`
@@ -3459,7 +3617,7 @@ pub const XKCD_CAMOUFLAGE_GREEN: Color = Color(75, 97, 19);
 
 /// Colour `(r = 77, g = 164, b = 9)`
 ///
-/// Colour `XKCD_LAWN_GREEN` from the set `XKCD_COLORS`. (Colour number `380`)
+/// Colour `XKCD_LAWN_GREEN` from the set `XKCD_COLORS`. (Colour number `380`, name `xkcd:lawn green`)
 /// ## Representations:
 /// - int tuple `(77, 164, 9)`
 /// - float tuple `(0.3, 0.64, 0.04)`
@@ -3468,7 +3626,7 @@ pub const XKCD_LAWN_GREEN: Color = Color(77, 164, 9);
 
 /// Colour `(r = 190, g = 174, b = 138)`
 ///
-/// Colour `XKCD_PUTTY` from the set `XKCD_COLORS`. (Colour number `381`)
+/// Colour `XKCD_PUTTY` from the set `XKCD_COLORS`. (Colour number `381`, name `xkcd:putty`)
 /// ## Representations:
 /// - int tuple `(190, 174, 138)`
 /// - float tuple `(0.75, 0.68, 0.54)`
@@ -3477,7 +3635,7 @@ pub const XKCD_PUTTY: Color = Color(190, 174, 138);
 
 /// Colour `(r = 3, g = 57, b = 248)`
 ///
-/// Colour `XKCD_VIBRANT_BLUE` from the set `XKCD_COLORS`. (Colour number `382`)
+/// Colour `XKCD_VIBRANT_BLUE` from the set `XKCD_COLORS`. (Colour number `382`, name `xkcd:vibrant blue`)
 /// ## Representations:
 /// - int tuple `(3, 57, 248)`
 /// - float tuple `(0.01, 0.22, 0.97)`
@@ -3486,7 +3644,7 @@ pub const XKCD_VIBRANT_BLUE: Color = Color(3, 57, 248);
 
 /// Colour `(r = 168, g = 143, b = 89)`
 ///
-/// Colour `XKCD_DARK_SAND` from the set `XKCD_COLORS`. (Colour number `383`)
+/// Colour `XKCD_DARK_SAND` from the set `XKCD_COLORS`. (Colour number `383`, name `xkcd:dark sand`)
 /// ## Representations:
 /// - int tuple `(168, 143, 89)`
 /// - float tuple `(0.66, 0.56, 0.35)`
@@ -3495,7 +3653,7 @@ pub const XKCD_DARK_SAND: Color = Color(168, 143, 89);
 
 /// Colour `(r = 93, g = 33, b = 208)`
 ///
-/// Colour `XKCD_PURPLE_SLASH_BLUE` from the set `XKCD_COLORS`. (Colour number `384`)
+/// Colour `XKCD_PURPLE_SLASH_BLUE` from the set `XKCD_COLORS`. (Colour number `384`, name `xkcd:purple/blue`)
 /// ## Representations:
 /// - int tuple `(93, 33, 208)`
 /// - float tuple `(0.36, 0.13, 0.82)`
@@ -3504,7 +3662,7 @@ pub const XKCD_PURPLE_SLASH_BLUE: Color = Color(93, 33, 208);
 
 /// Colour `(r = 254, g = 178, b = 9)`
 ///
-/// Colour `XKCD_SAFFRON` from the set `XKCD_COLORS`. (Colour number `385`)
+/// Colour `XKCD_SAFFRON` from the set `XKCD_COLORS`. (Colour number `385`, name `xkcd:saffron`)
 /// ## Representations:
 /// - int tuple `(254, 178, 9)`
 /// - float tuple `(1.0, 0.7, 0.04)`
@@ -3513,7 +3671,7 @@ pub const XKCD_SAFFRON: Color = Color(254, 178, 9);
 
 /// Colour `(r = 78, g = 81, b = 139)`
 ///
-/// Colour `XKCD_TWILIGHT` from the set `XKCD_COLORS`. (Colour number `386`)
+/// Colour `XKCD_TWILIGHT` from the set `XKCD_COLORS`. (Colour number `386`, name `xkcd:twilight`)
 /// ## Representations:
 /// - int tuple `(78, 81, 139)`
 /// - float tuple `(0.31, 0.32, 0.55)`
@@ -3522,7 +3680,7 @@ pub const XKCD_TWILIGHT: Color = Color(78, 81, 139);
 
 /// Colour `(r = 150, g = 78, b = 2)`
 ///
-/// Colour `XKCD_WARM_BROWN` from the set `XKCD_COLORS`. (Colour number `387`)
+/// Colour `XKCD_WARM_BROWN` from the set `XKCD_COLORS`. (Colour number `387`, name `xkcd:warm brown`)
 /// ## Representations:
 /// - int tuple `(150, 78, 2)`
 /// - float tuple `(0.59, 0.31, 0.01)`
@@ -3531,7 +3689,7 @@ pub const XKCD_WARM_BROWN: Color = Color(150, 78, 2);
 
 /// Colour `(r = 133, g = 163, b = 178)`
 ///
-/// Colour `XKCD_BLUEGREY` from the set `XKCD_COLORS`. (Colour number `388`)
+/// Colour `XKCD_BLUEGREY` from the set `XKCD_COLORS`. (Colour number `388`, name `xkcd:bluegrey`)
 /// ## Representations:
 /// - int tuple `(133, 163, 178)`
 /// - float tuple `(0.52, 0.64, 0.7)`
@@ -3540,7 +3698,7 @@ pub const XKCD_BLUEGREY: Color = Color(133, 163, 178);
 
 /// Colour `(r = 255, g = 105, b = 175)`
 ///
-/// Colour `XKCD_BUBBLE_GUM_PINK` from the set `XKCD_COLORS`. (Colour number `389`)
+/// Colour `XKCD_BUBBLE_GUM_PINK` from the set `XKCD_COLORS`. (Colour number `389`, name `xkcd:bubble gum pink`)
 /// ## Representations:
 /// - int tuple `(255, 105, 175)`
 /// - float tuple `(1.0, 0.41, 0.69)`
@@ -3549,7 +3707,7 @@ pub const XKCD_BUBBLE_GUM_PINK: Color = Color(255, 105, 175);
 
 /// Colour `(r = 195, g = 251, b = 244)`
 ///
-/// Colour `XKCD_DUCK_EGG_BLUE` from the set `XKCD_COLORS`. (Colour number `390`)
+/// Colour `XKCD_DUCK_EGG_BLUE` from the set `XKCD_COLORS`. (Colour number `390`, name `xkcd:duck egg blue`)
 /// ## Representations:
 /// - int tuple `(195, 251, 244)`
 /// - float tuple `(0.76, 0.98, 0.96)`
@@ -3558,7 +3716,7 @@ pub const XKCD_DUCK_EGG_BLUE: Color = Color(195, 251, 244);
 
 /// Colour `(r = 42, g = 254, b = 183)`
 ///
-/// Colour `XKCD_GREENISH_CYAN` from the set `XKCD_COLORS`. (Colour number `391`)
+/// Colour `XKCD_GREENISH_CYAN` from the set `XKCD_COLORS`. (Colour number `391`, name `xkcd:greenish cyan`)
 /// ## Representations:
 /// - int tuple `(42, 254, 183)`
 /// - float tuple `(0.16, 1.0, 0.72)`
@@ -3567,7 +3725,7 @@ pub const XKCD_GREENISH_CYAN: Color = Color(42, 254, 183);
 
 /// Colour `(r = 0, g = 95, b = 106)`
 ///
-/// Colour `XKCD_PETROL` from the set `XKCD_COLORS`. (Colour number `392`)
+/// Colour `XKCD_PETROL` from the set `XKCD_COLORS`. (Colour number `392`, name `xkcd:petrol`)
 /// ## Representations:
 /// - int tuple `(0, 95, 106)`
 /// - float tuple `(0.0, 0.37, 0.42)`
@@ -3576,7 +3734,7 @@ pub const XKCD_PETROL: Color = Color(0, 95, 106);
 
 /// Colour `(r = 12, g = 23, b = 147)`
 ///
-/// Colour `XKCD_ROYAL` from the set `XKCD_COLORS`. (Colour number `393`)
+/// Colour `XKCD_ROYAL` from the set `XKCD_COLORS`. (Colour number `393`, name `xkcd:royal`)
 /// ## Representations:
 /// - int tuple `(12, 23, 147)`
 /// - float tuple `(0.05, 0.09, 0.58)`
@@ -3585,7 +3743,7 @@ pub const XKCD_ROYAL: Color = Color(12, 23, 147);
 
 /// Colour `(r = 255, g = 255, b = 129)`
 ///
-/// Colour `XKCD_BUTTER` from the set `XKCD_COLORS`. (Colour number `394`)
+/// Colour `XKCD_BUTTER` from the set `XKCD_COLORS`. (Colour number `394`, name `xkcd:butter`)
 /// ## Representations:
 /// - int tuple `(255, 255, 129)`
 /// - float tuple `(1.0, 1.0, 0.51)`
@@ -3594,7 +3752,7 @@ pub const XKCD_BUTTER: Color = Color(255, 255, 129);
 
 /// Colour `(r = 240, g = 131, b = 58)`
 ///
-/// Colour `XKCD_DUSTY_ORANGE` from the set `XKCD_COLORS`. (Colour number `395`)
+/// Colour `XKCD_DUSTY_ORANGE` from the set `XKCD_COLORS`. (Colour number `395`, name `xkcd:dusty orange`)
 /// ## Representations:
 /// - int tuple `(240, 131, 58)`
 /// - float tuple `(0.94, 0.51, 0.23)`
@@ -3603,7 +3761,7 @@ pub const XKCD_DUSTY_ORANGE: Color = Color(240, 131, 58);
 
 /// Colour `(r = 241, g = 243, b = 63)`
 ///
-/// Colour `XKCD_OFF_YELLOW` from the set `XKCD_COLORS`. (Colour number `396`)
+/// Colour `XKCD_OFF_YELLOW` from the set `XKCD_COLORS`. (Colour number `396`, name `xkcd:off yellow`)
 /// ## Representations:
 /// - int tuple `(241, 243, 63)`
 /// - float tuple `(0.95, 0.95, 0.25)`
@@ -3612,7 +3770,7 @@ pub const XKCD_OFF_YELLOW: Color = Color(241, 243, 63);
 
 /// Colour `(r = 177, g = 210, b = 123)`
 ///
-/// Colour `XKCD_PALE_OLIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `397`)
+/// Colour `XKCD_PALE_OLIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `397`, name `xkcd:pale olive green`)
 /// ## Representations:
 /// - int tuple `(177, 210, 123)`
 /// - float tuple `(0.69, 0.82, 0.48)`
@@ -3621,7 +3779,7 @@ pub const XKCD_PALE_OLIVE_GREEN: Color = Color(177, 210, 123);
 
 /// Colour `(r = 252, g = 130, b = 74)`
 ///
-/// Colour `XKCD_ORANGISH` from the set `XKCD_COLORS`. (Colour number `398`)
+/// Colour `XKCD_ORANGISH` from the set `XKCD_COLORS`. (Colour number `398`, name `xkcd:orangish`)
 /// ## Representations:
 /// - int tuple `(252, 130, 74)`
 /// - float tuple `(0.99, 0.51, 0.29)`
@@ -3630,7 +3788,7 @@ pub const XKCD_ORANGISH: Color = Color(252, 130, 74);
 
 /// Colour `(r = 113, g = 170, b = 52)`
 ///
-/// Colour `XKCD_LEAF` from the set `XKCD_COLORS`. (Colour number `399`)
+/// Colour `XKCD_LEAF` from the set `XKCD_COLORS`. (Colour number `399`, name `xkcd:leaf`)
 /// ## Representations:
 /// - int tuple `(113, 170, 52)`
 /// - float tuple `(0.44, 0.67, 0.2)`
@@ -3639,7 +3797,7 @@ pub const XKCD_LEAF: Color = Color(113, 170, 52);
 
 /// Colour `(r = 183, g = 201, b = 226)`
 ///
-/// Colour `XKCD_LIGHT_BLUE_GREY` from the set `XKCD_COLORS`. (Colour number `400`)
+/// Colour `XKCD_LIGHT_BLUE_GREY` from the set `XKCD_COLORS`. (Colour number `400`, name `xkcd:light blue grey`)
 /// ## Representations:
 /// - int tuple `(183, 201, 226)`
 /// - float tuple `(0.72, 0.79, 0.89)`
@@ -3648,7 +3806,7 @@ pub const XKCD_LIGHT_BLUE_GREY: Color = Color(183, 201, 226);
 
 /// Colour `(r = 75, g = 1, b = 1)`
 ///
-/// Colour `XKCD_DRIED_BLOOD` from the set `XKCD_COLORS`. (Colour number `401`)
+/// Colour `XKCD_DRIED_BLOOD` from the set `XKCD_COLORS`. (Colour number `401`, name `xkcd:dried blood`)
 /// ## Representations:
 /// - int tuple `(75, 1, 1)`
 /// - float tuple `(0.29, 0.0, 0.0)`
@@ -3657,7 +3815,7 @@ pub const XKCD_DRIED_BLOOD: Color = Color(75, 1, 1);
 
 /// Colour `(r = 165, g = 82, b = 230)`
 ///
-/// Colour `XKCD_LIGHTISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `402`)
+/// Colour `XKCD_LIGHTISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `402`, name `xkcd:lightish purple`)
 /// ## Representations:
 /// - int tuple `(165, 82, 230)`
 /// - float tuple `(0.65, 0.32, 0.9)`
@@ -3666,7 +3824,7 @@ pub const XKCD_LIGHTISH_PURPLE: Color = Color(165, 82, 230);
 
 /// Colour `(r = 175, g = 47, b = 13)`
 ///
-/// Colour `XKCD_RUSTY_RED` from the set `XKCD_COLORS`. (Colour number `403`)
+/// Colour `XKCD_RUSTY_RED` from the set `XKCD_COLORS`. (Colour number `403`, name `xkcd:rusty red`)
 /// ## Representations:
 /// - int tuple `(175, 47, 13)`
 /// - float tuple `(0.69, 0.18, 0.05)`
@@ -3675,7 +3833,7 @@ pub const XKCD_RUSTY_RED: Color = Color(175, 47, 13);
 
 /// Colour `(r = 139, g = 136, b = 248)`
 ///
-/// Colour `XKCD_LAVENDER_BLUE` from the set `XKCD_COLORS`. (Colour number `404`)
+/// Colour `XKCD_LAVENDER_BLUE` from the set `XKCD_COLORS`. (Colour number `404`, name `xkcd:lavender blue`)
 /// ## Representations:
 /// - int tuple `(139, 136, 248)`
 /// - float tuple `(0.55, 0.53, 0.97)`
@@ -3684,7 +3842,7 @@ pub const XKCD_LAVENDER_BLUE: Color = Color(139, 136, 248);
 
 /// Colour `(r = 154, g = 247, b = 100)`
 ///
-/// Colour `XKCD_LIGHT_GRASS_GREEN` from the set `XKCD_COLORS`. (Colour number `405`)
+/// Colour `XKCD_LIGHT_GRASS_GREEN` from the set `XKCD_COLORS`. (Colour number `405`, name `xkcd:light grass green`)
 /// ## Representations:
 /// - int tuple `(154, 247, 100)`
 /// - float tuple `(0.6, 0.97, 0.39)`
@@ -3693,7 +3851,7 @@ pub const XKCD_LIGHT_GRASS_GREEN: Color = Color(154, 247, 100);
 
 /// Colour `(r = 166, g = 251, b = 178)`
 ///
-/// Colour `XKCD_LIGHT_MINT_GREEN` from the set `XKCD_COLORS`. (Colour number `406`)
+/// Colour `XKCD_LIGHT_MINT_GREEN` from the set `XKCD_COLORS`. (Colour number `406`, name `xkcd:light mint green`)
 /// ## Representations:
 /// - int tuple `(166, 251, 178)`
 /// - float tuple `(0.65, 0.98, 0.7)`
@@ -3702,7 +3860,7 @@ pub const XKCD_LIGHT_MINT_GREEN: Color = Color(166, 251, 178);
 
 /// Colour `(r = 255, g = 197, b = 18)`
 ///
-/// Colour `XKCD_SUNFLOWER` from the set `XKCD_COLORS`. (Colour number `407`)
+/// Colour `XKCD_SUNFLOWER` from the set `XKCD_COLORS`. (Colour number `407`, name `xkcd:sunflower`)
 /// ## Representations:
 /// - int tuple `(255, 197, 18)`
 /// - float tuple `(1.0, 0.77, 0.07)`
@@ -3711,7 +3869,7 @@ pub const XKCD_SUNFLOWER: Color = Color(255, 197, 18);
 
 /// Colour `(r = 117, g = 8, b = 81)`
 ///
-/// Colour `XKCD_VELVET` from the set `XKCD_COLORS`. (Colour number `408`)
+/// Colour `XKCD_VELVET` from the set `XKCD_COLORS`. (Colour number `408`, name `xkcd:velvet`)
 /// ## Representations:
 /// - int tuple `(117, 8, 81)`
 /// - float tuple `(0.46, 0.03, 0.32)`
@@ -3720,7 +3878,7 @@ pub const XKCD_VELVET: Color = Color(117, 8, 81);
 
 /// Colour `(r = 193, g = 74, b = 9)`
 ///
-/// Colour `XKCD_BRICK_ORANGE` from the set `XKCD_COLORS`. (Colour number `409`)
+/// Colour `XKCD_BRICK_ORANGE` from the set `XKCD_COLORS`. (Colour number `409`, name `xkcd:brick orange`)
 /// ## Representations:
 /// - int tuple `(193, 74, 9)`
 /// - float tuple `(0.76, 0.29, 0.04)`
@@ -3729,7 +3887,7 @@ pub const XKCD_BRICK_ORANGE: Color = Color(193, 74, 9);
 
 /// Colour `(r = 254, g = 47, b = 74)`
 ///
-/// Colour `XKCD_LIGHTISH_RED` from the set `XKCD_COLORS`. (Colour number `410`)
+/// Colour `XKCD_LIGHTISH_RED` from the set `XKCD_COLORS`. (Colour number `410`, name `xkcd:lightish red`)
 /// ## Representations:
 /// - int tuple `(254, 47, 74)`
 /// - float tuple `(1.0, 0.18, 0.29)`
@@ -3738,7 +3896,7 @@ pub const XKCD_LIGHTISH_RED: Color = Color(254, 47, 74);
 
 /// Colour `(r = 2, g = 3, b = 226)`
 ///
-/// Colour `XKCD_PURE_BLUE` from the set `XKCD_COLORS`. (Colour number `411`)
+/// Colour `XKCD_PURE_BLUE` from the set `XKCD_COLORS`. (Colour number `411`, name `xkcd:pure blue`)
 /// ## Representations:
 /// - int tuple `(2, 3, 226)`
 /// - float tuple `(0.01, 0.01, 0.89)`
@@ -3747,7 +3905,7 @@ pub const XKCD_PURE_BLUE: Color = Color(2, 3, 226);
 
 /// Colour `(r = 10, g = 67, b = 122)`
 ///
-/// Colour `XKCD_TWILIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `412`)
+/// Colour `XKCD_TWILIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `412`, name `xkcd:twilight blue`)
 /// ## Representations:
 /// - int tuple `(10, 67, 122)`
 /// - float tuple `(0.04, 0.26, 0.48)`
@@ -3756,7 +3914,7 @@ pub const XKCD_TWILIGHT_BLUE: Color = Color(10, 67, 122);
 
 /// Colour `(r = 165, g = 0, b = 85)`
 ///
-/// Colour `XKCD_VIOLET_RED` from the set `XKCD_COLORS`. (Colour number `413`)
+/// Colour `XKCD_VIOLET_RED` from the set `XKCD_COLORS`. (Colour number `413`, name `xkcd:violet red`)
 /// ## Representations:
 /// - int tuple `(165, 0, 85)`
 /// - float tuple `(0.65, 0.0, 0.33)`
@@ -3765,7 +3923,7 @@ pub const XKCD_VIOLET_RED: Color = Color(165, 0, 85);
 
 /// Colour `(r = 174, g = 139, b = 12)`
 ///
-/// Colour `XKCD_YELLOWY_BROWN` from the set `XKCD_COLORS`. (Colour number `414`)
+/// Colour `XKCD_YELLOWY_BROWN` from the set `XKCD_COLORS`. (Colour number `414`, name `xkcd:yellowy brown`)
 /// ## Representations:
 /// - int tuple `(174, 139, 12)`
 /// - float tuple `(0.68, 0.55, 0.05)`
@@ -3774,7 +3932,7 @@ pub const XKCD_YELLOWY_BROWN: Color = Color(174, 139, 12);
 
 /// Colour `(r = 253, g = 121, b = 143)`
 ///
-/// Colour `XKCD_CARNATION` from the set `XKCD_COLORS`. (Colour number `415`)
+/// Colour `XKCD_CARNATION` from the set `XKCD_COLORS`. (Colour number `415`, name `xkcd:carnation`)
 /// ## Representations:
 /// - int tuple `(253, 121, 143)`
 /// - float tuple `(0.99, 0.47, 0.56)`
@@ -3783,7 +3941,7 @@ pub const XKCD_CARNATION: Color = Color(253, 121, 143);
 
 /// Colour `(r = 191, g = 172, b = 5)`
 ///
-/// Colour `XKCD_MUDDY_YELLOW` from the set `XKCD_COLORS`. (Colour number `416`)
+/// Colour `XKCD_MUDDY_YELLOW` from the set `XKCD_COLORS`. (Colour number `416`, name `xkcd:muddy yellow`)
 /// ## Representations:
 /// - int tuple `(191, 172, 5)`
 /// - float tuple `(0.75, 0.67, 0.02)`
@@ -3792,7 +3950,7 @@ pub const XKCD_MUDDY_YELLOW: Color = Color(191, 172, 5);
 
 /// Colour `(r = 62, g = 175, b = 118)`
 ///
-/// Colour `XKCD_DARK_SEAFOAM_GREEN` from the set `XKCD_COLORS`. (Colour number `417`)
+/// Colour `XKCD_DARK_SEAFOAM_GREEN` from the set `XKCD_COLORS`. (Colour number `417`, name `xkcd:dark seafoam green`)
 /// ## Representations:
 /// - int tuple `(62, 175, 118)`
 /// - float tuple `(0.24, 0.69, 0.46)`
@@ -3801,7 +3959,7 @@ pub const XKCD_DARK_SEAFOAM_GREEN: Color = Color(62, 175, 118);
 
 /// Colour `(r = 199, g = 71, b = 103)`
 ///
-/// Colour `XKCD_DEEP_ROSE` from the set `XKCD_COLORS`. (Colour number `418`)
+/// Colour `XKCD_DEEP_ROSE` from the set `XKCD_COLORS`. (Colour number `418`, name `xkcd:deep rose`)
 /// ## Representations:
 /// - int tuple `(199, 71, 103)`
 /// - float tuple `(0.78, 0.28, 0.4)`
@@ -3810,7 +3968,7 @@ pub const XKCD_DEEP_ROSE: Color = Color(199, 71, 103);
 
 /// Colour `(r = 185, g = 72, b = 78)`
 ///
-/// Colour `XKCD_DUSTY_RED` from the set `XKCD_COLORS`. (Colour number `419`)
+/// Colour `XKCD_DUSTY_RED` from the set `XKCD_COLORS`. (Colour number `419`, name `xkcd:dusty red`)
 /// ## Representations:
 /// - int tuple `(185, 72, 78)`
 /// - float tuple `(0.73, 0.28, 0.31)`
@@ -3819,7 +3977,7 @@ pub const XKCD_DUSTY_RED: Color = Color(185, 72, 78);
 
 /// Colour `(r = 100, g = 125, b = 142)`
 ///
-/// Colour `XKCD_GREY_SLASH_BLUE` from the set `XKCD_COLORS`. (Colour number `420`)
+/// Colour `XKCD_GREY_SLASH_BLUE` from the set `XKCD_COLORS`. (Colour number `420`, name `xkcd:grey/blue`)
 /// ## Representations:
 /// - int tuple `(100, 125, 142)`
 /// - float tuple `(0.39, 0.49, 0.56)`
@@ -3828,7 +3986,7 @@ pub const XKCD_GREY_SLASH_BLUE: Color = Color(100, 125, 142);
 
 /// Colour `(r = 191, g = 254, b = 40)`
 ///
-/// Colour `XKCD_LEMON_LIME` from the set `XKCD_COLORS`. (Colour number `421`)
+/// Colour `XKCD_LEMON_LIME` from the set `XKCD_COLORS`. (Colour number `421`, name `xkcd:lemon lime`)
 /// ## Representations:
 /// - int tuple `(191, 254, 40)`
 /// - float tuple `(0.75, 1.0, 0.16)`
@@ -3837,7 +3995,7 @@ pub const XKCD_LEMON_LIME: Color = Color(191, 254, 40);
 
 /// Colour `(r = 215, g = 37, b = 222)`
 ///
-/// Colour `XKCD_PURPLE_SLASH_PINK` from the set `XKCD_COLORS`. (Colour number `422`)
+/// Colour `XKCD_PURPLE_SLASH_PINK` from the set `XKCD_COLORS`. (Colour number `422`, name `xkcd:purple/pink`)
 /// ## Representations:
 /// - int tuple `(215, 37, 222)`
 /// - float tuple `(0.84, 0.15, 0.87)`
@@ -3846,7 +4004,7 @@ pub const XKCD_PURPLE_SLASH_PINK: Color = Color(215, 37, 222);
 
 /// Colour `(r = 178, g = 151, b = 5)`
 ///
-/// Colour `XKCD_BROWN_YELLOW` from the set `XKCD_COLORS`. (Colour number `423`)
+/// Colour `XKCD_BROWN_YELLOW` from the set `XKCD_COLORS`. (Colour number `423`, name `xkcd:brown yellow`)
 /// ## Representations:
 /// - int tuple `(178, 151, 5)`
 /// - float tuple `(0.7, 0.59, 0.02)`
@@ -3855,7 +4013,7 @@ pub const XKCD_BROWN_YELLOW: Color = Color(178, 151, 5);
 
 /// Colour `(r = 103, g = 58, b = 63)`
 ///
-/// Colour `XKCD_PURPLE_BROWN` from the set `XKCD_COLORS`. (Colour number `424`)
+/// Colour `XKCD_PURPLE_BROWN` from the set `XKCD_COLORS`. (Colour number `424`, name `xkcd:purple brown`)
 /// ## Representations:
 /// - int tuple `(103, 58, 63)`
 /// - float tuple `(0.4, 0.23, 0.25)`
@@ -3864,7 +4022,7 @@ pub const XKCD_PURPLE_BROWN: Color = Color(103, 58, 63);
 
 /// Colour `(r = 168, g = 125, b = 194)`
 ///
-/// Colour `XKCD_WISTERIA` from the set `XKCD_COLORS`. (Colour number `425`)
+/// Colour `XKCD_WISTERIA` from the set `XKCD_COLORS`. (Colour number `425`, name `xkcd:wisteria`)
 /// ## Representations:
 /// - int tuple `(168, 125, 194)`
 /// - float tuple `(0.66, 0.49, 0.76)`
@@ -3873,7 +4031,7 @@ pub const XKCD_WISTERIA: Color = Color(168, 125, 194);
 
 /// Colour `(r = 250, g = 254, b = 75)`
 ///
-/// Colour `XKCD_BANANA_YELLOW` from the set `XKCD_COLORS`. (Colour number `426`)
+/// Colour `XKCD_BANANA_YELLOW` from the set `XKCD_COLORS`. (Colour number `426`, name `xkcd:banana yellow`)
 /// ## Representations:
 /// - int tuple `(250, 254, 75)`
 /// - float tuple `(0.98, 1.0, 0.29)`
@@ -3882,7 +4040,7 @@ pub const XKCD_BANANA_YELLOW: Color = Color(250, 254, 75);
 
 /// Colour `(r = 192, g = 2, b = 47)`
 ///
-/// Colour `XKCD_LIPSTICK_RED` from the set `XKCD_COLORS`. (Colour number `427`)
+/// Colour `XKCD_LIPSTICK_RED` from the set `XKCD_COLORS`. (Colour number `427`, name `xkcd:lipstick red`)
 /// ## Representations:
 /// - int tuple `(192, 2, 47)`
 /// - float tuple `(0.75, 0.01, 0.18)`
@@ -3891,7 +4049,7 @@ pub const XKCD_LIPSTICK_RED: Color = Color(192, 2, 47);
 
 /// Colour `(r = 14, g = 135, b = 204)`
 ///
-/// Colour `XKCD_WATER_BLUE` from the set `XKCD_COLORS`. (Colour number `428`)
+/// Colour `XKCD_WATER_BLUE` from the set `XKCD_COLORS`. (Colour number `428`, name `xkcd:water blue`)
 /// ## Representations:
 /// - int tuple `(14, 135, 204)`
 /// - float tuple `(0.05, 0.53, 0.8)`
@@ -3900,7 +4058,7 @@ pub const XKCD_WATER_BLUE: Color = Color(14, 135, 204);
 
 /// Colour `(r = 141, g = 132, b = 104)`
 ///
-/// Colour `XKCD_BROWN_GREY` from the set `XKCD_COLORS`. (Colour number `429`)
+/// Colour `XKCD_BROWN_GREY` from the set `XKCD_COLORS`. (Colour number `429`, name `xkcd:brown grey`)
 /// ## Representations:
 /// - int tuple `(141, 132, 104)`
 /// - float tuple `(0.55, 0.52, 0.41)`
@@ -3909,7 +4067,7 @@ pub const XKCD_BROWN_GREY: Color = Color(141, 132, 104);
 
 /// Colour `(r = 173, g = 3, b = 222)`
 ///
-/// Colour `XKCD_VIBRANT_PURPLE` from the set `XKCD_COLORS`. (Colour number `430`)
+/// Colour `XKCD_VIBRANT_PURPLE` from the set `XKCD_COLORS`. (Colour number `430`, name `xkcd:vibrant purple`)
 /// ## Representations:
 /// - int tuple `(173, 3, 222)`
 /// - float tuple `(0.68, 0.01, 0.87)`
@@ -3918,7 +4076,7 @@ pub const XKCD_VIBRANT_PURPLE: Color = Color(173, 3, 222);
 
 /// Colour `(r = 140, g = 255, b = 158)`
 ///
-/// Colour `XKCD_BABY_GREEN` from the set `XKCD_COLORS`. (Colour number `431`)
+/// Colour `XKCD_BABY_GREEN` from the set `XKCD_COLORS`. (Colour number `431`, name `xkcd:baby green`)
 /// ## Representations:
 /// - int tuple `(140, 255, 158)`
 /// - float tuple `(0.55, 1.0, 0.62)`
@@ -3927,7 +4085,7 @@ pub const XKCD_BABY_GREEN: Color = Color(140, 255, 158);
 
 /// Colour `(r = 148, g = 172, b = 2)`
 ///
-/// Colour `XKCD_BARF_GREEN` from the set `XKCD_COLORS`. (Colour number `432`)
+/// Colour `XKCD_BARF_GREEN` from the set `XKCD_COLORS`. (Colour number `432`, name `xkcd:barf green`)
 /// ## Representations:
 /// - int tuple `(148, 172, 2)`
 /// - float tuple `(0.58, 0.67, 0.01)`
@@ -3936,7 +4094,7 @@ pub const XKCD_BARF_GREEN: Color = Color(148, 172, 2);
 
 /// Colour `(r = 196, g = 255, b = 247)`
 ///
-/// Colour `XKCD_EGGSHELL_BLUE` from the set `XKCD_COLORS`. (Colour number `433`)
+/// Colour `XKCD_EGGSHELL_BLUE` from the set `XKCD_COLORS`. (Colour number `433`, name `xkcd:eggshell blue`)
 /// ## Representations:
 /// - int tuple `(196, 255, 247)`
 /// - float tuple `(0.77, 1.0, 0.97)`
@@ -3945,7 +4103,7 @@ pub const XKCD_EGGSHELL_BLUE: Color = Color(196, 255, 247);
 
 /// Colour `(r = 253, g = 238, b = 115)`
 ///
-/// Colour `XKCD_SANDY_YELLOW` from the set `XKCD_COLORS`. (Colour number `434`)
+/// Colour `XKCD_SANDY_YELLOW` from the set `XKCD_COLORS`. (Colour number `434`, name `xkcd:sandy yellow`)
 /// ## Representations:
 /// - int tuple `(253, 238, 115)`
 /// - float tuple `(0.99, 0.93, 0.45)`
@@ -3954,7 +4112,7 @@ pub const XKCD_SANDY_YELLOW: Color = Color(253, 238, 115);
 
 /// Colour `(r = 51, g = 184, b = 100)`
 ///
-/// Colour `XKCD_COOL_GREEN` from the set `XKCD_COLORS`. (Colour number `435`)
+/// Colour `XKCD_COOL_GREEN` from the set `XKCD_COLORS`. (Colour number `435`, name `xkcd:cool green`)
 /// ## Representations:
 /// - int tuple `(51, 184, 100)`
 /// - float tuple `(0.2, 0.72, 0.39)`
@@ -3963,7 +4121,7 @@ pub const XKCD_COOL_GREEN: Color = Color(51, 184, 100);
 
 /// Colour `(r = 255, g = 249, b = 208)`
 ///
-/// Colour `XKCD_PALE` from the set `XKCD_COLORS`. (Colour number `436`)
+/// Colour `XKCD_PALE` from the set `XKCD_COLORS`. (Colour number `436`, name `xkcd:pale`)
 /// ## Representations:
 /// - int tuple `(255, 249, 208)`
 /// - float tuple `(1.0, 0.98, 0.82)`
@@ -3972,7 +4130,7 @@ pub const XKCD_PALE: Color = Color(255, 249, 208);
 
 /// Colour `(r = 117, g = 141, b = 163)`
 ///
-/// Colour `XKCD_BLUE_SLASH_GREY` from the set `XKCD_COLORS`. (Colour number `437`)
+/// Colour `XKCD_BLUE_SLASH_GREY` from the set `XKCD_COLORS`. (Colour number `437`, name `xkcd:blue/grey`)
 /// ## Representations:
 /// - int tuple `(117, 141, 163)`
 /// - float tuple `(0.46, 0.55, 0.64)`
@@ -3981,7 +4139,7 @@ pub const XKCD_BLUE_SLASH_GREY: Color = Color(117, 141, 163);
 
 /// Colour `(r = 245, g = 4, b = 201)`
 ///
-/// Colour `XKCD_HOT_MAGENTA` from the set `XKCD_COLORS`. (Colour number `438`)
+/// Colour `XKCD_HOT_MAGENTA` from the set `XKCD_COLORS`. (Colour number `438`, name `xkcd:hot magenta`)
 /// ## Representations:
 /// - int tuple `(245, 4, 201)`
 /// - float tuple `(0.96, 0.02, 0.79)`
@@ -3990,7 +4148,7 @@ pub const XKCD_HOT_MAGENTA: Color = Color(245, 4, 201);
 
 /// Colour `(r = 119, g = 161, b = 181)`
 ///
-/// Colour `XKCD_GREYBLUE` from the set `XKCD_COLORS`. (Colour number `439`)
+/// Colour `XKCD_GREYBLUE` from the set `XKCD_COLORS`. (Colour number `439`, name `xkcd:greyblue`)
 /// ## Representations:
 /// - int tuple `(119, 161, 181)`
 /// - float tuple `(0.47, 0.63, 0.71)`
@@ -3999,7 +4157,7 @@ pub const XKCD_GREYBLUE: Color = Color(119, 161, 181);
 
 /// Colour `(r = 135, g = 86, b = 228)`
 ///
-/// Colour `XKCD_PURPLEY` from the set `XKCD_COLORS`. (Colour number `440`)
+/// Colour `XKCD_PURPLEY` from the set `XKCD_COLORS`. (Colour number `440`, name `xkcd:purpley`)
 /// ## Representations:
 /// - int tuple `(135, 86, 228)`
 /// - float tuple `(0.53, 0.34, 0.89)`
@@ -4008,7 +4166,7 @@ pub const XKCD_PURPLEY: Color = Color(135, 86, 228);
 
 /// Colour `(r = 136, g = 151, b = 23)`
 ///
-/// Colour `XKCD_BABY_SHIT_GREEN` from the set `XKCD_COLORS`. (Colour number `441`)
+/// Colour `XKCD_BABY_SHIT_GREEN` from the set `XKCD_COLORS`. (Colour number `441`, name `xkcd:baby shit green`)
 /// ## Representations:
 /// - int tuple `(136, 151, 23)`
 /// - float tuple `(0.53, 0.59, 0.09)`
@@ -4017,7 +4175,7 @@ pub const XKCD_BABY_SHIT_GREEN: Color = Color(136, 151, 23);
 
 /// Colour `(r = 194, g = 126, b = 121)`
 ///
-/// Colour `XKCD_BROWNISH_PINK` from the set `XKCD_COLORS`. (Colour number `442`)
+/// Colour `XKCD_BROWNISH_PINK` from the set `XKCD_COLORS`. (Colour number `442`, name `xkcd:brownish pink`)
 /// ## Representations:
 /// - int tuple `(194, 126, 121)`
 /// - float tuple `(0.76, 0.49, 0.47)`
@@ -4026,7 +4184,7 @@ pub const XKCD_BROWNISH_PINK: Color = Color(194, 126, 121);
 
 /// Colour `(r = 1, g = 115, b = 113)`
 ///
-/// Colour `XKCD_DARK_AQUAMARINE` from the set `XKCD_COLORS`. (Colour number `443`)
+/// Colour `XKCD_DARK_AQUAMARINE` from the set `XKCD_COLORS`. (Colour number `443`, name `xkcd:dark aquamarine`)
 /// ## Representations:
 /// - int tuple `(1, 115, 113)`
 /// - float tuple `(0.0, 0.45, 0.44)`
@@ -4035,7 +4193,7 @@ pub const XKCD_DARK_AQUAMARINE: Color = Color(1, 115, 113);
 
 /// Colour `(r = 159, g = 131, b = 3)`
 ///
-/// Colour `XKCD_DIARRHEA` from the set `XKCD_COLORS`. (Colour number `444`)
+/// Colour `XKCD_DIARRHEA` from the set `XKCD_COLORS`. (Colour number `444`, name `xkcd:diarrhea`)
 /// ## Representations:
 /// - int tuple `(159, 131, 3)`
 /// - float tuple `(0.62, 0.51, 0.01)`
@@ -4044,7 +4202,7 @@ pub const XKCD_DIARRHEA: Color = Color(159, 131, 3);
 
 /// Colour `(r = 247, g = 213, b = 96)`
 ///
-/// Colour `XKCD_LIGHT_MUSTARD` from the set `XKCD_COLORS`. (Colour number `445`)
+/// Colour `XKCD_LIGHT_MUSTARD` from the set `XKCD_COLORS`. (Colour number `445`, name `xkcd:light mustard`)
 /// ## Representations:
 /// - int tuple `(247, 213, 96)`
 /// - float tuple `(0.97, 0.84, 0.38)`
@@ -4053,7 +4211,7 @@ pub const XKCD_LIGHT_MUSTARD: Color = Color(247, 213, 96);
 
 /// Colour `(r = 189, g = 246, b = 254)`
 ///
-/// Colour `XKCD_PALE_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `446`)
+/// Colour `XKCD_PALE_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `446`, name `xkcd:pale sky blue`)
 /// ## Representations:
 /// - int tuple `(189, 246, 254)`
 /// - float tuple `(0.74, 0.96, 1.0)`
@@ -4062,7 +4220,7 @@ pub const XKCD_PALE_SKY_BLUE: Color = Color(189, 246, 254);
 
 /// Colour `(r = 117, g = 184, b = 79)`
 ///
-/// Colour `XKCD_TURTLE_GREEN` from the set `XKCD_COLORS`. (Colour number `447`)
+/// Colour `XKCD_TURTLE_GREEN` from the set `XKCD_COLORS`. (Colour number `447`, name `xkcd:turtle green`)
 /// ## Representations:
 /// - int tuple `(117, 184, 79)`
 /// - float tuple `(0.46, 0.72, 0.31)`
@@ -4071,7 +4229,7 @@ pub const XKCD_TURTLE_GREEN: Color = Color(117, 184, 79);
 
 /// Colour `(r = 156, g = 187, b = 4)`
 ///
-/// Colour `XKCD_BRIGHT_OLIVE` from the set `XKCD_COLORS`. (Colour number `448`)
+/// Colour `XKCD_BRIGHT_OLIVE` from the set `XKCD_COLORS`. (Colour number `448`, name `xkcd:bright olive`)
 /// ## Representations:
 /// - int tuple `(156, 187, 4)`
 /// - float tuple `(0.61, 0.73, 0.02)`
@@ -4080,7 +4238,7 @@ pub const XKCD_BRIGHT_OLIVE: Color = Color(156, 187, 4);
 
 /// Colour `(r = 41, g = 70, b = 91)`
 ///
-/// Colour `XKCD_DARK_GREY_BLUE` from the set `XKCD_COLORS`. (Colour number `449`)
+/// Colour `XKCD_DARK_GREY_BLUE` from the set `XKCD_COLORS`. (Colour number `449`, name `xkcd:dark grey blue`)
 /// ## Representations:
 /// - int tuple `(41, 70, 91)`
 /// - float tuple `(0.16, 0.27, 0.36)`
@@ -4089,7 +4247,7 @@ pub const XKCD_DARK_GREY_BLUE: Color = Color(41, 70, 91);
 
 /// Colour `(r = 105, g = 96, b = 6)`
 ///
-/// Colour `XKCD_GREENY_BROWN` from the set `XKCD_COLORS`. (Colour number `450`)
+/// Colour `XKCD_GREENY_BROWN` from the set `XKCD_COLORS`. (Colour number `450`, name `xkcd:greeny brown`)
 /// ## Representations:
 /// - int tuple `(105, 96, 6)`
 /// - float tuple `(0.41, 0.38, 0.02)`
@@ -4098,7 +4256,7 @@ pub const XKCD_GREENY_BROWN: Color = Color(105, 96, 6);
 
 /// Colour `(r = 173, g = 248, b = 2)`
 ///
-/// Colour `XKCD_LEMON_GREEN` from the set `XKCD_COLORS`. (Colour number `451`)
+/// Colour `XKCD_LEMON_GREEN` from the set `XKCD_COLORS`. (Colour number `451`, name `xkcd:lemon green`)
 /// ## Representations:
 /// - int tuple `(173, 248, 2)`
 /// - float tuple `(0.68, 0.97, 0.01)`
@@ -4107,7 +4265,7 @@ pub const XKCD_LEMON_GREEN: Color = Color(173, 248, 2);
 
 /// Colour `(r = 193, g = 198, b = 252)`
 ///
-/// Colour `XKCD_LIGHT_PERIWINKLE` from the set `XKCD_COLORS`. (Colour number `452`)
+/// Colour `XKCD_LIGHT_PERIWINKLE` from the set `XKCD_COLORS`. (Colour number `452`, name `xkcd:light periwinkle`)
 /// ## Representations:
 /// - int tuple `(193, 198, 252)`
 /// - float tuple `(0.76, 0.78, 0.99)`
@@ -4116,7 +4274,7 @@ pub const XKCD_LIGHT_PERIWINKLE: Color = Color(193, 198, 252);
 
 /// Colour `(r = 53, g = 173, b = 107)`
 ///
-/// Colour `XKCD_SEAWEED_GREEN` from the set `XKCD_COLORS`. (Colour number `453`)
+/// Colour `XKCD_SEAWEED_GREEN` from the set `XKCD_COLORS`. (Colour number `453`, name `xkcd:seaweed green`)
 /// ## Representations:
 /// - int tuple `(53, 173, 107)`
 /// - float tuple `(0.21, 0.68, 0.42)`
@@ -4125,7 +4283,7 @@ pub const XKCD_SEAWEED_GREEN: Color = Color(53, 173, 107);
 
 /// Colour `(r = 255, g = 253, b = 55)`
 ///
-/// Colour `XKCD_SUNSHINE_YELLOW` from the set `XKCD_COLORS`. (Colour number `454`)
+/// Colour `XKCD_SUNSHINE_YELLOW` from the set `XKCD_COLORS`. (Colour number `454`, name `xkcd:sunshine yellow`)
 /// ## Representations:
 /// - int tuple `(255, 253, 55)`
 /// - float tuple `(1.0, 0.99, 0.22)`
@@ -4134,7 +4292,7 @@ pub const XKCD_SUNSHINE_YELLOW: Color = Color(255, 253, 55);
 
 /// Colour `(r = 164, g = 66, b = 160)`
 ///
-/// Colour `XKCD_UGLY_PURPLE` from the set `XKCD_COLORS`. (Colour number `455`)
+/// Colour `XKCD_UGLY_PURPLE` from the set `XKCD_COLORS`. (Colour number `455`, name `xkcd:ugly purple`)
 /// ## Representations:
 /// - int tuple `(164, 66, 160)`
 /// - float tuple `(0.64, 0.26, 0.63)`
@@ -4143,7 +4301,7 @@ pub const XKCD_UGLY_PURPLE: Color = Color(164, 66, 160);
 
 /// Colour `(r = 243, g = 97, b = 150)`
 ///
-/// Colour `XKCD_MEDIUM_PINK` from the set `XKCD_COLORS`. (Colour number `456`)
+/// Colour `XKCD_MEDIUM_PINK` from the set `XKCD_COLORS`. (Colour number `456`, name `xkcd:medium pink`)
 /// ## Representations:
 /// - int tuple `(243, 97, 150)`
 /// - float tuple `(0.95, 0.38, 0.59)`
@@ -4152,7 +4310,7 @@ pub const XKCD_MEDIUM_PINK: Color = Color(243, 97, 150);
 
 /// Colour `(r = 148, g = 119, b = 6)`
 ///
-/// Colour `XKCD_PUKE_BROWN` from the set `XKCD_COLORS`. (Colour number `457`)
+/// Colour `XKCD_PUKE_BROWN` from the set `XKCD_COLORS`. (Colour number `457`, name `xkcd:puke brown`)
 /// ## Representations:
 /// - int tuple `(148, 119, 6)`
 /// - float tuple `(0.58, 0.47, 0.02)`
@@ -4161,7 +4319,7 @@ pub const XKCD_PUKE_BROWN: Color = Color(148, 119, 6);
 
 /// Colour `(r = 255, g = 244, b = 242)`
 ///
-/// Colour `XKCD_VERY_LIGHT_PINK` from the set `XKCD_COLORS`. (Colour number `458`)
+/// Colour `XKCD_VERY_LIGHT_PINK` from the set `XKCD_COLORS`. (Colour number `458`, name `xkcd:very light pink`)
 /// ## Representations:
 /// - int tuple `(255, 244, 242)`
 /// - float tuple `(1.0, 0.96, 0.95)`
@@ -4170,7 +4328,7 @@ pub const XKCD_VERY_LIGHT_PINK: Color = Color(255, 244, 242);
 
 /// Colour `(r = 30, g = 145, b = 103)`
 ///
-/// Colour `XKCD_VIRIDIAN` from the set `XKCD_COLORS`. (Colour number `459`)
+/// Colour `XKCD_VIRIDIAN` from the set `XKCD_COLORS`. (Colour number `459`, name `xkcd:viridian`)
 /// ## Representations:
 /// - int tuple `(30, 145, 103)`
 /// - float tuple `(0.12, 0.57, 0.4)`
@@ -4179,7 +4337,7 @@ pub const XKCD_VIRIDIAN: Color = Color(30, 145, 103);
 
 /// Colour `(r = 181, g = 195, b = 6)`
 ///
-/// Colour `XKCD_BILE` from the set `XKCD_COLORS`. (Colour number `460`)
+/// Colour `XKCD_BILE` from the set `XKCD_COLORS`. (Colour number `460`, name `xkcd:bile`)
 /// ## Representations:
 /// - int tuple `(181, 195, 6)`
 /// - float tuple `(0.71, 0.76, 0.02)`
@@ -4188,7 +4346,7 @@ pub const XKCD_BILE: Color = Color(181, 195, 6);
 
 /// Colour `(r = 254, g = 255, b = 127)`
 ///
-/// Colour `XKCD_FADED_YELLOW` from the set `XKCD_COLORS`. (Colour number `461`)
+/// Colour `XKCD_FADED_YELLOW` from the set `XKCD_COLORS`. (Colour number `461`, name `xkcd:faded yellow`)
 /// ## Representations:
 /// - int tuple `(254, 255, 127)`
 /// - float tuple `(1.0, 1.0, 0.5)`
@@ -4197,7 +4355,7 @@ pub const XKCD_FADED_YELLOW: Color = Color(254, 255, 127);
 
 /// Colour `(r = 207, g = 253, b = 188)`
 ///
-/// Colour `XKCD_VERY_PALE_GREEN` from the set `XKCD_COLORS`. (Colour number `462`)
+/// Colour `XKCD_VERY_PALE_GREEN` from the set `XKCD_COLORS`. (Colour number `462`, name `xkcd:very pale green`)
 /// ## Representations:
 /// - int tuple `(207, 253, 188)`
 /// - float tuple `(0.81, 0.99, 0.74)`
@@ -4206,7 +4364,7 @@ pub const XKCD_VERY_PALE_GREEN: Color = Color(207, 253, 188);
 
 /// Colour `(r = 10, g = 221, b = 8)`
 ///
-/// Colour `XKCD_VIBRANT_GREEN` from the set `XKCD_COLORS`. (Colour number `463`)
+/// Colour `XKCD_VIBRANT_GREEN` from the set `XKCD_COLORS`. (Colour number `463`, name `xkcd:vibrant green`)
 /// ## Representations:
 /// - int tuple `(10, 221, 8)`
 /// - float tuple `(0.04, 0.87, 0.03)`
@@ -4215,7 +4373,7 @@ pub const XKCD_VIBRANT_GREEN: Color = Color(10, 221, 8);
 
 /// Colour `(r = 135, g = 253, b = 5)`
 ///
-/// Colour `XKCD_BRIGHT_LIME` from the set `XKCD_COLORS`. (Colour number `464`)
+/// Colour `XKCD_BRIGHT_LIME` from the set `XKCD_COLORS`. (Colour number `464`, name `xkcd:bright lime`)
 /// ## Representations:
 /// - int tuple `(135, 253, 5)`
 /// - float tuple `(0.53, 0.99, 0.02)`
@@ -4224,7 +4382,7 @@ pub const XKCD_BRIGHT_LIME: Color = Color(135, 253, 5);
 
 /// Colour `(r = 30, g = 248, b = 118)`
 ///
-/// Colour `XKCD_SPEARMINT` from the set `XKCD_COLORS`. (Colour number `465`)
+/// Colour `XKCD_SPEARMINT` from the set `XKCD_COLORS`. (Colour number `465`, name `xkcd:spearmint`)
 /// ## Representations:
 /// - int tuple `(30, 248, 118)`
 /// - float tuple `(0.12, 0.97, 0.46)`
@@ -4233,7 +4391,7 @@ pub const XKCD_SPEARMINT: Color = Color(30, 248, 118);
 
 /// Colour `(r = 123, g = 253, b = 199)`
 ///
-/// Colour `XKCD_LIGHT_AQUAMARINE` from the set `XKCD_COLORS`. (Colour number `466`)
+/// Colour `XKCD_LIGHT_AQUAMARINE` from the set `XKCD_COLORS`. (Colour number `466`, name `xkcd:light aquamarine`)
 /// ## Representations:
 /// - int tuple `(123, 253, 199)`
 /// - float tuple `(0.48, 0.99, 0.78)`
@@ -4242,7 +4400,7 @@ pub const XKCD_LIGHT_AQUAMARINE: Color = Color(123, 253, 199);
 
 /// Colour `(r = 188, g = 236, b = 172)`
 ///
-/// Colour `XKCD_LIGHT_SAGE` from the set `XKCD_COLORS`. (Colour number `467`)
+/// Colour `XKCD_LIGHT_SAGE` from the set `XKCD_COLORS`. (Colour number `467`, name `xkcd:light sage`)
 /// ## Representations:
 /// - int tuple `(188, 236, 172)`
 /// - float tuple `(0.74, 0.93, 0.67)`
@@ -4251,7 +4409,7 @@ pub const XKCD_LIGHT_SAGE: Color = Color(188, 236, 172);
 
 /// Colour `(r = 187, g = 249, b = 15)`
 ///
-/// Colour `XKCD_YELLOWGREEN` from the set `XKCD_COLORS`. (Colour number `468`)
+/// Colour `XKCD_YELLOWGREEN` from the set `XKCD_COLORS`. (Colour number `468`, name `xkcd:yellowgreen`)
 /// ## Representations:
 /// - int tuple `(187, 249, 15)`
 /// - float tuple `(0.73, 0.98, 0.06)`
@@ -4260,7 +4418,7 @@ pub const XKCD_YELLOWGREEN: Color = Color(187, 249, 15);
 
 /// Colour `(r = 171, g = 144, b = 4)`
 ///
-/// Colour `XKCD_BABY_POO` from the set `XKCD_COLORS`. (Colour number `469`)
+/// Colour `XKCD_BABY_POO` from the set `XKCD_COLORS`. (Colour number `469`, name `xkcd:baby poo`)
 /// ## Representations:
 /// - int tuple `(171, 144, 4)`
 /// - float tuple `(0.67, 0.56, 0.02)`
@@ -4269,7 +4427,7 @@ pub const XKCD_BABY_POO: Color = Color(171, 144, 4);
 
 /// Colour `(r = 31, g = 181, b = 122)`
 ///
-/// Colour `XKCD_DARK_SEAFOAM` from the set `XKCD_COLORS`. (Colour number `470`)
+/// Colour `XKCD_DARK_SEAFOAM` from the set `XKCD_COLORS`. (Colour number `470`, name `xkcd:dark seafoam`)
 /// ## Representations:
 /// - int tuple `(31, 181, 122)`
 /// - float tuple `(0.12, 0.71, 0.48)`
@@ -4278,7 +4436,7 @@ pub const XKCD_DARK_SEAFOAM: Color = Color(31, 181, 122);
 
 /// Colour `(r = 0, g = 85, b = 90)`
 ///
-/// Colour `XKCD_DEEP_TEAL` from the set `XKCD_COLORS`. (Colour number `471`)
+/// Colour `XKCD_DEEP_TEAL` from the set `XKCD_COLORS`. (Colour number `471`, name `xkcd:deep teal`)
 /// ## Representations:
 /// - int tuple `(0, 85, 90)`
 /// - float tuple `(0.0, 0.33, 0.35)`
@@ -4287,7 +4445,7 @@ pub const XKCD_DEEP_TEAL: Color = Color(0, 85, 90);
 
 /// Colour `(r = 164, g = 132, b = 172)`
 ///
-/// Colour `XKCD_HEATHER` from the set `XKCD_COLORS`. (Colour number `472`)
+/// Colour `XKCD_HEATHER` from the set `XKCD_COLORS`. (Colour number `472`, name `xkcd:heather`)
 /// ## Representations:
 /// - int tuple `(164, 132, 172)`
 /// - float tuple `(0.64, 0.52, 0.67)`
@@ -4296,7 +4454,7 @@ pub const XKCD_HEATHER: Color = Color(164, 132, 172);
 
 /// Colour `(r = 196, g = 85, b = 8)`
 ///
-/// Colour `XKCD_RUST_ORANGE` from the set `XKCD_COLORS`. (Colour number `473`)
+/// Colour `XKCD_RUST_ORANGE` from the set `XKCD_COLORS`. (Colour number `473`, name `xkcd:rust orange`)
 /// ## Representations:
 /// - int tuple `(196, 85, 8)`
 /// - float tuple `(0.77, 0.33, 0.03)`
@@ -4305,7 +4463,7 @@ pub const XKCD_RUST_ORANGE: Color = Color(196, 85, 8);
 
 /// Colour `(r = 63, g = 130, b = 157)`
 ///
-/// Colour `XKCD_DIRTY_BLUE` from the set `XKCD_COLORS`. (Colour number `474`)
+/// Colour `XKCD_DIRTY_BLUE` from the set `XKCD_COLORS`. (Colour number `474`, name `xkcd:dirty blue`)
 /// ## Representations:
 /// - int tuple `(63, 130, 157)`
 /// - float tuple `(0.25, 0.51, 0.62)`
@@ -4314,7 +4472,7 @@ pub const XKCD_DIRTY_BLUE: Color = Color(63, 130, 157);
 
 /// Colour `(r = 84, g = 141, b = 68)`
 ///
-/// Colour `XKCD_FERN_GREEN` from the set `XKCD_COLORS`. (Colour number `475`)
+/// Colour `XKCD_FERN_GREEN` from the set `XKCD_COLORS`. (Colour number `475`, name `xkcd:fern green`)
 /// ## Representations:
 /// - int tuple `(84, 141, 68)`
 /// - float tuple `(0.33, 0.55, 0.27)`
@@ -4323,7 +4481,7 @@ pub const XKCD_FERN_GREEN: Color = Color(84, 141, 68);
 
 /// Colour `(r = 201, g = 94, b = 251)`
 ///
-/// Colour `XKCD_BRIGHT_LILAC` from the set `XKCD_COLORS`. (Colour number `476`)
+/// Colour `XKCD_BRIGHT_LILAC` from the set `XKCD_COLORS`. (Colour number `476`, name `xkcd:bright lilac`)
 /// ## Representations:
 /// - int tuple `(201, 94, 251)`
 /// - float tuple `(0.79, 0.37, 0.98)`
@@ -4332,7 +4490,7 @@ pub const XKCD_BRIGHT_LILAC: Color = Color(201, 94, 251);
 
 /// Colour `(r = 58, g = 229, b = 127)`
 ///
-/// Colour `XKCD_WEIRD_GREEN` from the set `XKCD_COLORS`. (Colour number `477`)
+/// Colour `XKCD_WEIRD_GREEN` from the set `XKCD_COLORS`. (Colour number `477`, name `xkcd:weird green`)
 /// ## Representations:
 /// - int tuple `(58, 229, 127)`
 /// - float tuple `(0.23, 0.9, 0.5)`
@@ -4341,7 +4499,7 @@ pub const XKCD_WEIRD_GREEN: Color = Color(58, 229, 127);
 
 /// Colour `(r = 1, g = 103, b = 149)`
 ///
-/// Colour `XKCD_PEACOCK_BLUE` from the set `XKCD_COLORS`. (Colour number `478`)
+/// Colour `XKCD_PEACOCK_BLUE` from the set `XKCD_COLORS`. (Colour number `478`, name `xkcd:peacock blue`)
 /// ## Representations:
 /// - int tuple `(1, 103, 149)`
 /// - float tuple `(0.0, 0.4, 0.58)`
@@ -4350,7 +4508,7 @@ pub const XKCD_PEACOCK_BLUE: Color = Color(1, 103, 149);
 
 /// Colour `(r = 135, g = 169, b = 34)`
 ///
-/// Colour `XKCD_AVOCADO_GREEN` from the set `XKCD_COLORS`. (Colour number `479`)
+/// Colour `XKCD_AVOCADO_GREEN` from the set `XKCD_COLORS`. (Colour number `479`, name `xkcd:avocado green`)
 /// ## Representations:
 /// - int tuple `(135, 169, 34)`
 /// - float tuple `(0.53, 0.66, 0.13)`
@@ -4359,7 +4517,7 @@ pub const XKCD_AVOCADO_GREEN: Color = Color(135, 169, 34);
 
 /// Colour `(r = 240, g = 148, b = 77)`
 ///
-/// Colour `XKCD_FADED_ORANGE` from the set `XKCD_COLORS`. (Colour number `480`)
+/// Colour `XKCD_FADED_ORANGE` from the set `XKCD_COLORS`. (Colour number `480`, name `xkcd:faded orange`)
 /// ## Representations:
 /// - int tuple `(240, 148, 77)`
 /// - float tuple `(0.94, 0.58, 0.3)`
@@ -4368,7 +4526,7 @@ pub const XKCD_FADED_ORANGE: Color = Color(240, 148, 77);
 
 /// Colour `(r = 93, g = 20, b = 81)`
 ///
-/// Colour `XKCD_GRAPE_PURPLE` from the set `XKCD_COLORS`. (Colour number `481`)
+/// Colour `XKCD_GRAPE_PURPLE` from the set `XKCD_COLORS`. (Colour number `481`, name `xkcd:grape purple`)
 /// ## Representations:
 /// - int tuple `(93, 20, 81)`
 /// - float tuple `(0.36, 0.08, 0.32)`
@@ -4377,7 +4535,7 @@ pub const XKCD_GRAPE_PURPLE: Color = Color(93, 20, 81);
 
 /// Colour `(r = 37, g = 255, b = 41)`
 ///
-/// Colour `XKCD_HOT_GREEN` from the set `XKCD_COLORS`. (Colour number `482`)
+/// Colour `XKCD_HOT_GREEN` from the set `XKCD_COLORS`. (Colour number `482`, name `xkcd:hot green`)
 /// ## Representations:
 /// - int tuple `(37, 255, 41)`
 /// - float tuple `(0.15, 1.0, 0.16)`
@@ -4386,7 +4544,7 @@ pub const XKCD_HOT_GREEN: Color = Color(37, 255, 41);
 
 /// Colour `(r = 208, g = 254, b = 29)`
 ///
-/// Colour `XKCD_LIME_YELLOW` from the set `XKCD_COLORS`. (Colour number `483`)
+/// Colour `XKCD_LIME_YELLOW` from the set `XKCD_COLORS`. (Colour number `483`, name `xkcd:lime yellow`)
 /// ## Representations:
 /// - int tuple `(208, 254, 29)`
 /// - float tuple `(0.82, 1.0, 0.11)`
@@ -4395,7 +4553,7 @@ pub const XKCD_LIME_YELLOW: Color = Color(208, 254, 29);
 
 /// Colour `(r = 255, g = 166, b = 43)`
 ///
-/// Colour `XKCD_MANGO` from the set `XKCD_COLORS`. (Colour number `484`)
+/// Colour `XKCD_MANGO` from the set `XKCD_COLORS`. (Colour number `484`, name `xkcd:mango`)
 /// ## Representations:
 /// - int tuple `(255, 166, 43)`
 /// - float tuple `(1.0, 0.65, 0.17)`
@@ -4404,7 +4562,7 @@ pub const XKCD_MANGO: Color = Color(255, 166, 43);
 
 /// Colour `(r = 1, g = 180, b = 76)`
 ///
-/// Colour `XKCD_SHAMROCK` from the set `XKCD_COLORS`. (Colour number `485`)
+/// Colour `XKCD_SHAMROCK` from the set `XKCD_COLORS`. (Colour number `485`, name `xkcd:shamrock`)
 /// ## Representations:
 /// - int tuple `(1, 180, 76)`
 /// - float tuple `(0.0, 0.71, 0.3)`
@@ -4413,7 +4571,7 @@ pub const XKCD_SHAMROCK: Color = Color(1, 180, 76);
 
 /// Colour `(r = 255, g = 108, b = 181)`
 ///
-/// Colour `XKCD_BUBBLEGUM` from the set `XKCD_COLORS`. (Colour number `486`)
+/// Colour `XKCD_BUBBLEGUM` from the set `XKCD_COLORS`. (Colour number `486`, name `xkcd:bubblegum`)
 /// ## Representations:
 /// - int tuple `(255, 108, 181)`
 /// - float tuple `(1.0, 0.42, 0.71)`
@@ -4422,7 +4580,7 @@ pub const XKCD_BUBBLEGUM: Color = Color(255, 108, 181);
 
 /// Colour `(r = 107, g = 66, b = 71)`
 ///
-/// Colour `XKCD_PURPLISH_BROWN` from the set `XKCD_COLORS`. (Colour number `487`)
+/// Colour `XKCD_PURPLISH_BROWN` from the set `XKCD_COLORS`. (Colour number `487`, name `xkcd:purplish brown`)
 /// ## Representations:
 /// - int tuple `(107, 66, 71)`
 /// - float tuple `(0.42, 0.26, 0.28)`
@@ -4431,7 +4589,7 @@ pub const XKCD_PURPLISH_BROWN: Color = Color(107, 66, 71);
 
 /// Colour `(r = 199, g = 193, b = 12)`
 ///
-/// Colour `XKCD_VOMIT_YELLOW` from the set `XKCD_COLORS`. (Colour number `488`)
+/// Colour `XKCD_VOMIT_YELLOW` from the set `XKCD_COLORS`. (Colour number `488`, name `xkcd:vomit yellow`)
 /// ## Representations:
 /// - int tuple `(199, 193, 12)`
 /// - float tuple `(0.78, 0.76, 0.05)`
@@ -4440,7 +4598,7 @@ pub const XKCD_VOMIT_YELLOW: Color = Color(199, 193, 12);
 
 /// Colour `(r = 183, g = 255, b = 250)`
 ///
-/// Colour `XKCD_PALE_CYAN` from the set `XKCD_COLORS`. (Colour number `489`)
+/// Colour `XKCD_PALE_CYAN` from the set `XKCD_COLORS`. (Colour number `489`, name `xkcd:pale cyan`)
 /// ## Representations:
 /// - int tuple `(183, 255, 250)`
 /// - float tuple `(0.72, 1.0, 0.98)`
@@ -4449,7 +4607,7 @@ pub const XKCD_PALE_CYAN: Color = Color(183, 255, 250);
 
 /// Colour `(r = 174, g = 255, b = 110)`
 ///
-/// Colour `XKCD_KEY_LIME` from the set `XKCD_COLORS`. (Colour number `490`)
+/// Colour `XKCD_KEY_LIME` from the set `XKCD_COLORS`. (Colour number `490`, name `xkcd:key lime`)
 /// ## Representations:
 /// - int tuple `(174, 255, 110)`
 /// - float tuple `(0.68, 1.0, 0.43)`
@@ -4458,7 +4616,7 @@ pub const XKCD_KEY_LIME: Color = Color(174, 255, 110);
 
 /// Colour `(r = 236, g = 45, b = 1)`
 ///
-/// Colour `XKCD_TOMATO_RED` from the set `XKCD_COLORS`. (Colour number `491`)
+/// Colour `XKCD_TOMATO_RED` from the set `XKCD_COLORS`. (Colour number `491`, name `xkcd:tomato red`)
 /// ## Representations:
 /// - int tuple `(236, 45, 1)`
 /// - float tuple `(0.93, 0.18, 0.0)`
@@ -4467,7 +4625,7 @@ pub const XKCD_TOMATO_RED: Color = Color(236, 45, 1);
 
 /// Colour `(r = 118, g = 255, b = 123)`
 ///
-/// Colour `XKCD_LIGHTGREEN` from the set `XKCD_COLORS`. (Colour number `492`)
+/// Colour `XKCD_LIGHTGREEN` from the set `XKCD_COLORS`. (Colour number `492`, name `xkcd:lightgreen`)
 /// ## Representations:
 /// - int tuple `(118, 255, 123)`
 /// - float tuple `(0.46, 1.0, 0.48)`
@@ -4476,7 +4634,7 @@ pub const XKCD_LIGHTGREEN: Color = Color(118, 255, 123);
 
 /// Colour `(r = 115, g = 0, b = 57)`
 ///
-/// Colour `XKCD_MERLOT` from the set `XKCD_COLORS`. (Colour number `493`)
+/// Colour `XKCD_MERLOT` from the set `XKCD_COLORS`. (Colour number `493`, name `xkcd:merlot`)
 /// ## Representations:
 /// - int tuple `(115, 0, 57)`
 /// - float tuple `(0.45, 0.0, 0.22)`
@@ -4485,7 +4643,7 @@ pub const XKCD_MERLOT: Color = Color(115, 0, 57);
 
 /// Colour `(r = 4, g = 3, b = 72)`
 ///
-/// Colour `XKCD_NIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `494`)
+/// Colour `XKCD_NIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `494`, name `xkcd:night blue`)
 /// ## Representations:
 /// - int tuple `(4, 3, 72)`
 /// - float tuple `(0.02, 0.01, 0.28)`
@@ -4494,7 +4652,7 @@ pub const XKCD_NIGHT_BLUE: Color = Color(4, 3, 72);
 
 /// Colour `(r = 223, g = 78, b = 200)`
 ///
-/// Colour `XKCD_PURPLEISH_PINK` from the set `XKCD_COLORS`. (Colour number `495`)
+/// Colour `XKCD_PURPLEISH_PINK` from the set `XKCD_COLORS`. (Colour number `495`, name `xkcd:purpleish pink`)
 /// ## Representations:
 /// - int tuple `(223, 78, 200)`
 /// - float tuple `(0.87, 0.31, 0.78)`
@@ -4503,7 +4661,7 @@ pub const XKCD_PURPLEISH_PINK: Color = Color(223, 78, 200);
 
 /// Colour `(r = 110, g = 203, b = 60)`
 ///
-/// Colour `XKCD_APPLE` from the set `XKCD_COLORS`. (Colour number `496`)
+/// Colour `XKCD_APPLE` from the set `XKCD_COLORS`. (Colour number `496`, name `xkcd:apple`)
 /// ## Representations:
 /// - int tuple `(110, 203, 60)`
 /// - float tuple `(0.43, 0.8, 0.24)`
@@ -4512,7 +4670,7 @@ pub const XKCD_APPLE: Color = Color(110, 203, 60);
 
 /// Colour `(r = 143, g = 152, b = 5)`
 ///
-/// Colour `XKCD_BABY_POOP_GREEN` from the set `XKCD_COLORS`. (Colour number `497`)
+/// Colour `XKCD_BABY_POOP_GREEN` from the set `XKCD_COLORS`. (Colour number `497`, name `xkcd:baby poop green`)
 /// ## Representations:
 /// - int tuple `(143, 152, 5)`
 /// - float tuple `(0.56, 0.6, 0.02)`
@@ -4521,7 +4679,7 @@ pub const XKCD_BABY_POOP_GREEN: Color = Color(143, 152, 5);
 
 /// Colour `(r = 94, g = 220, b = 31)`
 ///
-/// Colour `XKCD_GREEN_APPLE` from the set `XKCD_COLORS`. (Colour number `498`)
+/// Colour `XKCD_GREEN_APPLE` from the set `XKCD_COLORS`. (Colour number `498`, name `xkcd:green apple`)
 /// ## Representations:
 /// - int tuple `(94, 220, 31)`
 /// - float tuple `(0.37, 0.86, 0.12)`
@@ -4530,7 +4688,7 @@ pub const XKCD_GREEN_APPLE: Color = Color(94, 220, 31);
 
 /// Colour `(r = 217, g = 79, b = 245)`
 ///
-/// Colour `XKCD_HELIOTROPE` from the set `XKCD_COLORS`. (Colour number `499`)
+/// Colour `XKCD_HELIOTROPE` from the set `XKCD_COLORS`. (Colour number `499`, name `xkcd:heliotrope`)
 /// ## Representations:
 /// - int tuple `(217, 79, 245)`
 /// - float tuple `(0.85, 0.31, 0.96)`
@@ -4539,7 +4697,7 @@ pub const XKCD_HELIOTROPE: Color = Color(217, 79, 245);
 
 /// Colour `(r = 200, g = 253, b = 61)`
 ///
-/// Colour `XKCD_YELLOW_SLASH_GREEN` from the set `XKCD_COLORS`. (Colour number `500`)
+/// Colour `XKCD_YELLOW_SLASH_GREEN` from the set `XKCD_COLORS`. (Colour number `500`, name `xkcd:yellow/green`)
 /// ## Representations:
 /// - int tuple `(200, 253, 61)`
 /// - float tuple `(0.78, 0.99, 0.24)`
@@ -4548,7 +4706,7 @@ pub const XKCD_YELLOW_SLASH_GREEN: Color = Color(200, 253, 61);
 
 /// Colour `(r = 7, g = 13, b = 13)`
 ///
-/// Colour `XKCD_ALMOST_BLACK` from the set `XKCD_COLORS`. (Colour number `501`)
+/// Colour `XKCD_ALMOST_BLACK` from the set `XKCD_COLORS`. (Colour number `501`, name `xkcd:almost black`)
 /// ## Representations:
 /// - int tuple `(7, 13, 13)`
 /// - float tuple `(0.03, 0.05, 0.05)`
@@ -4557,7 +4715,7 @@ pub const XKCD_ALMOST_BLACK: Color = Color(7, 13, 13);
 
 /// Colour `(r = 73, g = 132, b = 184)`
 ///
-/// Colour `XKCD_COOL_BLUE` from the set `XKCD_COLORS`. (Colour number `502`)
+/// Colour `XKCD_COOL_BLUE` from the set `XKCD_COLORS`. (Colour number `502`, name `xkcd:cool blue`)
 /// ## Representations:
 /// - int tuple `(73, 132, 184)`
 /// - float tuple `(0.29, 0.52, 0.72)`
@@ -4566,7 +4724,7 @@ pub const XKCD_COOL_BLUE: Color = Color(73, 132, 184);
 
 /// Colour `(r = 81, g = 183, b = 59)`
 ///
-/// Colour `XKCD_LEAFY_GREEN` from the set `XKCD_COLORS`. (Colour number `503`)
+/// Colour `XKCD_LEAFY_GREEN` from the set `XKCD_COLORS`. (Colour number `503`, name `xkcd:leafy green`)
 /// ## Representations:
 /// - int tuple `(81, 183, 59)`
 /// - float tuple `(0.32, 0.72, 0.23)`
@@ -4575,7 +4733,7 @@ pub const XKCD_LEAFY_GREEN: Color = Color(81, 183, 59);
 
 /// Colour `(r = 172, g = 126, b = 4)`
 ///
-/// Colour `XKCD_MUSTARD_BROWN` from the set `XKCD_COLORS`. (Colour number `504`)
+/// Colour `XKCD_MUSTARD_BROWN` from the set `XKCD_COLORS`. (Colour number `504`, name `xkcd:mustard brown`)
 /// ## Representations:
 /// - int tuple `(172, 126, 4)`
 /// - float tuple `(0.67, 0.49, 0.02)`
@@ -4584,7 +4742,7 @@ pub const XKCD_MUSTARD_BROWN: Color = Color(172, 126, 4);
 
 /// Colour `(r = 78, g = 84, b = 129)`
 ///
-/// Colour `XKCD_DUSK` from the set `XKCD_COLORS`. (Colour number `505`)
+/// Colour `XKCD_DUSK` from the set `XKCD_COLORS`. (Colour number `505`, name `xkcd:dusk`)
 /// ## Representations:
 /// - int tuple `(78, 84, 129)`
 /// - float tuple `(0.31, 0.33, 0.51)`
@@ -4593,7 +4751,7 @@ pub const XKCD_DUSK: Color = Color(78, 84, 129);
 
 /// Colour `(r = 135, g = 110, b = 75)`
 ///
-/// Colour `XKCD_DULL_BROWN` from the set `XKCD_COLORS`. (Colour number `506`)
+/// Colour `XKCD_DULL_BROWN` from the set `XKCD_COLORS`. (Colour number `506`, name `xkcd:dull brown`)
 /// ## Representations:
 /// - int tuple `(135, 110, 75)`
 /// - float tuple `(0.53, 0.43, 0.29)`
@@ -4602,7 +4760,7 @@ pub const XKCD_DULL_BROWN: Color = Color(135, 110, 75);
 
 /// Colour `(r = 88, g = 188, b = 8)`
 ///
-/// Colour `XKCD_FROG_GREEN` from the set `XKCD_COLORS`. (Colour number `507`)
+/// Colour `XKCD_FROG_GREEN` from the set `XKCD_COLORS`. (Colour number `507`, name `xkcd:frog green`)
 /// ## Representations:
 /// - int tuple `(88, 188, 8)`
 /// - float tuple `(0.35, 0.74, 0.03)`
@@ -4611,7 +4769,7 @@ pub const XKCD_FROG_GREEN: Color = Color(88, 188, 8);
 
 /// Colour `(r = 47, g = 239, b = 16)`
 ///
-/// Colour `XKCD_VIVID_GREEN` from the set `XKCD_COLORS`. (Colour number `508`)
+/// Colour `XKCD_VIVID_GREEN` from the set `XKCD_COLORS`. (Colour number `508`, name `xkcd:vivid green`)
 /// ## Representations:
 /// - int tuple `(47, 239, 16)`
 /// - float tuple `(0.18, 0.94, 0.06)`
@@ -4620,7 +4778,7 @@ pub const XKCD_VIVID_GREEN: Color = Color(47, 239, 16);
 
 /// Colour `(r = 45, g = 254, b = 84)`
 ///
-/// Colour `XKCD_BRIGHT_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `509`)
+/// Colour `XKCD_BRIGHT_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `509`, name `xkcd:bright light green`)
 /// ## Representations:
 /// - int tuple `(45, 254, 84)`
 /// - float tuple `(0.18, 1.0, 0.33)`
@@ -4629,7 +4787,7 @@ pub const XKCD_BRIGHT_LIGHT_GREEN: Color = Color(45, 254, 84);
 
 /// Colour `(r = 10, g = 255, b = 2)`
 ///
-/// Colour `XKCD_FLURO_GREEN` from the set `XKCD_COLORS`. (Colour number `510`)
+/// Colour `XKCD_FLURO_GREEN` from the set `XKCD_COLORS`. (Colour number `510`, name `xkcd:fluro green`)
 /// ## Representations:
 /// - int tuple `(10, 255, 2)`
 /// - float tuple `(0.04, 1.0, 0.01)`
@@ -4638,7 +4796,7 @@ pub const XKCD_FLURO_GREEN: Color = Color(10, 255, 2);
 
 /// Colour `(r = 156, g = 239, b = 67)`
 ///
-/// Colour `XKCD_KIWI` from the set `XKCD_COLORS`. (Colour number `511`)
+/// Colour `XKCD_KIWI` from the set `XKCD_COLORS`. (Colour number `511`, name `xkcd:kiwi`)
 /// ## Representations:
 /// - int tuple `(156, 239, 67)`
 /// - float tuple `(0.61, 0.94, 0.26)`
@@ -4647,7 +4805,7 @@ pub const XKCD_KIWI: Color = Color(156, 239, 67);
 
 /// Colour `(r = 24, g = 209, b = 123)`
 ///
-/// Colour `XKCD_SEAWEED` from the set `XKCD_COLORS`. (Colour number `512`)
+/// Colour `XKCD_SEAWEED` from the set `XKCD_COLORS`. (Colour number `512`, name `xkcd:seaweed`)
 /// ## Representations:
 /// - int tuple `(24, 209, 123)`
 /// - float tuple `(0.09, 0.82, 0.48)`
@@ -4656,7 +4814,7 @@ pub const XKCD_SEAWEED: Color = Color(24, 209, 123);
 
 /// Colour `(r = 53, g = 83, b = 10)`
 ///
-/// Colour `XKCD_NAVY_GREEN` from the set `XKCD_COLORS`. (Colour number `513`)
+/// Colour `XKCD_NAVY_GREEN` from the set `XKCD_COLORS`. (Colour number `513`, name `xkcd:navy green`)
 /// ## Representations:
 /// - int tuple `(53, 83, 10)`
 /// - float tuple `(0.21, 0.33, 0.04)`
@@ -4665,7 +4823,7 @@ pub const XKCD_NAVY_GREEN: Color = Color(53, 83, 10);
 
 /// Colour `(r = 24, g = 5, b = 219)`
 ///
-/// Colour `XKCD_ULTRAMARINE_BLUE` from the set `XKCD_COLORS`. (Colour number `514`)
+/// Colour `XKCD_ULTRAMARINE_BLUE` from the set `XKCD_COLORS`. (Colour number `514`, name `xkcd:ultramarine blue`)
 /// ## Representations:
 /// - int tuple `(24, 5, 219)`
 /// - float tuple `(0.09, 0.02, 0.86)`
@@ -4674,7 +4832,7 @@ pub const XKCD_ULTRAMARINE_BLUE: Color = Color(24, 5, 219);
 
 /// Colour `(r = 98, g = 88, b = 196)`
 ///
-/// Colour `XKCD_IRIS` from the set `XKCD_COLORS`. (Colour number `515`)
+/// Colour `XKCD_IRIS` from the set `XKCD_COLORS`. (Colour number `515`, name `xkcd:iris`)
 /// ## Representations:
 /// - int tuple `(98, 88, 196)`
 /// - float tuple `(0.38, 0.35, 0.77)`
@@ -4683,7 +4841,7 @@ pub const XKCD_IRIS: Color = Color(98, 88, 196);
 
 /// Colour `(r = 255, g = 150, b = 79)`
 ///
-/// Colour `XKCD_PASTEL_ORANGE` from the set `XKCD_COLORS`. (Colour number `516`)
+/// Colour `XKCD_PASTEL_ORANGE` from the set `XKCD_COLORS`. (Colour number `516`, name `xkcd:pastel orange`)
 /// ## Representations:
 /// - int tuple `(255, 150, 79)`
 /// - float tuple `(1.0, 0.59, 0.31)`
@@ -4692,7 +4850,7 @@ pub const XKCD_PASTEL_ORANGE: Color = Color(255, 150, 79);
 
 /// Colour `(r = 255, g = 171, b = 15)`
 ///
-/// Colour `XKCD_YELLOWISH_ORANGE` from the set `XKCD_COLORS`. (Colour number `517`)
+/// Colour `XKCD_YELLOWISH_ORANGE` from the set `XKCD_COLORS`. (Colour number `517`, name `xkcd:yellowish orange`)
 /// ## Representations:
 /// - int tuple `(255, 171, 15)`
 /// - float tuple `(1.0, 0.67, 0.06)`
@@ -4701,7 +4859,7 @@ pub const XKCD_YELLOWISH_ORANGE: Color = Color(255, 171, 15);
 
 /// Colour `(r = 143, g = 140, b = 231)`
 ///
-/// Colour `XKCD_PERRYWINKLE` from the set `XKCD_COLORS`. (Colour number `518`)
+/// Colour `XKCD_PERRYWINKLE` from the set `XKCD_COLORS`. (Colour number `518`, name `xkcd:perrywinkle`)
 /// ## Representations:
 /// - int tuple `(143, 140, 231)`
 /// - float tuple `(0.56, 0.55, 0.91)`
@@ -4710,7 +4868,7 @@ pub const XKCD_PERRYWINKLE: Color = Color(143, 140, 231);
 
 /// Colour `(r = 36, g = 188, b = 168)`
 ///
-/// Colour `XKCD_TEALISH` from the set `XKCD_COLORS`. (Colour number `519`)
+/// Colour `XKCD_TEALISH` from the set `XKCD_COLORS`. (Colour number `519`, name `xkcd:tealish`)
 /// ## Representations:
 /// - int tuple `(36, 188, 168)`
 /// - float tuple `(0.14, 0.74, 0.66)`
@@ -4719,7 +4877,7 @@ pub const XKCD_TEALISH: Color = Color(36, 188, 168);
 
 /// Colour `(r = 63, g = 1, b = 44)`
 ///
-/// Colour `XKCD_DARK_PLUM` from the set `XKCD_COLORS`. (Colour number `520`)
+/// Colour `XKCD_DARK_PLUM` from the set `XKCD_COLORS`. (Colour number `520`, name `xkcd:dark plum`)
 /// ## Representations:
 /// - int tuple `(63, 1, 44)`
 /// - float tuple `(0.25, 0.0, 0.17)`
@@ -4728,7 +4886,7 @@ pub const XKCD_DARK_PLUM: Color = Color(63, 1, 44);
 
 /// Colour `(r = 203, g = 248, b = 95)`
 ///
-/// Colour `XKCD_PEAR` from the set `XKCD_COLORS`. (Colour number `521`)
+/// Colour `XKCD_PEAR` from the set `XKCD_COLORS`. (Colour number `521`, name `xkcd:pear`)
 /// ## Representations:
 /// - int tuple `(203, 248, 95)`
 /// - float tuple `(0.8, 0.97, 0.37)`
@@ -4737,7 +4895,7 @@ pub const XKCD_PEAR: Color = Color(203, 248, 95);
 
 /// Colour `(r = 255, g = 114, b = 76)`
 ///
-/// Colour `XKCD_PINKISH_ORANGE` from the set `XKCD_COLORS`. (Colour number `522`)
+/// Colour `XKCD_PINKISH_ORANGE` from the set `XKCD_COLORS`. (Colour number `522`, name `xkcd:pinkish orange`)
 /// ## Representations:
 /// - int tuple `(255, 114, 76)`
 /// - float tuple `(1.0, 0.45, 0.3)`
@@ -4746,7 +4904,7 @@ pub const XKCD_PINKISH_ORANGE: Color = Color(255, 114, 76);
 
 /// Colour `(r = 40, g = 1, b = 55)`
 ///
-/// Colour `XKCD_MIDNIGHT_PURPLE` from the set `XKCD_COLORS`. (Colour number `523`)
+/// Colour `XKCD_MIDNIGHT_PURPLE` from the set `XKCD_COLORS`. (Colour number `523`, name `xkcd:midnight purple`)
 /// ## Representations:
 /// - int tuple `(40, 1, 55)`
 /// - float tuple `(0.16, 0.0, 0.22)`
@@ -4755,7 +4913,7 @@ pub const XKCD_MIDNIGHT_PURPLE: Color = Color(40, 1, 55);
 
 /// Colour `(r = 179, g = 111, b = 246)`
 ///
-/// Colour `XKCD_LIGHT_URPLE` from the set `XKCD_COLORS`. (Colour number `524`)
+/// Colour `XKCD_LIGHT_URPLE` from the set `XKCD_COLORS`. (Colour number `524`, name `xkcd:light urple`)
 /// ## Representations:
 /// - int tuple `(179, 111, 246)`
 /// - float tuple `(0.7, 0.44, 0.96)`
@@ -4764,7 +4922,7 @@ pub const XKCD_LIGHT_URPLE: Color = Color(179, 111, 246);
 
 /// Colour `(r = 72, g = 192, b = 114)`
 ///
-/// Colour `XKCD_DARK_MINT` from the set `XKCD_COLORS`. (Colour number `525`)
+/// Colour `XKCD_DARK_MINT` from the set `XKCD_COLORS`. (Colour number `525`, name `xkcd:dark mint`)
 /// ## Representations:
 /// - int tuple `(72, 192, 114)`
 /// - float tuple `(0.28, 0.75, 0.45)`
@@ -4773,7 +4931,7 @@ pub const XKCD_DARK_MINT: Color = Color(72, 192, 114);
 
 /// Colour `(r = 188, g = 203, b = 122)`
 ///
-/// Colour `XKCD_GREENISH_TAN` from the set `XKCD_COLORS`. (Colour number `526`)
+/// Colour `XKCD_GREENISH_TAN` from the set `XKCD_COLORS`. (Colour number `526`, name `xkcd:greenish tan`)
 /// ## Representations:
 /// - int tuple `(188, 203, 122)`
 /// - float tuple `(0.74, 0.8, 0.48)`
@@ -4782,7 +4940,7 @@ pub const XKCD_GREENISH_TAN: Color = Color(188, 203, 122);
 
 /// Colour `(r = 168, g = 65, b = 91)`
 ///
-/// Colour `XKCD_LIGHT_BURGUNDY` from the set `XKCD_COLORS`. (Colour number `527`)
+/// Colour `XKCD_LIGHT_BURGUNDY` from the set `XKCD_COLORS`. (Colour number `527`, name `xkcd:light burgundy`)
 /// ## Representations:
 /// - int tuple `(168, 65, 91)`
 /// - float tuple `(0.66, 0.25, 0.36)`
@@ -4791,7 +4949,7 @@ pub const XKCD_LIGHT_BURGUNDY: Color = Color(168, 65, 91);
 
 /// Colour `(r = 6, g = 177, b = 196)`
 ///
-/// Colour `XKCD_TURQUOISE_BLUE` from the set `XKCD_COLORS`. (Colour number `528`)
+/// Colour `XKCD_TURQUOISE_BLUE` from the set `XKCD_COLORS`. (Colour number `528`, name `xkcd:turquoise blue`)
 /// ## Representations:
 /// - int tuple `(6, 177, 196)`
 /// - float tuple `(0.02, 0.69, 0.77)`
@@ -4800,7 +4958,7 @@ pub const XKCD_TURQUOISE_BLUE: Color = Color(6, 177, 196);
 
 /// Colour `(r = 205, g = 117, b = 132)`
 ///
-/// Colour `XKCD_UGLY_PINK` from the set `XKCD_COLORS`. (Colour number `529`)
+/// Colour `XKCD_UGLY_PINK` from the set `XKCD_COLORS`. (Colour number `529`, name `xkcd:ugly pink`)
 /// ## Representations:
 /// - int tuple `(205, 117, 132)`
 /// - float tuple `(0.8, 0.46, 0.52)`
@@ -4809,7 +4967,7 @@ pub const XKCD_UGLY_PINK: Color = Color(205, 117, 132);
 
 /// Colour `(r = 241, g = 218, b = 122)`
 ///
-/// Colour `XKCD_SANDY` from the set `XKCD_COLORS`. (Colour number `530`)
+/// Colour `XKCD_SANDY` from the set `XKCD_COLORS`. (Colour number `530`, name `xkcd:sandy`)
 /// ## Representations:
 /// - int tuple `(241, 218, 122)`
 /// - float tuple `(0.95, 0.85, 0.48)`
@@ -4818,7 +4976,7 @@ pub const XKCD_SANDY: Color = Color(241, 218, 122);
 
 /// Colour `(r = 255, g = 4, b = 144)`
 ///
-/// Colour `XKCD_ELECTRIC_PINK` from the set `XKCD_COLORS`. (Colour number `531`)
+/// Colour `XKCD_ELECTRIC_PINK` from the set `XKCD_COLORS`. (Colour number `531`, name `xkcd:electric pink`)
 /// ## Representations:
 /// - int tuple `(255, 4, 144)`
 /// - float tuple `(1.0, 0.02, 0.56)`
@@ -4827,7 +4985,7 @@ pub const XKCD_ELECTRIC_PINK: Color = Color(255, 4, 144);
 
 /// Colour `(r = 128, g = 91, b = 135)`
 ///
-/// Colour `XKCD_MUTED_PURPLE` from the set `XKCD_COLORS`. (Colour number `532`)
+/// Colour `XKCD_MUTED_PURPLE` from the set `XKCD_COLORS`. (Colour number `532`, name `xkcd:muted purple`)
 /// ## Representations:
 /// - int tuple `(128, 91, 135)`
 /// - float tuple `(0.5, 0.36, 0.53)`
@@ -4836,7 +4994,7 @@ pub const XKCD_MUTED_PURPLE: Color = Color(128, 91, 135);
 
 /// Colour `(r = 80, g = 167, b = 71)`
 ///
-/// Colour `XKCD_MID_GREEN` from the set `XKCD_COLORS`. (Colour number `533`)
+/// Colour `XKCD_MID_GREEN` from the set `XKCD_COLORS`. (Colour number `533`, name `xkcd:mid green`)
 /// ## Representations:
 /// - int tuple `(80, 167, 71)`
 /// - float tuple `(0.31, 0.65, 0.28)`
@@ -4845,7 +5003,7 @@ pub const XKCD_MID_GREEN: Color = Color(80, 167, 71);
 
 /// Colour `(r = 168, g = 164, b = 149)`
 ///
-/// Colour `XKCD_GREYISH` from the set `XKCD_COLORS`. (Colour number `534`)
+/// Colour `XKCD_GREYISH` from the set `XKCD_COLORS`. (Colour number `534`, name `xkcd:greyish`)
 /// ## Representations:
 /// - int tuple `(168, 164, 149)`
 /// - float tuple `(0.66, 0.64, 0.58)`
@@ -4854,7 +5012,7 @@ pub const XKCD_GREYISH: Color = Color(168, 164, 149);
 
 /// Colour `(r = 207, g = 255, b = 4)`
 ///
-/// Colour `XKCD_NEON_YELLOW` from the set `XKCD_COLORS`. (Colour number `535`)
+/// Colour `XKCD_NEON_YELLOW` from the set `XKCD_COLORS`. (Colour number `535`, name `xkcd:neon yellow`)
 /// ## Representations:
 /// - int tuple `(207, 255, 4)`
 /// - float tuple `(0.81, 1.0, 0.02)`
@@ -4863,7 +5021,7 @@ pub const XKCD_NEON_YELLOW: Color = Color(207, 255, 4);
 
 /// Colour `(r = 255, g = 255, b = 126)`
 ///
-/// Colour `XKCD_BANANA` from the set `XKCD_COLORS`. (Colour number `536`)
+/// Colour `XKCD_BANANA` from the set `XKCD_COLORS`. (Colour number `536`, name `xkcd:banana`)
 /// ## Representations:
 /// - int tuple `(255, 255, 126)`
 /// - float tuple `(1.0, 1.0, 0.49)`
@@ -4872,7 +5030,7 @@ pub const XKCD_BANANA: Color = Color(255, 255, 126);
 
 /// Colour `(r = 255, g = 127, b = 167)`
 ///
-/// Colour `XKCD_CARNATION_PINK` from the set `XKCD_COLORS`. (Colour number `537`)
+/// Colour `XKCD_CARNATION_PINK` from the set `XKCD_COLORS`. (Colour number `537`, name `xkcd:carnation pink`)
 /// ## Representations:
 /// - int tuple `(255, 127, 167)`
 /// - float tuple `(1.0, 0.5, 0.65)`
@@ -4881,7 +5039,7 @@ pub const XKCD_CARNATION_PINK: Color = Color(255, 127, 167);
 
 /// Colour `(r = 239, g = 64, b = 38)`
 ///
-/// Colour `XKCD_TOMATO` from the set `XKCD_COLORS`. (Colour number `538`)
+/// Colour `XKCD_TOMATO` from the set `XKCD_COLORS`. (Colour number `538`, name `xkcd:tomato`)
 /// ## Representations:
 /// - int tuple `(239, 64, 38)`
 /// - float tuple `(0.94, 0.25, 0.15)`
@@ -4890,7 +5048,7 @@ pub const XKCD_TOMATO: Color = Color(239, 64, 38);
 
 /// Colour `(r = 60, g = 153, b = 146)`
 ///
-/// Colour `XKCD_SEA` from the set `XKCD_COLORS`. (Colour number `539`)
+/// Colour `XKCD_SEA` from the set `XKCD_COLORS`. (Colour number `539`, name `xkcd:sea`)
 /// ## Representations:
 /// - int tuple `(60, 153, 146)`
 /// - float tuple `(0.24, 0.6, 0.57)`
@@ -4899,7 +5057,7 @@ pub const XKCD_SEA: Color = Color(60, 153, 146);
 
 /// Colour `(r = 136, g = 104, b = 6)`
 ///
-/// Colour `XKCD_MUDDY_BROWN` from the set `XKCD_COLORS`. (Colour number `540`)
+/// Colour `XKCD_MUDDY_BROWN` from the set `XKCD_COLORS`. (Colour number `540`, name `xkcd:muddy brown`)
 /// ## Representations:
 /// - int tuple `(136, 104, 6)`
 /// - float tuple `(0.53, 0.41, 0.02)`
@@ -4908,7 +5066,7 @@ pub const XKCD_MUDDY_BROWN: Color = Color(136, 104, 6);
 
 /// Colour `(r = 4, g = 244, b = 137)`
 ///
-/// Colour `XKCD_TURQUOISE_GREEN` from the set `XKCD_COLORS`. (Colour number `541`)
+/// Colour `XKCD_TURQUOISE_GREEN` from the set `XKCD_COLORS`. (Colour number `541`, name `xkcd:turquoise green`)
 /// ## Representations:
 /// - int tuple `(4, 244, 137)`
 /// - float tuple `(0.02, 0.96, 0.54)`
@@ -4917,7 +5075,7 @@ pub const XKCD_TURQUOISE_GREEN: Color = Color(4, 244, 137);
 
 /// Colour `(r = 254, g = 246, b = 158)`
 ///
-/// Colour `XKCD_BUFF` from the set `XKCD_COLORS`. (Colour number `542`)
+/// Colour `XKCD_BUFF` from the set `XKCD_COLORS`. (Colour number `542`, name `xkcd:buff`)
 /// ## Representations:
 /// - int tuple `(254, 246, 158)`
 /// - float tuple `(1.0, 0.96, 0.62)`
@@ -4926,7 +5084,7 @@ pub const XKCD_BUFF: Color = Color(254, 246, 158);
 
 /// Colour `(r = 207, g = 175, b = 123)`
 ///
-/// Colour `XKCD_FAWN` from the set `XKCD_COLORS`. (Colour number `543`)
+/// Colour `XKCD_FAWN` from the set `XKCD_COLORS`. (Colour number `543`, name `xkcd:fawn`)
 /// ## Representations:
 /// - int tuple `(207, 175, 123)`
 /// - float tuple `(0.81, 0.69, 0.48)`
@@ -4935,7 +5093,7 @@ pub const XKCD_FAWN: Color = Color(207, 175, 123);
 
 /// Colour `(r = 59, g = 113, b = 159)`
 ///
-/// Colour `XKCD_MUTED_BLUE` from the set `XKCD_COLORS`. (Colour number `544`)
+/// Colour `XKCD_MUTED_BLUE` from the set `XKCD_COLORS`. (Colour number `544`, name `xkcd:muted blue`)
 /// ## Representations:
 /// - int tuple `(59, 113, 159)`
 /// - float tuple `(0.23, 0.44, 0.62)`
@@ -4944,7 +5102,7 @@ pub const XKCD_MUTED_BLUE: Color = Color(59, 113, 159);
 
 /// Colour `(r = 253, g = 193, b = 197)`
 ///
-/// Colour `XKCD_PALE_ROSE` from the set `XKCD_COLORS`. (Colour number `545`)
+/// Colour `XKCD_PALE_ROSE` from the set `XKCD_COLORS`. (Colour number `545`, name `xkcd:pale rose`)
 /// ## Representations:
 /// - int tuple `(253, 193, 197)`
 /// - float tuple `(0.99, 0.76, 0.77)`
@@ -4953,7 +5111,7 @@ pub const XKCD_PALE_ROSE: Color = Color(253, 193, 197);
 
 /// Colour `(r = 32, g = 192, b = 115)`
 ///
-/// Colour `XKCD_DARK_MINT_GREEN` from the set `XKCD_COLORS`. (Colour number `546`)
+/// Colour `XKCD_DARK_MINT_GREEN` from the set `XKCD_COLORS`. (Colour number `546`, name `xkcd:dark mint green`)
 /// ## Representations:
 /// - int tuple `(32, 192, 115)`
 /// - float tuple `(0.13, 0.75, 0.45)`
@@ -4962,7 +5120,7 @@ pub const XKCD_DARK_MINT_GREEN: Color = Color(32, 192, 115);
 
 /// Colour `(r = 155, g = 95, b = 192)`
 ///
-/// Colour `XKCD_AMETHYST` from the set `XKCD_COLORS`. (Colour number `547`)
+/// Colour `XKCD_AMETHYST` from the set `XKCD_COLORS`. (Colour number `547`, name `xkcd:amethyst`)
 /// ## Representations:
 /// - int tuple `(155, 95, 192)`
 /// - float tuple `(0.61, 0.37, 0.75)`
@@ -4971,7 +5129,7 @@ pub const XKCD_AMETHYST: Color = Color(155, 95, 192);
 
 /// Colour `(r = 15, g = 155, b = 142)`
 ///
-/// Colour `XKCD_BLUE_SLASH_GREEN` from the set `XKCD_COLORS`. (Colour number `548`)
+/// Colour `XKCD_BLUE_SLASH_GREEN` from the set `XKCD_COLORS`. (Colour number `548`, name `xkcd:blue/green`)
 /// ## Representations:
 /// - int tuple `(15, 155, 142)`
 /// - float tuple `(0.06, 0.61, 0.56)`
@@ -4980,7 +5138,7 @@ pub const XKCD_BLUE_SLASH_GREEN: Color = Color(15, 155, 142);
 
 /// Colour `(r = 116, g = 40, b = 2)`
 ///
-/// Colour `XKCD_CHESTNUT` from the set `XKCD_COLORS`. (Colour number `549`)
+/// Colour `XKCD_CHESTNUT` from the set `XKCD_COLORS`. (Colour number `549`, name `xkcd:chestnut`)
 /// ## Representations:
 /// - int tuple `(116, 40, 2)`
 /// - float tuple `(0.45, 0.16, 0.01)`
@@ -4989,7 +5147,7 @@ pub const XKCD_CHESTNUT: Color = Color(116, 40, 2);
 
 /// Colour `(r = 157, g = 185, b = 44)`
 ///
-/// Colour `XKCD_SICK_GREEN` from the set `XKCD_COLORS`. (Colour number `550`)
+/// Colour `XKCD_SICK_GREEN` from the set `XKCD_COLORS`. (Colour number `550`, name `xkcd:sick green`)
 /// ## Representations:
 /// - int tuple `(157, 185, 44)`
 /// - float tuple `(0.62, 0.73, 0.17)`
@@ -4998,7 +5156,7 @@ pub const XKCD_SICK_GREEN: Color = Color(157, 185, 44);
 
 /// Colour `(r = 164, g = 191, b = 32)`
 ///
-/// Colour `XKCD_PEA` from the set `XKCD_COLORS`. (Colour number `551`)
+/// Colour `XKCD_PEA` from the set `XKCD_COLORS`. (Colour number `551`, name `xkcd:pea`)
 /// ## Representations:
 /// - int tuple `(164, 191, 32)`
 /// - float tuple `(0.64, 0.75, 0.13)`
@@ -5007,7 +5165,7 @@ pub const XKCD_PEA: Color = Color(164, 191, 32);
 
 /// Colour `(r = 205, g = 89, b = 9)`
 ///
-/// Colour `XKCD_RUSTY_ORANGE` from the set `XKCD_COLORS`. (Colour number `552`)
+/// Colour `XKCD_RUSTY_ORANGE` from the set `XKCD_COLORS`. (Colour number `552`, name `xkcd:rusty orange`)
 /// ## Representations:
 /// - int tuple `(205, 89, 9)`
 /// - float tuple `(0.8, 0.35, 0.04)`
@@ -5016,7 +5174,7 @@ pub const XKCD_RUSTY_ORANGE: Color = Color(205, 89, 9);
 
 /// Colour `(r = 173, g = 165, b = 135)`
 ///
-/// Colour `XKCD_STONE` from the set `XKCD_COLORS`. (Colour number `553`)
+/// Colour `XKCD_STONE` from the set `XKCD_COLORS`. (Colour number `553`, name `xkcd:stone`)
 /// ## Representations:
 /// - int tuple `(173, 165, 135)`
 /// - float tuple `(0.68, 0.65, 0.53)`
@@ -5025,7 +5183,7 @@ pub const XKCD_STONE: Color = Color(173, 165, 135);
 
 /// Colour `(r = 190, g = 1, b = 60)`
 ///
-/// Colour `XKCD_ROSE_RED` from the set `XKCD_COLORS`. (Colour number `554`)
+/// Colour `XKCD_ROSE_RED` from the set `XKCD_COLORS`. (Colour number `554`, name `xkcd:rose red`)
 /// ## Representations:
 /// - int tuple `(190, 1, 60)`
 /// - float tuple `(0.75, 0.0, 0.24)`
@@ -5034,7 +5192,7 @@ pub const XKCD_ROSE_RED: Color = Color(190, 1, 60);
 
 /// Colour `(r = 184, g = 255, b = 235)`
 ///
-/// Colour `XKCD_PALE_AQUA` from the set `XKCD_COLORS`. (Colour number `555`)
+/// Colour `XKCD_PALE_AQUA` from the set `XKCD_COLORS`. (Colour number `555`, name `xkcd:pale aqua`)
 /// ## Representations:
 /// - int tuple `(184, 255, 235)`
 /// - float tuple `(0.72, 1.0, 0.92)`
@@ -5043,7 +5201,7 @@ pub const XKCD_PALE_AQUA: Color = Color(184, 255, 235);
 
 /// Colour `(r = 220, g = 77, b = 1)`
 ///
-/// Colour `XKCD_DEEP_ORANGE` from the set `XKCD_COLORS`. (Colour number `556`)
+/// Colour `XKCD_DEEP_ORANGE` from the set `XKCD_COLORS`. (Colour number `556`, name `xkcd:deep orange`)
 /// ## Representations:
 /// - int tuple `(220, 77, 1)`
 /// - float tuple `(0.86, 0.3, 0.0)`
@@ -5052,7 +5210,7 @@ pub const XKCD_DEEP_ORANGE: Color = Color(220, 77, 1);
 
 /// Colour `(r = 162, g = 101, b = 62)`
 ///
-/// Colour `XKCD_EARTH` from the set `XKCD_COLORS`. (Colour number `557`)
+/// Colour `XKCD_EARTH` from the set `XKCD_COLORS`. (Colour number `557`, name `xkcd:earth`)
 /// ## Representations:
 /// - int tuple `(162, 101, 62)`
 /// - float tuple `(0.64, 0.4, 0.24)`
@@ -5061,7 +5219,7 @@ pub const XKCD_EARTH: Color = Color(162, 101, 62);
 
 /// Colour `(r = 99, g = 139, b = 39)`
 ///
-/// Colour `XKCD_MOSSY_GREEN` from the set `XKCD_COLORS`. (Colour number `558`)
+/// Colour `XKCD_MOSSY_GREEN` from the set `XKCD_COLORS`. (Colour number `558`, name `xkcd:mossy green`)
 /// ## Representations:
 /// - int tuple `(99, 139, 39)`
 /// - float tuple `(0.39, 0.55, 0.15)`
@@ -5070,7 +5228,7 @@ pub const XKCD_MOSSY_GREEN: Color = Color(99, 139, 39);
 
 /// Colour `(r = 65, g = 156, b = 3)`
 ///
-/// Colour `XKCD_GRASSY_GREEN` from the set `XKCD_COLORS`. (Colour number `559`)
+/// Colour `XKCD_GRASSY_GREEN` from the set `XKCD_COLORS`. (Colour number `559`, name `xkcd:grassy green`)
 /// ## Representations:
 /// - int tuple `(65, 156, 3)`
 /// - float tuple `(0.25, 0.61, 0.01)`
@@ -5079,7 +5237,7 @@ pub const XKCD_GRASSY_GREEN: Color = Color(65, 156, 3);
 
 /// Colour `(r = 177, g = 255, b = 101)`
 ///
-/// Colour `XKCD_PALE_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `560`)
+/// Colour `XKCD_PALE_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `560`, name `xkcd:pale lime green`)
 /// ## Representations:
 /// - int tuple `(177, 255, 101)`
 /// - float tuple `(0.69, 1.0, 0.4)`
@@ -5088,7 +5246,7 @@ pub const XKCD_PALE_LIME_GREEN: Color = Color(177, 255, 101);
 
 /// Colour `(r = 157, g = 188, b = 212)`
 ///
-/// Colour `XKCD_LIGHT_GREY_BLUE` from the set `XKCD_COLORS`. (Colour number `561`)
+/// Colour `XKCD_LIGHT_GREY_BLUE` from the set `XKCD_COLORS`. (Colour number `561`, name `xkcd:light grey blue`)
 /// ## Representations:
 /// - int tuple `(157, 188, 212)`
 /// - float tuple `(0.62, 0.74, 0.83)`
@@ -5097,7 +5255,7 @@ pub const XKCD_LIGHT_GREY_BLUE: Color = Color(157, 188, 212);
 
 /// Colour `(r = 253, g = 253, b = 254)`
 ///
-/// Colour `XKCD_PALE_GREY` from the set `XKCD_COLORS`. (Colour number `562`)
+/// Colour `XKCD_PALE_GREY` from the set `XKCD_COLORS`. (Colour number `562`, name `xkcd:pale grey`)
 /// ## Representations:
 /// - int tuple `(253, 253, 254)`
 /// - float tuple `(0.99, 0.99, 1.0)`
@@ -5106,7 +5264,7 @@ pub const XKCD_PALE_GREY: Color = Color(253, 253, 254);
 
 /// Colour `(r = 119, g = 171, b = 86)`
 ///
-/// Colour `XKCD_ASPARAGUS` from the set `XKCD_COLORS`. (Colour number `563`)
+/// Colour `XKCD_ASPARAGUS` from the set `XKCD_COLORS`. (Colour number `563`, name `xkcd:asparagus`)
 /// ## Representations:
 /// - int tuple `(119, 171, 86)`
 /// - float tuple `(0.47, 0.67, 0.34)`
@@ -5115,7 +5273,7 @@ pub const XKCD_ASPARAGUS: Color = Color(119, 171, 86);
 
 /// Colour `(r = 70, g = 65, b = 150)`
 ///
-/// Colour `XKCD_BLUEBERRY` from the set `XKCD_COLORS`. (Colour number `564`)
+/// Colour `XKCD_BLUEBERRY` from the set `XKCD_COLORS`. (Colour number `564`, name `xkcd:blueberry`)
 /// ## Representations:
 /// - int tuple `(70, 65, 150)`
 /// - float tuple `(0.27, 0.25, 0.59)`
@@ -5124,7 +5282,7 @@ pub const XKCD_BLUEBERRY: Color = Color(70, 65, 150);
 
 /// Colour `(r = 153, g = 1, b = 71)`
 ///
-/// Colour `XKCD_PURPLE_RED` from the set `XKCD_COLORS`. (Colour number `565`)
+/// Colour `XKCD_PURPLE_RED` from the set `XKCD_COLORS`. (Colour number `565`, name `xkcd:purple red`)
 /// ## Representations:
 /// - int tuple `(153, 1, 71)`
 /// - float tuple `(0.6, 0.0, 0.28)`
@@ -5133,7 +5291,7 @@ pub const XKCD_PURPLE_RED: Color = Color(153, 1, 71);
 
 /// Colour `(r = 190, g = 253, b = 115)`
 ///
-/// Colour `XKCD_PALE_LIME` from the set `XKCD_COLORS`. (Colour number `566`)
+/// Colour `XKCD_PALE_LIME` from the set `XKCD_COLORS`. (Colour number `566`, name `xkcd:pale lime`)
 /// ## Representations:
 /// - int tuple `(190, 253, 115)`
 /// - float tuple `(0.75, 0.99, 0.45)`
@@ -5142,7 +5300,7 @@ pub const XKCD_PALE_LIME: Color = Color(190, 253, 115);
 
 /// Colour `(r = 50, g = 191, b = 132)`
 ///
-/// Colour `XKCD_GREENISH_TEAL` from the set `XKCD_COLORS`. (Colour number `567`)
+/// Colour `XKCD_GREENISH_TEAL` from the set `XKCD_COLORS`. (Colour number `567`, name `xkcd:greenish teal`)
 /// ## Representations:
 /// - int tuple `(50, 191, 132)`
 /// - float tuple `(0.2, 0.75, 0.52)`
@@ -5151,7 +5309,7 @@ pub const XKCD_GREENISH_TEAL: Color = Color(50, 191, 132);
 
 /// Colour `(r = 175, g = 111, b = 9)`
 ///
-/// Colour `XKCD_CARAMEL` from the set `XKCD_COLORS`. (Colour number `568`)
+/// Colour `XKCD_CARAMEL` from the set `XKCD_COLORS`. (Colour number `568`, name `xkcd:caramel`)
 /// ## Representations:
 /// - int tuple `(175, 111, 9)`
 /// - float tuple `(0.69, 0.44, 0.04)`
@@ -5160,7 +5318,7 @@ pub const XKCD_CARAMEL: Color = Color(175, 111, 9);
 
 /// Colour `(r = 160, g = 2, b = 92)`
 ///
-/// Colour `XKCD_DEEP_MAGENTA` from the set `XKCD_COLORS`. (Colour number `569`)
+/// Colour `XKCD_DEEP_MAGENTA` from the set `XKCD_COLORS`. (Colour number `569`, name `xkcd:deep magenta`)
 /// ## Representations:
 /// - int tuple `(160, 2, 92)`
 /// - float tuple `(0.63, 0.01, 0.36)`
@@ -5169,7 +5327,7 @@ pub const XKCD_DEEP_MAGENTA: Color = Color(160, 2, 92);
 
 /// Colour `(r = 255, g = 216, b = 177)`
 ///
-/// Colour `XKCD_LIGHT_PEACH` from the set `XKCD_COLORS`. (Colour number `570`)
+/// Colour `XKCD_LIGHT_PEACH` from the set `XKCD_COLORS`. (Colour number `570`, name `xkcd:light peach`)
 /// ## Representations:
 /// - int tuple `(255, 216, 177)`
 /// - float tuple `(1.0, 0.85, 0.69)`
@@ -5178,7 +5336,7 @@ pub const XKCD_LIGHT_PEACH: Color = Color(255, 216, 177);
 
 /// Colour `(r = 127, g = 78, b = 30)`
 ///
-/// Colour `XKCD_MILK_CHOCOLATE` from the set `XKCD_COLORS`. (Colour number `571`)
+/// Colour `XKCD_MILK_CHOCOLATE` from the set `XKCD_COLORS`. (Colour number `571`, name `xkcd:milk chocolate`)
 /// ## Representations:
 /// - int tuple `(127, 78, 30)`
 /// - float tuple `(0.5, 0.31, 0.12)`
@@ -5187,7 +5345,7 @@ pub const XKCD_MILK_CHOCOLATE: Color = Color(127, 78, 30);
 
 /// Colour `(r = 191, g = 155, b = 12)`
 ///
-/// Colour `XKCD_OCHER` from the set `XKCD_COLORS`. (Colour number `572`)
+/// Colour `XKCD_OCHER` from the set `XKCD_COLORS`. (Colour number `572`, name `xkcd:ocher`)
 /// ## Representations:
 /// - int tuple `(191, 155, 12)`
 /// - float tuple `(0.75, 0.61, 0.05)`
@@ -5196,7 +5354,7 @@ pub const XKCD_OCHER: Color = Color(191, 155, 12);
 
 /// Colour `(r = 107, g = 163, b = 83)`
 ///
-/// Colour `XKCD_OFF_GREEN` from the set `XKCD_COLORS`. (Colour number `573`)
+/// Colour `XKCD_OFF_GREEN` from the set `XKCD_COLORS`. (Colour number `573`, name `xkcd:off green`)
 /// ## Representations:
 /// - int tuple `(107, 163, 83)`
 /// - float tuple `(0.42, 0.64, 0.33)`
@@ -5205,7 +5363,7 @@ pub const XKCD_OFF_GREEN: Color = Color(107, 163, 83);
 
 /// Colour `(r = 240, g = 117, b = 230)`
 ///
-/// Colour `XKCD_PURPLY_PINK` from the set `XKCD_COLORS`. (Colour number `574`)
+/// Colour `XKCD_PURPLY_PINK` from the set `XKCD_COLORS`. (Colour number `574`, name `xkcd:purply pink`)
 /// ## Representations:
 /// - int tuple `(240, 117, 230)`
 /// - float tuple `(0.94, 0.46, 0.9)`
@@ -5214,7 +5372,7 @@ pub const XKCD_PURPLY_PINK: Color = Color(240, 117, 230);
 
 /// Colour `(r = 123, g = 200, b = 246)`
 ///
-/// Colour `XKCD_LIGHTBLUE` from the set `XKCD_COLORS`. (Colour number `575`)
+/// Colour `XKCD_LIGHTBLUE` from the set `XKCD_COLORS`. (Colour number `575`, name `xkcd:lightblue`)
 /// ## Representations:
 /// - int tuple `(123, 200, 246)`
 /// - float tuple `(0.48, 0.78, 0.96)`
@@ -5223,7 +5381,7 @@ pub const XKCD_LIGHTBLUE: Color = Color(123, 200, 246);
 
 /// Colour `(r = 71, g = 95, b = 148)`
 ///
-/// Colour `XKCD_DUSKY_BLUE` from the set `XKCD_COLORS`. (Colour number `576`)
+/// Colour `XKCD_DUSKY_BLUE` from the set `XKCD_COLORS`. (Colour number `576`, name `xkcd:dusky blue`)
 /// ## Representations:
 /// - int tuple `(71, 95, 148)`
 /// - float tuple `(0.28, 0.37, 0.58)`
@@ -5232,7 +5390,7 @@ pub const XKCD_DUSKY_BLUE: Color = Color(71, 95, 148);
 
 /// Colour `(r = 245, g = 191, b = 3)`
 ///
-/// Colour `XKCD_GOLDEN` from the set `XKCD_COLORS`. (Colour number `577`)
+/// Colour `XKCD_GOLDEN` from the set `XKCD_COLORS`. (Colour number `577`, name `xkcd:golden`)
 /// ## Representations:
 /// - int tuple `(245, 191, 3)`
 /// - float tuple `(0.96, 0.75, 0.01)`
@@ -5241,7 +5399,7 @@ pub const XKCD_GOLDEN: Color = Color(245, 191, 3);
 
 /// Colour `(r = 255, g = 254, b = 182)`
 ///
-/// Colour `XKCD_LIGHT_BEIGE` from the set `XKCD_COLORS`. (Colour number `578`)
+/// Colour `XKCD_LIGHT_BEIGE` from the set `XKCD_COLORS`. (Colour number `578`, name `xkcd:light beige`)
 /// ## Representations:
 /// - int tuple `(255, 254, 182)`
 /// - float tuple `(1.0, 1.0, 0.71)`
@@ -5250,7 +5408,7 @@ pub const XKCD_LIGHT_BEIGE: Color = Color(255, 254, 182);
 
 /// Colour `(r = 255, g = 253, b = 116)`
 ///
-/// Colour `XKCD_BUTTER_YELLOW` from the set `XKCD_COLORS`. (Colour number `579`)
+/// Colour `XKCD_BUTTER_YELLOW` from the set `XKCD_COLORS`. (Colour number `579`, name `xkcd:butter yellow`)
 /// ## Representations:
 /// - int tuple `(255, 253, 116)`
 /// - float tuple `(1.0, 0.99, 0.45)`
@@ -5259,7 +5417,7 @@ pub const XKCD_BUTTER_YELLOW: Color = Color(255, 253, 116);
 
 /// Colour `(r = 137, g = 91, b = 123)`
 ///
-/// Colour `XKCD_DUSKY_PURPLE` from the set `XKCD_COLORS`. (Colour number `580`)
+/// Colour `XKCD_DUSKY_PURPLE` from the set `XKCD_COLORS`. (Colour number `580`, name `xkcd:dusky purple`)
 /// ## Representations:
 /// - int tuple `(137, 91, 123)`
 /// - float tuple `(0.54, 0.36, 0.48)`
@@ -5268,7 +5426,7 @@ pub const XKCD_DUSKY_PURPLE: Color = Color(137, 91, 123);
 
 /// Colour `(r = 67, g = 107, b = 173)`
 ///
-/// Colour `XKCD_FRENCH_BLUE` from the set `XKCD_COLORS`. (Colour number `581`)
+/// Colour `XKCD_FRENCH_BLUE` from the set `XKCD_COLORS`. (Colour number `581`, name `xkcd:french blue`)
 /// ## Representations:
 /// - int tuple `(67, 107, 173)`
 /// - float tuple `(0.26, 0.42, 0.68)`
@@ -5277,7 +5435,7 @@ pub const XKCD_FRENCH_BLUE: Color = Color(67, 107, 173);
 
 /// Colour `(r = 208, g = 193, b = 1)`
 ///
-/// Colour `XKCD_UGLY_YELLOW` from the set `XKCD_COLORS`. (Colour number `582`)
+/// Colour `XKCD_UGLY_YELLOW` from the set `XKCD_COLORS`. (Colour number `582`, name `xkcd:ugly yellow`)
 /// ## Representations:
 /// - int tuple `(208, 193, 1)`
 /// - float tuple `(0.82, 0.76, 0.0)`
@@ -5286,7 +5444,7 @@ pub const XKCD_UGLY_YELLOW: Color = Color(208, 193, 1);
 
 /// Colour `(r = 198, g = 248, b = 8)`
 ///
-/// Colour `XKCD_GREENY_YELLOW` from the set `XKCD_COLORS`. (Colour number `583`)
+/// Colour `XKCD_GREENY_YELLOW` from the set `XKCD_COLORS`. (Colour number `583`, name `xkcd:greeny yellow`)
 /// ## Representations:
 /// - int tuple `(198, 248, 8)`
 /// - float tuple `(0.78, 0.97, 0.03)`
@@ -5295,7 +5453,7 @@ pub const XKCD_GREENY_YELLOW: Color = Color(198, 248, 8);
 
 /// Colour `(r = 244, g = 54, b = 5)`
 ///
-/// Colour `XKCD_ORANGISH_RED` from the set `XKCD_COLORS`. (Colour number `584`)
+/// Colour `XKCD_ORANGISH_RED` from the set `XKCD_COLORS`. (Colour number `584`, name `xkcd:orangish red`)
 /// ## Representations:
 /// - int tuple `(244, 54, 5)`
 /// - float tuple `(0.96, 0.21, 0.02)`
@@ -5304,7 +5462,7 @@ pub const XKCD_ORANGISH_RED: Color = Color(244, 54, 5);
 
 /// Colour `(r = 2, g = 193, b = 77)`
 ///
-/// Colour `XKCD_SHAMROCK_GREEN` from the set `XKCD_COLORS`. (Colour number `585`)
+/// Colour `XKCD_SHAMROCK_GREEN` from the set `XKCD_COLORS`. (Colour number `585`, name `xkcd:shamrock green`)
 /// ## Representations:
 /// - int tuple `(2, 193, 77)`
 /// - float tuple `(0.01, 0.76, 0.3)`
@@ -5313,7 +5471,7 @@ pub const XKCD_SHAMROCK_GREEN: Color = Color(2, 193, 77);
 
 /// Colour `(r = 178, g = 95, b = 3)`
 ///
-/// Colour `XKCD_ORANGISH_BROWN` from the set `XKCD_COLORS`. (Colour number `586`)
+/// Colour `XKCD_ORANGISH_BROWN` from the set `XKCD_COLORS`. (Colour number `586`, name `xkcd:orangish brown`)
 /// ## Representations:
 /// - int tuple `(178, 95, 3)`
 /// - float tuple `(0.7, 0.37, 0.01)`
@@ -5322,7 +5480,7 @@ pub const XKCD_ORANGISH_BROWN: Color = Color(178, 95, 3);
 
 /// Colour `(r = 42, g = 126, b = 25)`
 ///
-/// Colour `XKCD_TREE_GREEN` from the set `XKCD_COLORS`. (Colour number `587`)
+/// Colour `XKCD_TREE_GREEN` from the set `XKCD_COLORS`. (Colour number `587`, name `xkcd:tree green`)
 /// ## Representations:
 /// - int tuple `(42, 126, 25)`
 /// - float tuple `(0.16, 0.49, 0.1)`
@@ -5331,7 +5489,7 @@ pub const XKCD_TREE_GREEN: Color = Color(42, 126, 25);
 
 /// Colour `(r = 73, g = 6, b = 72)`
 ///
-/// Colour `XKCD_DEEP_VIOLET` from the set `XKCD_COLORS`. (Colour number `588`)
+/// Colour `XKCD_DEEP_VIOLET` from the set `XKCD_COLORS`. (Colour number `588`, name `xkcd:deep violet`)
 /// ## Representations:
 /// - int tuple `(73, 6, 72)`
 /// - float tuple `(0.29, 0.02, 0.28)`
@@ -5340,7 +5498,7 @@ pub const XKCD_DEEP_VIOLET: Color = Color(73, 6, 72);
 
 /// Colour `(r = 83, g = 98, b = 103)`
 ///
-/// Colour `XKCD_GUNMETAL` from the set `XKCD_COLORS`. (Colour number `589`)
+/// Colour `XKCD_GUNMETAL` from the set `XKCD_COLORS`. (Colour number `589`, name `xkcd:gunmetal`)
 /// ## Representations:
 /// - int tuple `(83, 98, 103)`
 /// - float tuple `(0.33, 0.38, 0.4)`
@@ -5349,7 +5507,7 @@ pub const XKCD_GUNMETAL: Color = Color(83, 98, 103);
 
 /// Colour `(r = 90, g = 6, b = 239)`
 ///
-/// Colour `XKCD_BLUE_SLASH_PURPLE` from the set `XKCD_COLORS`. (Colour number `590`)
+/// Colour `XKCD_BLUE_SLASH_PURPLE` from the set `XKCD_COLORS`. (Colour number `590`, name `xkcd:blue/purple`)
 /// ## Representations:
 /// - int tuple `(90, 6, 239)`
 /// - float tuple `(0.35, 0.02, 0.94)`
@@ -5358,7 +5516,7 @@ pub const XKCD_BLUE_SLASH_PURPLE: Color = Color(90, 6, 239);
 
 /// Colour `(r = 207, g = 2, b = 52)`
 ///
-/// Colour `XKCD_CHERRY` from the set `XKCD_COLORS`. (Colour number `591`)
+/// Colour `XKCD_CHERRY` from the set `XKCD_COLORS`. (Colour number `591`, name `xkcd:cherry`)
 /// ## Representations:
 /// - int tuple `(207, 2, 52)`
 /// - float tuple `(0.81, 0.01, 0.2)`
@@ -5367,7 +5525,7 @@ pub const XKCD_CHERRY: Color = Color(207, 2, 52);
 
 /// Colour `(r = 196, g = 166, b = 97)`
 ///
-/// Colour `XKCD_SANDY_BROWN` from the set `XKCD_COLORS`. (Colour number `592`)
+/// Colour `XKCD_SANDY_BROWN` from the set `XKCD_COLORS`. (Colour number `592`, name `xkcd:sandy brown`)
 /// ## Representations:
 /// - int tuple `(196, 166, 97)`
 /// - float tuple `(0.77, 0.65, 0.38)`
@@ -5376,7 +5534,7 @@ pub const XKCD_SANDY_BROWN: Color = Color(196, 166, 97);
 
 /// Colour `(r = 151, g = 138, b = 132)`
 ///
-/// Colour `XKCD_WARM_GREY` from the set `XKCD_COLORS`. (Colour number `593`)
+/// Colour `XKCD_WARM_GREY` from the set `XKCD_COLORS`. (Colour number `593`, name `xkcd:warm grey`)
 /// ## Representations:
 /// - int tuple `(151, 138, 132)`
 /// - float tuple `(0.59, 0.54, 0.52)`
@@ -5385,7 +5543,7 @@ pub const XKCD_WARM_GREY: Color = Color(151, 138, 132);
 
 /// Colour `(r = 31, g = 9, b = 84)`
 ///
-/// Colour `XKCD_DARK_INDIGO` from the set `XKCD_COLORS`. (Colour number `594`)
+/// Colour `XKCD_DARK_INDIGO` from the set `XKCD_COLORS`. (Colour number `594`, name `xkcd:dark indigo`)
 /// ## Representations:
 /// - int tuple `(31, 9, 84)`
 /// - float tuple `(0.12, 0.04, 0.33)`
@@ -5394,7 +5552,7 @@ pub const XKCD_DARK_INDIGO: Color = Color(31, 9, 84);
 
 /// Colour `(r = 3, g = 1, b = 45)`
 ///
-/// Colour `XKCD_MIDNIGHT` from the set `XKCD_COLORS`. (Colour number `595`)
+/// Colour `XKCD_MIDNIGHT` from the set `XKCD_COLORS`. (Colour number `595`, name `xkcd:midnight`)
 /// ## Representations:
 /// - int tuple `(3, 1, 45)`
 /// - float tuple `(0.01, 0.0, 0.18)`
@@ -5403,7 +5561,7 @@ pub const XKCD_MIDNIGHT: Color = Color(3, 1, 45);
 
 /// Colour `(r = 43, g = 177, b = 121)`
 ///
-/// Colour `XKCD_BLUEY_GREEN` from the set `XKCD_COLORS`. (Colour number `596`)
+/// Colour `XKCD_BLUEY_GREEN` from the set `XKCD_COLORS`. (Colour number `596`, name `xkcd:bluey green`)
 /// ## Representations:
 /// - int tuple `(43, 177, 121)`
 /// - float tuple `(0.17, 0.69, 0.47)`
@@ -5412,7 +5570,7 @@ pub const XKCD_BLUEY_GREEN: Color = Color(43, 177, 121);
 
 /// Colour `(r = 195, g = 144, b = 155)`
 ///
-/// Colour `XKCD_GREY_PINK` from the set `XKCD_COLORS`. (Colour number `597`)
+/// Colour `XKCD_GREY_PINK` from the set `XKCD_COLORS`. (Colour number `597`, name `xkcd:grey pink`)
 /// ## Representations:
 /// - int tuple `(195, 144, 155)`
 /// - float tuple `(0.76, 0.56, 0.61)`
@@ -5421,7 +5579,7 @@ pub const XKCD_GREY_PINK: Color = Color(195, 144, 155);
 
 /// Colour `(r = 166, g = 111, b = 181)`
 ///
-/// Colour `XKCD_SOFT_PURPLE` from the set `XKCD_COLORS`. (Colour number `598`)
+/// Colour `XKCD_SOFT_PURPLE` from the set `XKCD_COLORS`. (Colour number `598`, name `xkcd:soft purple`)
 /// ## Representations:
 /// - int tuple `(166, 111, 181)`
 /// - float tuple `(0.65, 0.44, 0.71)`
@@ -5430,7 +5588,7 @@ pub const XKCD_SOFT_PURPLE: Color = Color(166, 111, 181);
 
 /// Colour `(r = 119, g = 0, b = 1)`
 ///
-/// Colour `XKCD_BLOOD` from the set `XKCD_COLORS`. (Colour number `599`)
+/// Colour `XKCD_BLOOD` from the set `XKCD_COLORS`. (Colour number `599`, name `xkcd:blood`)
 /// ## Representations:
 /// - int tuple `(119, 0, 1)`
 /// - float tuple `(0.47, 0.0, 0.0)`
@@ -5439,7 +5597,7 @@ pub const XKCD_BLOOD: Color = Color(119, 0, 1);
 
 /// Colour `(r = 146, g = 43, b = 5)`
 ///
-/// Colour `XKCD_BROWN_RED` from the set `XKCD_COLORS`. (Colour number `600`)
+/// Colour `XKCD_BROWN_RED` from the set `XKCD_COLORS`. (Colour number `600`, name `xkcd:brown red`)
 /// ## Representations:
 /// - int tuple `(146, 43, 5)`
 /// - float tuple `(0.57, 0.17, 0.02)`
@@ -5448,7 +5606,7 @@ pub const XKCD_BROWN_RED: Color = Color(146, 43, 5);
 
 /// Colour `(r = 125, g = 127, b = 124)`
 ///
-/// Colour `XKCD_MEDIUM_GREY` from the set `XKCD_COLORS`. (Colour number `601`)
+/// Colour `XKCD_MEDIUM_GREY` from the set `XKCD_COLORS`. (Colour number `601`, name `xkcd:medium grey`)
 /// ## Representations:
 /// - int tuple `(125, 127, 124)`
 /// - float tuple `(0.49, 0.5, 0.49)`
@@ -5457,7 +5615,7 @@ pub const XKCD_MEDIUM_GREY: Color = Color(125, 127, 124);
 
 /// Colour `(r = 153, g = 15, b = 75)`
 ///
-/// Colour `XKCD_BERRY` from the set `XKCD_COLORS`. (Colour number `602`)
+/// Colour `XKCD_BERRY` from the set `XKCD_COLORS`. (Colour number `602`, name `xkcd:berry`)
 /// ## Representations:
 /// - int tuple `(153, 15, 75)`
 /// - float tuple `(0.6, 0.06, 0.29)`
@@ -5466,7 +5624,7 @@ pub const XKCD_BERRY: Color = Color(153, 15, 75);
 
 /// Colour `(r = 143, g = 115, b = 3)`
 ///
-/// Colour `XKCD_POO` from the set `XKCD_COLORS`. (Colour number `603`)
+/// Colour `XKCD_POO` from the set `XKCD_COLORS`. (Colour number `603`, name `xkcd:poo`)
 /// ## Representations:
 /// - int tuple `(143, 115, 3)`
 /// - float tuple `(0.56, 0.45, 0.01)`
@@ -5475,7 +5633,7 @@ pub const XKCD_POO: Color = Color(143, 115, 3);
 
 /// Colour `(r = 200, g = 60, b = 185)`
 ///
-/// Colour `XKCD_PURPLEY_PINK` from the set `XKCD_COLORS`. (Colour number `604`)
+/// Colour `XKCD_PURPLEY_PINK` from the set `XKCD_COLORS`. (Colour number `604`, name `xkcd:purpley pink`)
 /// ## Representations:
 /// - int tuple `(200, 60, 185)`
 /// - float tuple `(0.78, 0.24, 0.73)`
@@ -5484,7 +5642,7 @@ pub const XKCD_PURPLEY_PINK: Color = Color(200, 60, 185);
 
 /// Colour `(r = 254, g = 169, b = 147)`
 ///
-/// Colour `XKCD_LIGHT_SALMON` from the set `XKCD_COLORS`. (Colour number `605`)
+/// Colour `XKCD_LIGHT_SALMON` from the set `XKCD_COLORS`. (Colour number `605`, name `xkcd:light salmon`)
 /// ## Representations:
 /// - int tuple `(254, 169, 147)`
 /// - float tuple `(1.0, 0.66, 0.58)`
@@ -5493,7 +5651,7 @@ pub const XKCD_LIGHT_SALMON: Color = Color(254, 169, 147);
 
 /// Colour `(r = 172, g = 187, b = 13)`
 ///
-/// Colour `XKCD_SNOT` from the set `XKCD_COLORS`. (Colour number `606`)
+/// Colour `XKCD_SNOT` from the set `XKCD_COLORS`. (Colour number `606`, name `xkcd:snot`)
 /// ## Representations:
 /// - int tuple `(172, 187, 13)`
 /// - float tuple `(0.67, 0.73, 0.05)`
@@ -5502,7 +5660,7 @@ pub const XKCD_SNOT: Color = Color(172, 187, 13);
 
 /// Colour `(r = 192, g = 113, b = 254)`
 ///
-/// Colour `XKCD_EASTER_PURPLE` from the set `XKCD_COLORS`. (Colour number `607`)
+/// Colour `XKCD_EASTER_PURPLE` from the set `XKCD_COLORS`. (Colour number `607`, name `xkcd:easter purple`)
 /// ## Representations:
 /// - int tuple `(192, 113, 254)`
 /// - float tuple `(0.75, 0.44, 1.0)`
@@ -5511,7 +5669,7 @@ pub const XKCD_EASTER_PURPLE: Color = Color(192, 113, 254);
 
 /// Colour `(r = 204, g = 253, b = 127)`
 ///
-/// Colour `XKCD_LIGHT_YELLOW_GREEN` from the set `XKCD_COLORS`. (Colour number `608`)
+/// Colour `XKCD_LIGHT_YELLOW_GREEN` from the set `XKCD_COLORS`. (Colour number `608`, name `xkcd:light yellow green`)
 /// ## Representations:
 /// - int tuple `(204, 253, 127)`
 /// - float tuple `(0.8, 0.99, 0.5)`
@@ -5520,7 +5678,7 @@ pub const XKCD_LIGHT_YELLOW_GREEN: Color = Color(204, 253, 127);
 
 /// Colour `(r = 0, g = 2, b = 46)`
 ///
-/// Colour `XKCD_DARK_NAVY_BLUE` from the set `XKCD_COLORS`. (Colour number `609`)
+/// Colour `XKCD_DARK_NAVY_BLUE` from the set `XKCD_COLORS`. (Colour number `609`, name `xkcd:dark navy blue`)
 /// ## Representations:
 /// - int tuple `(0, 2, 46)`
 /// - float tuple `(0.0, 0.01, 0.18)`
@@ -5529,7 +5687,7 @@ pub const XKCD_DARK_NAVY_BLUE: Color = Color(0, 2, 46);
 
 /// Colour `(r = 130, g = 131, b = 68)`
 ///
-/// Colour `XKCD_DRAB` from the set `XKCD_COLORS`. (Colour number `610`)
+/// Colour `XKCD_DRAB` from the set `XKCD_COLORS`. (Colour number `610`, name `xkcd:drab`)
 /// ## Representations:
 /// - int tuple `(130, 131, 68)`
 /// - float tuple `(0.51, 0.51, 0.27)`
@@ -5538,7 +5696,7 @@ pub const XKCD_DRAB: Color = Color(130, 131, 68);
 
 /// Colour `(r = 255, g = 197, b = 203)`
 ///
-/// Colour `XKCD_LIGHT_ROSE` from the set `XKCD_COLORS`. (Colour number `611`)
+/// Colour `XKCD_LIGHT_ROSE` from the set `XKCD_COLORS`. (Colour number `611`, name `xkcd:light rose`)
 /// ## Representations:
 /// - int tuple `(255, 197, 203)`
 /// - float tuple `(1.0, 0.77, 0.8)`
@@ -5547,7 +5705,7 @@ pub const XKCD_LIGHT_ROSE: Color = Color(255, 197, 203);
 
 /// Colour `(r = 171, g = 18, b = 57)`
 ///
-/// Colour `XKCD_ROUGE` from the set `XKCD_COLORS`. (Colour number `612`)
+/// Colour `XKCD_ROUGE` from the set `XKCD_COLORS`. (Colour number `612`, name `xkcd:rouge`)
 /// ## Representations:
 /// - int tuple `(171, 18, 57)`
 /// - float tuple `(0.67, 0.07, 0.22)`
@@ -5556,7 +5714,7 @@ pub const XKCD_ROUGE: Color = Color(171, 18, 57);
 
 /// Colour `(r = 176, g = 5, b = 75)`
 ///
-/// Colour `XKCD_PURPLISH_RED` from the set `XKCD_COLORS`. (Colour number `613`)
+/// Colour `XKCD_PURPLISH_RED` from the set `XKCD_COLORS`. (Colour number `613`, name `xkcd:purplish red`)
 /// ## Representations:
 /// - int tuple `(176, 5, 75)`
 /// - float tuple `(0.69, 0.02, 0.29)`
@@ -5565,7 +5723,7 @@ pub const XKCD_PURPLISH_RED: Color = Color(176, 5, 75);
 
 /// Colour `(r = 153, g = 204, b = 4)`
 ///
-/// Colour `XKCD_SLIME_GREEN` from the set `XKCD_COLORS`. (Colour number `614`)
+/// Colour `XKCD_SLIME_GREEN` from the set `XKCD_COLORS`. (Colour number `614`, name `xkcd:slime green`)
 /// ## Representations:
 /// - int tuple `(153, 204, 4)`
 /// - float tuple `(0.6, 0.8, 0.02)`
@@ -5574,7 +5732,7 @@ pub const XKCD_SLIME_GREEN: Color = Color(153, 204, 4);
 
 /// Colour `(r = 147, g = 124, b = 0)`
 ///
-/// Colour `XKCD_BABY_POOP` from the set `XKCD_COLORS`. (Colour number `615`)
+/// Colour `XKCD_BABY_POOP` from the set `XKCD_COLORS`. (Colour number `615`, name `xkcd:baby poop`)
 /// ## Representations:
 /// - int tuple `(147, 124, 0)`
 /// - float tuple `(0.58, 0.49, 0.0)`
@@ -5583,7 +5741,7 @@ pub const XKCD_BABY_POOP: Color = Color(147, 124, 0);
 
 /// Colour `(r = 1, g = 149, b = 41)`
 ///
-/// Colour `XKCD_IRISH_GREEN` from the set `XKCD_COLORS`. (Colour number `616`)
+/// Colour `XKCD_IRISH_GREEN` from the set `XKCD_COLORS`. (Colour number `616`, name `xkcd:irish green`)
 /// ## Representations:
 /// - int tuple `(1, 149, 41)`
 /// - float tuple `(0.0, 0.58, 0.16)`
@@ -5592,7 +5750,7 @@ pub const XKCD_IRISH_GREEN: Color = Color(1, 149, 41);
 
 /// Colour `(r = 239, g = 29, b = 231)`
 ///
-/// Colour `XKCD_PINK_SLASH_PURPLE` from the set `XKCD_COLORS`. (Colour number `617`)
+/// Colour `XKCD_PINK_SLASH_PURPLE` from the set `XKCD_COLORS`. (Colour number `617`, name `xkcd:pink/purple`)
 /// ## Representations:
 /// - int tuple `(239, 29, 231)`
 /// - float tuple `(0.94, 0.11, 0.91)`
@@ -5601,7 +5759,7 @@ pub const XKCD_PINK_SLASH_PURPLE: Color = Color(239, 29, 231);
 
 /// Colour `(r = 0, g = 4, b = 53)`
 ///
-/// Colour `XKCD_DARK_NAVY` from the set `XKCD_COLORS`. (Colour number `618`)
+/// Colour `XKCD_DARK_NAVY` from the set `XKCD_COLORS`. (Colour number `618`, name `xkcd:dark navy`)
 /// ## Representations:
 /// - int tuple `(0, 4, 53)`
 /// - float tuple `(0.0, 0.02, 0.21)`
@@ -5610,7 +5768,7 @@ pub const XKCD_DARK_NAVY: Color = Color(0, 4, 53);
 
 /// Colour `(r = 66, g = 179, b = 149)`
 ///
-/// Colour `XKCD_GREENY_BLUE` from the set `XKCD_COLORS`. (Colour number `619`)
+/// Colour `XKCD_GREENY_BLUE` from the set `XKCD_COLORS`. (Colour number `619`, name `xkcd:greeny blue`)
 /// ## Representations:
 /// - int tuple `(66, 179, 149)`
 /// - float tuple `(0.26, 0.7, 0.58)`
@@ -5619,7 +5777,7 @@ pub const XKCD_GREENY_BLUE: Color = Color(66, 179, 149);
 
 /// Colour `(r = 157, g = 87, b = 131)`
 ///
-/// Colour `XKCD_LIGHT_PLUM` from the set `XKCD_COLORS`. (Colour number `620`)
+/// Colour `XKCD_LIGHT_PLUM` from the set `XKCD_COLORS`. (Colour number `620`, name `xkcd:light plum`)
 /// ## Representations:
 /// - int tuple `(157, 87, 131)`
 /// - float tuple `(0.62, 0.34, 0.51)`
@@ -5628,7 +5786,7 @@ pub const XKCD_LIGHT_PLUM: Color = Color(157, 87, 131);
 
 /// Colour `(r = 200, g = 172, b = 169)`
 ///
-/// Colour `XKCD_PINKISH_GREY` from the set `XKCD_COLORS`. (Colour number `621`)
+/// Colour `XKCD_PINKISH_GREY` from the set `XKCD_COLORS`. (Colour number `621`, name `xkcd:pinkish grey`)
 /// ## Representations:
 /// - int tuple `(200, 172, 169)`
 /// - float tuple `(0.78, 0.67, 0.66)`
@@ -5637,7 +5795,7 @@ pub const XKCD_PINKISH_GREY: Color = Color(200, 172, 169);
 
 /// Colour `(r = 200, g = 118, b = 6)`
 ///
-/// Colour `XKCD_DIRTY_ORANGE` from the set `XKCD_COLORS`. (Colour number `622`)
+/// Colour `XKCD_DIRTY_ORANGE` from the set `XKCD_COLORS`. (Colour number `622`, name `xkcd:dirty orange`)
 /// ## Representations:
 /// - int tuple `(200, 118, 6)`
 /// - float tuple `(0.78, 0.46, 0.02)`
@@ -5646,7 +5804,7 @@ pub const XKCD_DIRTY_ORANGE: Color = Color(200, 118, 6);
 
 /// Colour `(r = 170, g = 39, b = 4)`
 ///
-/// Colour `XKCD_RUST_RED` from the set `XKCD_COLORS`. (Colour number `623`)
+/// Colour `XKCD_RUST_RED` from the set `XKCD_COLORS`. (Colour number `623`, name `xkcd:rust red`)
 /// ## Representations:
 /// - int tuple `(170, 39, 4)`
 /// - float tuple `(0.67, 0.15, 0.02)`
@@ -5655,7 +5813,7 @@ pub const XKCD_RUST_RED: Color = Color(170, 39, 4);
 
 /// Colour `(r = 228, g = 203, b = 255)`
 ///
-/// Colour `XKCD_PALE_LILAC` from the set `XKCD_COLORS`. (Colour number `624`)
+/// Colour `XKCD_PALE_LILAC` from the set `XKCD_COLORS`. (Colour number `624`, name `xkcd:pale lilac`)
 /// ## Representations:
 /// - int tuple `(228, 203, 255)`
 /// - float tuple `(0.89, 0.8, 1.0)`
@@ -5664,7 +5822,7 @@ pub const XKCD_PALE_LILAC: Color = Color(228, 203, 255);
 
 /// Colour `(r = 250, g = 66, b = 36)`
 ///
-/// Colour `XKCD_ORANGEY_RED` from the set `XKCD_COLORS`. (Colour number `625`)
+/// Colour `XKCD_ORANGEY_RED` from the set `XKCD_COLORS`. (Colour number `625`, name `xkcd:orangey red`)
 /// ## Representations:
 /// - int tuple `(250, 66, 36)`
 /// - float tuple `(0.98, 0.26, 0.14)`
@@ -5673,7 +5831,7 @@ pub const XKCD_ORANGEY_RED: Color = Color(250, 66, 36);
 
 /// Colour `(r = 8, g = 4, b = 249)`
 ///
-/// Colour `XKCD_PRIMARY_BLUE` from the set `XKCD_COLORS`. (Colour number `626`)
+/// Colour `XKCD_PRIMARY_BLUE` from the set `XKCD_COLORS`. (Colour number `626`, name `xkcd:primary blue`)
 /// ## Representations:
 /// - int tuple `(8, 4, 249)`
 /// - float tuple `(0.03, 0.02, 0.98)`
@@ -5682,7 +5840,7 @@ pub const XKCD_PRIMARY_BLUE: Color = Color(8, 4, 249);
 
 /// Colour `(r = 92, g = 178, b = 0)`
 ///
-/// Colour `XKCD_KERMIT_GREEN` from the set `XKCD_COLORS`. (Colour number `627`)
+/// Colour `XKCD_KERMIT_GREEN` from the set `XKCD_COLORS`. (Colour number `627`, name `xkcd:kermit green`)
 /// ## Representations:
 /// - int tuple `(92, 178, 0)`
 /// - float tuple `(0.36, 0.7, 0.0)`
@@ -5691,7 +5849,7 @@ pub const XKCD_KERMIT_GREEN: Color = Color(92, 178, 0);
 
 /// Colour `(r = 118, g = 66, b = 78)`
 ///
-/// Colour `XKCD_BROWNISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `628`)
+/// Colour `XKCD_BROWNISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `628`, name `xkcd:brownish purple`)
 /// ## Representations:
 /// - int tuple `(118, 66, 78)`
 /// - float tuple `(0.46, 0.26, 0.31)`
@@ -5700,7 +5858,7 @@ pub const XKCD_BROWNISH_PURPLE: Color = Color(118, 66, 78);
 
 /// Colour `(r = 108, g = 122, b = 14)`
 ///
-/// Colour `XKCD_MURKY_GREEN` from the set `XKCD_COLORS`. (Colour number `629`)
+/// Colour `XKCD_MURKY_GREEN` from the set `XKCD_COLORS`. (Colour number `629`, name `xkcd:murky green`)
 /// ## Representations:
 /// - int tuple `(108, 122, 14)`
 /// - float tuple `(0.42, 0.48, 0.05)`
@@ -5709,7 +5867,7 @@ pub const XKCD_MURKY_GREEN: Color = Color(108, 122, 14);
 
 /// Colour `(r = 251, g = 221, b = 126)`
 ///
-/// Colour `XKCD_WHEAT` from the set `XKCD_COLORS`. (Colour number `630`)
+/// Colour `XKCD_WHEAT` from the set `XKCD_COLORS`. (Colour number `630`, name `xkcd:wheat`)
 /// ## Representations:
 /// - int tuple `(251, 221, 126)`
 /// - float tuple `(0.98, 0.87, 0.49)`
@@ -5718,7 +5876,7 @@ pub const XKCD_WHEAT: Color = Color(251, 221, 126);
 
 /// Colour `(r = 42, g = 1, b = 52)`
 ///
-/// Colour `XKCD_VERY_DARK_PURPLE` from the set `XKCD_COLORS`. (Colour number `631`)
+/// Colour `XKCD_VERY_DARK_PURPLE` from the set `XKCD_COLORS`. (Colour number `631`, name `xkcd:very dark purple`)
 /// ## Representations:
 /// - int tuple `(42, 1, 52)`
 /// - float tuple `(0.16, 0.0, 0.2)`
@@ -5727,7 +5885,7 @@ pub const XKCD_VERY_DARK_PURPLE: Color = Color(42, 1, 52);
 
 /// Colour `(r = 4, g = 74, b = 5)`
 ///
-/// Colour `XKCD_BOTTLE_GREEN` from the set `XKCD_COLORS`. (Colour number `632`)
+/// Colour `XKCD_BOTTLE_GREEN` from the set `XKCD_COLORS`. (Colour number `632`, name `xkcd:bottle green`)
 /// ## Representations:
 /// - int tuple `(4, 74, 5)`
 /// - float tuple `(0.02, 0.29, 0.02)`
@@ -5736,7 +5894,7 @@ pub const XKCD_BOTTLE_GREEN: Color = Color(4, 74, 5);
 
 /// Colour `(r = 253, g = 70, b = 89)`
 ///
-/// Colour `XKCD_WATERMELON` from the set `XKCD_COLORS`. (Colour number `633`)
+/// Colour `XKCD_WATERMELON` from the set `XKCD_COLORS`. (Colour number `633`, name `xkcd:watermelon`)
 /// ## Representations:
 /// - int tuple `(253, 70, 89)`
 /// - float tuple `(0.99, 0.27, 0.35)`
@@ -5745,7 +5903,7 @@ pub const XKCD_WATERMELON: Color = Color(253, 70, 89);
 
 /// Colour `(r = 13, g = 117, b = 248)`
 ///
-/// Colour `XKCD_DEEP_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `634`)
+/// Colour `XKCD_DEEP_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `634`, name `xkcd:deep sky blue`)
 /// ## Representations:
 /// - int tuple `(13, 117, 248)`
 /// - float tuple `(0.05, 0.46, 0.97)`
@@ -5754,7 +5912,7 @@ pub const XKCD_DEEP_SKY_BLUE: Color = Color(13, 117, 248);
 
 /// Colour `(r = 254, g = 0, b = 2)`
 ///
-/// Colour `XKCD_FIRE_ENGINE_RED` from the set `XKCD_COLORS`. (Colour number `635`)
+/// Colour `XKCD_FIRE_ENGINE_RED` from the set `XKCD_COLORS`. (Colour number `635`, name `xkcd:fire engine red`)
 /// ## Representations:
 /// - int tuple `(254, 0, 2)`
 /// - float tuple `(1.0, 0.0, 0.01)`
@@ -5763,7 +5921,7 @@ pub const XKCD_FIRE_ENGINE_RED: Color = Color(254, 0, 2);
 
 /// Colour `(r = 203, g = 157, b = 6)`
 ///
-/// Colour `XKCD_YELLOW_OCHRE` from the set `XKCD_COLORS`. (Colour number `636`)
+/// Colour `XKCD_YELLOW_OCHRE` from the set `XKCD_COLORS`. (Colour number `636`, name `xkcd:yellow ochre`)
 /// ## Representations:
 /// - int tuple `(203, 157, 6)`
 /// - float tuple `(0.8, 0.62, 0.02)`
@@ -5772,7 +5930,7 @@ pub const XKCD_YELLOW_OCHRE: Color = Color(203, 157, 6);
 
 /// Colour `(r = 251, g = 125, b = 7)`
 ///
-/// Colour `XKCD_PUMPKIN_ORANGE` from the set `XKCD_COLORS`. (Colour number `637`)
+/// Colour `XKCD_PUMPKIN_ORANGE` from the set `XKCD_COLORS`. (Colour number `637`, name `xkcd:pumpkin orange`)
 /// ## Representations:
 /// - int tuple `(251, 125, 7)`
 /// - float tuple `(0.98, 0.49, 0.03)`
@@ -5781,7 +5939,7 @@ pub const XKCD_PUMPKIN_ORANGE: Color = Color(251, 125, 7);
 
 /// Colour `(r = 185, g = 204, b = 129)`
 ///
-/// Colour `XKCD_PALE_OLIVE` from the set `XKCD_COLORS`. (Colour number `638`)
+/// Colour `XKCD_PALE_OLIVE` from the set `XKCD_COLORS`. (Colour number `638`, name `xkcd:pale olive`)
 /// ## Representations:
 /// - int tuple `(185, 204, 129)`
 /// - float tuple `(0.73, 0.8, 0.51)`
@@ -5790,7 +5948,7 @@ pub const XKCD_PALE_OLIVE: Color = Color(185, 204, 129);
 
 /// Colour `(r = 237, g = 200, b = 255)`
 ///
-/// Colour `XKCD_LIGHT_LILAC` from the set `XKCD_COLORS`. (Colour number `639`)
+/// Colour `XKCD_LIGHT_LILAC` from the set `XKCD_COLORS`. (Colour number `639`, name `xkcd:light lilac`)
 /// ## Representations:
 /// - int tuple `(237, 200, 255)`
 /// - float tuple `(0.93, 0.78, 1.0)`
@@ -5799,7 +5957,7 @@ pub const XKCD_LIGHT_LILAC: Color = Color(237, 200, 255);
 
 /// Colour `(r = 97, g = 225, b = 96)`
 ///
-/// Colour `XKCD_LIGHTISH_GREEN` from the set `XKCD_COLORS`. (Colour number `640`)
+/// Colour `XKCD_LIGHTISH_GREEN` from the set `XKCD_COLORS`. (Colour number `640`, name `xkcd:lightish green`)
 /// ## Representations:
 /// - int tuple `(97, 225, 96)`
 /// - float tuple `(0.38, 0.88, 0.38)`
@@ -5808,7 +5966,7 @@ pub const XKCD_LIGHTISH_GREEN: Color = Color(97, 225, 96);
 
 /// Colour `(r = 138, g = 184, b = 254)`
 ///
-/// Colour `XKCD_CAROLINA_BLUE` from the set `XKCD_COLORS`. (Colour number `641`)
+/// Colour `XKCD_CAROLINA_BLUE` from the set `XKCD_COLORS`. (Colour number `641`, name `xkcd:carolina blue`)
 /// ## Representations:
 /// - int tuple `(138, 184, 254)`
 /// - float tuple `(0.54, 0.72, 1.0)`
@@ -5817,7 +5975,7 @@ pub const XKCD_CAROLINA_BLUE: Color = Color(138, 184, 254);
 
 /// Colour `(r = 146, g = 10, b = 78)`
 ///
-/// Colour `XKCD_MULBERRY` from the set `XKCD_COLORS`. (Colour number `642`)
+/// Colour `XKCD_MULBERRY` from the set `XKCD_COLORS`. (Colour number `642`, name `xkcd:mulberry`)
 /// ## Representations:
 /// - int tuple `(146, 10, 78)`
 /// - float tuple `(0.57, 0.04, 0.31)`
@@ -5826,7 +5984,7 @@ pub const XKCD_MULBERRY: Color = Color(146, 10, 78);
 
 /// Colour `(r = 254, g = 2, b = 162)`
 ///
-/// Colour `XKCD_SHOCKING_PINK` from the set `XKCD_COLORS`. (Colour number `643`)
+/// Colour `XKCD_SHOCKING_PINK` from the set `XKCD_COLORS`. (Colour number `643`, name `xkcd:shocking pink`)
 /// ## Representations:
 /// - int tuple `(254, 2, 162)`
 /// - float tuple `(1.0, 0.01, 0.64)`
@@ -5835,7 +5993,7 @@ pub const XKCD_SHOCKING_PINK: Color = Color(254, 2, 162);
 
 /// Colour `(r = 154, g = 48, b = 1)`
 ///
-/// Colour `XKCD_AUBURN` from the set `XKCD_COLORS`. (Colour number `644`)
+/// Colour `XKCD_AUBURN` from the set `XKCD_COLORS`. (Colour number `644`, name `xkcd:auburn`)
 /// ## Representations:
 /// - int tuple `(154, 48, 1)`
 /// - float tuple `(0.6, 0.19, 0.0)`
@@ -5844,7 +6002,7 @@ pub const XKCD_AUBURN: Color = Color(154, 48, 1);
 
 /// Colour `(r = 101, g = 254, b = 8)`
 ///
-/// Colour `XKCD_BRIGHT_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `645`)
+/// Colour `XKCD_BRIGHT_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `645`, name `xkcd:bright lime green`)
 /// ## Representations:
 /// - int tuple `(101, 254, 8)`
 /// - float tuple `(0.4, 1.0, 0.03)`
@@ -5853,7 +6011,7 @@ pub const XKCD_BRIGHT_LIME_GREEN: Color = Color(101, 254, 8);
 
 /// Colour `(r = 190, g = 253, b = 183)`
 ///
-/// Colour `XKCD_CELADON` from the set `XKCD_COLORS`. (Colour number `646`)
+/// Colour `XKCD_CELADON` from the set `XKCD_COLORS`. (Colour number `646`, name `xkcd:celadon`)
 /// ## Representations:
 /// - int tuple `(190, 253, 183)`
 /// - float tuple `(0.75, 0.99, 0.72)`
@@ -5862,7 +6020,7 @@ pub const XKCD_CELADON: Color = Color(190, 253, 183);
 
 /// Colour `(r = 177, g = 114, b = 97)`
 ///
-/// Colour `XKCD_PINKISH_BROWN` from the set `XKCD_COLORS`. (Colour number `647`)
+/// Colour `XKCD_PINKISH_BROWN` from the set `XKCD_COLORS`. (Colour number `647`, name `xkcd:pinkish brown`)
 /// ## Representations:
 /// - int tuple `(177, 114, 97)`
 /// - float tuple `(0.69, 0.45, 0.38)`
@@ -5871,7 +6029,7 @@ pub const XKCD_PINKISH_BROWN: Color = Color(177, 114, 97);
 
 /// Colour `(r = 136, g = 95, b = 1)`
 ///
-/// Colour `XKCD_POO_BROWN` from the set `XKCD_COLORS`. (Colour number `648`)
+/// Colour `XKCD_POO_BROWN` from the set `XKCD_COLORS`. (Colour number `648`, name `xkcd:poo brown`)
 /// ## Representations:
 /// - int tuple `(136, 95, 1)`
 /// - float tuple `(0.53, 0.37, 0.0)`
@@ -5880,7 +6038,7 @@ pub const XKCD_POO_BROWN: Color = Color(136, 95, 1);
 
 /// Colour `(r = 2, g = 204, b = 254)`
 ///
-/// Colour `XKCD_BRIGHT_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `649`)
+/// Colour `XKCD_BRIGHT_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `649`, name `xkcd:bright sky blue`)
 /// ## Representations:
 /// - int tuple `(2, 204, 254)`
 /// - float tuple `(0.01, 0.8, 1.0)`
@@ -5889,7 +6047,7 @@ pub const XKCD_BRIGHT_SKY_BLUE: Color = Color(2, 204, 254);
 
 /// Colour `(r = 193, g = 253, b = 149)`
 ///
-/// Colour `XKCD_CELERY` from the set `XKCD_COLORS`. (Colour number `650`)
+/// Colour `XKCD_CELERY` from the set `XKCD_COLORS`. (Colour number `650`, name `xkcd:celery`)
 /// ## Representations:
 /// - int tuple `(193, 253, 149)`
 /// - float tuple `(0.76, 0.99, 0.58)`
@@ -5898,7 +6056,7 @@ pub const XKCD_CELERY: Color = Color(193, 253, 149);
 
 /// Colour `(r = 131, g = 101, b = 57)`
 ///
-/// Colour `XKCD_DIRT_BROWN` from the set `XKCD_COLORS`. (Colour number `651`)
+/// Colour `XKCD_DIRT_BROWN` from the set `XKCD_COLORS`. (Colour number `651`, name `xkcd:dirt brown`)
 /// ## Representations:
 /// - int tuple `(131, 101, 57)`
 /// - float tuple `(0.51, 0.4, 0.22)`
@@ -5907,7 +6065,7 @@ pub const XKCD_DIRT_BROWN: Color = Color(131, 101, 57);
 
 /// Colour `(r = 251, g = 41, b = 67)`
 ///
-/// Colour `XKCD_STRAWBERRY` from the set `XKCD_COLORS`. (Colour number `652`)
+/// Colour `XKCD_STRAWBERRY` from the set `XKCD_COLORS`. (Colour number `652`, name `xkcd:strawberry`)
 /// ## Representations:
 /// - int tuple `(251, 41, 67)`
 /// - float tuple `(0.98, 0.16, 0.26)`
@@ -5916,7 +6074,7 @@ pub const XKCD_STRAWBERRY: Color = Color(251, 41, 67);
 
 /// Colour `(r = 132, g = 183, b = 1)`
 ///
-/// Colour `XKCD_DARK_LIME` from the set `XKCD_COLORS`. (Colour number `653`)
+/// Colour `XKCD_DARK_LIME` from the set `XKCD_COLORS`. (Colour number `653`, name `xkcd:dark lime`)
 /// ## Representations:
 /// - int tuple `(132, 183, 1)`
 /// - float tuple `(0.52, 0.72, 0.0)`
@@ -5925,7 +6083,7 @@ pub const XKCD_DARK_LIME: Color = Color(132, 183, 1);
 
 /// Colour `(r = 182, g = 99, b = 37)`
 ///
-/// Colour `XKCD_COPPER` from the set `XKCD_COLORS`. (Colour number `654`)
+/// Colour `XKCD_COPPER` from the set `XKCD_COLORS`. (Colour number `654`, name `xkcd:copper`)
 /// ## Representations:
 /// - int tuple `(182, 99, 37)`
 /// - float tuple `(0.71, 0.39, 0.15)`
@@ -5934,7 +6092,7 @@ pub const XKCD_COPPER: Color = Color(182, 99, 37);
 
 /// Colour `(r = 127, g = 81, b = 18)`
 ///
-/// Colour `XKCD_MEDIUM_BROWN` from the set `XKCD_COLORS`. (Colour number `655`)
+/// Colour `XKCD_MEDIUM_BROWN` from the set `XKCD_COLORS`. (Colour number `655`, name `xkcd:medium brown`)
 /// ## Representations:
 /// - int tuple `(127, 81, 18)`
 /// - float tuple `(0.5, 0.32, 0.07)`
@@ -5943,7 +6101,7 @@ pub const XKCD_MEDIUM_BROWN: Color = Color(127, 81, 18);
 
 /// Colour `(r = 95, g = 160, b = 82)`
 ///
-/// Colour `XKCD_MUTED_GREEN` from the set `XKCD_COLORS`. (Colour number `656`)
+/// Colour `XKCD_MUTED_GREEN` from the set `XKCD_COLORS`. (Colour number `656`, name `xkcd:muted green`)
 /// ## Representations:
 /// - int tuple `(95, 160, 82)`
 /// - float tuple `(0.37, 0.63, 0.32)`
@@ -5952,7 +6110,7 @@ pub const XKCD_MUTED_GREEN: Color = Color(95, 160, 82);
 
 /// Colour `(r = 109, g = 237, b = 253)`
 ///
-/// Colour `XKCD_ROBIN_S_EGG` from the set `XKCD_COLORS`. (Colour number `657`)
+/// Colour `XKCD_ROBIN_S_EGG` from the set `XKCD_COLORS`. (Colour number `657`, name `xkcd:robin's egg`)
 /// ## Representations:
 /// - int tuple `(109, 237, 253)`
 /// - float tuple `(0.43, 0.93, 0.99)`
@@ -5961,7 +6119,7 @@ pub const XKCD_ROBIN_S_EGG: Color = Color(109, 237, 253);
 
 /// Colour `(r = 11, g = 249, b = 234)`
 ///
-/// Colour `XKCD_BRIGHT_AQUA` from the set `XKCD_COLORS`. (Colour number `658`)
+/// Colour `XKCD_BRIGHT_AQUA` from the set `XKCD_COLORS`. (Colour number `658`, name `xkcd:bright aqua`)
 /// ## Representations:
 /// - int tuple `(11, 249, 234)`
 /// - float tuple `(0.04, 0.98, 0.92)`
@@ -5970,7 +6128,7 @@ pub const XKCD_BRIGHT_AQUA: Color = Color(11, 249, 234);
 
 /// Colour `(r = 199, g = 96, b = 255)`
 ///
-/// Colour `XKCD_BRIGHT_LAVENDER` from the set `XKCD_COLORS`. (Colour number `659`)
+/// Colour `XKCD_BRIGHT_LAVENDER` from the set `XKCD_COLORS`. (Colour number `659`, name `xkcd:bright lavender`)
 /// ## Representations:
 /// - int tuple `(199, 96, 255)`
 /// - float tuple `(0.78, 0.38, 1.0)`
@@ -5979,7 +6137,7 @@ pub const XKCD_BRIGHT_LAVENDER: Color = Color(199, 96, 255);
 
 /// Colour `(r = 255, g = 255, b = 203)`
 ///
-/// Colour `XKCD_IVORY` from the set `XKCD_COLORS`. (Colour number `660`)
+/// Colour `XKCD_IVORY` from the set `XKCD_COLORS`. (Colour number `660`, name `xkcd:ivory`)
 /// ## Representations:
 /// - int tuple `(255, 255, 203)`
 /// - float tuple `(1.0, 1.0, 0.8)`
@@ -5988,7 +6146,7 @@ pub const XKCD_IVORY: Color = Color(255, 255, 203);
 
 /// Colour `(r = 246, g = 206, b = 252)`
 ///
-/// Colour `XKCD_VERY_LIGHT_PURPLE` from the set `XKCD_COLORS`. (Colour number `661`)
+/// Colour `XKCD_VERY_LIGHT_PURPLE` from the set `XKCD_COLORS`. (Colour number `661`, name `xkcd:very light purple`)
 /// ## Representations:
 /// - int tuple `(246, 206, 252)`
 /// - float tuple `(0.96, 0.81, 0.99)`
@@ -5997,7 +6155,7 @@ pub const XKCD_VERY_LIGHT_PURPLE: Color = Color(246, 206, 252);
 
 /// Colour `(r = 21, g = 80, b = 132)`
 ///
-/// Colour `XKCD_LIGHT_NAVY` from the set `XKCD_COLORS`. (Colour number `662`)
+/// Colour `XKCD_LIGHT_NAVY` from the set `XKCD_COLORS`. (Colour number `662`, name `xkcd:light navy`)
 /// ## Representations:
 /// - int tuple `(21, 80, 132)`
 /// - float tuple `(0.08, 0.31, 0.52)`
@@ -6006,7 +6164,7 @@ pub const XKCD_LIGHT_NAVY: Color = Color(21, 80, 132);
 
 /// Colour `(r = 245, g = 5, b = 79)`
 ///
-/// Colour `XKCD_PINK_RED` from the set `XKCD_COLORS`. (Colour number `663`)
+/// Colour `XKCD_PINK_RED` from the set `XKCD_COLORS`. (Colour number `663`, name `xkcd:pink red`)
 /// ## Representations:
 /// - int tuple `(245, 5, 79)`
 /// - float tuple `(0.96, 0.02, 0.31)`
@@ -6015,7 +6173,7 @@ pub const XKCD_PINK_RED: Color = Color(245, 5, 79);
 
 /// Colour `(r = 100, g = 84, b = 3)`
 ///
-/// Colour `XKCD_OLIVE_BROWN` from the set `XKCD_COLORS`. (Colour number `664`)
+/// Colour `XKCD_OLIVE_BROWN` from the set `XKCD_COLORS`. (Colour number `664`, name `xkcd:olive brown`)
 /// ## Representations:
 /// - int tuple `(100, 84, 3)`
 /// - float tuple `(0.39, 0.33, 0.01)`
@@ -6024,7 +6182,7 @@ pub const XKCD_OLIVE_BROWN: Color = Color(100, 84, 3);
 
 /// Colour `(r = 122, g = 89, b = 1)`
 ///
-/// Colour `XKCD_POOP_BROWN` from the set `XKCD_COLORS`. (Colour number `665`)
+/// Colour `XKCD_POOP_BROWN` from the set `XKCD_COLORS`. (Colour number `665`, name `xkcd:poop brown`)
 /// ## Representations:
 /// - int tuple `(122, 89, 1)`
 /// - float tuple `(0.48, 0.35, 0.0)`
@@ -6033,7 +6191,7 @@ pub const XKCD_POOP_BROWN: Color = Color(122, 89, 1);
 
 /// Colour `(r = 168, g = 181, b = 4)`
 ///
-/// Colour `XKCD_MUSTARD_GREEN` from the set `XKCD_COLORS`. (Colour number `666`)
+/// Colour `XKCD_MUSTARD_GREEN` from the set `XKCD_COLORS`. (Colour number `666`, name `xkcd:mustard green`)
 /// ## Representations:
 /// - int tuple `(168, 181, 4)`
 /// - float tuple `(0.66, 0.71, 0.02)`
@@ -6042,7 +6200,7 @@ pub const XKCD_MUSTARD_GREEN: Color = Color(168, 181, 4);
 
 /// Colour `(r = 61, g = 153, b = 115)`
 ///
-/// Colour `XKCD_OCEAN_GREEN` from the set `XKCD_COLORS`. (Colour number `667`)
+/// Colour `XKCD_OCEAN_GREEN` from the set `XKCD_COLORS`. (Colour number `667`, name `xkcd:ocean green`)
 /// ## Representations:
 /// - int tuple `(61, 153, 115)`
 /// - float tuple `(0.24, 0.6, 0.45)`
@@ -6051,7 +6209,7 @@ pub const XKCD_OCEAN_GREEN: Color = Color(61, 153, 115);
 
 /// Colour `(r = 0, g = 1, b = 51)`
 ///
-/// Colour `XKCD_VERY_DARK_BLUE` from the set `XKCD_COLORS`. (Colour number `668`)
+/// Colour `XKCD_VERY_DARK_BLUE` from the set `XKCD_COLORS`. (Colour number `668`, name `xkcd:very dark blue`)
 /// ## Representations:
 /// - int tuple `(0, 1, 51)`
 /// - float tuple `(0.0, 0.0, 0.2)`
@@ -6060,7 +6218,7 @@ pub const XKCD_VERY_DARK_BLUE: Color = Color(0, 1, 51);
 
 /// Colour `(r = 118, g = 169, b = 115)`
 ///
-/// Colour `XKCD_DUSTY_GREEN` from the set `XKCD_COLORS`. (Colour number `669`)
+/// Colour `XKCD_DUSTY_GREEN` from the set `XKCD_COLORS`. (Colour number `669`, name `xkcd:dusty green`)
 /// ## Representations:
 /// - int tuple `(118, 169, 115)`
 /// - float tuple `(0.46, 0.66, 0.45)`
@@ -6069,7 +6227,7 @@ pub const XKCD_DUSTY_GREEN: Color = Color(118, 169, 115);
 
 /// Colour `(r = 46, g = 90, b = 136)`
 ///
-/// Colour `XKCD_LIGHT_NAVY_BLUE` from the set `XKCD_COLORS`. (Colour number `670`)
+/// Colour `XKCD_LIGHT_NAVY_BLUE` from the set `XKCD_COLORS`. (Colour number `670`, name `xkcd:light navy blue`)
 /// ## Representations:
 /// - int tuple `(46, 90, 136)`
 /// - float tuple `(0.18, 0.35, 0.53)`
@@ -6078,7 +6236,7 @@ pub const XKCD_LIGHT_NAVY_BLUE: Color = Color(46, 90, 136);
 
 /// Colour `(r = 11, g = 247, b = 125)`
 ///
-/// Colour `XKCD_MINTY_GREEN` from the set `XKCD_COLORS`. (Colour number `671`)
+/// Colour `XKCD_MINTY_GREEN` from the set `XKCD_COLORS`. (Colour number `671`, name `xkcd:minty green`)
 /// ## Representations:
 /// - int tuple `(11, 247, 125)`
 /// - float tuple `(0.04, 0.97, 0.49)`
@@ -6087,7 +6245,7 @@ pub const XKCD_MINTY_GREEN: Color = Color(11, 247, 125);
 
 /// Colour `(r = 189, g = 108, b = 72)`
 ///
-/// Colour `XKCD_ADOBE` from the set `XKCD_COLORS`. (Colour number `672`)
+/// Colour `XKCD_ADOBE` from the set `XKCD_COLORS`. (Colour number `672`, name `xkcd:adobe`)
 /// ## Representations:
 /// - int tuple `(189, 108, 72)`
 /// - float tuple `(0.74, 0.42, 0.28)`
@@ -6096,7 +6254,7 @@ pub const XKCD_ADOBE: Color = Color(189, 108, 72);
 
 /// Colour `(r = 172, g = 29, b = 184)`
 ///
-/// Colour `XKCD_BARNEY` from the set `XKCD_COLORS`. (Colour number `673`)
+/// Colour `XKCD_BARNEY` from the set `XKCD_COLORS`. (Colour number `673`, name `xkcd:barney`)
 /// ## Representations:
 /// - int tuple `(172, 29, 184)`
 /// - float tuple `(0.67, 0.11, 0.72)`
@@ -6105,7 +6263,7 @@ pub const XKCD_BARNEY: Color = Color(172, 29, 184);
 
 /// Colour `(r = 43, g = 175, b = 106)`
 ///
-/// Colour `XKCD_JADE_GREEN` from the set `XKCD_COLORS`. (Colour number `674`)
+/// Colour `XKCD_JADE_GREEN` from the set `XKCD_COLORS`. (Colour number `674`, name `xkcd:jade green`)
 /// ## Representations:
 /// - int tuple `(43, 175, 106)`
 /// - float tuple `(0.17, 0.69, 0.42)`
@@ -6114,7 +6272,7 @@ pub const XKCD_JADE_GREEN: Color = Color(43, 175, 106);
 
 /// Colour `(r = 38, g = 247, b = 253)`
 ///
-/// Colour `XKCD_BRIGHT_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `675`)
+/// Colour `XKCD_BRIGHT_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `675`, name `xkcd:bright light blue`)
 /// ## Representations:
 /// - int tuple `(38, 247, 253)`
 /// - float tuple `(0.15, 0.97, 0.99)`
@@ -6123,7 +6281,7 @@ pub const XKCD_BRIGHT_LIGHT_BLUE: Color = Color(38, 247, 253);
 
 /// Colour `(r = 174, g = 253, b = 108)`
 ///
-/// Colour `XKCD_LIGHT_LIME` from the set `XKCD_COLORS`. (Colour number `676`)
+/// Colour `XKCD_LIGHT_LIME` from the set `XKCD_COLORS`. (Colour number `676`, name `xkcd:light lime`)
 /// ## Representations:
 /// - int tuple `(174, 253, 108)`
 /// - float tuple `(0.68, 0.99, 0.42)`
@@ -6132,7 +6290,7 @@ pub const XKCD_LIGHT_LIME: Color = Color(174, 253, 108);
 
 /// Colour `(r = 155, g = 143, b = 85)`
 ///
-/// Colour `XKCD_DARK_KHAKI` from the set `XKCD_COLORS`. (Colour number `677`)
+/// Colour `XKCD_DARK_KHAKI` from the set `XKCD_COLORS`. (Colour number `677`, name `xkcd:dark khaki`)
 /// ## Representations:
 /// - int tuple `(155, 143, 85)`
 /// - float tuple `(0.61, 0.56, 0.33)`
@@ -6141,7 +6299,7 @@ pub const XKCD_DARK_KHAKI: Color = Color(155, 143, 85);
 
 /// Colour `(r = 255, g = 173, b = 1)`
 ///
-/// Colour `XKCD_ORANGE_YELLOW` from the set `XKCD_COLORS`. (Colour number `678`)
+/// Colour `XKCD_ORANGE_YELLOW` from the set `XKCD_COLORS`. (Colour number `678`, name `xkcd:orange yellow`)
 /// ## Representations:
 /// - int tuple `(255, 173, 1)`
 /// - float tuple `(1.0, 0.68, 0.0)`
@@ -6150,7 +6308,7 @@ pub const XKCD_ORANGE_YELLOW: Color = Color(255, 173, 1);
 
 /// Colour `(r = 198, g = 156, b = 4)`
 ///
-/// Colour `XKCD_OCRE` from the set `XKCD_COLORS`. (Colour number `679`)
+/// Colour `XKCD_OCRE` from the set `XKCD_COLORS`. (Colour number `679`, name `xkcd:ocre`)
 /// ## Representations:
 /// - int tuple `(198, 156, 4)`
 /// - float tuple `(0.78, 0.61, 0.02)`
@@ -6159,7 +6317,7 @@ pub const XKCD_OCRE: Color = Color(198, 156, 4);
 
 /// Colour `(r = 244, g = 208, b = 84)`
 ///
-/// Colour `XKCD_MAIZE` from the set `XKCD_COLORS`. (Colour number `680`)
+/// Colour `XKCD_MAIZE` from the set `XKCD_COLORS`. (Colour number `680`, name `xkcd:maize`)
 /// ## Representations:
 /// - int tuple `(244, 208, 84)`
 /// - float tuple `(0.96, 0.82, 0.33)`
@@ -6168,7 +6326,7 @@ pub const XKCD_MAIZE: Color = Color(244, 208, 84);
 
 /// Colour `(r = 222, g = 157, b = 172)`
 ///
-/// Colour `XKCD_FADED_PINK` from the set `XKCD_COLORS`. (Colour number `681`)
+/// Colour `XKCD_FADED_PINK` from the set `XKCD_COLORS`. (Colour number `681`, name `xkcd:faded pink`)
 /// ## Representations:
 /// - int tuple `(222, 157, 172)`
 /// - float tuple `(0.87, 0.62, 0.67)`
@@ -6177,7 +6335,7 @@ pub const XKCD_FADED_PINK: Color = Color(222, 157, 172);
 
 /// Colour `(r = 5, g = 72, b = 13)`
 ///
-/// Colour `XKCD_BRITISH_RACING_GREEN` from the set `XKCD_COLORS`. (Colour number `682`)
+/// Colour `XKCD_BRITISH_RACING_GREEN` from the set `XKCD_COLORS`. (Colour number `682`, name `xkcd:british racing green`)
 /// ## Representations:
 /// - int tuple `(5, 72, 13)`
 /// - float tuple `(0.02, 0.28, 0.05)`
@@ -6186,7 +6344,7 @@ pub const XKCD_BRITISH_RACING_GREEN: Color = Color(5, 72, 13);
 
 /// Colour `(r = 201, g = 174, b = 116)`
 ///
-/// Colour `XKCD_SANDSTONE` from the set `XKCD_COLORS`. (Colour number `683`)
+/// Colour `XKCD_SANDSTONE` from the set `XKCD_COLORS`. (Colour number `683`, name `xkcd:sandstone`)
 /// ## Representations:
 /// - int tuple `(201, 174, 116)`
 /// - float tuple `(0.79, 0.68, 0.45)`
@@ -6195,7 +6353,7 @@ pub const XKCD_SANDSTONE: Color = Color(201, 174, 116);
 
 /// Colour `(r = 96, g = 70, b = 15)`
 ///
-/// Colour `XKCD_MUD_BROWN` from the set `XKCD_COLORS`. (Colour number `684`)
+/// Colour `XKCD_MUD_BROWN` from the set `XKCD_COLORS`. (Colour number `684`, name `xkcd:mud brown`)
 /// ## Representations:
 /// - int tuple `(96, 70, 15)`
 /// - float tuple `(0.38, 0.27, 0.06)`
@@ -6204,7 +6362,7 @@ pub const XKCD_MUD_BROWN: Color = Color(96, 70, 15);
 
 /// Colour `(r = 152, g = 246, b = 176)`
 ///
-/// Colour `XKCD_LIGHT_SEA_GREEN` from the set `XKCD_COLORS`. (Colour number `685`)
+/// Colour `XKCD_LIGHT_SEA_GREEN` from the set `XKCD_COLORS`. (Colour number `685`, name `xkcd:light sea green`)
 /// ## Representations:
 /// - int tuple `(152, 246, 176)`
 /// - float tuple `(0.6, 0.96, 0.69)`
@@ -6213,7 +6371,7 @@ pub const XKCD_LIGHT_SEA_GREEN: Color = Color(152, 246, 176);
 
 /// Colour `(r = 138, g = 241, b = 254)`
 ///
-/// Colour `XKCD_ROBIN_EGG_BLUE` from the set `XKCD_COLORS`. (Colour number `686`)
+/// Colour `XKCD_ROBIN_EGG_BLUE` from the set `XKCD_COLORS`. (Colour number `686`, name `xkcd:robin egg blue`)
 /// ## Representations:
 /// - int tuple `(138, 241, 254)`
 /// - float tuple `(0.54, 0.95, 1.0)`
@@ -6222,7 +6380,7 @@ pub const XKCD_ROBIN_EGG_BLUE: Color = Color(138, 241, 254);
 
 /// Colour `(r = 46, g = 232, b = 187)`
 ///
-/// Colour `XKCD_AQUA_MARINE` from the set `XKCD_COLORS`. (Colour number `687`)
+/// Colour `XKCD_AQUA_MARINE` from the set `XKCD_COLORS`. (Colour number `687`, name `xkcd:aqua marine`)
 /// ## Representations:
 /// - int tuple `(46, 232, 187)`
 /// - float tuple `(0.18, 0.91, 0.73)`
@@ -6231,7 +6389,7 @@ pub const XKCD_AQUA_MARINE: Color = Color(46, 232, 187);
 
 /// Colour `(r = 17, g = 135, b = 93)`
 ///
-/// Colour `XKCD_DARK_SEA_GREEN` from the set `XKCD_COLORS`. (Colour number `688`)
+/// Colour `XKCD_DARK_SEA_GREEN` from the set `XKCD_COLORS`. (Colour number `688`, name `xkcd:dark sea green`)
 /// ## Representations:
 /// - int tuple `(17, 135, 93)`
 /// - float tuple `(0.07, 0.53, 0.36)`
@@ -6240,7 +6398,7 @@ pub const XKCD_DARK_SEA_GREEN: Color = Color(17, 135, 93);
 
 /// Colour `(r = 253, g = 176, b = 192)`
 ///
-/// Colour `XKCD_SOFT_PINK` from the set `XKCD_COLORS`. (Colour number `689`)
+/// Colour `XKCD_SOFT_PINK` from the set `XKCD_COLORS`. (Colour number `689`, name `xkcd:soft pink`)
 /// ## Representations:
 /// - int tuple `(253, 176, 192)`
 /// - float tuple `(0.99, 0.69, 0.75)`
@@ -6249,7 +6407,7 @@ pub const XKCD_SOFT_PINK: Color = Color(253, 176, 192);
 
 /// Colour `(r = 177, g = 96, b = 2)`
 ///
-/// Colour `XKCD_ORANGEY_BROWN` from the set `XKCD_COLORS`. (Colour number `690`)
+/// Colour `XKCD_ORANGEY_BROWN` from the set `XKCD_COLORS`. (Colour number `690`, name `xkcd:orangey brown`)
 /// ## Representations:
 /// - int tuple `(177, 96, 2)`
 /// - float tuple `(0.69, 0.38, 0.01)`
@@ -6258,7 +6416,7 @@ pub const XKCD_ORANGEY_BROWN: Color = Color(177, 96, 2);
 
 /// Colour `(r = 247, g = 2, b = 42)`
 ///
-/// Colour `XKCD_CHERRY_RED` from the set `XKCD_COLORS`. (Colour number `691`)
+/// Colour `XKCD_CHERRY_RED` from the set `XKCD_COLORS`. (Colour number `691`, name `xkcd:cherry red`)
 /// ## Representations:
 /// - int tuple `(247, 2, 42)`
 /// - float tuple `(0.97, 0.01, 0.16)`
@@ -6267,7 +6425,7 @@ pub const XKCD_CHERRY_RED: Color = Color(247, 2, 42);
 
 /// Colour `(r = 213, g = 171, b = 9)`
 ///
-/// Colour `XKCD_BURNT_YELLOW` from the set `XKCD_COLORS`. (Colour number `692`)
+/// Colour `XKCD_BURNT_YELLOW` from the set `XKCD_COLORS`. (Colour number `692`, name `xkcd:burnt yellow`)
 /// ## Representations:
 /// - int tuple `(213, 171, 9)`
 /// - float tuple `(0.84, 0.67, 0.04)`
@@ -6276,7 +6434,7 @@ pub const XKCD_BURNT_YELLOW: Color = Color(213, 171, 9);
 
 /// Colour `(r = 134, g = 119, b = 95)`
 ///
-/// Colour `XKCD_BROWNISH_GREY` from the set `XKCD_COLORS`. (Colour number `693`)
+/// Colour `XKCD_BROWNISH_GREY` from the set `XKCD_COLORS`. (Colour number `693`, name `xkcd:brownish grey`)
 /// ## Representations:
 /// - int tuple `(134, 119, 95)`
 /// - float tuple `(0.53, 0.47, 0.37)`
@@ -6285,7 +6443,7 @@ pub const XKCD_BROWNISH_GREY: Color = Color(134, 119, 95);
 
 /// Colour `(r = 198, g = 159, b = 89)`
 ///
-/// Colour `XKCD_CAMEL` from the set `XKCD_COLORS`. (Colour number `694`)
+/// Colour `XKCD_CAMEL` from the set `XKCD_COLORS`. (Colour number `694`, name `xkcd:camel`)
 /// ## Representations:
 /// - int tuple `(198, 159, 89)`
 /// - float tuple `(0.78, 0.62, 0.35)`
@@ -6294,7 +6452,7 @@ pub const XKCD_CAMEL: Color = Color(198, 159, 89);
 
 /// Colour `(r = 122, g = 104, b = 127)`
 ///
-/// Colour `XKCD_PURPLISH_GREY` from the set `XKCD_COLORS`. (Colour number `695`)
+/// Colour `XKCD_PURPLISH_GREY` from the set `XKCD_COLORS`. (Colour number `695`, name `xkcd:purplish grey`)
 /// ## Representations:
 /// - int tuple `(122, 104, 127)`
 /// - float tuple `(0.48, 0.41, 0.5)`
@@ -6303,7 +6461,7 @@ pub const XKCD_PURPLISH_GREY: Color = Color(122, 104, 127);
 
 /// Colour `(r = 4, g = 46, b = 96)`
 ///
-/// Colour `XKCD_MARINE` from the set `XKCD_COLORS`. (Colour number `696`)
+/// Colour `XKCD_MARINE` from the set `XKCD_COLORS`. (Colour number `696`, name `xkcd:marine`)
 /// ## Representations:
 /// - int tuple `(4, 46, 96)`
 /// - float tuple `(0.02, 0.18, 0.38)`
@@ -6312,7 +6470,7 @@ pub const XKCD_MARINE: Color = Color(4, 46, 96);
 
 /// Colour `(r = 200, g = 141, b = 148)`
 ///
-/// Colour `XKCD_GREYISH_PINK` from the set `XKCD_COLORS`. (Colour number `697`)
+/// Colour `XKCD_GREYISH_PINK` from the set `XKCD_COLORS`. (Colour number `697`, name `xkcd:greyish pink`)
 /// ## Representations:
 /// - int tuple `(200, 141, 148)`
 /// - float tuple `(0.78, 0.55, 0.58)`
@@ -6321,7 +6479,7 @@ pub const XKCD_GREYISH_PINK: Color = Color(200, 141, 148);
 
 /// Colour `(r = 165, g = 251, b = 213)`
 ///
-/// Colour `XKCD_PALE_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `698`)
+/// Colour `XKCD_PALE_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `698`, name `xkcd:pale turquoise`)
 /// ## Representations:
 /// - int tuple `(165, 251, 213)`
 /// - float tuple `(0.65, 0.98, 0.84)`
@@ -6330,7 +6488,7 @@ pub const XKCD_PALE_TURQUOISE: Color = Color(165, 251, 213);
 
 /// Colour `(r = 255, g = 254, b = 113)`
 ///
-/// Colour `XKCD_PASTEL_YELLOW` from the set `XKCD_COLORS`. (Colour number `699`)
+/// Colour `XKCD_PASTEL_YELLOW` from the set `XKCD_COLORS`. (Colour number `699`, name `xkcd:pastel yellow`)
 /// ## Representations:
 /// - int tuple `(255, 254, 113)`
 /// - float tuple `(1.0, 1.0, 0.44)`
@@ -6339,7 +6497,7 @@ pub const XKCD_PASTEL_YELLOW: Color = Color(255, 254, 113);
 
 /// Colour `(r = 98, g = 65, b = 199)`
 ///
-/// Colour `XKCD_BLUEY_PURPLE` from the set `XKCD_COLORS`. (Colour number `700`)
+/// Colour `XKCD_BLUEY_PURPLE` from the set `XKCD_COLORS`. (Colour number `700`, name `xkcd:bluey purple`)
 /// ## Representations:
 /// - int tuple `(98, 65, 199)`
 /// - float tuple `(0.38, 0.25, 0.78)`
@@ -6348,7 +6506,7 @@ pub const XKCD_BLUEY_PURPLE: Color = Color(98, 65, 199);
 
 /// Colour `(r = 255, g = 254, b = 64)`
 ///
-/// Colour `XKCD_CANARY_YELLOW` from the set `XKCD_COLORS`. (Colour number `701`)
+/// Colour `XKCD_CANARY_YELLOW` from the set `XKCD_COLORS`. (Colour number `701`, name `xkcd:canary yellow`)
 /// ## Representations:
 /// - int tuple `(255, 254, 64)`
 /// - float tuple `(1.0, 1.0, 0.25)`
@@ -6357,7 +6515,7 @@ pub const XKCD_CANARY_YELLOW: Color = Color(255, 254, 64);
 
 /// Colour `(r = 211, g = 73, b = 78)`
 ///
-/// Colour `XKCD_FADED_RED` from the set `XKCD_COLORS`. (Colour number `702`)
+/// Colour `XKCD_FADED_RED` from the set `XKCD_COLORS`. (Colour number `702`, name `xkcd:faded red`)
 /// ## Representations:
 /// - int tuple `(211, 73, 78)`
 /// - float tuple `(0.83, 0.29, 0.31)`
@@ -6366,7 +6524,7 @@ pub const XKCD_FADED_RED: Color = Color(211, 73, 78);
 
 /// Colour `(r = 152, g = 94, b = 43)`
 ///
-/// Colour `XKCD_SEPIA` from the set `XKCD_COLORS`. (Colour number `703`)
+/// Colour `XKCD_SEPIA` from the set `XKCD_COLORS`. (Colour number `703`, name `xkcd:sepia`)
 /// ## Representations:
 /// - int tuple `(152, 94, 43)`
 /// - float tuple `(0.6, 0.37, 0.17)`
@@ -6375,7 +6533,7 @@ pub const XKCD_SEPIA: Color = Color(152, 94, 43);
 
 /// Colour `(r = 166, g = 129, b = 76)`
 ///
-/// Colour `XKCD_COFFEE` from the set `XKCD_COLORS`. (Colour number `704`)
+/// Colour `XKCD_COFFEE` from the set `XKCD_COLORS`. (Colour number `704`, name `xkcd:coffee`)
 /// ## Representations:
 /// - int tuple `(166, 129, 76)`
 /// - float tuple `(0.65, 0.51, 0.3)`
@@ -6384,7 +6542,7 @@ pub const XKCD_COFFEE: Color = Color(166, 129, 76);
 
 /// Colour `(r = 255, g = 8, b = 232)`
 ///
-/// Colour `XKCD_BRIGHT_MAGENTA` from the set `XKCD_COLORS`. (Colour number `705`)
+/// Colour `XKCD_BRIGHT_MAGENTA` from the set `XKCD_COLORS`. (Colour number `705`, name `xkcd:bright magenta`)
 /// ## Representations:
 /// - int tuple `(255, 8, 232)`
 /// - float tuple `(1.0, 0.03, 0.91)`
@@ -6393,7 +6551,7 @@ pub const XKCD_BRIGHT_MAGENTA: Color = Color(255, 8, 232);
 
 /// Colour `(r = 157, g = 118, b = 81)`
 ///
-/// Colour `XKCD_MOCHA` from the set `XKCD_COLORS`. (Colour number `706`)
+/// Colour `XKCD_MOCHA` from the set `XKCD_COLORS`. (Colour number `706`, name `xkcd:mocha`)
 /// ## Representations:
 /// - int tuple `(157, 118, 81)`
 /// - float tuple `(0.62, 0.46, 0.32)`
@@ -6402,7 +6560,7 @@ pub const XKCD_MOCHA: Color = Color(157, 118, 81);
 
 /// Colour `(r = 254, g = 255, b = 202)`
 ///
-/// Colour `XKCD_ECRU` from the set `XKCD_COLORS`. (Colour number `707`)
+/// Colour `XKCD_ECRU` from the set `XKCD_COLORS`. (Colour number `707`, name `xkcd:ecru`)
 /// ## Representations:
 /// - int tuple `(254, 255, 202)`
 /// - float tuple `(1.0, 1.0, 0.79)`
@@ -6411,7 +6569,7 @@ pub const XKCD_ECRU: Color = Color(254, 255, 202);
 
 /// Colour `(r = 152, g = 86, b = 141)`
 ///
-/// Colour `XKCD_PURPLEISH` from the set `XKCD_COLORS`. (Colour number `708`)
+/// Colour `XKCD_PURPLEISH` from the set `XKCD_COLORS`. (Colour number `708`, name `xkcd:purpleish`)
 /// ## Representations:
 /// - int tuple `(152, 86, 141)`
 /// - float tuple `(0.6, 0.34, 0.55)`
@@ -6420,7 +6578,7 @@ pub const XKCD_PURPLEISH: Color = Color(152, 86, 141);
 
 /// Colour `(r = 158, g = 0, b = 58)`
 ///
-/// Colour `XKCD_CRANBERRY` from the set `XKCD_COLORS`. (Colour number `709`)
+/// Colour `XKCD_CRANBERRY` from the set `XKCD_COLORS`. (Colour number `709`, name `xkcd:cranberry`)
 /// ## Representations:
 /// - int tuple `(158, 0, 58)`
 /// - float tuple `(0.62, 0.0, 0.23)`
@@ -6429,7 +6587,7 @@ pub const XKCD_CRANBERRY: Color = Color(158, 0, 58);
 
 /// Colour `(r = 40, g = 124, b = 55)`
 ///
-/// Colour `XKCD_DARKISH_GREEN` from the set `XKCD_COLORS`. (Colour number `710`)
+/// Colour `XKCD_DARKISH_GREEN` from the set `XKCD_COLORS`. (Colour number `710`, name `xkcd:darkish green`)
 /// ## Representations:
 /// - int tuple `(40, 124, 55)`
 /// - float tuple `(0.16, 0.49, 0.22)`
@@ -6438,7 +6596,7 @@ pub const XKCD_DARKISH_GREEN: Color = Color(40, 124, 55);
 
 /// Colour `(r = 185, g = 105, b = 2)`
 ///
-/// Colour `XKCD_BROWN_ORANGE` from the set `XKCD_COLORS`. (Colour number `711`)
+/// Colour `XKCD_BROWN_ORANGE` from the set `XKCD_COLORS`. (Colour number `711`, name `xkcd:brown orange`)
 /// ## Representations:
 /// - int tuple `(185, 105, 2)`
 /// - float tuple `(0.73, 0.41, 0.01)`
@@ -6447,7 +6605,7 @@ pub const XKCD_BROWN_ORANGE: Color = Color(185, 105, 2);
 
 /// Colour `(r = 186, g = 104, b = 115)`
 ///
-/// Colour `XKCD_DUSKY_ROSE` from the set `XKCD_COLORS`. (Colour number `712`)
+/// Colour `XKCD_DUSKY_ROSE` from the set `XKCD_COLORS`. (Colour number `712`, name `xkcd:dusky rose`)
 /// ## Representations:
 /// - int tuple `(186, 104, 115)`
 /// - float tuple `(0.73, 0.41, 0.45)`
@@ -6456,7 +6614,7 @@ pub const XKCD_DUSKY_ROSE: Color = Color(186, 104, 115);
 
 /// Colour `(r = 255, g = 120, b = 85)`
 ///
-/// Colour `XKCD_MELON` from the set `XKCD_COLORS`. (Colour number `713`)
+/// Colour `XKCD_MELON` from the set `XKCD_COLORS`. (Colour number `713`, name `xkcd:melon`)
 /// ## Representations:
 /// - int tuple `(255, 120, 85)`
 /// - float tuple `(1.0, 0.47, 0.33)`
@@ -6465,7 +6623,7 @@ pub const XKCD_MELON: Color = Color(255, 120, 85);
 
 /// Colour `(r = 148, g = 178, b = 28)`
 ///
-/// Colour `XKCD_SICKLY_GREEN` from the set `XKCD_COLORS`. (Colour number `714`)
+/// Colour `XKCD_SICKLY_GREEN` from the set `XKCD_COLORS`. (Colour number `714`, name `xkcd:sickly green`)
 /// ## Representations:
 /// - int tuple `(148, 178, 28)`
 /// - float tuple `(0.58, 0.7, 0.11)`
@@ -6474,7 +6632,7 @@ pub const XKCD_SICKLY_GREEN: Color = Color(148, 178, 28);
 
 /// Colour `(r = 197, g = 201, b = 199)`
 ///
-/// Colour `XKCD_SILVER` from the set `XKCD_COLORS`. (Colour number `715`)
+/// Colour `XKCD_SILVER` from the set `XKCD_COLORS`. (Colour number `715`, name `xkcd:silver`)
 /// ## Representations:
 /// - int tuple `(197, 201, 199)`
 /// - float tuple `(0.77, 0.79, 0.78)`
@@ -6483,7 +6641,7 @@ pub const XKCD_SILVER: Color = Color(197, 201, 199);
 
 /// Colour `(r = 102, g = 26, b = 238)`
 ///
-/// Colour `XKCD_PURPLY_BLUE` from the set `XKCD_COLORS`. (Colour number `716`)
+/// Colour `XKCD_PURPLY_BLUE` from the set `XKCD_COLORS`. (Colour number `716`, name `xkcd:purply blue`)
 /// ## Representations:
 /// - int tuple `(102, 26, 238)`
 /// - float tuple `(0.4, 0.1, 0.93)`
@@ -6492,7 +6650,7 @@ pub const XKCD_PURPLY_BLUE: Color = Color(102, 26, 238);
 
 /// Colour `(r = 97, g = 64, b = 239)`
 ///
-/// Colour `XKCD_PURPLEISH_BLUE` from the set `XKCD_COLORS`. (Colour number `717`)
+/// Colour `XKCD_PURPLEISH_BLUE` from the set `XKCD_COLORS`. (Colour number `717`, name `xkcd:purpleish blue`)
 /// ## Representations:
 /// - int tuple `(97, 64, 239)`
 /// - float tuple `(0.38, 0.25, 0.94)`
@@ -6501,7 +6659,7 @@ pub const XKCD_PURPLEISH_BLUE: Color = Color(97, 64, 239);
 
 /// Colour `(r = 155, g = 229, b = 170)`
 ///
-/// Colour `XKCD_HOSPITAL_GREEN` from the set `XKCD_COLORS`. (Colour number `718`)
+/// Colour `XKCD_HOSPITAL_GREEN` from the set `XKCD_COLORS`. (Colour number `718`, name `xkcd:hospital green`)
 /// ## Representations:
 /// - int tuple `(155, 229, 170)`
 /// - float tuple `(0.61, 0.9, 0.67)`
@@ -6510,7 +6668,7 @@ pub const XKCD_HOSPITAL_GREEN: Color = Color(155, 229, 170);
 
 /// Colour `(r = 123, g = 88, b = 4)`
 ///
-/// Colour `XKCD_SHIT_BROWN` from the set `XKCD_COLORS`. (Colour number `719`)
+/// Colour `XKCD_SHIT_BROWN` from the set `XKCD_COLORS`. (Colour number `719`, name `xkcd:shit brown`)
 /// ## Representations:
 /// - int tuple `(123, 88, 4)`
 /// - float tuple `(0.48, 0.35, 0.02)`
@@ -6519,7 +6677,7 @@ pub const XKCD_SHIT_BROWN: Color = Color(123, 88, 4);
 
 /// Colour `(r = 39, g = 106, b = 179)`
 ///
-/// Colour `XKCD_MID_BLUE` from the set `XKCD_COLORS`. (Colour number `720`)
+/// Colour `XKCD_MID_BLUE` from the set `XKCD_COLORS`. (Colour number `720`, name `xkcd:mid blue`)
 /// ## Representations:
 /// - int tuple `(39, 106, 179)`
 /// - float tuple `(0.15, 0.42, 0.7)`
@@ -6528,7 +6686,7 @@ pub const XKCD_MID_BLUE: Color = Color(39, 106, 179);
 
 /// Colour `(r = 254, g = 179, b = 8)`
 ///
-/// Colour `XKCD_AMBER` from the set `XKCD_COLORS`. (Colour number `721`)
+/// Colour `XKCD_AMBER` from the set `XKCD_COLORS`. (Colour number `721`, name `xkcd:amber`)
 /// ## Representations:
 /// - int tuple `(254, 179, 8)`
 /// - float tuple `(1.0, 0.7, 0.03)`
@@ -6537,7 +6695,7 @@ pub const XKCD_AMBER: Color = Color(254, 179, 8);
 
 /// Colour `(r = 140, g = 253, b = 126)`
 ///
-/// Colour `XKCD_EASTER_GREEN` from the set `XKCD_COLORS`. (Colour number `722`)
+/// Colour `XKCD_EASTER_GREEN` from the set `XKCD_COLORS`. (Colour number `722`, name `xkcd:easter green`)
 /// ## Representations:
 /// - int tuple `(140, 253, 126)`
 /// - float tuple `(0.55, 0.99, 0.49)`
@@ -6546,7 +6704,7 @@ pub const XKCD_EASTER_GREEN: Color = Color(140, 253, 126);
 
 /// Colour `(r = 100, g = 136, b = 234)`
 ///
-/// Colour `XKCD_SOFT_BLUE` from the set `XKCD_COLORS`. (Colour number `723`)
+/// Colour `XKCD_SOFT_BLUE` from the set `XKCD_COLORS`. (Colour number `723`, name `xkcd:soft blue`)
 /// ## Representations:
 /// - int tuple `(100, 136, 234)`
 /// - float tuple `(0.39, 0.53, 0.92)`
@@ -6555,7 +6713,7 @@ pub const XKCD_SOFT_BLUE: Color = Color(100, 136, 234);
 
 /// Colour `(r = 5, g = 110, b = 238)`
 ///
-/// Colour `XKCD_CERULEAN_BLUE` from the set `XKCD_COLORS`. (Colour number `724`)
+/// Colour `XKCD_CERULEAN_BLUE` from the set `XKCD_COLORS`. (Colour number `724`, name `xkcd:cerulean blue`)
 /// ## Representations:
 /// - int tuple `(5, 110, 238)`
 /// - float tuple `(0.02, 0.43, 0.93)`
@@ -6564,7 +6722,7 @@ pub const XKCD_CERULEAN_BLUE: Color = Color(5, 110, 238);
 
 /// Colour `(r = 178, g = 122, b = 1)`
 ///
-/// Colour `XKCD_GOLDEN_BROWN` from the set `XKCD_COLORS`. (Colour number `725`)
+/// Colour `XKCD_GOLDEN_BROWN` from the set `XKCD_COLORS`. (Colour number `725`, name `xkcd:golden brown`)
 /// ## Representations:
 /// - int tuple `(178, 122, 1)`
 /// - float tuple `(0.7, 0.48, 0.0)`
@@ -6573,7 +6731,7 @@ pub const XKCD_GOLDEN_BROWN: Color = Color(178, 122, 1);
 
 /// Colour `(r = 15, g = 254, b = 249)`
 ///
-/// Colour `XKCD_BRIGHT_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `726`)
+/// Colour `XKCD_BRIGHT_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `726`, name `xkcd:bright turquoise`)
 /// ## Representations:
 /// - int tuple `(15, 254, 249)`
 /// - float tuple `(0.06, 1.0, 0.98)`
@@ -6582,7 +6740,7 @@ pub const XKCD_BRIGHT_TURQUOISE: Color = Color(15, 254, 249);
 
 /// Colour `(r = 250, g = 42, b = 85)`
 ///
-/// Colour `XKCD_RED_PINK` from the set `XKCD_COLORS`. (Colour number `727`)
+/// Colour `XKCD_RED_PINK` from the set `XKCD_COLORS`. (Colour number `727`, name `xkcd:red pink`)
 /// ## Representations:
 /// - int tuple `(250, 42, 85)`
 /// - float tuple `(0.98, 0.16, 0.33)`
@@ -6591,7 +6749,7 @@ pub const XKCD_RED_PINK: Color = Color(250, 42, 85);
 
 /// Colour `(r = 130, g = 7, b = 71)`
 ///
-/// Colour `XKCD_RED_PURPLE` from the set `XKCD_COLORS`. (Colour number `728`)
+/// Colour `XKCD_RED_PURPLE` from the set `XKCD_COLORS`. (Colour number `728`, name `xkcd:red purple`)
 /// ## Representations:
 /// - int tuple `(130, 7, 71)`
 /// - float tuple `(0.51, 0.03, 0.28)`
@@ -6600,7 +6758,7 @@ pub const XKCD_RED_PURPLE: Color = Color(130, 7, 71);
 
 /// Colour `(r = 122, g = 106, b = 79)`
 ///
-/// Colour `XKCD_GREYISH_BROWN` from the set `XKCD_COLORS`. (Colour number `729`)
+/// Colour `XKCD_GREYISH_BROWN` from the set `XKCD_COLORS`. (Colour number `729`, name `xkcd:greyish brown`)
 /// ## Representations:
 /// - int tuple `(122, 106, 79)`
 /// - float tuple `(0.48, 0.42, 0.31)`
@@ -6609,7 +6767,7 @@ pub const XKCD_GREYISH_BROWN: Color = Color(122, 106, 79);
 
 /// Colour `(r = 244, g = 50, b = 12)`
 ///
-/// Colour `XKCD_VERMILLION` from the set `XKCD_COLORS`. (Colour number `730`)
+/// Colour `XKCD_VERMILLION` from the set `XKCD_COLORS`. (Colour number `730`, name `xkcd:vermillion`)
 /// ## Representations:
 /// - int tuple `(244, 50, 12)`
 /// - float tuple `(0.96, 0.2, 0.05)`
@@ -6618,7 +6776,7 @@ pub const XKCD_VERMILLION: Color = Color(244, 50, 12);
 
 /// Colour `(r = 161, g = 57, b = 5)`
 ///
-/// Colour `XKCD_RUSSET` from the set `XKCD_COLORS`. (Colour number `731`)
+/// Colour `XKCD_RUSSET` from the set `XKCD_COLORS`. (Colour number `731`, name `xkcd:russet`)
 /// ## Representations:
 /// - int tuple `(161, 57, 5)`
 /// - float tuple `(0.63, 0.22, 0.02)`
@@ -6627,7 +6785,7 @@ pub const XKCD_RUSSET: Color = Color(161, 57, 5);
 
 /// Colour `(r = 111, g = 130, b = 138)`
 ///
-/// Colour `XKCD_STEEL_GREY` from the set `XKCD_COLORS`. (Colour number `732`)
+/// Colour `XKCD_STEEL_GREY` from the set `XKCD_COLORS`. (Colour number `732`, name `xkcd:steel grey`)
 /// ## Representations:
 /// - int tuple `(111, 130, 138)`
 /// - float tuple `(0.44, 0.51, 0.54)`
@@ -6636,7 +6794,7 @@ pub const XKCD_STEEL_GREY: Color = Color(111, 130, 138);
 
 /// Colour `(r = 165, g = 90, b = 244)`
 ///
-/// Colour `XKCD_LIGHTER_PURPLE` from the set `XKCD_COLORS`. (Colour number `733`)
+/// Colour `XKCD_LIGHTER_PURPLE` from the set `XKCD_COLORS`. (Colour number `733`, name `xkcd:lighter purple`)
 /// ## Representations:
 /// - int tuple `(165, 90, 244)`
 /// - float tuple `(0.65, 0.35, 0.96)`
@@ -6645,7 +6803,7 @@ pub const XKCD_LIGHTER_PURPLE: Color = Color(165, 90, 244);
 
 /// Colour `(r = 173, g = 10, b = 253)`
 ///
-/// Colour `XKCD_BRIGHT_VIOLET` from the set `XKCD_COLORS`. (Colour number `734`)
+/// Colour `XKCD_BRIGHT_VIOLET` from the set `XKCD_COLORS`. (Colour number `734`, name `xkcd:bright violet`)
 /// ## Representations:
 /// - int tuple `(173, 10, 253)`
 /// - float tuple `(0.68, 0.04, 0.99)`
@@ -6654,7 +6812,7 @@ pub const XKCD_BRIGHT_VIOLET: Color = Color(173, 10, 253);
 
 /// Colour `(r = 0, g = 69, b = 119)`
 ///
-/// Colour `XKCD_PRUSSIAN_BLUE` from the set `XKCD_COLORS`. (Colour number `735`)
+/// Colour `XKCD_PRUSSIAN_BLUE` from the set `XKCD_COLORS`. (Colour number `735`, name `xkcd:prussian blue`)
 /// ## Representations:
 /// - int tuple `(0, 69, 119)`
 /// - float tuple `(0.0, 0.27, 0.47)`
@@ -6663,7 +6821,7 @@ pub const XKCD_PRUSSIAN_BLUE: Color = Color(0, 69, 119);
 
 /// Colour `(r = 101, g = 141, b = 109)`
 ///
-/// Colour `XKCD_SLATE_GREEN` from the set `XKCD_COLORS`. (Colour number `736`)
+/// Colour `XKCD_SLATE_GREEN` from the set `XKCD_COLORS`. (Colour number `736`, name `xkcd:slate green`)
 /// ## Representations:
 /// - int tuple `(101, 141, 109)`
 /// - float tuple `(0.4, 0.55, 0.43)`
@@ -6672,7 +6830,7 @@ pub const XKCD_SLATE_GREEN: Color = Color(101, 141, 109);
 
 /// Colour `(r = 202, g = 123, b = 128)`
 ///
-/// Colour `XKCD_DIRTY_PINK` from the set `XKCD_COLORS`. (Colour number `737`)
+/// Colour `XKCD_DIRTY_PINK` from the set `XKCD_COLORS`. (Colour number `737`, name `xkcd:dirty pink`)
 /// ## Representations:
 /// - int tuple `(202, 123, 128)`
 /// - float tuple `(0.79, 0.48, 0.5)`
@@ -6681,7 +6839,7 @@ pub const XKCD_DIRTY_PINK: Color = Color(202, 123, 128);
 
 /// Colour `(r = 0, g = 82, b = 73)`
 ///
-/// Colour `XKCD_DARK_BLUE_GREEN` from the set `XKCD_COLORS`. (Colour number `738`)
+/// Colour `XKCD_DARK_BLUE_GREEN` from the set `XKCD_COLORS`. (Colour number `738`, name `xkcd:dark blue green`)
 /// ## Representations:
 /// - int tuple `(0, 82, 73)`
 /// - float tuple `(0.0, 0.32, 0.29)`
@@ -6690,7 +6848,7 @@ pub const XKCD_DARK_BLUE_GREEN: Color = Color(0, 82, 73);
 
 /// Colour `(r = 43, g = 93, b = 52)`
 ///
-/// Colour `XKCD_PINE` from the set `XKCD_COLORS`. (Colour number `739`)
+/// Colour `XKCD_PINE` from the set `XKCD_COLORS`. (Colour number `739`, name `xkcd:pine`)
 /// ## Representations:
 /// - int tuple `(43, 93, 52)`
 /// - float tuple `(0.17, 0.36, 0.2)`
@@ -6699,7 +6857,7 @@ pub const XKCD_PINE: Color = Color(43, 93, 52);
 
 /// Colour `(r = 191, g = 241, b = 40)`
 ///
-/// Colour `XKCD_YELLOWY_GREEN` from the set `XKCD_COLORS`. (Colour number `740`)
+/// Colour `XKCD_YELLOWY_GREEN` from the set `XKCD_COLORS`. (Colour number `740`, name `xkcd:yellowy green`)
 /// ## Representations:
 /// - int tuple `(191, 241, 40)`
 /// - float tuple `(0.75, 0.95, 0.16)`
@@ -6708,7 +6866,7 @@ pub const XKCD_YELLOWY_GREEN: Color = Color(191, 241, 40);
 
 /// Colour `(r = 181, g = 148, b = 16)`
 ///
-/// Colour `XKCD_DARK_GOLD` from the set `XKCD_COLORS`. (Colour number `741`)
+/// Colour `XKCD_DARK_GOLD` from the set `XKCD_COLORS`. (Colour number `741`, name `xkcd:dark gold`)
 /// ## Representations:
 /// - int tuple `(181, 148, 16)`
 /// - float tuple `(0.71, 0.58, 0.06)`
@@ -6717,7 +6875,7 @@ pub const XKCD_DARK_GOLD: Color = Color(181, 148, 16);
 
 /// Colour `(r = 41, g = 118, b = 187)`
 ///
-/// Colour `XKCD_BLUISH` from the set `XKCD_COLORS`. (Colour number `742`)
+/// Colour `XKCD_BLUISH` from the set `XKCD_COLORS`. (Colour number `742`, name `xkcd:bluish`)
 /// ## Representations:
 /// - int tuple `(41, 118, 187)`
 /// - float tuple `(0.16, 0.46, 0.73)`
@@ -6726,7 +6884,7 @@ pub const XKCD_BLUISH: Color = Color(41, 118, 187);
 
 /// Colour `(r = 1, g = 65, b = 130)`
 ///
-/// Colour `XKCD_DARKISH_BLUE` from the set `XKCD_COLORS`. (Colour number `743`)
+/// Colour `XKCD_DARKISH_BLUE` from the set `XKCD_COLORS`. (Colour number `743`, name `xkcd:darkish blue`)
 /// ## Representations:
 /// - int tuple `(1, 65, 130)`
 /// - float tuple `(0.0, 0.25, 0.51)`
@@ -6735,7 +6893,7 @@ pub const XKCD_DARKISH_BLUE: Color = Color(1, 65, 130);
 
 /// Colour `(r = 187, g = 63, b = 63)`
 ///
-/// Colour `XKCD_DULL_RED` from the set `XKCD_COLORS`. (Colour number `744`)
+/// Colour `XKCD_DULL_RED` from the set `XKCD_COLORS`. (Colour number `744`, name `xkcd:dull red`)
 /// ## Representations:
 /// - int tuple `(187, 63, 63)`
 /// - float tuple `(0.73, 0.25, 0.25)`
@@ -6744,7 +6902,7 @@ pub const XKCD_DULL_RED: Color = Color(187, 63, 63);
 
 /// Colour `(r = 252, g = 38, b = 71)`
 ///
-/// Colour `XKCD_PINKY_RED` from the set `XKCD_COLORS`. (Colour number `745`)
+/// Colour `XKCD_PINKY_RED` from the set `XKCD_COLORS`. (Colour number `745`, name `xkcd:pinky red`)
 /// ## Representations:
 /// - int tuple `(252, 38, 71)`
 /// - float tuple `(0.99, 0.15, 0.28)`
@@ -6753,7 +6911,7 @@ pub const XKCD_PINKY_RED: Color = Color(252, 38, 71);
 
 /// Colour `(r = 168, g = 121, b = 0)`
 ///
-/// Colour `XKCD_BRONZE` from the set `XKCD_COLORS`. (Colour number `746`)
+/// Colour `XKCD_BRONZE` from the set `XKCD_COLORS`. (Colour number `746`, name `xkcd:bronze`)
 /// ## Representations:
 /// - int tuple `(168, 121, 0)`
 /// - float tuple `(0.66, 0.47, 0.0)`
@@ -6762,7 +6920,7 @@ pub const XKCD_BRONZE: Color = Color(168, 121, 0);
 
 /// Colour `(r = 130, g = 203, b = 178)`
 ///
-/// Colour `XKCD_PALE_TEAL` from the set `XKCD_COLORS`. (Colour number `747`)
+/// Colour `XKCD_PALE_TEAL` from the set `XKCD_COLORS`. (Colour number `747`, name `xkcd:pale teal`)
 /// ## Representations:
 /// - int tuple `(130, 203, 178)`
 /// - float tuple `(0.51, 0.8, 0.7)`
@@ -6771,7 +6929,7 @@ pub const XKCD_PALE_TEAL: Color = Color(130, 203, 178);
 
 /// Colour `(r = 102, g = 124, b = 62)`
 ///
-/// Colour `XKCD_MILITARY_GREEN` from the set `XKCD_COLORS`. (Colour number `748`)
+/// Colour `XKCD_MILITARY_GREEN` from the set `XKCD_COLORS`. (Colour number `748`, name `xkcd:military green`)
 /// ## Representations:
 /// - int tuple `(102, 124, 62)`
 /// - float tuple `(0.4, 0.49, 0.24)`
@@ -6780,7 +6938,7 @@ pub const XKCD_MILITARY_GREEN: Color = Color(102, 124, 62);
 
 /// Colour `(r = 254, g = 70, b = 165)`
 ///
-/// Colour `XKCD_BARBIE_PINK` from the set `XKCD_COLORS`. (Colour number `749`)
+/// Colour `XKCD_BARBIE_PINK` from the set `XKCD_COLORS`. (Colour number `749`, name `xkcd:barbie pink`)
 /// ## Representations:
 /// - int tuple `(254, 70, 165)`
 /// - float tuple `(1.0, 0.27, 0.65)`
@@ -6789,7 +6947,7 @@ pub const XKCD_BARBIE_PINK: Color = Color(254, 70, 165);
 
 /// Colour `(r = 254, g = 131, b = 204)`
 ///
-/// Colour `XKCD_BUBBLEGUM_PINK` from the set `XKCD_COLORS`. (Colour number `750`)
+/// Colour `XKCD_BUBBLEGUM_PINK` from the set `XKCD_COLORS`. (Colour number `750`, name `xkcd:bubblegum pink`)
 /// ## Representations:
 /// - int tuple `(254, 131, 204)`
 /// - float tuple `(1.0, 0.51, 0.8)`
@@ -6798,7 +6956,7 @@ pub const XKCD_BUBBLEGUM_PINK: Color = Color(254, 131, 204);
 
 /// Colour `(r = 148, g = 166, b = 23)`
 ///
-/// Colour `XKCD_PEA_SOUP_GREEN` from the set `XKCD_COLORS`. (Colour number `751`)
+/// Colour `XKCD_PEA_SOUP_GREEN` from the set `XKCD_COLORS`. (Colour number `751`, name `xkcd:pea soup green`)
 /// ## Representations:
 /// - int tuple `(148, 166, 23)`
 /// - float tuple `(0.58, 0.65, 0.09)`
@@ -6807,7 +6965,7 @@ pub const XKCD_PEA_SOUP_GREEN: Color = Color(148, 166, 23);
 
 /// Colour `(r = 168, g = 137, b = 5)`
 ///
-/// Colour `XKCD_DARK_MUSTARD` from the set `XKCD_COLORS`. (Colour number `752`)
+/// Colour `XKCD_DARK_MUSTARD` from the set `XKCD_COLORS`. (Colour number `752`, name `xkcd:dark mustard`)
 /// ## Representations:
 /// - int tuple `(168, 137, 5)`
 /// - float tuple `(0.66, 0.54, 0.02)`
@@ -6816,7 +6974,7 @@ pub const XKCD_DARK_MUSTARD: Color = Color(168, 137, 5);
 
 /// Colour `(r = 127, g = 95, b = 0)`
 ///
-/// Colour `XKCD_SHIT` from the set `XKCD_COLORS`. (Colour number `753`)
+/// Colour `XKCD_SHIT` from the set `XKCD_COLORS`. (Colour number `753`, name `xkcd:shit`)
 /// ## Representations:
 /// - int tuple `(127, 95, 0)`
 /// - float tuple `(0.5, 0.37, 0.0)`
@@ -6825,7 +6983,7 @@ pub const XKCD_SHIT: Color = Color(127, 95, 0);
 
 /// Colour `(r = 158, g = 67, b = 162)`
 ///
-/// Colour `XKCD_MEDIUM_PURPLE` from the set `XKCD_COLORS`. (Colour number `754`)
+/// Colour `XKCD_MEDIUM_PURPLE` from the set `XKCD_COLORS`. (Colour number `754`, name `xkcd:medium purple`)
 /// ## Representations:
 /// - int tuple `(158, 67, 162)`
 /// - float tuple `(0.62, 0.26, 0.64)`
@@ -6834,7 +6992,7 @@ pub const XKCD_MEDIUM_PURPLE: Color = Color(158, 67, 162);
 
 /// Colour `(r = 6, g = 46, b = 3)`
 ///
-/// Colour `XKCD_VERY_DARK_GREEN` from the set `XKCD_COLORS`. (Colour number `755`)
+/// Colour `XKCD_VERY_DARK_GREEN` from the set `XKCD_COLORS`. (Colour number `755`, name `xkcd:very dark green`)
 /// ## Representations:
 /// - int tuple `(6, 46, 3)`
 /// - float tuple `(0.02, 0.18, 0.01)`
@@ -6843,7 +7001,7 @@ pub const XKCD_VERY_DARK_GREEN: Color = Color(6, 46, 3);
 
 /// Colour `(r = 138, g = 110, b = 69)`
 ///
-/// Colour `XKCD_DIRT` from the set `XKCD_COLORS`. (Colour number `756`)
+/// Colour `XKCD_DIRT` from the set `XKCD_COLORS`. (Colour number `756`, name `xkcd:dirt`)
 /// ## Representations:
 /// - int tuple `(138, 110, 69)`
 /// - float tuple `(0.54, 0.43, 0.27)`
@@ -6852,7 +7010,7 @@ pub const XKCD_DIRT: Color = Color(138, 110, 69);
 
 /// Colour `(r = 204, g = 122, b = 139)`
 ///
-/// Colour `XKCD_DUSKY_PINK` from the set `XKCD_COLORS`. (Colour number `757`)
+/// Colour `XKCD_DUSKY_PINK` from the set `XKCD_COLORS`. (Colour number `757`, name `xkcd:dusky pink`)
 /// ## Representations:
 /// - int tuple `(204, 122, 139)`
 /// - float tuple `(0.8, 0.48, 0.55)`
@@ -6861,7 +7019,7 @@ pub const XKCD_DUSKY_PINK: Color = Color(204, 122, 139);
 
 /// Colour `(r = 158, g = 1, b = 104)`
 ///
-/// Colour `XKCD_RED_VIOLET` from the set `XKCD_COLORS`. (Colour number `758`)
+/// Colour `XKCD_RED_VIOLET` from the set `XKCD_COLORS`. (Colour number `758`, name `xkcd:red violet`)
 /// ## Representations:
 /// - int tuple `(158, 1, 104)`
 /// - float tuple `(0.62, 0.0, 0.41)`
@@ -6870,7 +7028,7 @@ pub const XKCD_RED_VIOLET: Color = Color(158, 1, 104);
 
 /// Colour `(r = 253, g = 255, b = 56)`
 ///
-/// Colour `XKCD_LEMON_YELLOW` from the set `XKCD_COLORS`. (Colour number `759`)
+/// Colour `XKCD_LEMON_YELLOW` from the set `XKCD_COLORS`. (Colour number `759`, name `xkcd:lemon yellow`)
 /// ## Representations:
 /// - int tuple `(253, 255, 56)`
 /// - float tuple `(0.99, 1.0, 0.22)`
@@ -6879,7 +7037,7 @@ pub const XKCD_LEMON_YELLOW: Color = Color(253, 255, 56);
 
 /// Colour `(r = 192, g = 250, b = 139)`
 ///
-/// Colour `XKCD_PISTACHIO` from the set `XKCD_COLORS`. (Colour number `760`)
+/// Colour `XKCD_PISTACHIO` from the set `XKCD_COLORS`. (Colour number `760`, name `xkcd:pistachio`)
 /// ## Representations:
 /// - int tuple `(192, 250, 139)`
 /// - float tuple `(0.75, 0.98, 0.55)`
@@ -6888,7 +7046,7 @@ pub const XKCD_PISTACHIO: Color = Color(192, 250, 139);
 
 /// Colour `(r = 238, g = 220, b = 91)`
 ///
-/// Colour `XKCD_DULL_YELLOW` from the set `XKCD_COLORS`. (Colour number `761`)
+/// Colour `XKCD_DULL_YELLOW` from the set `XKCD_COLORS`. (Colour number `761`, name `xkcd:dull yellow`)
 /// ## Representations:
 /// - int tuple `(238, 220, 91)`
 /// - float tuple `(0.93, 0.86, 0.36)`
@@ -6897,7 +7055,7 @@ pub const XKCD_DULL_YELLOW: Color = Color(238, 220, 91);
 
 /// Colour `(r = 126, g = 189, b = 1)`
 ///
-/// Colour `XKCD_DARK_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `762`)
+/// Colour `XKCD_DARK_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `762`, name `xkcd:dark lime green`)
 /// ## Representations:
 /// - int tuple `(126, 189, 1)`
 /// - float tuple `(0.49, 0.74, 0.0)`
@@ -6906,7 +7064,7 @@ pub const XKCD_DARK_LIME_GREEN: Color = Color(126, 189, 1);
 
 /// Colour `(r = 59, g = 91, b = 146)`
 ///
-/// Colour `XKCD_DENIM_BLUE` from the set `XKCD_COLORS`. (Colour number `763`)
+/// Colour `XKCD_DENIM_BLUE` from the set `XKCD_COLORS`. (Colour number `763`, name `xkcd:denim blue`)
 /// ## Representations:
 /// - int tuple `(59, 91, 146)`
 /// - float tuple `(0.23, 0.36, 0.57)`
@@ -6915,7 +7073,7 @@ pub const XKCD_DENIM_BLUE: Color = Color(59, 91, 146);
 
 /// Colour `(r = 1, g = 136, b = 159)`
 ///
-/// Colour `XKCD_TEAL_BLUE` from the set `XKCD_COLORS`. (Colour number `764`)
+/// Colour `XKCD_TEAL_BLUE` from the set `XKCD_COLORS`. (Colour number `764`, name `xkcd:teal blue`)
 /// ## Representations:
 /// - int tuple `(1, 136, 159)`
 /// - float tuple `(0.0, 0.53, 0.62)`
@@ -6924,7 +7082,7 @@ pub const XKCD_TEAL_BLUE: Color = Color(1, 136, 159);
 
 /// Colour `(r = 61, g = 122, b = 253)`
 ///
-/// Colour `XKCD_LIGHTISH_BLUE` from the set `XKCD_COLORS`. (Colour number `765`)
+/// Colour `XKCD_LIGHTISH_BLUE` from the set `XKCD_COLORS`. (Colour number `765`, name `xkcd:lightish blue`)
 /// ## Representations:
 /// - int tuple `(61, 122, 253)`
 /// - float tuple `(0.24, 0.48, 0.99)`
@@ -6933,7 +7091,7 @@ pub const XKCD_LIGHTISH_BLUE: Color = Color(61, 122, 253);
 
 /// Colour `(r = 95, g = 52, b = 231)`
 ///
-/// Colour `XKCD_PURPLEY_BLUE` from the set `XKCD_COLORS`. (Colour number `766`)
+/// Colour `XKCD_PURPLEY_BLUE` from the set `XKCD_COLORS`. (Colour number `766`, name `xkcd:purpley blue`)
 /// ## Representations:
 /// - int tuple `(95, 52, 231)`
 /// - float tuple `(0.37, 0.2, 0.91)`
@@ -6942,7 +7100,7 @@ pub const XKCD_PURPLEY_BLUE: Color = Color(95, 52, 231);
 
 /// Colour `(r = 109, g = 90, b = 207)`
 ///
-/// Colour `XKCD_LIGHT_INDIGO` from the set `XKCD_COLORS`. (Colour number `767`)
+/// Colour `XKCD_LIGHT_INDIGO` from the set `XKCD_COLORS`. (Colour number `767`, name `xkcd:light indigo`)
 /// ## Representations:
 /// - int tuple `(109, 90, 207)`
 /// - float tuple `(0.43, 0.35, 0.81)`
@@ -6951,7 +7109,7 @@ pub const XKCD_LIGHT_INDIGO: Color = Color(109, 90, 207);
 
 /// Colour `(r = 116, g = 133, b = 0)`
 ///
-/// Colour `XKCD_SWAMP_GREEN` from the set `XKCD_COLORS`. (Colour number `768`)
+/// Colour `XKCD_SWAMP_GREEN` from the set `XKCD_COLORS`. (Colour number `768`, name `xkcd:swamp green`)
 /// ## Representations:
 /// - int tuple `(116, 133, 0)`
 /// - float tuple `(0.45, 0.52, 0.0)`
@@ -6960,7 +7118,7 @@ pub const XKCD_SWAMP_GREEN: Color = Color(116, 133, 0);
 
 /// Colour `(r = 112, g = 108, b = 17)`
 ///
-/// Colour `XKCD_BROWN_GREEN` from the set `XKCD_COLORS`. (Colour number `769`)
+/// Colour `XKCD_BROWN_GREEN` from the set `XKCD_COLORS`. (Colour number `769`, name `xkcd:brown green`)
 /// ## Representations:
 /// - int tuple `(112, 108, 17)`
 /// - float tuple `(0.44, 0.42, 0.07)`
@@ -6969,7 +7127,7 @@ pub const XKCD_BROWN_GREEN: Color = Color(112, 108, 17);
 
 /// Colour `(r = 60, g = 0, b = 8)`
 ///
-/// Colour `XKCD_DARK_MAROON` from the set `XKCD_COLORS`. (Colour number `770`)
+/// Colour `XKCD_DARK_MAROON` from the set `XKCD_COLORS`. (Colour number `770`, name `xkcd:dark maroon`)
 /// ## Representations:
 /// - int tuple `(60, 0, 8)`
 /// - float tuple `(0.24, 0.0, 0.03)`
@@ -6978,7 +7136,7 @@ pub const XKCD_DARK_MAROON: Color = Color(60, 0, 8);
 
 /// Colour `(r = 203, g = 0, b = 245)`
 ///
-/// Colour `XKCD_HOT_PURPLE` from the set `XKCD_COLORS`. (Colour number `771`)
+/// Colour `XKCD_HOT_PURPLE` from the set `XKCD_COLORS`. (Colour number `771`, name `xkcd:hot purple`)
 /// ## Representations:
 /// - int tuple `(203, 0, 245)`
 /// - float tuple `(0.8, 0.0, 0.96)`
@@ -6987,7 +7145,7 @@ pub const XKCD_HOT_PURPLE: Color = Color(203, 0, 245);
 
 /// Colour `(r = 0, g = 45, b = 4)`
 ///
-/// Colour `XKCD_DARK_FOREST_GREEN` from the set `XKCD_COLORS`. (Colour number `772`)
+/// Colour `XKCD_DARK_FOREST_GREEN` from the set `XKCD_COLORS`. (Colour number `772`, name `xkcd:dark forest green`)
 /// ## Representations:
 /// - int tuple `(0, 45, 4)`
 /// - float tuple `(0.0, 0.18, 0.02)`
@@ -6996,7 +7154,7 @@ pub const XKCD_DARK_FOREST_GREEN: Color = Color(0, 45, 4);
 
 /// Colour `(r = 101, g = 140, b = 187)`
 ///
-/// Colour `XKCD_FADED_BLUE` from the set `XKCD_COLORS`. (Colour number `773`)
+/// Colour `XKCD_FADED_BLUE` from the set `XKCD_COLORS`. (Colour number `773`, name `xkcd:faded blue`)
 /// ## Representations:
 /// - int tuple `(101, 140, 187)`
 /// - float tuple `(0.4, 0.55, 0.73)`
@@ -7005,7 +7163,7 @@ pub const XKCD_FADED_BLUE: Color = Color(101, 140, 187);
 
 /// Colour `(r = 116, g = 149, b = 81)`
 ///
-/// Colour `XKCD_DRAB_GREEN` from the set `XKCD_COLORS`. (Colour number `774`)
+/// Colour `XKCD_DRAB_GREEN` from the set `XKCD_COLORS`. (Colour number `774`, name `xkcd:drab green`)
 /// ## Representations:
 /// - int tuple `(116, 149, 81)`
 /// - float tuple `(0.45, 0.58, 0.32)`
@@ -7014,7 +7172,7 @@ pub const XKCD_DRAB_GREEN: Color = Color(116, 149, 81);
 
 /// Colour `(r = 185, g = 255, b = 102)`
 ///
-/// Colour `XKCD_LIGHT_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `775`)
+/// Colour `XKCD_LIGHT_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `775`, name `xkcd:light lime green`)
 /// ## Representations:
 /// - int tuple `(185, 255, 102)`
 /// - float tuple `(0.73, 1.0, 0.4)`
@@ -7023,7 +7181,7 @@ pub const XKCD_LIGHT_LIME_GREEN: Color = Color(185, 255, 102);
 
 /// Colour `(r = 157, g = 193, b = 0)`
 ///
-/// Colour `XKCD_SNOT_GREEN` from the set `XKCD_COLORS`. (Colour number `776`)
+/// Colour `XKCD_SNOT_GREEN` from the set `XKCD_COLORS`. (Colour number `776`, name `xkcd:snot green`)
 /// ## Representations:
 /// - int tuple `(157, 193, 0)`
 /// - float tuple `(0.62, 0.76, 0.0)`
@@ -7032,7 +7190,7 @@ pub const XKCD_SNOT_GREEN: Color = Color(157, 193, 0);
 
 /// Colour `(r = 250, g = 238, b = 102)`
 ///
-/// Colour `XKCD_YELLOWISH` from the set `XKCD_COLORS`. (Colour number `777`)
+/// Colour `XKCD_YELLOWISH` from the set `XKCD_COLORS`. (Colour number `777`, name `xkcd:yellowish`)
 /// ## Representations:
 /// - int tuple `(250, 238, 102)`
 /// - float tuple `(0.98, 0.93, 0.4)`
@@ -7041,7 +7199,7 @@ pub const XKCD_YELLOWISH: Color = Color(250, 238, 102);
 
 /// Colour `(r = 126, g = 251, b = 179)`
 ///
-/// Colour `XKCD_LIGHT_BLUE_GREEN` from the set `XKCD_COLORS`. (Colour number `778`)
+/// Colour `XKCD_LIGHT_BLUE_GREEN` from the set `XKCD_COLORS`. (Colour number `778`, name `xkcd:light blue green`)
 /// ## Representations:
 /// - int tuple `(126, 251, 179)`
 /// - float tuple `(0.49, 0.98, 0.7)`
@@ -7050,7 +7208,7 @@ pub const XKCD_LIGHT_BLUE_GREEN: Color = Color(126, 251, 179);
 
 /// Colour `(r = 123, g = 0, b = 44)`
 ///
-/// Colour `XKCD_BORDEAUX` from the set `XKCD_COLORS`. (Colour number `779`)
+/// Colour `XKCD_BORDEAUX` from the set `XKCD_COLORS`. (Colour number `779`, name `xkcd:bordeaux`)
 /// ## Representations:
 /// - int tuple `(123, 0, 44)`
 /// - float tuple `(0.48, 0.0, 0.17)`
@@ -7059,7 +7217,7 @@ pub const XKCD_BORDEAUX: Color = Color(123, 0, 44);
 
 /// Colour `(r = 194, g = 146, b = 161)`
 ///
-/// Colour `XKCD_LIGHT_MAUVE` from the set `XKCD_COLORS`. (Colour number `780`)
+/// Colour `XKCD_LIGHT_MAUVE` from the set `XKCD_COLORS`. (Colour number `780`, name `xkcd:light mauve`)
 /// ## Representations:
 /// - int tuple `(194, 146, 161)`
 /// - float tuple `(0.76, 0.57, 0.63)`
@@ -7068,7 +7226,7 @@ pub const XKCD_LIGHT_MAUVE: Color = Color(194, 146, 161);
 
 /// Colour `(r = 1, g = 123, b = 146)`
 ///
-/// Colour `XKCD_OCEAN` from the set `XKCD_COLORS`. (Colour number `781`)
+/// Colour `XKCD_OCEAN` from the set `XKCD_COLORS`. (Colour number `781`, name `xkcd:ocean`)
 /// ## Representations:
 /// - int tuple `(1, 123, 146)`
 /// - float tuple `(0.0, 0.48, 0.57)`
@@ -7077,7 +7235,7 @@ pub const XKCD_OCEAN: Color = Color(1, 123, 146);
 
 /// Colour `(r = 252, g = 192, b = 6)`
 ///
-/// Colour `XKCD_MARIGOLD` from the set `XKCD_COLORS`. (Colour number `782`)
+/// Colour `XKCD_MARIGOLD` from the set `XKCD_COLORS`. (Colour number `782`, name `xkcd:marigold`)
 /// ## Representations:
 /// - int tuple `(252, 192, 6)`
 /// - float tuple `(0.99, 0.75, 0.02)`
@@ -7086,7 +7244,7 @@ pub const XKCD_MARIGOLD: Color = Color(252, 192, 6);
 
 /// Colour `(r = 101, g = 116, b = 50)`
 ///
-/// Colour `XKCD_MUDDY_GREEN` from the set `XKCD_COLORS`. (Colour number `783`)
+/// Colour `XKCD_MUDDY_GREEN` from the set `XKCD_COLORS`. (Colour number `783`, name `xkcd:muddy green`)
 /// ## Representations:
 /// - int tuple `(101, 116, 50)`
 /// - float tuple `(0.4, 0.45, 0.2)`
@@ -7095,7 +7253,7 @@ pub const XKCD_MUDDY_GREEN: Color = Color(101, 116, 50);
 
 /// Colour `(r = 216, g = 134, b = 59)`
 ///
-/// Colour `XKCD_DULL_ORANGE` from the set `XKCD_COLORS`. (Colour number `784`)
+/// Colour `XKCD_DULL_ORANGE` from the set `XKCD_COLORS`. (Colour number `784`, name `xkcd:dull orange`)
 /// ## Representations:
 /// - int tuple `(216, 134, 59)`
 /// - float tuple `(0.85, 0.53, 0.23)`
@@ -7104,7 +7262,7 @@ pub const XKCD_DULL_ORANGE: Color = Color(216, 134, 59);
 
 /// Colour `(r = 115, g = 133, b = 149)`
 ///
-/// Colour `XKCD_STEEL` from the set `XKCD_COLORS`. (Colour number `785`)
+/// Colour `XKCD_STEEL` from the set `XKCD_COLORS`. (Colour number `785`, name `xkcd:steel`)
 /// ## Representations:
 /// - int tuple `(115, 133, 149)`
 /// - float tuple `(0.45, 0.52, 0.58)`
@@ -7113,7 +7271,7 @@ pub const XKCD_STEEL: Color = Color(115, 133, 149);
 
 /// Colour `(r = 170, g = 35, b = 255)`
 ///
-/// Colour `XKCD_ELECTRIC_PURPLE` from the set `XKCD_COLORS`. (Colour number `786`)
+/// Colour `XKCD_ELECTRIC_PURPLE` from the set `XKCD_COLORS`. (Colour number `786`, name `xkcd:electric purple`)
 /// ## Representations:
 /// - int tuple `(170, 35, 255)`
 /// - float tuple `(0.67, 0.14, 1.0)`
@@ -7122,7 +7280,7 @@ pub const XKCD_ELECTRIC_PURPLE: Color = Color(170, 35, 255);
 
 /// Colour `(r = 8, g = 255, b = 8)`
 ///
-/// Colour `XKCD_FLUORESCENT_GREEN` from the set `XKCD_COLORS`. (Colour number `787`)
+/// Colour `XKCD_FLUORESCENT_GREEN` from the set `XKCD_COLORS`. (Colour number `787`, name `xkcd:fluorescent green`)
 /// ## Representations:
 /// - int tuple `(8, 255, 8)`
 /// - float tuple `(0.03, 1.0, 0.03)`
@@ -7131,7 +7289,7 @@ pub const XKCD_FLUORESCENT_GREEN: Color = Color(8, 255, 8);
 
 /// Colour `(r = 155, g = 122, b = 1)`
 ///
-/// Colour `XKCD_YELLOWISH_BROWN` from the set `XKCD_COLORS`. (Colour number `788`)
+/// Colour `XKCD_YELLOWISH_BROWN` from the set `XKCD_COLORS`. (Colour number `788`, name `xkcd:yellowish brown`)
 /// ## Representations:
 /// - int tuple `(155, 122, 1)`
 /// - float tuple `(0.61, 0.48, 0.0)`
@@ -7140,7 +7298,7 @@ pub const XKCD_YELLOWISH_BROWN: Color = Color(155, 122, 1);
 
 /// Colour `(r = 242, g = 158, b = 142)`
 ///
-/// Colour `XKCD_BLUSH` from the set `XKCD_COLORS`. (Colour number `789`)
+/// Colour `XKCD_BLUSH` from the set `XKCD_COLORS`. (Colour number `789`, name `xkcd:blush`)
 /// ## Representations:
 /// - int tuple `(242, 158, 142)`
 /// - float tuple `(0.95, 0.62, 0.56)`
@@ -7149,7 +7307,7 @@ pub const XKCD_BLUSH: Color = Color(242, 158, 142);
 
 /// Colour `(r = 111, g = 194, b = 118)`
 ///
-/// Colour `XKCD_SOFT_GREEN` from the set `XKCD_COLORS`. (Colour number `790`)
+/// Colour `XKCD_SOFT_GREEN` from the set `XKCD_COLORS`. (Colour number `790`, name `xkcd:soft green`)
 /// ## Representations:
 /// - int tuple `(111, 194, 118)`
 /// - float tuple `(0.44, 0.76, 0.46)`
@@ -7158,7 +7316,7 @@ pub const XKCD_SOFT_GREEN: Color = Color(111, 194, 118);
 
 /// Colour `(r = 255, g = 91, b = 0)`
 ///
-/// Colour `XKCD_BRIGHT_ORANGE` from the set `XKCD_COLORS`. (Colour number `791`)
+/// Colour `XKCD_BRIGHT_ORANGE` from the set `XKCD_COLORS`. (Colour number `791`, name `xkcd:bright orange`)
 /// ## Representations:
 /// - int tuple `(255, 91, 0)`
 /// - float tuple `(1.0, 0.36, 0.0)`
@@ -7167,7 +7325,7 @@ pub const XKCD_BRIGHT_ORANGE: Color = Color(255, 91, 0);
 
 /// Colour `(r = 253, g = 255, b = 82)`
 ///
-/// Colour `XKCD_LEMON` from the set `XKCD_COLORS`. (Colour number `792`)
+/// Colour `XKCD_LEMON` from the set `XKCD_COLORS`. (Colour number `792`, name `xkcd:lemon`)
 /// ## Representations:
 /// - int tuple `(253, 255, 82)`
 /// - float tuple `(0.99, 1.0, 0.32)`
@@ -7176,7 +7334,7 @@ pub const XKCD_LEMON: Color = Color(253, 255, 82);
 
 /// Colour `(r = 134, g = 111, b = 133)`
 ///
-/// Colour `XKCD_PURPLE_GREY` from the set `XKCD_COLORS`. (Colour number `793`)
+/// Colour `XKCD_PURPLE_GREY` from the set `XKCD_COLORS`. (Colour number `793`, name `xkcd:purple grey`)
 /// ## Representations:
 /// - int tuple `(134, 111, 133)`
 /// - float tuple `(0.53, 0.44, 0.52)`
@@ -7185,7 +7343,7 @@ pub const XKCD_PURPLE_GREY: Color = Color(134, 111, 133);
 
 /// Colour `(r = 143, g = 254, b = 9)`
 ///
-/// Colour `XKCD_ACID_GREEN` from the set `XKCD_COLORS`. (Colour number `794`)
+/// Colour `XKCD_ACID_GREEN` from the set `XKCD_COLORS`. (Colour number `794`, name `xkcd:acid green`)
 /// ## Representations:
 /// - int tuple `(143, 254, 9)`
 /// - float tuple `(0.56, 1.0, 0.04)`
@@ -7194,7 +7352,7 @@ pub const XKCD_ACID_GREEN: Color = Color(143, 254, 9);
 
 /// Colour `(r = 238, g = 207, b = 254)`
 ///
-/// Colour `XKCD_PALE_LAVENDER` from the set `XKCD_COLORS`. (Colour number `795`)
+/// Colour `XKCD_PALE_LAVENDER` from the set `XKCD_COLORS`. (Colour number `795`, name `xkcd:pale lavender`)
 /// ## Representations:
 /// - int tuple `(238, 207, 254)`
 /// - float tuple `(0.93, 0.81, 1.0)`
@@ -7203,7 +7361,7 @@ pub const XKCD_PALE_LAVENDER: Color = Color(238, 207, 254);
 
 /// Colour `(r = 81, g = 10, b = 201)`
 ///
-/// Colour `XKCD_VIOLET_BLUE` from the set `XKCD_COLORS`. (Colour number `796`)
+/// Colour `XKCD_VIOLET_BLUE` from the set `XKCD_COLORS`. (Colour number `796`, name `xkcd:violet blue`)
 /// ## Representations:
 /// - int tuple `(81, 10, 201)`
 /// - float tuple `(0.32, 0.04, 0.79)`
@@ -7212,7 +7370,7 @@ pub const XKCD_VIOLET_BLUE: Color = Color(81, 10, 201);
 
 /// Colour `(r = 79, g = 145, b = 83)`
 ///
-/// Colour `XKCD_LIGHT_FOREST_GREEN` from the set `XKCD_COLORS`. (Colour number `797`)
+/// Colour `XKCD_LIGHT_FOREST_GREEN` from the set `XKCD_COLORS`. (Colour number `797`, name `xkcd:light forest green`)
 /// ## Representations:
 /// - int tuple `(79, 145, 83)`
 /// - float tuple `(0.31, 0.57, 0.33)`
@@ -7221,7 +7379,7 @@ pub const XKCD_LIGHT_FOREST_GREEN: Color = Color(79, 145, 83);
 
 /// Colour `(r = 159, g = 35, b = 5)`
 ///
-/// Colour `XKCD_BURNT_RED` from the set `XKCD_COLORS`. (Colour number `798`)
+/// Colour `XKCD_BURNT_RED` from the set `XKCD_COLORS`. (Colour number `798`, name `xkcd:burnt red`)
 /// ## Representations:
 /// - int tuple `(159, 35, 5)`
 /// - float tuple `(0.62, 0.14, 0.02)`
@@ -7230,7 +7388,7 @@ pub const XKCD_BURNT_RED: Color = Color(159, 35, 5);
 
 /// Colour `(r = 114, g = 134, b = 57)`
 ///
-/// Colour `XKCD_KHAKI_GREEN` from the set `XKCD_COLORS`. (Colour number `799`)
+/// Colour `XKCD_KHAKI_GREEN` from the set `XKCD_COLORS`. (Colour number `799`, name `xkcd:khaki green`)
 /// ## Representations:
 /// - int tuple `(114, 134, 57)`
 /// - float tuple `(0.45, 0.53, 0.22)`
@@ -7239,7 +7397,7 @@ pub const XKCD_KHAKI_GREEN: Color = Color(114, 134, 57);
 
 /// Colour `(r = 222, g = 12, b = 98)`
 ///
-/// Colour `XKCD_CERISE` from the set `XKCD_COLORS`. (Colour number `800`)
+/// Colour `XKCD_CERISE` from the set `XKCD_COLORS`. (Colour number `800`, name `xkcd:cerise`)
 /// ## Representations:
 /// - int tuple `(222, 12, 98)`
 /// - float tuple `(0.87, 0.05, 0.38)`
@@ -7248,7 +7406,7 @@ pub const XKCD_CERISE: Color = Color(222, 12, 98);
 
 /// Colour `(r = 145, g = 110, b = 153)`
 ///
-/// Colour `XKCD_FADED_PURPLE` from the set `XKCD_COLORS`. (Colour number `801`)
+/// Colour `XKCD_FADED_PURPLE` from the set `XKCD_COLORS`. (Colour number `801`, name `xkcd:faded purple`)
 /// ## Representations:
 /// - int tuple `(145, 110, 153)`
 /// - float tuple `(0.57, 0.43, 0.6)`
@@ -7257,7 +7415,7 @@ pub const XKCD_FADED_PURPLE: Color = Color(145, 110, 153);
 
 /// Colour `(r = 255, g = 177, b = 109)`
 ///
-/// Colour `XKCD_APRICOT` from the set `XKCD_COLORS`. (Colour number `802`)
+/// Colour `XKCD_APRICOT` from the set `XKCD_COLORS`. (Colour number `802`, name `xkcd:apricot`)
 /// ## Representations:
 /// - int tuple `(255, 177, 109)`
 /// - float tuple `(1.0, 0.69, 0.43)`
@@ -7266,7 +7424,7 @@ pub const XKCD_APRICOT: Color = Color(255, 177, 109);
 
 /// Colour `(r = 60, g = 77, b = 3)`
 ///
-/// Colour `XKCD_DARK_OLIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `803`)
+/// Colour `XKCD_DARK_OLIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `803`, name `xkcd:dark olive green`)
 /// ## Representations:
 /// - int tuple `(60, 77, 3)`
 /// - float tuple `(0.24, 0.3, 0.01)`
@@ -7275,7 +7433,7 @@ pub const XKCD_DARK_OLIVE_GREEN: Color = Color(60, 77, 3);
 
 /// Colour `(r = 127, g = 112, b = 83)`
 ///
-/// Colour `XKCD_GREY_BROWN` from the set `XKCD_COLORS`. (Colour number `804`)
+/// Colour `XKCD_GREY_BROWN` from the set `XKCD_COLORS`. (Colour number `804`, name `xkcd:grey brown`)
 /// ## Representations:
 /// - int tuple `(127, 112, 83)`
 /// - float tuple `(0.5, 0.44, 0.33)`
@@ -7284,7 +7442,7 @@ pub const XKCD_GREY_BROWN: Color = Color(127, 112, 83);
 
 /// Colour `(r = 119, g = 146, b = 111)`
 ///
-/// Colour `XKCD_GREEN_GREY` from the set `XKCD_COLORS`. (Colour number `805`)
+/// Colour `XKCD_GREEN_GREY` from the set `XKCD_COLORS`. (Colour number `805`, name `xkcd:green grey`)
 /// ## Representations:
 /// - int tuple `(119, 146, 111)`
 /// - float tuple `(0.47, 0.57, 0.44)`
@@ -7293,7 +7451,7 @@ pub const XKCD_GREEN_GREY: Color = Color(119, 146, 111);
 
 /// Colour `(r = 1, g = 15, b = 204)`
 ///
-/// Colour `XKCD_TRUE_BLUE` from the set `XKCD_COLORS`. (Colour number `806`)
+/// Colour `XKCD_TRUE_BLUE` from the set `XKCD_COLORS`. (Colour number `806`, name `xkcd:true blue`)
 /// ## Representations:
 /// - int tuple `(1, 15, 204)`
 /// - float tuple `(0.0, 0.06, 0.8)`
@@ -7302,7 +7460,7 @@ pub const XKCD_TRUE_BLUE: Color = Color(1, 15, 204);
 
 /// Colour `(r = 206, g = 174, b = 250)`
 ///
-/// Colour `XKCD_PALE_VIOLET` from the set `XKCD_COLORS`. (Colour number `807`)
+/// Colour `XKCD_PALE_VIOLET` from the set `XKCD_COLORS`. (Colour number `807`, name `xkcd:pale violet`)
 /// ## Representations:
 /// - int tuple `(206, 174, 250)`
 /// - float tuple `(0.81, 0.68, 0.98)`
@@ -7311,7 +7469,7 @@ pub const XKCD_PALE_VIOLET: Color = Color(206, 174, 250);
 
 /// Colour `(r = 143, g = 153, b = 251)`
 ///
-/// Colour `XKCD_PERIWINKLE_BLUE` from the set `XKCD_COLORS`. (Colour number `808`)
+/// Colour `XKCD_PERIWINKLE_BLUE` from the set `XKCD_COLORS`. (Colour number `808`, name `xkcd:periwinkle blue`)
 /// ## Representations:
 /// - int tuple `(143, 153, 251)`
 /// - float tuple `(0.56, 0.6, 0.98)`
@@ -7320,7 +7478,7 @@ pub const XKCD_PERIWINKLE_BLUE: Color = Color(143, 153, 251);
 
 /// Colour `(r = 198, g = 252, b = 255)`
 ///
-/// Colour `XKCD_LIGHT_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `809`)
+/// Colour `XKCD_LIGHT_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `809`, name `xkcd:light sky blue`)
 /// ## Representations:
 /// - int tuple `(198, 252, 255)`
 /// - float tuple `(0.78, 0.99, 1.0)`
@@ -7329,7 +7487,7 @@ pub const XKCD_LIGHT_SKY_BLUE: Color = Color(198, 252, 255);
 
 /// Colour `(r = 85, g = 57, b = 204)`
 ///
-/// Colour `XKCD_BLURPLE` from the set `XKCD_COLORS`. (Colour number `810`)
+/// Colour `XKCD_BLURPLE` from the set `XKCD_COLORS`. (Colour number `810`, name `xkcd:blurple`)
 /// ## Representations:
 /// - int tuple `(85, 57, 204)`
 /// - float tuple `(0.33, 0.22, 0.8)`
@@ -7338,7 +7496,7 @@ pub const XKCD_BLURPLE: Color = Color(85, 57, 204);
 
 /// Colour `(r = 84, g = 78, b = 3)`
 ///
-/// Colour `XKCD_GREEN_BROWN` from the set `XKCD_COLORS`. (Colour number `811`)
+/// Colour `XKCD_GREEN_BROWN` from the set `XKCD_COLORS`. (Colour number `811`, name `xkcd:green brown`)
 /// ## Representations:
 /// - int tuple `(84, 78, 3)`
 /// - float tuple `(0.33, 0.31, 0.01)`
@@ -7347,7 +7505,7 @@ pub const XKCD_GREEN_BROWN: Color = Color(84, 78, 3);
 
 /// Colour `(r = 1, g = 122, b = 121)`
 ///
-/// Colour `XKCD_BLUEGREEN` from the set `XKCD_COLORS`. (Colour number `812`)
+/// Colour `XKCD_BLUEGREEN` from the set `XKCD_COLORS`. (Colour number `812`, name `xkcd:bluegreen`)
 /// ## Representations:
 /// - int tuple `(1, 122, 121)`
 /// - float tuple `(0.0, 0.48, 0.47)`
@@ -7356,7 +7514,7 @@ pub const XKCD_BLUEGREEN: Color = Color(1, 122, 121);
 
 /// Colour `(r = 1, g = 249, b = 198)`
 ///
-/// Colour `XKCD_BRIGHT_TEAL` from the set `XKCD_COLORS`. (Colour number `813`)
+/// Colour `XKCD_BRIGHT_TEAL` from the set `XKCD_COLORS`. (Colour number `813`, name `xkcd:bright teal`)
 /// ## Representations:
 /// - int tuple `(1, 249, 198)`
 /// - float tuple `(0.0, 0.98, 0.78)`
@@ -7365,7 +7523,7 @@ pub const XKCD_BRIGHT_TEAL: Color = Color(1, 249, 198);
 
 /// Colour `(r = 201, g = 176, b = 3)`
 ///
-/// Colour `XKCD_BROWNISH_YELLOW` from the set `XKCD_COLORS`. (Colour number `814`)
+/// Colour `XKCD_BROWNISH_YELLOW` from the set `XKCD_COLORS`. (Colour number `814`, name `xkcd:brownish yellow`)
 /// ## Representations:
 /// - int tuple `(201, 176, 3)`
 /// - float tuple `(0.79, 0.69, 0.01)`
@@ -7374,7 +7532,7 @@ pub const XKCD_BROWNISH_YELLOW: Color = Color(201, 176, 3);
 
 /// Colour `(r = 146, g = 153, b = 1)`
 ///
-/// Colour `XKCD_PEA_SOUP` from the set `XKCD_COLORS`. (Colour number `815`)
+/// Colour `XKCD_PEA_SOUP` from the set `XKCD_COLORS`. (Colour number `815`, name `xkcd:pea soup`)
 /// ## Representations:
 /// - int tuple `(146, 153, 1)`
 /// - float tuple `(0.57, 0.6, 0.0)`
@@ -7383,7 +7541,7 @@ pub const XKCD_PEA_SOUP: Color = Color(146, 153, 1);
 
 /// Colour `(r = 11, g = 85, b = 9)`
 ///
-/// Colour `XKCD_FOREST` from the set `XKCD_COLORS`. (Colour number `816`)
+/// Colour `XKCD_FOREST` from the set `XKCD_COLORS`. (Colour number `816`, name `xkcd:forest`)
 /// ## Representations:
 /// - int tuple `(11, 85, 9)`
 /// - float tuple `(0.04, 0.33, 0.04)`
@@ -7392,7 +7550,7 @@ pub const XKCD_FOREST: Color = Color(11, 85, 9);
 
 /// Colour `(r = 160, g = 4, b = 152)`
 ///
-/// Colour `XKCD_BARNEY_PURPLE` from the set `XKCD_COLORS`. (Colour number `817`)
+/// Colour `XKCD_BARNEY_PURPLE` from the set `XKCD_COLORS`. (Colour number `817`, name `xkcd:barney purple`)
 /// ## Representations:
 /// - int tuple `(160, 4, 152)`
 /// - float tuple `(0.63, 0.02, 0.6)`
@@ -7401,7 +7559,7 @@ pub const XKCD_BARNEY_PURPLE: Color = Color(160, 4, 152);
 
 /// Colour `(r = 32, g = 0, b = 177)`
 ///
-/// Colour `XKCD_ULTRAMARINE` from the set `XKCD_COLORS`. (Colour number `818`)
+/// Colour `XKCD_ULTRAMARINE` from the set `XKCD_COLORS`. (Colour number `818`, name `xkcd:ultramarine`)
 /// ## Representations:
 /// - int tuple `(32, 0, 177)`
 /// - float tuple `(0.13, 0.0, 0.69)`
@@ -7410,7 +7568,7 @@ pub const XKCD_ULTRAMARINE: Color = Color(32, 0, 177);
 
 /// Colour `(r = 148, g = 86, b = 140)`
 ///
-/// Colour `XKCD_PURPLISH` from the set `XKCD_COLORS`. (Colour number `819`)
+/// Colour `XKCD_PURPLISH` from the set `XKCD_COLORS`. (Colour number `819`, name `xkcd:purplish`)
 /// ## Representations:
 /// - int tuple `(148, 86, 140)`
 /// - float tuple `(0.58, 0.34, 0.55)`
@@ -7419,7 +7577,7 @@ pub const XKCD_PURPLISH: Color = Color(148, 86, 140);
 
 /// Colour `(r = 194, g = 190, b = 14)`
 ///
-/// Colour `XKCD_PUKE_YELLOW` from the set `XKCD_COLORS`. (Colour number `820`)
+/// Colour `XKCD_PUKE_YELLOW` from the set `XKCD_COLORS`. (Colour number `820`, name `xkcd:puke yellow`)
 /// ## Representations:
 /// - int tuple `(194, 190, 14)`
 /// - float tuple `(0.76, 0.75, 0.05)`
@@ -7428,7 +7586,7 @@ pub const XKCD_PUKE_YELLOW: Color = Color(194, 190, 14);
 
 /// Colour `(r = 116, g = 139, b = 151)`
 ///
-/// Colour `XKCD_BLUISH_GREY` from the set `XKCD_COLORS`. (Colour number `821`)
+/// Colour `XKCD_BLUISH_GREY` from the set `XKCD_COLORS`. (Colour number `821`, name `xkcd:bluish grey`)
 /// ## Representations:
 /// - int tuple `(116, 139, 151)`
 /// - float tuple `(0.45, 0.55, 0.59)`
@@ -7437,7 +7595,7 @@ pub const XKCD_BLUISH_GREY: Color = Color(116, 139, 151);
 
 /// Colour `(r = 102, g = 95, b = 209)`
 ///
-/// Colour `XKCD_DARK_PERIWINKLE` from the set `XKCD_COLORS`. (Colour number `822`)
+/// Colour `XKCD_DARK_PERIWINKLE` from the set `XKCD_COLORS`. (Colour number `822`, name `xkcd:dark periwinkle`)
 /// ## Representations:
 /// - int tuple `(102, 95, 209)`
 /// - float tuple `(0.4, 0.37, 0.82)`
@@ -7446,7 +7604,7 @@ pub const XKCD_DARK_PERIWINKLE: Color = Color(102, 95, 209);
 
 /// Colour `(r = 156, g = 109, b = 165)`
 ///
-/// Colour `XKCD_DARK_LILAC` from the set `XKCD_COLORS`. (Colour number `823`)
+/// Colour `XKCD_DARK_LILAC` from the set `XKCD_COLORS`. (Colour number `823`, name `xkcd:dark lilac`)
 /// ## Representations:
 /// - int tuple `(156, 109, 165)`
 /// - float tuple `(0.61, 0.43, 0.65)`
@@ -7455,7 +7613,7 @@ pub const XKCD_DARK_LILAC: Color = Color(156, 109, 165);
 
 /// Colour `(r = 196, g = 66, b = 64)`
 ///
-/// Colour `XKCD_REDDISH` from the set `XKCD_COLORS`. (Colour number `824`)
+/// Colour `XKCD_REDDISH` from the set `XKCD_COLORS`. (Colour number `824`, name `xkcd:reddish`)
 /// ## Representations:
 /// - int tuple `(196, 66, 64)`
 /// - float tuple `(0.77, 0.26, 0.25)`
@@ -7464,7 +7622,7 @@ pub const XKCD_REDDISH: Color = Color(196, 66, 64);
 
 /// Colour `(r = 162, g = 72, b = 87)`
 ///
-/// Colour `XKCD_LIGHT_MAROON` from the set `XKCD_COLORS`. (Colour number `825`)
+/// Colour `XKCD_LIGHT_MAROON` from the set `XKCD_COLORS`. (Colour number `825`, name `xkcd:light maroon`)
 /// ## Representations:
 /// - int tuple `(162, 72, 87)`
 /// - float tuple `(0.64, 0.28, 0.34)`
@@ -7473,7 +7631,7 @@ pub const XKCD_LIGHT_MAROON: Color = Color(162, 72, 87);
 
 /// Colour `(r = 130, g = 95, b = 135)`
 ///
-/// Colour `XKCD_DUSTY_PURPLE` from the set `XKCD_COLORS`. (Colour number `826`)
+/// Colour `XKCD_DUSTY_PURPLE` from the set `XKCD_COLORS`. (Colour number `826`, name `xkcd:dusty purple`)
 /// ## Representations:
 /// - int tuple `(130, 95, 135)`
 /// - float tuple `(0.51, 0.37, 0.53)`
@@ -7482,7 +7640,7 @@ pub const XKCD_DUSTY_PURPLE: Color = Color(130, 95, 135);
 
 /// Colour `(r = 201, g = 100, b = 59)`
 ///
-/// Colour `XKCD_TERRA_COTTA` from the set `XKCD_COLORS`. (Colour number `827`)
+/// Colour `XKCD_TERRA_COTTA` from the set `XKCD_COLORS`. (Colour number `827`, name `xkcd:terra cotta`)
 /// ## Representations:
 /// - int tuple `(201, 100, 59)`
 /// - float tuple `(0.79, 0.39, 0.23)`
@@ -7491,7 +7649,7 @@ pub const XKCD_TERRA_COTTA: Color = Color(201, 100, 59);
 
 /// Colour `(r = 144, g = 177, b = 52)`
 ///
-/// Colour `XKCD_AVOCADO` from the set `XKCD_COLORS`. (Colour number `828`)
+/// Colour `XKCD_AVOCADO` from the set `XKCD_COLORS`. (Colour number `828`, name `xkcd:avocado`)
 /// ## Representations:
 /// - int tuple `(144, 177, 52)`
 /// - float tuple `(0.56, 0.69, 0.2)`
@@ -7500,7 +7658,7 @@ pub const XKCD_AVOCADO: Color = Color(144, 177, 52);
 
 /// Colour `(r = 1, g = 56, b = 106)`
 ///
-/// Colour `XKCD_MARINE_BLUE` from the set `XKCD_COLORS`. (Colour number `829`)
+/// Colour `XKCD_MARINE_BLUE` from the set `XKCD_COLORS`. (Colour number `829`, name `xkcd:marine blue`)
 /// ## Representations:
 /// - int tuple `(1, 56, 106)`
 /// - float tuple `(0.0, 0.22, 0.42)`
@@ -7509,7 +7667,7 @@ pub const XKCD_MARINE_BLUE: Color = Color(1, 56, 106);
 
 /// Colour `(r = 37, g = 163, b = 111)`
 ///
-/// Colour `XKCD_TEAL_GREEN` from the set `XKCD_COLORS`. (Colour number `830`)
+/// Colour `XKCD_TEAL_GREEN` from the set `XKCD_COLORS`. (Colour number `830`, name `xkcd:teal green`)
 /// ## Representations:
 /// - int tuple `(37, 163, 111)`
 /// - float tuple `(0.15, 0.64, 0.44)`
@@ -7518,7 +7676,7 @@ pub const XKCD_TEAL_GREEN: Color = Color(37, 163, 111);
 
 /// Colour `(r = 89, g = 101, b = 109)`
 ///
-/// Colour `XKCD_SLATE_GREY` from the set `XKCD_COLORS`. (Colour number `831`)
+/// Colour `XKCD_SLATE_GREY` from the set `XKCD_COLORS`. (Colour number `831`, name `xkcd:slate grey`)
 /// ## Representations:
 /// - int tuple `(89, 101, 109)`
 /// - float tuple `(0.35, 0.4, 0.43)`
@@ -7527,7 +7685,7 @@ pub const XKCD_SLATE_GREY: Color = Color(89, 101, 109);
 
 /// Colour `(r = 117, g = 253, b = 99)`
 ///
-/// Colour `XKCD_LIGHTER_GREEN` from the set `XKCD_COLORS`. (Colour number `832`)
+/// Colour `XKCD_LIGHTER_GREEN` from the set `XKCD_COLORS`. (Colour number `832`, name `xkcd:lighter green`)
 /// ## Representations:
 /// - int tuple `(117, 253, 99)`
 /// - float tuple `(0.46, 0.99, 0.39)`
@@ -7536,7 +7694,7 @@ pub const XKCD_LIGHTER_GREEN: Color = Color(117, 253, 99);
 
 /// Colour `(r = 33, g = 252, b = 13)`
 ///
-/// Colour `XKCD_ELECTRIC_GREEN` from the set `XKCD_COLORS`. (Colour number `833`)
+/// Colour `XKCD_ELECTRIC_GREEN` from the set `XKCD_COLORS`. (Colour number `833`, name `xkcd:electric green`)
 /// ## Representations:
 /// - int tuple `(33, 252, 13)`
 /// - float tuple `(0.13, 0.99, 0.05)`
@@ -7545,7 +7703,7 @@ pub const XKCD_ELECTRIC_GREEN: Color = Color(33, 252, 13);
 
 /// Colour `(r = 90, g = 134, b = 173)`
 ///
-/// Colour `XKCD_DUSTY_BLUE` from the set `XKCD_COLORS`. (Colour number `834`)
+/// Colour `XKCD_DUSTY_BLUE` from the set `XKCD_COLORS`. (Colour number `834`, name `xkcd:dusty blue`)
 /// ## Representations:
 /// - int tuple `(90, 134, 173)`
 /// - float tuple `(0.35, 0.53, 0.68)`
@@ -7554,7 +7712,7 @@ pub const XKCD_DUSTY_BLUE: Color = Color(90, 134, 173);
 
 /// Colour `(r = 254, g = 198, b = 21)`
 ///
-/// Colour `XKCD_GOLDEN_YELLOW` from the set `XKCD_COLORS`. (Colour number `835`)
+/// Colour `XKCD_GOLDEN_YELLOW` from the set `XKCD_COLORS`. (Colour number `835`, name `xkcd:golden yellow`)
 /// ## Representations:
 /// - int tuple `(254, 198, 21)`
 /// - float tuple `(1.0, 0.78, 0.08)`
@@ -7563,7 +7721,7 @@ pub const XKCD_GOLDEN_YELLOW: Color = Color(254, 198, 21);
 
 /// Colour `(r = 255, g = 253, b = 1)`
 ///
-/// Colour `XKCD_BRIGHT_YELLOW` from the set `XKCD_COLORS`. (Colour number `836`)
+/// Colour `XKCD_BRIGHT_YELLOW` from the set `XKCD_COLORS`. (Colour number `836`, name `xkcd:bright yellow`)
 /// ## Representations:
 /// - int tuple `(255, 253, 1)`
 /// - float tuple `(1.0, 0.99, 0.0)`
@@ -7572,7 +7730,7 @@ pub const XKCD_BRIGHT_YELLOW: Color = Color(255, 253, 1);
 
 /// Colour `(r = 223, g = 197, b = 254)`
 ///
-/// Colour `XKCD_LIGHT_LAVENDER` from the set `XKCD_COLORS`. (Colour number `837`)
+/// Colour `XKCD_LIGHT_LAVENDER` from the set `XKCD_COLORS`. (Colour number `837`, name `xkcd:light lavender`)
 /// ## Representations:
 /// - int tuple `(223, 197, 254)`
 /// - float tuple `(0.87, 0.77, 1.0)`
@@ -7581,7 +7739,7 @@ pub const XKCD_LIGHT_LAVENDER: Color = Color(223, 197, 254);
 
 /// Colour `(r = 178, g = 100, b = 0)`
 ///
-/// Colour `XKCD_UMBER` from the set `XKCD_COLORS`. (Colour number `838`)
+/// Colour `XKCD_UMBER` from the set `XKCD_COLORS`. (Colour number `838`, name `xkcd:umber`)
 /// ## Representations:
 /// - int tuple `(178, 100, 0)`
 /// - float tuple `(0.7, 0.39, 0.0)`
@@ -7590,7 +7748,7 @@ pub const XKCD_UMBER: Color = Color(178, 100, 0);
 
 /// Colour `(r = 127, g = 94, b = 0)`
 ///
-/// Colour `XKCD_POOP` from the set `XKCD_COLORS`. (Colour number `839`)
+/// Colour `XKCD_POOP` from the set `XKCD_COLORS`. (Colour number `839`, name `xkcd:poop`)
 /// ## Representations:
 /// - int tuple `(127, 94, 0)`
 /// - float tuple `(0.5, 0.37, 0.0)`
@@ -7599,7 +7757,7 @@ pub const XKCD_POOP: Color = Color(127, 94, 0);
 
 /// Colour `(r = 222, g = 126, b = 93)`
 ///
-/// Colour `XKCD_DARK_PEACH` from the set `XKCD_COLORS`. (Colour number `840`)
+/// Colour `XKCD_DARK_PEACH` from the set `XKCD_COLORS`. (Colour number `840`, name `xkcd:dark peach`)
 /// ## Representations:
 /// - int tuple `(222, 126, 93)`
 /// - float tuple `(0.87, 0.49, 0.36)`
@@ -7608,7 +7766,7 @@ pub const XKCD_DARK_PEACH: Color = Color(222, 126, 93);
 
 /// Colour `(r = 4, g = 130, b = 67)`
 ///
-/// Colour `XKCD_JUNGLE_GREEN` from the set `XKCD_COLORS`. (Colour number `841`)
+/// Colour `XKCD_JUNGLE_GREEN` from the set `XKCD_COLORS`. (Colour number `841`, name `xkcd:jungle green`)
 /// ## Representations:
 /// - int tuple `(4, 130, 67)`
 /// - float tuple `(0.02, 0.51, 0.26)`
@@ -7617,7 +7775,7 @@ pub const XKCD_JUNGLE_GREEN: Color = Color(4, 130, 67);
 
 /// Colour `(r = 255, g = 255, b = 212)`
 ///
-/// Colour `XKCD_EGGSHELL` from the set `XKCD_COLORS`. (Colour number `842`)
+/// Colour `XKCD_EGGSHELL` from the set `XKCD_COLORS`. (Colour number `842`, name `xkcd:eggshell`)
 /// ## Representations:
 /// - int tuple `(255, 255, 212)`
 /// - float tuple `(1.0, 1.0, 0.83)`
@@ -7626,7 +7784,7 @@ pub const XKCD_EGGSHELL: Color = Color(255, 255, 212);
 
 /// Colour `(r = 59, g = 99, b = 140)`
 ///
-/// Colour `XKCD_DENIM` from the set `XKCD_COLORS`. (Colour number `843`)
+/// Colour `XKCD_DENIM` from the set `XKCD_COLORS`. (Colour number `843`, name `xkcd:denim`)
 /// ## Representations:
 /// - int tuple `(59, 99, 140)`
 /// - float tuple `(0.23, 0.39, 0.55)`
@@ -7635,7 +7793,7 @@ pub const XKCD_DENIM: Color = Color(59, 99, 140);
 
 /// Colour `(r = 183, g = 148, b = 0)`
 ///
-/// Colour `XKCD_YELLOW_BROWN` from the set `XKCD_COLORS`. (Colour number `844`)
+/// Colour `XKCD_YELLOW_BROWN` from the set `XKCD_COLORS`. (Colour number `844`, name `xkcd:yellow brown`)
 /// ## Representations:
 /// - int tuple `(183, 148, 0)`
 /// - float tuple `(0.72, 0.58, 0.0)`
@@ -7644,7 +7802,7 @@ pub const XKCD_YELLOW_BROWN: Color = Color(183, 148, 0);
 
 /// Colour `(r = 132, g = 89, b = 126)`
 ///
-/// Colour `XKCD_DULL_PURPLE` from the set `XKCD_COLORS`. (Colour number `845`)
+/// Colour `XKCD_DULL_PURPLE` from the set `XKCD_COLORS`. (Colour number `845`, name `xkcd:dull purple`)
 /// ## Representations:
 /// - int tuple `(132, 89, 126)`
 /// - float tuple `(0.52, 0.35, 0.49)`
@@ -7653,7 +7811,7 @@ pub const XKCD_DULL_PURPLE: Color = Color(132, 89, 126);
 
 /// Colour `(r = 65, g = 25, b = 0)`
 ///
-/// Colour `XKCD_CHOCOLATE_BROWN` from the set `XKCD_COLORS`. (Colour number `846`)
+/// Colour `XKCD_CHOCOLATE_BROWN` from the set `XKCD_COLORS`. (Colour number `846`, name `xkcd:chocolate brown`)
 /// ## Representations:
 /// - int tuple `(65, 25, 0)`
 /// - float tuple `(0.25, 0.1, 0.0)`
@@ -7662,7 +7820,7 @@ pub const XKCD_CHOCOLATE_BROWN: Color = Color(65, 25, 0);
 
 /// Colour `(r = 123, g = 3, b = 35)`
 ///
-/// Colour `XKCD_WINE_RED` from the set `XKCD_COLORS`. (Colour number `847`)
+/// Colour `XKCD_WINE_RED` from the set `XKCD_COLORS`. (Colour number `847`, name `xkcd:wine red`)
 /// ## Representations:
 /// - int tuple `(123, 3, 35)`
 /// - float tuple `(0.48, 0.01, 0.14)`
@@ -7671,7 +7829,7 @@ pub const XKCD_WINE_RED: Color = Color(123, 3, 35);
 
 /// Colour `(r = 4, g = 217, b = 255)`
 ///
-/// Colour `XKCD_NEON_BLUE` from the set `XKCD_COLORS`. (Colour number `848`)
+/// Colour `XKCD_NEON_BLUE` from the set `XKCD_COLORS`. (Colour number `848`, name `xkcd:neon blue`)
 /// ## Representations:
 /// - int tuple `(4, 217, 255)`
 /// - float tuple `(0.02, 0.85, 1.0)`
@@ -7680,7 +7838,7 @@ pub const XKCD_NEON_BLUE: Color = Color(4, 217, 255);
 
 /// Colour `(r = 102, g = 126, b = 44)`
 ///
-/// Colour `XKCD_DIRTY_GREEN` from the set `XKCD_COLORS`. (Colour number `849`)
+/// Colour `XKCD_DIRTY_GREEN` from the set `XKCD_COLORS`. (Colour number `849`, name `xkcd:dirty green`)
 /// ## Representations:
 /// - int tuple `(102, 126, 44)`
 /// - float tuple `(0.4, 0.49, 0.17)`
@@ -7689,7 +7847,7 @@ pub const XKCD_DIRTY_GREEN: Color = Color(102, 126, 44);
 
 /// Colour `(r = 251, g = 238, b = 172)`
 ///
-/// Colour `XKCD_LIGHT_TAN` from the set `XKCD_COLORS`. (Colour number `850`)
+/// Colour `XKCD_LIGHT_TAN` from the set `XKCD_COLORS`. (Colour number `850`, name `xkcd:light tan`)
 /// ## Representations:
 /// - int tuple `(251, 238, 172)`
 /// - float tuple `(0.98, 0.93, 0.67)`
@@ -7698,7 +7856,7 @@ pub const XKCD_LIGHT_TAN: Color = Color(251, 238, 172);
 
 /// Colour `(r = 215, g = 255, b = 254)`
 ///
-/// Colour `XKCD_ICE_BLUE` from the set `XKCD_COLORS`. (Colour number `851`)
+/// Colour `XKCD_ICE_BLUE` from the set `XKCD_COLORS`. (Colour number `851`, name `xkcd:ice blue`)
 /// ## Representations:
 /// - int tuple `(215, 255, 254)`
 /// - float tuple `(0.84, 1.0, 1.0)`
@@ -7707,7 +7865,7 @@ pub const XKCD_ICE_BLUE: Color = Color(215, 255, 254);
 
 /// Colour `(r = 78, g = 116, b = 150)`
 ///
-/// Colour `XKCD_CADET_BLUE` from the set `XKCD_COLORS`. (Colour number `852`)
+/// Colour `XKCD_CADET_BLUE` from the set `XKCD_COLORS`. (Colour number `852`, name `xkcd:cadet blue`)
 /// ## Representations:
 /// - int tuple `(78, 116, 150)`
 /// - float tuple `(0.31, 0.45, 0.59)`
@@ -7716,7 +7874,7 @@ pub const XKCD_CADET_BLUE: Color = Color(78, 116, 150);
 
 /// Colour `(r = 135, g = 76, b = 98)`
 ///
-/// Colour `XKCD_DARK_MAUVE` from the set `XKCD_COLORS`. (Colour number `853`)
+/// Colour `XKCD_DARK_MAUVE` from the set `XKCD_COLORS`. (Colour number `853`, name `xkcd:dark mauve`)
 /// ## Representations:
 /// - int tuple `(135, 76, 98)`
 /// - float tuple `(0.53, 0.3, 0.38)`
@@ -7725,7 +7883,7 @@ pub const XKCD_DARK_MAUVE: Color = Color(135, 76, 98);
 
 /// Colour `(r = 213, g = 255, b = 255)`
 ///
-/// Colour `XKCD_VERY_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `854`)
+/// Colour `XKCD_VERY_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `854`, name `xkcd:very light blue`)
 /// ## Representations:
 /// - int tuple `(213, 255, 255)`
 /// - float tuple `(0.84, 1.0, 1.0)`
@@ -7734,7 +7892,7 @@ pub const XKCD_VERY_LIGHT_BLUE: Color = Color(213, 255, 255);
 
 /// Colour `(r = 130, g = 109, b = 140)`
 ///
-/// Colour `XKCD_GREY_PURPLE` from the set `XKCD_COLORS`. (Colour number `855`)
+/// Colour `XKCD_GREY_PURPLE` from the set `XKCD_COLORS`. (Colour number `855`, name `xkcd:grey purple`)
 /// ## Representations:
 /// - int tuple `(130, 109, 140)`
 /// - float tuple `(0.51, 0.43, 0.55)`
@@ -7743,7 +7901,7 @@ pub const XKCD_GREY_PURPLE: Color = Color(130, 109, 140);
 
 /// Colour `(r = 255, g = 186, b = 205)`
 ///
-/// Colour `XKCD_PASTEL_PINK` from the set `XKCD_COLORS`. (Colour number `856`)
+/// Colour `XKCD_PASTEL_PINK` from the set `XKCD_COLORS`. (Colour number `856`, name `xkcd:pastel pink`)
 /// ## Representations:
 /// - int tuple `(255, 186, 205)`
 /// - float tuple `(1.0, 0.73, 0.8)`
@@ -7752,7 +7910,7 @@ pub const XKCD_PASTEL_PINK: Color = Color(255, 186, 205);
 
 /// Colour `(r = 209, g = 255, b = 189)`
 ///
-/// Colour `XKCD_VERY_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `857`)
+/// Colour `XKCD_VERY_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `857`, name `xkcd:very light green`)
 /// ## Representations:
 /// - int tuple `(209, 255, 189)`
 /// - float tuple `(0.82, 1.0, 0.74)`
@@ -7761,7 +7919,7 @@ pub const XKCD_VERY_LIGHT_GREEN: Color = Color(209, 255, 189);
 
 /// Colour `(r = 68, g = 142, b = 228)`
 ///
-/// Colour `XKCD_DARK_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `858`)
+/// Colour `XKCD_DARK_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `858`, name `xkcd:dark sky blue`)
 /// ## Representations:
 /// - int tuple `(68, 142, 228)`
 /// - float tuple `(0.27, 0.56, 0.89)`
@@ -7770,7 +7928,7 @@ pub const XKCD_DARK_SKY_BLUE: Color = Color(68, 142, 228);
 
 /// Colour `(r = 5, g = 71, b = 42)`
 ///
-/// Colour `XKCD_EVERGREEN` from the set `XKCD_COLORS`. (Colour number `859`)
+/// Colour `XKCD_EVERGREEN` from the set `XKCD_COLORS`. (Colour number `859`, name `xkcd:evergreen`)
 /// ## Representations:
 /// - int tuple `(5, 71, 42)`
 /// - float tuple `(0.02, 0.28, 0.16)`
@@ -7779,7 +7937,7 @@ pub const XKCD_EVERGREEN: Color = Color(5, 71, 42);
 
 /// Colour `(r = 213, g = 134, b = 157)`
 ///
-/// Colour `XKCD_DULL_PINK` from the set `XKCD_COLORS`. (Colour number `860`)
+/// Colour `XKCD_DULL_PINK` from the set `XKCD_COLORS`. (Colour number `860`, name `xkcd:dull pink`)
 /// ## Representations:
 /// - int tuple `(213, 134, 157)`
 /// - float tuple `(0.84, 0.53, 0.62)`
@@ -7788,7 +7946,7 @@ pub const XKCD_DULL_PINK: Color = Color(213, 134, 157);
 
 /// Colour `(r = 61, g = 7, b = 52)`
 ///
-/// Colour `XKCD_AUBERGINE` from the set `XKCD_COLORS`. (Colour number `861`)
+/// Colour `XKCD_AUBERGINE` from the set `XKCD_COLORS`. (Colour number `861`, name `xkcd:aubergine`)
 /// ## Representations:
 /// - int tuple `(61, 7, 52)`
 /// - float tuple `(0.24, 0.03, 0.2)`
@@ -7797,7 +7955,7 @@ pub const XKCD_AUBERGINE: Color = Color(61, 7, 52);
 
 /// Colour `(r = 74, g = 1, b = 0)`
 ///
-/// Colour `XKCD_MAHOGANY` from the set `XKCD_COLORS`. (Colour number `862`)
+/// Colour `XKCD_MAHOGANY` from the set `XKCD_COLORS`. (Colour number `862`, name `xkcd:mahogany`)
 /// ## Representations:
 /// - int tuple `(74, 1, 0)`
 /// - float tuple `(0.29, 0.0, 0.0)`
@@ -7806,7 +7964,7 @@ pub const XKCD_MAHOGANY: Color = Color(74, 1, 0);
 
 /// Colour `(r = 248, g = 72, b = 28)`
 ///
-/// Colour `XKCD_REDDISH_ORANGE` from the set `XKCD_COLORS`. (Colour number `863`)
+/// Colour `XKCD_REDDISH_ORANGE` from the set `XKCD_COLORS`. (Colour number `863`, name `xkcd:reddish orange`)
 /// ## Representations:
 /// - int tuple `(248, 72, 28)`
 /// - float tuple `(0.97, 0.28, 0.11)`
@@ -7815,7 +7973,7 @@ pub const XKCD_REDDISH_ORANGE: Color = Color(248, 72, 28);
 
 /// Colour `(r = 2, g = 89, b = 15)`
 ///
-/// Colour `XKCD_DEEP_GREEN` from the set `XKCD_COLORS`. (Colour number `864`)
+/// Colour `XKCD_DEEP_GREEN` from the set `XKCD_COLORS`. (Colour number `864`, name `xkcd:deep green`)
 /// ## Representations:
 /// - int tuple `(2, 89, 15)`
 /// - float tuple `(0.01, 0.35, 0.06)`
@@ -7824,7 +7982,7 @@ pub const XKCD_DEEP_GREEN: Color = Color(2, 89, 15);
 
 /// Colour `(r = 137, g = 162, b = 3)`
 ///
-/// Colour `XKCD_VOMIT_GREEN` from the set `XKCD_COLORS`. (Colour number `865`)
+/// Colour `XKCD_VOMIT_GREEN` from the set `XKCD_COLORS`. (Colour number `865`, name `xkcd:vomit green`)
 /// ## Representations:
 /// - int tuple `(137, 162, 3)`
 /// - float tuple `(0.54, 0.64, 0.01)`
@@ -7833,7 +7991,7 @@ pub const XKCD_VOMIT_GREEN: Color = Color(137, 162, 3);
 
 /// Colour `(r = 224, g = 63, b = 216)`
 ///
-/// Colour `XKCD_PURPLE_PINK` from the set `XKCD_COLORS`. (Colour number `866`)
+/// Colour `XKCD_PURPLE_PINK` from the set `XKCD_COLORS`. (Colour number `866`, name `xkcd:purple pink`)
 /// ## Representations:
 /// - int tuple `(224, 63, 216)`
 /// - float tuple `(0.88, 0.25, 0.85)`
@@ -7842,7 +8000,7 @@ pub const XKCD_PURPLE_PINK: Color = Color(224, 63, 216);
 
 /// Colour `(r = 213, g = 138, b = 148)`
 ///
-/// Colour `XKCD_DUSTY_PINK` from the set `XKCD_COLORS`. (Colour number `867`)
+/// Colour `XKCD_DUSTY_PINK` from the set `XKCD_COLORS`. (Colour number `867`, name `xkcd:dusty pink`)
 /// ## Representations:
 /// - int tuple `(213, 138, 148)`
 /// - float tuple `(0.84, 0.54, 0.58)`
@@ -7851,7 +8009,7 @@ pub const XKCD_DUSTY_PINK: Color = Color(213, 138, 148);
 
 /// Colour `(r = 123, g = 178, b = 116)`
 ///
-/// Colour `XKCD_FADED_GREEN` from the set `XKCD_COLORS`. (Colour number `868`)
+/// Colour `XKCD_FADED_GREEN` from the set `XKCD_COLORS`. (Colour number `868`, name `xkcd:faded green`)
 /// ## Representations:
 /// - int tuple `(123, 178, 116)`
 /// - float tuple `(0.48, 0.7, 0.45)`
@@ -7860,7 +8018,7 @@ pub const XKCD_FADED_GREEN: Color = Color(123, 178, 116);
 
 /// Colour `(r = 82, g = 101, b = 37)`
 ///
-/// Colour `XKCD_CAMO_GREEN` from the set `XKCD_COLORS`. (Colour number `869`)
+/// Colour `XKCD_CAMO_GREEN` from the set `XKCD_COLORS`. (Colour number `869`, name `xkcd:camo green`)
 /// ## Representations:
 /// - int tuple `(82, 101, 37)`
 /// - float tuple `(0.32, 0.4, 0.15)`
@@ -7869,7 +8027,7 @@ pub const XKCD_CAMO_GREEN: Color = Color(82, 101, 37);
 
 /// Colour `(r = 201, g = 76, b = 190)`
 ///
-/// Colour `XKCD_PINKY_PURPLE` from the set `XKCD_COLORS`. (Colour number `870`)
+/// Colour `XKCD_PINKY_PURPLE` from the set `XKCD_COLORS`. (Colour number `870`, name `xkcd:pinky purple`)
 /// ## Representations:
 /// - int tuple `(201, 76, 190)`
 /// - float tuple `(0.79, 0.3, 0.75)`
@@ -7878,7 +8036,7 @@ pub const XKCD_PINKY_PURPLE: Color = Color(201, 76, 190);
 
 /// Colour `(r = 219, g = 75, b = 218)`
 ///
-/// Colour `XKCD_PINK_PURPLE` from the set `XKCD_COLORS`. (Colour number `871`)
+/// Colour `XKCD_PINK_PURPLE` from the set `XKCD_COLORS`. (Colour number `871`, name `xkcd:pink purple`)
 /// ## Representations:
 /// - int tuple `(219, 75, 218)`
 /// - float tuple `(0.86, 0.29, 0.85)`
@@ -7887,7 +8045,7 @@ pub const XKCD_PINK_PURPLE: Color = Color(219, 75, 218);
 
 /// Colour `(r = 158, g = 54, b = 35)`
 ///
-/// Colour `XKCD_BROWNISH_RED` from the set `XKCD_COLORS`. (Colour number `872`)
+/// Colour `XKCD_BROWNISH_RED` from the set `XKCD_COLORS`. (Colour number `872`, name `xkcd:brownish red`)
 /// ## Representations:
 /// - int tuple `(158, 54, 35)`
 /// - float tuple `(0.62, 0.21, 0.14)`
@@ -7896,7 +8054,7 @@ pub const XKCD_BROWNISH_RED: Color = Color(158, 54, 35);
 
 /// Colour `(r = 181, g = 72, b = 93)`
 ///
-/// Colour `XKCD_DARK_ROSE` from the set `XKCD_COLORS`. (Colour number `873`)
+/// Colour `XKCD_DARK_ROSE` from the set `XKCD_COLORS`. (Colour number `873`, name `xkcd:dark rose`)
 /// ## Representations:
 /// - int tuple `(181, 72, 93)`
 /// - float tuple `(0.71, 0.28, 0.36)`
@@ -7905,7 +8063,7 @@ pub const XKCD_DARK_ROSE: Color = Color(181, 72, 93);
 
 /// Colour `(r = 115, g = 92, b = 18)`
 ///
-/// Colour `XKCD_MUD` from the set `XKCD_COLORS`. (Colour number `874`)
+/// Colour `XKCD_MUD` from the set `XKCD_COLORS`. (Colour number `874`, name `xkcd:mud`)
 /// ## Representations:
 /// - int tuple `(115, 92, 18)`
 /// - float tuple `(0.45, 0.36, 0.07)`
@@ -7914,7 +8072,7 @@ pub const XKCD_MUD: Color = Color(115, 92, 18);
 
 /// Colour `(r = 156, g = 109, b = 87)`
 ///
-/// Colour `XKCD_BROWNISH` from the set `XKCD_COLORS`. (Colour number `875`)
+/// Colour `XKCD_BROWNISH` from the set `XKCD_COLORS`. (Colour number `875`, name `xkcd:brownish`)
 /// ## Representations:
 /// - int tuple `(156, 109, 87)`
 /// - float tuple `(0.61, 0.43, 0.34)`
@@ -7923,7 +8081,7 @@ pub const XKCD_BROWNISH: Color = Color(156, 109, 87);
 
 /// Colour `(r = 2, g = 143, b = 30)`
 ///
-/// Colour `XKCD_EMERALD_GREEN` from the set `XKCD_COLORS`. (Colour number `876`)
+/// Colour `XKCD_EMERALD_GREEN` from the set `XKCD_COLORS`. (Colour number `876`, name `xkcd:emerald green`)
 /// ## Representations:
 /// - int tuple `(2, 143, 30)`
 /// - float tuple `(0.01, 0.56, 0.12)`
@@ -7932,7 +8090,7 @@ pub const XKCD_EMERALD_GREEN: Color = Color(2, 143, 30);
 
 /// Colour `(r = 177, g = 145, b = 110)`
 ///
-/// Colour `XKCD_PALE_BROWN` from the set `XKCD_COLORS`. (Colour number `877`)
+/// Colour `XKCD_PALE_BROWN` from the set `XKCD_COLORS`. (Colour number `877`, name `xkcd:pale brown`)
 /// ## Representations:
 /// - int tuple `(177, 145, 110)`
 /// - float tuple `(0.69, 0.57, 0.43)`
@@ -7941,7 +8099,7 @@ pub const XKCD_PALE_BROWN: Color = Color(177, 145, 110);
 
 /// Colour `(r = 73, g = 117, b = 156)`
 ///
-/// Colour `XKCD_DULL_BLUE` from the set `XKCD_COLORS`. (Colour number `878`)
+/// Colour `XKCD_DULL_BLUE` from the set `XKCD_COLORS`. (Colour number `878`, name `xkcd:dull blue`)
 /// ## Representations:
 /// - int tuple `(73, 117, 156)`
 /// - float tuple `(0.29, 0.46, 0.61)`
@@ -7950,7 +8108,7 @@ pub const XKCD_DULL_BLUE: Color = Color(73, 117, 156);
 
 /// Colour `(r = 160, g = 69, b = 14)`
 ///
-/// Colour `XKCD_BURNT_UMBER` from the set `XKCD_COLORS`. (Colour number `879`)
+/// Colour `XKCD_BURNT_UMBER` from the set `XKCD_COLORS`. (Colour number `879`, name `xkcd:burnt umber`)
 /// ## Representations:
 /// - int tuple `(160, 69, 14)`
 /// - float tuple `(0.63, 0.27, 0.05)`
@@ -7959,7 +8117,7 @@ pub const XKCD_BURNT_UMBER: Color = Color(160, 69, 14);
 
 /// Colour `(r = 57, g = 173, b = 72)`
 ///
-/// Colour `XKCD_MEDIUM_GREEN` from the set `XKCD_COLORS`. (Colour number `880`)
+/// Colour `XKCD_MEDIUM_GREEN` from the set `XKCD_COLORS`. (Colour number `880`, name `xkcd:medium green`)
 /// ## Representations:
 /// - int tuple `(57, 173, 72)`
 /// - float tuple `(0.22, 0.68, 0.28)`
@@ -7968,7 +8126,7 @@ pub const XKCD_MEDIUM_GREEN: Color = Color(57, 173, 72);
 
 /// Colour `(r = 182, g = 106, b = 80)`
 ///
-/// Colour `XKCD_CLAY` from the set `XKCD_COLORS`. (Colour number `881`)
+/// Colour `XKCD_CLAY` from the set `XKCD_COLORS`. (Colour number `881`, name `xkcd:clay`)
 /// ## Representations:
 /// - int tuple `(182, 106, 80)`
 /// - float tuple `(0.71, 0.42, 0.31)`
@@ -7977,7 +8135,7 @@ pub const XKCD_CLAY: Color = Color(182, 106, 80);
 
 /// Colour `(r = 140, g = 255, b = 219)`
 ///
-/// Colour `XKCD_LIGHT_AQUA` from the set `XKCD_COLORS`. (Colour number `882`)
+/// Colour `XKCD_LIGHT_AQUA` from the set `XKCD_COLORS`. (Colour number `882`, name `xkcd:light aqua`)
 /// ## Representations:
 /// - int tuple `(140, 255, 219)`
 /// - float tuple `(0.55, 1.0, 0.86)`
@@ -7986,7 +8144,7 @@ pub const XKCD_LIGHT_AQUA: Color = Color(140, 255, 219);
 
 /// Colour `(r = 164, g = 190, b = 92)`
 ///
-/// Colour `XKCD_LIGHT_OLIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `883`)
+/// Colour `XKCD_LIGHT_OLIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `883`, name `xkcd:light olive green`)
 /// ## Representations:
 /// - int tuple `(164, 190, 92)`
 /// - float tuple `(0.64, 0.75, 0.36)`
@@ -7995,7 +8153,7 @@ pub const XKCD_LIGHT_OLIVE_GREEN: Color = Color(164, 190, 92);
 
 /// Colour `(r = 203, g = 119, b = 35)`
 ///
-/// Colour `XKCD_BROWNISH_ORANGE` from the set `XKCD_COLORS`. (Colour number `884`)
+/// Colour `XKCD_BROWNISH_ORANGE` from the set `XKCD_COLORS`. (Colour number `884`, name `xkcd:brownish orange`)
 /// ## Representations:
 /// - int tuple `(203, 119, 35)`
 /// - float tuple `(0.8, 0.47, 0.14)`
@@ -8004,7 +8162,7 @@ pub const XKCD_BROWNISH_ORANGE: Color = Color(203, 119, 35);
 
 /// Colour `(r = 5, g = 105, b = 107)`
 ///
-/// Colour `XKCD_DARK_AQUA` from the set `XKCD_COLORS`. (Colour number `885`)
+/// Colour `XKCD_DARK_AQUA` from the set `XKCD_COLORS`. (Colour number `885`, name `xkcd:dark aqua`)
 /// ## Representations:
 /// - int tuple `(5, 105, 107)`
 /// - float tuple `(0.02, 0.41, 0.42)`
@@ -8013,7 +8171,7 @@ pub const XKCD_DARK_AQUA: Color = Color(5, 105, 107);
 
 /// Colour `(r = 206, g = 93, b = 174)`
 ///
-/// Colour `XKCD_PURPLISH_PINK` from the set `XKCD_COLORS`. (Colour number `886`)
+/// Colour `XKCD_PURPLISH_PINK` from the set `XKCD_COLORS`. (Colour number `886`, name `xkcd:purplish pink`)
 /// ## Representations:
 /// - int tuple `(206, 93, 174)`
 /// - float tuple `(0.81, 0.36, 0.68)`
@@ -8022,7 +8180,7 @@ pub const XKCD_PURPLISH_PINK: Color = Color(206, 93, 174);
 
 /// Colour `(r = 200, g = 90, b = 83)`
 ///
-/// Colour `XKCD_DARK_SALMON` from the set `XKCD_COLORS`. (Colour number `887`)
+/// Colour `XKCD_DARK_SALMON` from the set `XKCD_COLORS`. (Colour number `887`, name `xkcd:dark salmon`)
 /// ## Representations:
 /// - int tuple `(200, 90, 83)`
 /// - float tuple `(0.78, 0.35, 0.33)`
@@ -8031,7 +8189,7 @@ pub const XKCD_DARK_SALMON: Color = Color(200, 90, 83);
 
 /// Colour `(r = 150, g = 174, b = 141)`
 ///
-/// Colour `XKCD_GREENISH_GREY` from the set `XKCD_COLORS`. (Colour number `888`)
+/// Colour `XKCD_GREENISH_GREY` from the set `XKCD_COLORS`. (Colour number `888`, name `xkcd:greenish grey`)
 /// ## Representations:
 /// - int tuple `(150, 174, 141)`
 /// - float tuple `(0.59, 0.68, 0.55)`
@@ -8040,7 +8198,7 @@ pub const XKCD_GREENISH_GREY: Color = Color(150, 174, 141);
 
 /// Colour `(r = 31, g = 167, b = 116)`
 ///
-/// Colour `XKCD_JADE` from the set `XKCD_COLORS`. (Colour number `889`)
+/// Colour `XKCD_JADE` from the set `XKCD_COLORS`. (Colour number `889`, name `xkcd:jade`)
 /// ## Representations:
 /// - int tuple `(31, 167, 116)`
 /// - float tuple `(0.12, 0.65, 0.45)`
@@ -8049,7 +8207,7 @@ pub const XKCD_JADE: Color = Color(31, 167, 116);
 
 /// Colour `(r = 122, g = 151, b = 3)`
 ///
-/// Colour `XKCD_UGLY_GREEN` from the set `XKCD_COLORS`. (Colour number `890`)
+/// Colour `XKCD_UGLY_GREEN` from the set `XKCD_COLORS`. (Colour number `890`, name `xkcd:ugly green`)
 /// ## Representations:
 /// - int tuple `(122, 151, 3)`
 /// - float tuple `(0.48, 0.59, 0.01)`
@@ -8058,7 +8216,7 @@ pub const XKCD_UGLY_GREEN: Color = Color(122, 151, 3);
 
 /// Colour `(r = 172, g = 147, b = 98)`
 ///
-/// Colour `XKCD_DARK_BEIGE` from the set `XKCD_COLORS`. (Colour number `891`)
+/// Colour `XKCD_DARK_BEIGE` from the set `XKCD_COLORS`. (Colour number `891`, name `xkcd:dark beige`)
 /// ## Representations:
 /// - int tuple `(172, 147, 98)`
 /// - float tuple `(0.67, 0.58, 0.38)`
@@ -8067,7 +8225,7 @@ pub const XKCD_DARK_BEIGE: Color = Color(172, 147, 98);
 
 /// Colour `(r = 1, g = 160, b = 73)`
 ///
-/// Colour `XKCD_EMERALD` from the set `XKCD_COLORS`. (Colour number `892`)
+/// Colour `XKCD_EMERALD` from the set `XKCD_COLORS`. (Colour number `892`, name `xkcd:emerald`)
 /// ## Representations:
 /// - int tuple `(1, 160, 73)`
 /// - float tuple `(0.0, 0.63, 0.29)`
@@ -8076,7 +8234,7 @@ pub const XKCD_EMERALD: Color = Color(1, 160, 73);
 
 /// Colour `(r = 217, g = 84, b = 77)`
 ///
-/// Colour `XKCD_PALE_RED` from the set `XKCD_COLORS`. (Colour number `893`)
+/// Colour `XKCD_PALE_RED` from the set `XKCD_COLORS`. (Colour number `893`, name `xkcd:pale red`)
 /// ## Representations:
 /// - int tuple `(217, 84, 77)`
 /// - float tuple `(0.85, 0.33, 0.3)`
@@ -8085,7 +8243,7 @@ pub const XKCD_PALE_RED: Color = Color(217, 84, 77);
 
 /// Colour `(r = 250, g = 95, b = 247)`
 ///
-/// Colour `XKCD_LIGHT_MAGENTA` from the set `XKCD_COLORS`. (Colour number `894`)
+/// Colour `XKCD_LIGHT_MAGENTA` from the set `XKCD_COLORS`. (Colour number `894`, name `xkcd:light magenta`)
 /// ## Representations:
 /// - int tuple `(250, 95, 247)`
 /// - float tuple `(0.98, 0.37, 0.97)`
@@ -8094,7 +8252,7 @@ pub const XKCD_LIGHT_MAGENTA: Color = Color(250, 95, 247);
 
 /// Colour `(r = 130, g = 202, b = 252)`
 ///
-/// Colour `XKCD_SKY` from the set `XKCD_COLORS`. (Colour number `895`)
+/// Colour `XKCD_SKY` from the set `XKCD_COLORS`. (Colour number `895`, name `xkcd:sky`)
 /// ## Representations:
 /// - int tuple `(130, 202, 252)`
 /// - float tuple `(0.51, 0.79, 0.99)`
@@ -8103,7 +8261,7 @@ pub const XKCD_SKY: Color = Color(130, 202, 252);
 
 /// Colour `(r = 172, g = 255, b = 252)`
 ///
-/// Colour `XKCD_LIGHT_CYAN` from the set `XKCD_COLORS`. (Colour number `896`)
+/// Colour `XKCD_LIGHT_CYAN` from the set `XKCD_COLORS`. (Colour number `896`, name `xkcd:light cyan`)
 /// ## Representations:
 /// - int tuple `(172, 255, 252)`
 /// - float tuple `(0.67, 1.0, 0.99)`
@@ -8112,7 +8270,7 @@ pub const XKCD_LIGHT_CYAN: Color = Color(172, 255, 252);
 
 /// Colour `(r = 252, g = 176, b = 1)`
 ///
-/// Colour `XKCD_YELLOW_ORANGE` from the set `XKCD_COLORS`. (Colour number `897`)
+/// Colour `XKCD_YELLOW_ORANGE` from the set `XKCD_COLORS`. (Colour number `897`, name `xkcd:yellow orange`)
 /// ## Representations:
 /// - int tuple `(252, 176, 1)`
 /// - float tuple `(0.99, 0.69, 0.0)`
@@ -8121,7 +8279,7 @@ pub const XKCD_YELLOW_ORANGE: Color = Color(252, 176, 1);
 
 /// Colour `(r = 145, g = 9, b = 81)`
 ///
-/// Colour `XKCD_REDDISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `898`)
+/// Colour `XKCD_REDDISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `898`, name `xkcd:reddish purple`)
 /// ## Representations:
 /// - int tuple `(145, 9, 81)`
 /// - float tuple `(0.57, 0.04, 0.32)`
@@ -8130,7 +8288,7 @@ pub const XKCD_REDDISH_PURPLE: Color = Color(145, 9, 81);
 
 /// Colour `(r = 254, g = 44, b = 84)`
 ///
-/// Colour `XKCD_REDDISH_PINK` from the set `XKCD_COLORS`. (Colour number `899`)
+/// Colour `XKCD_REDDISH_PINK` from the set `XKCD_COLORS`. (Colour number `899`, name `xkcd:reddish pink`)
 /// ## Representations:
 /// - int tuple `(254, 44, 84)`
 /// - float tuple `(1.0, 0.17, 0.33)`
@@ -8139,7 +8297,7 @@ pub const XKCD_REDDISH_PINK: Color = Color(254, 44, 84);
 
 /// Colour `(r = 200, g = 117, b = 196)`
 ///
-/// Colour `XKCD_ORCHID` from the set `XKCD_COLORS`. (Colour number `900`)
+/// Colour `XKCD_ORCHID` from the set `XKCD_COLORS`. (Colour number `900`, name `xkcd:orchid`)
 /// ## Representations:
 /// - int tuple `(200, 117, 196)`
 /// - float tuple `(0.78, 0.46, 0.77)`
@@ -8148,7 +8306,7 @@ pub const XKCD_ORCHID: Color = Color(200, 117, 196);
 
 /// Colour `(r = 205, g = 197, b = 10)`
 ///
-/// Colour `XKCD_DIRTY_YELLOW` from the set `XKCD_COLORS`. (Colour number `901`)
+/// Colour `XKCD_DIRTY_YELLOW` from the set `XKCD_COLORS`. (Colour number `901`, name `xkcd:dirty yellow`)
 /// ## Representations:
 /// - int tuple `(205, 197, 10)`
 /// - float tuple `(0.8, 0.77, 0.04)`
@@ -8157,7 +8315,7 @@ pub const XKCD_DIRTY_YELLOW: Color = Color(205, 197, 10);
 
 /// Colour `(r = 253, g = 65, b = 30)`
 ///
-/// Colour `XKCD_ORANGE_RED` from the set `XKCD_COLORS`. (Colour number `902`)
+/// Colour `XKCD_ORANGE_RED` from the set `XKCD_COLORS`. (Colour number `902`, name `xkcd:orange red`)
 /// ## Representations:
 /// - int tuple `(253, 65, 30)`
 /// - float tuple `(0.99, 0.25, 0.12)`
@@ -8166,7 +8324,7 @@ pub const XKCD_ORANGE_RED: Color = Color(253, 65, 30);
 
 /// Colour `(r = 154, g = 2, b = 0)`
 ///
-/// Colour `XKCD_DEEP_RED` from the set `XKCD_COLORS`. (Colour number `903`)
+/// Colour `XKCD_DEEP_RED` from the set `XKCD_COLORS`. (Colour number `903`, name `xkcd:deep red`)
 /// ## Representations:
 /// - int tuple `(154, 2, 0)`
 /// - float tuple `(0.6, 0.01, 0.0)`
@@ -8175,7 +8333,7 @@ pub const XKCD_DEEP_RED: Color = Color(154, 2, 0);
 
 /// Colour `(r = 190, g = 100, b = 0)`
 ///
-/// Colour `XKCD_ORANGE_BROWN` from the set `XKCD_COLORS`. (Colour number `904`)
+/// Colour `XKCD_ORANGE_BROWN` from the set `XKCD_COLORS`. (Colour number `904`, name `xkcd:orange brown`)
 /// ## Representations:
 /// - int tuple `(190, 100, 0)`
 /// - float tuple `(0.75, 0.39, 0.0)`
@@ -8184,7 +8342,7 @@ pub const XKCD_ORANGE_BROWN: Color = Color(190, 100, 0);
 
 /// Colour `(r = 3, g = 10, b = 167)`
 ///
-/// Colour `XKCD_COBALT_BLUE` from the set `XKCD_COLORS`. (Colour number `905`)
+/// Colour `XKCD_COBALT_BLUE` from the set `XKCD_COLORS`. (Colour number `905`, name `xkcd:cobalt blue`)
 /// ## Representations:
 /// - int tuple `(3, 10, 167)`
 /// - float tuple `(0.01, 0.04, 0.65)`
@@ -8193,7 +8351,7 @@ pub const XKCD_COBALT_BLUE: Color = Color(3, 10, 167);
 
 /// Colour `(r = 254, g = 1, b = 154)`
 ///
-/// Colour `XKCD_NEON_PINK` from the set `XKCD_COLORS`. (Colour number `906`)
+/// Colour `XKCD_NEON_PINK` from the set `XKCD_COLORS`. (Colour number `906`, name `xkcd:neon pink`)
 /// ## Representations:
 /// - int tuple `(254, 1, 154)`
 /// - float tuple `(1.0, 0.0, 0.6)`
@@ -8202,7 +8360,7 @@ pub const XKCD_NEON_PINK: Color = Color(254, 1, 154);
 
 /// Colour `(r = 247, g = 135, b = 154)`
 ///
-/// Colour `XKCD_ROSE_PINK` from the set `XKCD_COLORS`. (Colour number `907`)
+/// Colour `XKCD_ROSE_PINK` from the set `XKCD_COLORS`. (Colour number `907`, name `xkcd:rose pink`)
 /// ## Representations:
 /// - int tuple `(247, 135, 154)`
 /// - float tuple `(0.97, 0.53, 0.6)`
@@ -8211,7 +8369,7 @@ pub const XKCD_ROSE_PINK: Color = Color(247, 135, 154);
 
 /// Colour `(r = 136, g = 113, b = 145)`
 ///
-/// Colour `XKCD_GREYISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `908`)
+/// Colour `XKCD_GREYISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `908`, name `xkcd:greyish purple`)
 /// ## Representations:
 /// - int tuple `(136, 113, 145)`
 /// - float tuple `(0.53, 0.44, 0.57)`
@@ -8220,7 +8378,7 @@ pub const XKCD_GREYISH_PURPLE: Color = Color(136, 113, 145);
 
 /// Colour `(r = 176, g = 1, b = 73)`
 ///
-/// Colour `XKCD_RASPBERRY` from the set `XKCD_COLORS`. (Colour number `909`)
+/// Colour `XKCD_RASPBERRY` from the set `XKCD_COLORS`. (Colour number `909`, name `xkcd:raspberry`)
 /// ## Representations:
 /// - int tuple `(176, 1, 73)`
 /// - float tuple `(0.69, 0.0, 0.29)`
@@ -8229,7 +8387,7 @@ pub const XKCD_RASPBERRY: Color = Color(176, 1, 73);
 
 /// Colour `(r = 18, g = 225, b = 147)`
 ///
-/// Colour `XKCD_AQUA_GREEN` from the set `XKCD_COLORS`. (Colour number `910`)
+/// Colour `XKCD_AQUA_GREEN` from the set `XKCD_COLORS`. (Colour number `910`, name `xkcd:aqua green`)
 /// ## Representations:
 /// - int tuple `(18, 225, 147)`
 /// - float tuple `(0.07, 0.88, 0.58)`
@@ -8238,7 +8396,7 @@ pub const XKCD_AQUA_GREEN: Color = Color(18, 225, 147);
 
 /// Colour `(r = 254, g = 123, b = 124)`
 ///
-/// Colour `XKCD_SALMON_PINK` from the set `XKCD_COLORS`. (Colour number `911`)
+/// Colour `XKCD_SALMON_PINK` from the set `XKCD_COLORS`. (Colour number `911`, name `xkcd:salmon pink`)
 /// ## Representations:
 /// - int tuple `(254, 123, 124)`
 /// - float tuple `(1.0, 0.48, 0.49)`
@@ -8247,7 +8405,7 @@ pub const XKCD_SALMON_PINK: Color = Color(254, 123, 124);
 
 /// Colour `(r = 255, g = 148, b = 8)`
 ///
-/// Colour `XKCD_TANGERINE` from the set `XKCD_COLORS`. (Colour number `912`)
+/// Colour `XKCD_TANGERINE` from the set `XKCD_COLORS`. (Colour number `912`, name `xkcd:tangerine`)
 /// ## Representations:
 /// - int tuple `(255, 148, 8)`
 /// - float tuple `(1.0, 0.58, 0.03)`
@@ -8256,7 +8414,7 @@ pub const XKCD_TANGERINE: Color = Color(255, 148, 8);
 
 /// Colour `(r = 106, g = 110, b = 9)`
 ///
-/// Colour `XKCD_BROWNISH_GREEN` from the set `XKCD_COLORS`. (Colour number `913`)
+/// Colour `XKCD_BROWNISH_GREEN` from the set `XKCD_COLORS`. (Colour number `913`, name `xkcd:brownish green`)
 /// ## Representations:
 /// - int tuple `(106, 110, 9)`
 /// - float tuple `(0.42, 0.43, 0.04)`
@@ -8265,7 +8423,7 @@ pub const XKCD_BROWNISH_GREEN: Color = Color(106, 110, 9);
 
 /// Colour `(r = 139, g = 46, b = 22)`
 ///
-/// Colour `XKCD_RED_BROWN` from the set `XKCD_COLORS`. (Colour number `914`)
+/// Colour `XKCD_RED_BROWN` from the set `XKCD_COLORS`. (Colour number `914`, name `xkcd:red brown`)
 /// ## Representations:
 /// - int tuple `(139, 46, 22)`
 /// - float tuple `(0.55, 0.18, 0.09)`
@@ -8274,7 +8432,7 @@ pub const XKCD_RED_BROWN: Color = Color(139, 46, 22);
 
 /// Colour `(r = 105, g = 97, b = 18)`
 ///
-/// Colour `XKCD_GREENISH_BROWN` from the set `XKCD_COLORS`. (Colour number `915`)
+/// Colour `XKCD_GREENISH_BROWN` from the set `XKCD_COLORS`. (Colour number `915`, name `xkcd:greenish brown`)
 /// ## Representations:
 /// - int tuple `(105, 97, 18)`
 /// - float tuple `(0.41, 0.38, 0.07)`
@@ -8283,7 +8441,7 @@ pub const XKCD_GREENISH_BROWN: Color = Color(105, 97, 18);
 
 /// Colour `(r = 225, g = 119, b = 1)`
 ///
-/// Colour `XKCD_PUMPKIN` from the set `XKCD_COLORS`. (Colour number `916`)
+/// Colour `XKCD_PUMPKIN` from the set `XKCD_COLORS`. (Colour number `916`, name `xkcd:pumpkin`)
 /// ## Representations:
 /// - int tuple `(225, 119, 1)`
 /// - float tuple `(0.88, 0.47, 0.0)`
@@ -8292,7 +8450,7 @@ pub const XKCD_PUMPKIN: Color = Color(225, 119, 1);
 
 /// Colour `(r = 10, g = 72, b = 30)`
 ///
-/// Colour `XKCD_PINE_GREEN` from the set `XKCD_COLORS`. (Colour number `917`)
+/// Colour `XKCD_PINE_GREEN` from the set `XKCD_COLORS`. (Colour number `917`, name `xkcd:pine green`)
 /// ## Representations:
 /// - int tuple `(10, 72, 30)`
 /// - float tuple `(0.04, 0.28, 0.12)`
@@ -8301,7 +8459,7 @@ pub const XKCD_PINE_GREEN: Color = Color(10, 72, 30);
 
 /// Colour `(r = 52, g = 56, b = 55)`
 ///
-/// Colour `XKCD_CHARCOAL` from the set `XKCD_COLORS`. (Colour number `918`)
+/// Colour `XKCD_CHARCOAL` from the set `XKCD_COLORS`. (Colour number `918`, name `xkcd:charcoal`)
 /// ## Representations:
 /// - int tuple `(52, 56, 55)`
 /// - float tuple `(0.2, 0.22, 0.22)`
@@ -8310,7 +8468,7 @@ pub const XKCD_CHARCOAL: Color = Color(52, 56, 55);
 
 /// Colour `(r = 255, g = 183, b = 206)`
 ///
-/// Colour `XKCD_BABY_PINK` from the set `XKCD_COLORS`. (Colour number `919`)
+/// Colour `XKCD_BABY_PINK` from the set `XKCD_COLORS`. (Colour number `919`, name `xkcd:baby pink`)
 /// ## Representations:
 /// - int tuple `(255, 183, 206)`
 /// - float tuple `(1.0, 0.72, 0.81)`
@@ -8319,7 +8477,7 @@ pub const XKCD_BABY_PINK: Color = Color(255, 183, 206);
 
 /// Colour `(r = 106, g = 121, b = 247)`
 ///
-/// Colour `XKCD_CORNFLOWER` from the set `XKCD_COLORS`. (Colour number `920`)
+/// Colour `XKCD_CORNFLOWER` from the set `XKCD_COLORS`. (Colour number `920`, name `xkcd:cornflower`)
 /// ## Representations:
 /// - int tuple `(106, 121, 247)`
 /// - float tuple `(0.42, 0.47, 0.97)`
@@ -8328,7 +8486,7 @@ pub const XKCD_CORNFLOWER: Color = Color(106, 121, 247);
 
 /// Colour `(r = 93, g = 6, b = 233)`
 ///
-/// Colour `XKCD_BLUE_VIOLET` from the set `XKCD_COLORS`. (Colour number `921`)
+/// Colour `XKCD_BLUE_VIOLET` from the set `XKCD_COLORS`. (Colour number `921`, name `xkcd:blue violet`)
 /// ## Representations:
 /// - int tuple `(93, 6, 233)`
 /// - float tuple `(0.36, 0.02, 0.91)`
@@ -8337,7 +8495,7 @@ pub const XKCD_BLUE_VIOLET: Color = Color(93, 6, 233);
 
 /// Colour `(r = 61, g = 28, b = 2)`
 ///
-/// Colour `XKCD_CHOCOLATE` from the set `XKCD_COLORS`. (Colour number `922`)
+/// Colour `XKCD_CHOCOLATE` from the set `XKCD_COLORS`. (Colour number `922`, name `xkcd:chocolate`)
 /// ## Representations:
 /// - int tuple `(61, 28, 2)`
 /// - float tuple `(0.24, 0.11, 0.01)`
@@ -8346,7 +8504,7 @@ pub const XKCD_CHOCOLATE: Color = Color(61, 28, 2);
 
 /// Colour `(r = 130, g = 166, b = 125)`
 ///
-/// Colour `XKCD_GREYISH_GREEN` from the set `XKCD_COLORS`. (Colour number `923`)
+/// Colour `XKCD_GREYISH_GREEN` from the set `XKCD_COLORS`. (Colour number `923`, name `xkcd:greyish green`)
 /// ## Representations:
 /// - int tuple `(130, 166, 125)`
 /// - float tuple `(0.51, 0.65, 0.49)`
@@ -8355,7 +8513,7 @@ pub const XKCD_GREYISH_GREEN: Color = Color(130, 166, 125);
 
 /// Colour `(r = 190, g = 1, b = 25)`
 ///
-/// Colour `XKCD_SCARLET` from the set `XKCD_COLORS`. (Colour number `924`)
+/// Colour `XKCD_SCARLET` from the set `XKCD_COLORS`. (Colour number `924`, name `xkcd:scarlet`)
 /// ## Representations:
 /// - int tuple `(190, 1, 25)`
 /// - float tuple `(0.75, 0.0, 0.1)`
@@ -8364,7 +8522,7 @@ pub const XKCD_SCARLET: Color = Color(190, 1, 25);
 
 /// Colour `(r = 201, g = 255, b = 39)`
 ///
-/// Colour `XKCD_GREEN_YELLOW` from the set `XKCD_COLORS`. (Colour number `925`)
+/// Colour `XKCD_GREEN_YELLOW` from the set `XKCD_COLORS`. (Colour number `925`, name `xkcd:green yellow`)
 /// ## Representations:
 /// - int tuple `(201, 255, 39)`
 /// - float tuple `(0.79, 1.0, 0.15)`
@@ -8373,7 +8531,7 @@ pub const XKCD_GREEN_YELLOW: Color = Color(201, 255, 39);
 
 /// Colour `(r = 55, g = 62, b = 2)`
 ///
-/// Colour `XKCD_DARK_OLIVE` from the set `XKCD_COLORS`. (Colour number `926`)
+/// Colour `XKCD_DARK_OLIVE` from the set `XKCD_COLORS`. (Colour number `926`, name `xkcd:dark olive`)
 /// ## Representations:
 /// - int tuple `(55, 62, 2)`
 /// - float tuple `(0.22, 0.24, 0.01)`
@@ -8382,7 +8540,7 @@ pub const XKCD_DARK_OLIVE: Color = Color(55, 62, 2);
 
 /// Colour `(r = 169, g = 86, b = 30)`
 ///
-/// Colour `XKCD_SIENNA` from the set `XKCD_COLORS`. (Colour number `927`)
+/// Colour `XKCD_SIENNA` from the set `XKCD_COLORS`. (Colour number `927`, name `xkcd:sienna`)
 /// ## Representations:
 /// - int tuple `(169, 86, 30)`
 /// - float tuple `(0.66, 0.34, 0.12)`
@@ -8391,7 +8549,7 @@ pub const XKCD_SIENNA: Color = Color(169, 86, 30);
 
 /// Colour `(r = 202, g = 160, b = 255)`
 ///
-/// Colour `XKCD_PASTEL_PURPLE` from the set `XKCD_COLORS`. (Colour number `928`)
+/// Colour `XKCD_PASTEL_PURPLE` from the set `XKCD_COLORS`. (Colour number `928`, name `xkcd:pastel purple`)
 /// ## Representations:
 /// - int tuple `(202, 160, 255)`
 /// - float tuple `(0.79, 0.63, 1.0)`
@@ -8400,7 +8558,7 @@ pub const XKCD_PASTEL_PURPLE: Color = Color(202, 160, 255);
 
 /// Colour `(r = 202, g = 102, b = 65)`
 ///
-/// Colour `XKCD_TERRACOTTA` from the set `XKCD_COLORS`. (Colour number `929`)
+/// Colour `XKCD_TERRACOTTA` from the set `XKCD_COLORS`. (Colour number `929`, name `xkcd:terracotta`)
 /// ## Representations:
 /// - int tuple `(202, 102, 65)`
 /// - float tuple `(0.79, 0.4, 0.25)`
@@ -8409,7 +8567,7 @@ pub const XKCD_TERRACOTTA: Color = Color(202, 102, 65);
 
 /// Colour `(r = 2, g = 216, b = 233)`
 ///
-/// Colour `XKCD_AQUA_BLUE` from the set `XKCD_COLORS`. (Colour number `930`)
+/// Colour `XKCD_AQUA_BLUE` from the set `XKCD_COLORS`. (Colour number `930`, name `xkcd:aqua blue`)
 /// ## Representations:
 /// - int tuple `(2, 216, 233)`
 /// - float tuple `(0.01, 0.85, 0.91)`
@@ -8418,7 +8576,7 @@ pub const XKCD_AQUA_BLUE: Color = Color(2, 216, 233);
 
 /// Colour `(r = 136, g = 179, b = 120)`
 ///
-/// Colour `XKCD_SAGE_GREEN` from the set `XKCD_COLORS`. (Colour number `931`)
+/// Colour `XKCD_SAGE_GREEN` from the set `XKCD_COLORS`. (Colour number `931`, name `xkcd:sage green`)
 /// ## Representations:
 /// - int tuple `(136, 179, 120)`
 /// - float tuple `(0.53, 0.7, 0.47)`
@@ -8427,7 +8585,7 @@ pub const XKCD_SAGE_GREEN: Color = Color(136, 179, 120);
 
 /// Colour `(r = 152, g = 0, b = 2)`
 ///
-/// Colour `XKCD_BLOOD_RED` from the set `XKCD_COLORS`. (Colour number `932`)
+/// Colour `XKCD_BLOOD_RED` from the set `XKCD_COLORS`. (Colour number `932`, name `xkcd:blood red`)
 /// ## Representations:
 /// - int tuple `(152, 0, 2)`
 /// - float tuple `(0.6, 0.0, 0.01)`
@@ -8436,7 +8594,7 @@ pub const XKCD_BLOOD_RED: Color = Color(152, 0, 2);
 
 /// Colour `(r = 203, g = 1, b = 98)`
 ///
-/// Colour `XKCD_DEEP_PINK` from the set `XKCD_COLORS`. (Colour number `933`)
+/// Colour `XKCD_DEEP_PINK` from the set `XKCD_COLORS`. (Colour number `933`, name `xkcd:deep pink`)
 /// ## Representations:
 /// - int tuple `(203, 1, 98)`
 /// - float tuple `(0.8, 0.0, 0.38)`
@@ -8445,7 +8603,7 @@ pub const XKCD_DEEP_PINK: Color = Color(203, 1, 98);
 
 /// Colour `(r = 92, g = 172, b = 45)`
 ///
-/// Colour `XKCD_GRASS` from the set `XKCD_COLORS`. (Colour number `934`)
+/// Colour `XKCD_GRASS` from the set `XKCD_COLORS`. (Colour number `934`, name `xkcd:grass`)
 /// ## Representations:
 /// - int tuple `(92, 172, 45)`
 /// - float tuple `(0.36, 0.67, 0.18)`
@@ -8454,7 +8612,7 @@ pub const XKCD_GRASS: Color = Color(92, 172, 45);
 
 /// Colour `(r = 118, g = 153, b = 88)`
 ///
-/// Colour `XKCD_MOSS` from the set `XKCD_COLORS`. (Colour number `935`)
+/// Colour `XKCD_MOSS` from the set `XKCD_COLORS`. (Colour number `935`, name `xkcd:moss`)
 /// ## Representations:
 /// - int tuple `(118, 153, 88)`
 /// - float tuple `(0.46, 0.6, 0.35)`
@@ -8463,7 +8621,7 @@ pub const XKCD_MOSS: Color = Color(118, 153, 88);
 
 /// Colour `(r = 162, g = 191, b = 254)`
 ///
-/// Colour `XKCD_PASTEL_BLUE` from the set `XKCD_COLORS`. (Colour number `936`)
+/// Colour `XKCD_PASTEL_BLUE` from the set `XKCD_COLORS`. (Colour number `936`, name `xkcd:pastel blue`)
 /// ## Representations:
 /// - int tuple `(162, 191, 254)`
 /// - float tuple `(0.64, 0.75, 1.0)`
@@ -8472,7 +8630,7 @@ pub const XKCD_PASTEL_BLUE: Color = Color(162, 191, 254);
 
 /// Colour `(r = 16, g = 166, b = 116)`
 ///
-/// Colour `XKCD_BLUISH_GREEN` from the set `XKCD_COLORS`. (Colour number `937`)
+/// Colour `XKCD_BLUISH_GREEN` from the set `XKCD_COLORS`. (Colour number `937`, name `xkcd:bluish green`)
 /// ## Representations:
 /// - int tuple `(16, 166, 116)`
 /// - float tuple `(0.06, 0.65, 0.45)`
@@ -8481,7 +8639,7 @@ pub const XKCD_BLUISH_GREEN: Color = Color(16, 166, 116);
 
 /// Colour `(r = 6, g = 180, b = 139)`
 ///
-/// Colour `XKCD_GREEN_BLUE` from the set `XKCD_COLORS`. (Colour number `938`)
+/// Colour `XKCD_GREEN_BLUE` from the set `XKCD_COLORS`. (Colour number `938`, name `xkcd:green blue`)
 /// ## Representations:
 /// - int tuple `(6, 180, 139)`
 /// - float tuple `(0.02, 0.71, 0.55)`
@@ -8490,7 +8648,7 @@ pub const XKCD_GREEN_BLUE: Color = Color(6, 180, 139);
 
 /// Colour `(r = 175, g = 136, b = 74)`
 ///
-/// Colour `XKCD_DARK_TAN` from the set `XKCD_COLORS`. (Colour number `939`)
+/// Colour `XKCD_DARK_TAN` from the set `XKCD_COLORS`. (Colour number `939`, name `xkcd:dark tan`)
 /// ## Representations:
 /// - int tuple `(175, 136, 74)`
 /// - float tuple `(0.69, 0.53, 0.29)`
@@ -8499,7 +8657,7 @@ pub const XKCD_DARK_TAN: Color = Color(175, 136, 74);
 
 /// Colour `(r = 11, g = 139, b = 135)`
 ///
-/// Colour `XKCD_GREENISH_BLUE` from the set `XKCD_COLORS`. (Colour number `940`)
+/// Colour `XKCD_GREENISH_BLUE` from the set `XKCD_COLORS`. (Colour number `940`, name `xkcd:greenish blue`)
 /// ## Representations:
 /// - int tuple `(11, 139, 135)`
 /// - float tuple `(0.04, 0.55, 0.53)`
@@ -8508,7 +8666,7 @@ pub const XKCD_GREENISH_BLUE: Color = Color(11, 139, 135);
 
 /// Colour `(r = 255, g = 167, b = 86)`
 ///
-/// Colour `XKCD_PALE_ORANGE` from the set `XKCD_COLORS`. (Colour number `941`)
+/// Colour `XKCD_PALE_ORANGE` from the set `XKCD_COLORS`. (Colour number `941`, name `xkcd:pale orange`)
 /// ## Representations:
 /// - int tuple `(255, 167, 86)`
 /// - float tuple `(1.0, 0.65, 0.34)`
@@ -8517,7 +8675,7 @@ pub const XKCD_PALE_ORANGE: Color = Color(255, 167, 86);
 
 /// Colour `(r = 162, g = 164, b = 21)`
 ///
-/// Colour `XKCD_VOMIT` from the set `XKCD_COLORS`. (Colour number `942`)
+/// Colour `XKCD_VOMIT` from the set `XKCD_COLORS`. (Colour number `942`, name `xkcd:vomit`)
 /// ## Representations:
 /// - int tuple `(162, 164, 21)`
 /// - float tuple `(0.64, 0.64, 0.08)`
@@ -8526,7 +8684,7 @@ pub const XKCD_VOMIT: Color = Color(162, 164, 21);
 
 /// Colour `(r = 21, g = 68, b = 6)`
 ///
-/// Colour `XKCD_FORREST_GREEN` from the set `XKCD_COLORS`. (Colour number `943`)
+/// Colour `XKCD_FORREST_GREEN` from the set `XKCD_COLORS`. (Colour number `943`, name `xkcd:forrest green`)
 /// ## Representations:
 /// - int tuple `(21, 68, 6)`
 /// - float tuple `(0.08, 0.27, 0.02)`
@@ -8535,7 +8693,7 @@ pub const XKCD_FORREST_GREEN: Color = Color(21, 68, 6);
 
 /// Colour `(r = 133, g = 103, b = 152)`
 ///
-/// Colour `XKCD_DARK_LAVENDER` from the set `XKCD_COLORS`. (Colour number `944`)
+/// Colour `XKCD_DARK_LAVENDER` from the set `XKCD_COLORS`. (Colour number `944`, name `xkcd:dark lavender`)
 /// ## Representations:
 /// - int tuple `(133, 103, 152)`
 /// - float tuple `(0.52, 0.4, 0.6)`
@@ -8544,7 +8702,7 @@ pub const XKCD_DARK_LAVENDER: Color = Color(133, 103, 152);
 
 /// Colour `(r = 52, g = 1, b = 63)`
 ///
-/// Colour `XKCD_DARK_VIOLET` from the set `XKCD_COLORS`. (Colour number `945`)
+/// Colour `XKCD_DARK_VIOLET` from the set `XKCD_COLORS`. (Colour number `945`, name `xkcd:dark violet`)
 /// ## Representations:
 /// - int tuple `(52, 1, 63)`
 /// - float tuple `(0.2, 0.0, 0.25)`
@@ -8553,7 +8711,7 @@ pub const XKCD_DARK_VIOLET: Color = Color(52, 1, 63);
 
 /// Colour `(r = 99, g = 45, b = 233)`
 ///
-/// Colour `XKCD_PURPLE_BLUE` from the set `XKCD_COLORS`. (Colour number `946`)
+/// Colour `XKCD_PURPLE_BLUE` from the set `XKCD_COLORS`. (Colour number `946`, name `xkcd:purple blue`)
 /// ## Representations:
 /// - int tuple `(99, 45, 233)`
 /// - float tuple `(0.39, 0.18, 0.91)`
@@ -8562,7 +8720,7 @@ pub const XKCD_PURPLE_BLUE: Color = Color(99, 45, 233);
 
 /// Colour `(r = 10, g = 136, b = 138)`
 ///
-/// Colour `XKCD_DARK_CYAN` from the set `XKCD_COLORS`. (Colour number `947`)
+/// Colour `XKCD_DARK_CYAN` from the set `XKCD_COLORS`. (Colour number `947`, name `xkcd:dark cyan`)
 /// ## Representations:
 /// - int tuple `(10, 136, 138)`
 /// - float tuple `(0.04, 0.53, 0.54)`
@@ -8571,7 +8729,7 @@ pub const XKCD_DARK_CYAN: Color = Color(10, 136, 138);
 
 /// Colour `(r = 111, g = 118, b = 50)`
 ///
-/// Colour `XKCD_OLIVE_DRAB` from the set `XKCD_COLORS`. (Colour number `948`)
+/// Colour `XKCD_OLIVE_DRAB` from the set `XKCD_COLORS`. (Colour number `948`, name `xkcd:olive drab`)
 /// ## Representations:
 /// - int tuple `(111, 118, 50)`
 /// - float tuple `(0.44, 0.46, 0.2)`
@@ -8580,7 +8738,7 @@ pub const XKCD_OLIVE_DRAB: Color = Color(111, 118, 50);
 
 /// Colour `(r = 212, g = 106, b = 126)`
 ///
-/// Colour `XKCD_PINKISH` from the set `XKCD_COLORS`. (Colour number `949`)
+/// Colour `XKCD_PINKISH` from the set `XKCD_COLORS`. (Colour number `949`, name `xkcd:pinkish`)
 /// ## Representations:
 /// - int tuple `(212, 106, 126)`
 /// - float tuple `(0.83, 0.42, 0.49)`
@@ -8589,7 +8747,7 @@ pub const XKCD_PINKISH: Color = Color(212, 106, 126);
 
 /// Colour `(r = 30, g = 72, b = 143)`
 ///
-/// Colour `XKCD_COBALT` from the set `XKCD_COLORS`. (Colour number `950`)
+/// Colour `XKCD_COBALT` from the set `XKCD_COLORS`. (Colour number `950`, name `xkcd:cobalt`)
 /// ## Representations:
 /// - int tuple `(30, 72, 143)`
 /// - float tuple `(0.12, 0.28, 0.56)`
@@ -8598,7 +8756,7 @@ pub const XKCD_COBALT: Color = Color(30, 72, 143);
 
 /// Colour `(r = 188, g = 19, b = 254)`
 ///
-/// Colour `XKCD_NEON_PURPLE` from the set `XKCD_COLORS`. (Colour number `951`)
+/// Colour `XKCD_NEON_PURPLE` from the set `XKCD_COLORS`. (Colour number `951`, name `xkcd:neon purple`)
 /// ## Representations:
 /// - int tuple `(188, 19, 254)`
 /// - float tuple `(0.74, 0.07, 1.0)`
@@ -8607,7 +8765,7 @@ pub const XKCD_NEON_PURPLE: Color = Color(188, 19, 254);
 
 /// Colour `(r = 126, g = 244, b = 204)`
 ///
-/// Colour `XKCD_LIGHT_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `952`)
+/// Colour `XKCD_LIGHT_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `952`, name `xkcd:light turquoise`)
 /// ## Representations:
 /// - int tuple `(126, 244, 204)`
 /// - float tuple `(0.49, 0.96, 0.8)`
@@ -8616,7 +8774,7 @@ pub const XKCD_LIGHT_TURQUOISE: Color = Color(126, 244, 204);
 
 /// Colour `(r = 118, g = 205, b = 38)`
 ///
-/// Colour `XKCD_APPLE_GREEN` from the set `XKCD_COLORS`. (Colour number `953`)
+/// Colour `XKCD_APPLE_GREEN` from the set `XKCD_COLORS`. (Colour number `953`, name `xkcd:apple green`)
 /// ## Representations:
 /// - int tuple `(118, 205, 38)`
 /// - float tuple `(0.46, 0.8, 0.15)`
@@ -8625,7 +8783,7 @@ pub const XKCD_APPLE_GREEN: Color = Color(118, 205, 38);
 
 /// Colour `(r = 116, g = 166, b = 98)`
 ///
-/// Colour `XKCD_DULL_GREEN` from the set `XKCD_COLORS`. (Colour number `954`)
+/// Colour `XKCD_DULL_GREEN` from the set `XKCD_COLORS`. (Colour number `954`, name `xkcd:dull green`)
 /// ## Representations:
 /// - int tuple `(116, 166, 98)`
 /// - float tuple `(0.45, 0.65, 0.38)`
@@ -8634,7 +8792,7 @@ pub const XKCD_DULL_GREEN: Color = Color(116, 166, 98);
 
 /// Colour `(r = 128, g = 1, b = 63)`
 ///
-/// Colour `XKCD_WINE` from the set `XKCD_COLORS`. (Colour number `955`)
+/// Colour `XKCD_WINE` from the set `XKCD_COLORS`. (Colour number `955`, name `xkcd:wine`)
 /// ## Representations:
 /// - int tuple `(128, 1, 63)`
 /// - float tuple `(0.5, 0.0, 0.25)`
@@ -8643,7 +8801,7 @@ pub const XKCD_WINE: Color = Color(128, 1, 63);
 
 /// Colour `(r = 177, g = 209, b = 252)`
 ///
-/// Colour `XKCD_POWDER_BLUE` from the set `XKCD_COLORS`. (Colour number `956`)
+/// Colour `XKCD_POWDER_BLUE` from the set `XKCD_COLORS`. (Colour number `956`, name `xkcd:powder blue`)
 /// ## Representations:
 /// - int tuple `(177, 209, 252)`
 /// - float tuple `(0.69, 0.82, 0.99)`
@@ -8652,7 +8810,7 @@ pub const XKCD_POWDER_BLUE: Color = Color(177, 209, 252);
 
 /// Colour `(r = 255, g = 255, b = 228)`
 ///
-/// Colour `XKCD_OFF_WHITE` from the set `XKCD_COLORS`. (Colour number `957`)
+/// Colour `XKCD_OFF_WHITE` from the set `XKCD_COLORS`. (Colour number `957`, name `xkcd:off white`)
 /// ## Representations:
 /// - int tuple `(255, 255, 228)`
 /// - float tuple `(1.0, 1.0, 0.89)`
@@ -8661,7 +8819,7 @@ pub const XKCD_OFF_WHITE: Color = Color(255, 255, 228);
 
 /// Colour `(r = 6, g = 82, b = 255)`
 ///
-/// Colour `XKCD_ELECTRIC_BLUE` from the set `XKCD_COLORS`. (Colour number `958`)
+/// Colour `XKCD_ELECTRIC_BLUE` from the set `XKCD_COLORS`. (Colour number `958`, name `xkcd:electric blue`)
 /// ## Representations:
 /// - int tuple `(6, 82, 255)`
 /// - float tuple `(0.02, 0.32, 1.0)`
@@ -8670,7 +8828,7 @@ pub const XKCD_ELECTRIC_BLUE: Color = Color(6, 82, 255);
 
 /// Colour `(r = 4, g = 92, b = 90)`
 ///
-/// Colour `XKCD_DARK_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `959`)
+/// Colour `XKCD_DARK_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `959`, name `xkcd:dark turquoise`)
 /// ## Representations:
 /// - int tuple `(4, 92, 90)`
 /// - float tuple `(0.02, 0.36, 0.35)`
@@ -8679,7 +8837,7 @@ pub const XKCD_DARK_TURQUOISE: Color = Color(4, 92, 90);
 
 /// Colour `(r = 87, g = 41, b = 206)`
 ///
-/// Colour `XKCD_BLUE_PURPLE` from the set `XKCD_COLORS`. (Colour number `960`)
+/// Colour `XKCD_BLUE_PURPLE` from the set `XKCD_COLORS`. (Colour number `960`, name `xkcd:blue purple`)
 /// ## Representations:
 /// - int tuple `(87, 41, 206)`
 /// - float tuple `(0.34, 0.16, 0.81)`
@@ -8688,7 +8846,7 @@ pub const XKCD_BLUE_PURPLE: Color = Color(87, 41, 206);
 
 /// Colour `(r = 6, g = 154, b = 243)`
 ///
-/// Colour `XKCD_AZURE` from the set `XKCD_COLORS`. (Colour number `961`)
+/// Colour `XKCD_AZURE` from the set `XKCD_COLORS`. (Colour number `961`, name `xkcd:azure`)
 /// ## Representations:
 /// - int tuple `(6, 154, 243)`
 /// - float tuple `(0.02, 0.6, 0.95)`
@@ -8697,7 +8855,7 @@ pub const XKCD_AZURE: Color = Color(6, 154, 243);
 
 /// Colour `(r = 255, g = 0, b = 13)`
 ///
-/// Colour `XKCD_BRIGHT_RED` from the set `XKCD_COLORS`. (Colour number `962`)
+/// Colour `XKCD_BRIGHT_RED` from the set `XKCD_COLORS`. (Colour number `962`, name `xkcd:bright red`)
 /// ## Representations:
 /// - int tuple `(255, 0, 13)`
 /// - float tuple `(1.0, 0.0, 0.05)`
@@ -8706,7 +8864,7 @@ pub const XKCD_BRIGHT_RED: Color = Color(255, 0, 13);
 
 /// Colour `(r = 241, g = 12, b = 69)`
 ///
-/// Colour `XKCD_PINKISH_RED` from the set `XKCD_COLORS`. (Colour number `963`)
+/// Colour `XKCD_PINKISH_RED` from the set `XKCD_COLORS`. (Colour number `963`, name `xkcd:pinkish red`)
 /// ## Representations:
 /// - int tuple `(241, 12, 69)`
 /// - float tuple `(0.95, 0.05, 0.27)`
@@ -8715,7 +8873,7 @@ pub const XKCD_PINKISH_RED: Color = Color(241, 12, 69);
 
 /// Colour `(r = 81, g = 112, b = 215)`
 ///
-/// Colour `XKCD_CORNFLOWER_BLUE` from the set `XKCD_COLORS`. (Colour number `964`)
+/// Colour `XKCD_CORNFLOWER_BLUE` from the set `XKCD_COLORS`. (Colour number `964`, name `xkcd:cornflower blue`)
 /// ## Representations:
 /// - int tuple `(81, 112, 215)`
 /// - float tuple `(0.32, 0.44, 0.84)`
@@ -8724,7 +8882,7 @@ pub const XKCD_CORNFLOWER_BLUE: Color = Color(81, 112, 215);
 
 /// Colour `(r = 172, g = 191, b = 105)`
 ///
-/// Colour `XKCD_LIGHT_OLIVE` from the set `XKCD_COLORS`. (Colour number `965`)
+/// Colour `XKCD_LIGHT_OLIVE` from the set `XKCD_COLORS`. (Colour number `965`, name `xkcd:light olive`)
 /// ## Representations:
 /// - int tuple `(172, 191, 105)`
 /// - float tuple `(0.67, 0.75, 0.41)`
@@ -8733,7 +8891,7 @@ pub const XKCD_LIGHT_OLIVE: Color = Color(172, 191, 105);
 
 /// Colour `(r = 108, g = 52, b = 97)`
 ///
-/// Colour `XKCD_GRAPE` from the set `XKCD_COLORS`. (Colour number `966`)
+/// Colour `XKCD_GRAPE` from the set `XKCD_COLORS`. (Colour number `966`, name `xkcd:grape`)
 /// ## Representations:
 /// - int tuple `(108, 52, 97)`
 /// - float tuple `(0.42, 0.2, 0.38)`
@@ -8742,7 +8900,7 @@ pub const XKCD_GRAPE: Color = Color(108, 52, 97);
 
 /// Colour `(r = 94, g = 129, b = 157)`
 ///
-/// Colour `XKCD_GREYISH_BLUE` from the set `XKCD_COLORS`. (Colour number `967`)
+/// Colour `XKCD_GREYISH_BLUE` from the set `XKCD_COLORS`. (Colour number `967`, name `xkcd:greyish blue`)
 /// ## Representations:
 /// - int tuple `(94, 129, 157)`
 /// - float tuple `(0.37, 0.51, 0.62)`
@@ -8751,7 +8909,7 @@ pub const XKCD_GREYISH_BLUE: Color = Color(94, 129, 157);
 
 /// Colour `(r = 96, g = 30, b = 249)`
 ///
-/// Colour `XKCD_PURPLISH_BLUE` from the set `XKCD_COLORS`. (Colour number `968`)
+/// Colour `XKCD_PURPLISH_BLUE` from the set `XKCD_COLORS`. (Colour number `968`, name `xkcd:purplish blue`)
 /// ## Representations:
 /// - int tuple `(96, 30, 249)`
 /// - float tuple `(0.38, 0.12, 0.98)`
@@ -8760,7 +8918,7 @@ pub const XKCD_PURPLISH_BLUE: Color = Color(96, 30, 249);
 
 /// Colour `(r = 176, g = 221, b = 22)`
 ///
-/// Colour `XKCD_YELLOWISH_GREEN` from the set `XKCD_COLORS`. (Colour number `969`)
+/// Colour `XKCD_YELLOWISH_GREEN` from the set `XKCD_COLORS`. (Colour number `969`, name `xkcd:yellowish green`)
 /// ## Representations:
 /// - int tuple `(176, 221, 22)`
 /// - float tuple `(0.69, 0.87, 0.09)`
@@ -8769,7 +8927,7 @@ pub const XKCD_YELLOWISH_GREEN: Color = Color(176, 221, 22);
 
 /// Colour `(r = 205, g = 253, b = 2)`
 ///
-/// Colour `XKCD_GREENISH_YELLOW` from the set `XKCD_COLORS`. (Colour number `970`)
+/// Colour `XKCD_GREENISH_YELLOW` from the set `XKCD_COLORS`. (Colour number `970`, name `xkcd:greenish yellow`)
 /// ## Representations:
 /// - int tuple `(205, 253, 2)`
 /// - float tuple `(0.8, 0.99, 0.01)`
@@ -8778,7 +8936,7 @@ pub const XKCD_GREENISH_YELLOW: Color = Color(205, 253, 2);
 
 /// Colour `(r = 44, g = 111, b = 187)`
 ///
-/// Colour `XKCD_MEDIUM_BLUE` from the set `XKCD_COLORS`. (Colour number `971`)
+/// Colour `XKCD_MEDIUM_BLUE` from the set `XKCD_COLORS`. (Colour number `971`, name `xkcd:medium blue`)
 /// ## Representations:
 /// - int tuple `(44, 111, 187)`
 /// - float tuple `(0.17, 0.44, 0.73)`
@@ -8787,7 +8945,7 @@ pub const XKCD_MEDIUM_BLUE: Color = Color(44, 111, 187);
 
 /// Colour `(r = 192, g = 115, b = 122)`
 ///
-/// Colour `XKCD_DUSTY_ROSE` from the set `XKCD_COLORS`. (Colour number `972`)
+/// Colour `XKCD_DUSTY_ROSE` from the set `XKCD_COLORS`. (Colour number `972`, name `xkcd:dusty rose`)
 /// ## Representations:
 /// - int tuple `(192, 115, 122)`
 /// - float tuple `(0.75, 0.45, 0.48)`
@@ -8796,7 +8954,7 @@ pub const XKCD_DUSTY_ROSE: Color = Color(192, 115, 122);
 
 /// Colour `(r = 214, g = 180, b = 252)`
 ///
-/// Colour `XKCD_LIGHT_VIOLET` from the set `XKCD_COLORS`. (Colour number `973`)
+/// Colour `XKCD_LIGHT_VIOLET` from the set `XKCD_COLORS`. (Colour number `973`, name `xkcd:light violet`)
 /// ## Representations:
 /// - int tuple `(214, 180, 252)`
 /// - float tuple `(0.84, 0.71, 0.99)`
@@ -8805,7 +8963,7 @@ pub const XKCD_LIGHT_VIOLET: Color = Color(214, 180, 252);
 
 /// Colour `(r = 2, g = 0, b = 53)`
 ///
-/// Colour `XKCD_MIDNIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `974`)
+/// Colour `XKCD_MIDNIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `974`, name `xkcd:midnight blue`)
 /// ## Representations:
 /// - int tuple `(2, 0, 53)`
 /// - float tuple `(0.01, 0.0, 0.21)`
@@ -8814,7 +8972,7 @@ pub const XKCD_MIDNIGHT_BLUE: Color = Color(2, 0, 53);
 
 /// Colour `(r = 112, g = 59, b = 231)`
 ///
-/// Colour `XKCD_BLUISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `975`)
+/// Colour `XKCD_BLUISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `975`, name `xkcd:bluish purple`)
 /// ## Representations:
 /// - int tuple `(112, 59, 231)`
 /// - float tuple `(0.44, 0.23, 0.91)`
@@ -8823,7 +8981,7 @@ pub const XKCD_BLUISH_PURPLE: Color = Color(112, 59, 231);
 
 /// Colour `(r = 253, g = 60, b = 6)`
 ///
-/// Colour `XKCD_RED_ORANGE` from the set `XKCD_COLORS`. (Colour number `976`)
+/// Colour `XKCD_RED_ORANGE` from the set `XKCD_COLORS`. (Colour number `976`, name `xkcd:red orange`)
 /// ## Representations:
 /// - int tuple `(253, 60, 6)`
 /// - float tuple `(0.99, 0.24, 0.02)`
@@ -8832,7 +8990,7 @@ pub const XKCD_RED_ORANGE: Color = Color(253, 60, 6);
 
 /// Colour `(r = 150, g = 0, b = 86)`
 ///
-/// Colour `XKCD_DARK_MAGENTA` from the set `XKCD_COLORS`. (Colour number `977`)
+/// Colour `XKCD_DARK_MAGENTA` from the set `XKCD_COLORS`. (Colour number `977`, name `xkcd:dark magenta`)
 /// ## Representations:
 /// - int tuple `(150, 0, 86)`
 /// - float tuple `(0.59, 0.0, 0.34)`
@@ -8841,7 +8999,7 @@ pub const XKCD_DARK_MAGENTA: Color = Color(150, 0, 86);
 
 /// Colour `(r = 64, g = 163, b = 104)`
 ///
-/// Colour `XKCD_GREENISH` from the set `XKCD_COLORS`. (Colour number `978`)
+/// Colour `XKCD_GREENISH` from the set `XKCD_COLORS`. (Colour number `978`, name `xkcd:greenish`)
 /// ## Representations:
 /// - int tuple `(64, 163, 104)`
 /// - float tuple `(0.25, 0.64, 0.41)`
@@ -8850,7 +9008,7 @@ pub const XKCD_GREENISH: Color = Color(64, 163, 104);
 
 /// Colour `(r = 3, g = 113, b = 156)`
 ///
-/// Colour `XKCD_OCEAN_BLUE` from the set `XKCD_COLORS`. (Colour number `979`)
+/// Colour `XKCD_OCEAN_BLUE` from the set `XKCD_COLORS`. (Colour number `979`, name `xkcd:ocean blue`)
 /// ## Representations:
 /// - int tuple `(3, 113, 156)`
 /// - float tuple `(0.01, 0.44, 0.61)`
@@ -8859,7 +9017,7 @@ pub const XKCD_OCEAN_BLUE: Color = Color(3, 113, 156);
 
 /// Colour `(r = 252, g = 90, b = 80)`
 ///
-/// Colour `XKCD_CORAL` from the set `XKCD_COLORS`. (Colour number `980`)
+/// Colour `XKCD_CORAL` from the set `XKCD_COLORS`. (Colour number `980`, name `xkcd:coral`)
 /// ## Representations:
 /// - int tuple `(252, 90, 80)`
 /// - float tuple `(0.99, 0.35, 0.31)`
@@ -8868,7 +9026,7 @@ pub const XKCD_CORAL: Color = Color(252, 90, 80);
 
 /// Colour `(r = 255, g = 255, b = 194)`
 ///
-/// Colour `XKCD_CREAM` from the set `XKCD_COLORS`. (Colour number `981`)
+/// Colour `XKCD_CREAM` from the set `XKCD_COLORS`. (Colour number `981`, name `xkcd:cream`)
 /// ## Representations:
 /// - int tuple `(255, 255, 194)`
 /// - float tuple `(1.0, 1.0, 0.76)`
@@ -8877,7 +9035,7 @@ pub const XKCD_CREAM: Color = Color(255, 255, 194);
 
 /// Colour `(r = 127, g = 43, b = 10)`
 ///
-/// Colour `XKCD_REDDISH_BROWN` from the set `XKCD_COLORS`. (Colour number `982`)
+/// Colour `XKCD_REDDISH_BROWN` from the set `XKCD_COLORS`. (Colour number `982`, name `xkcd:reddish brown`)
 /// ## Representations:
 /// - int tuple `(127, 43, 10)`
 /// - float tuple `(0.5, 0.17, 0.04)`
@@ -8886,7 +9044,7 @@ pub const XKCD_REDDISH_BROWN: Color = Color(127, 43, 10);
 
 /// Colour `(r = 176, g = 78, b = 15)`
 ///
-/// Colour `XKCD_BURNT_SIENNA` from the set `XKCD_COLORS`. (Colour number `983`)
+/// Colour `XKCD_BURNT_SIENNA` from the set `XKCD_COLORS`. (Colour number `983`, name `xkcd:burnt sienna`)
 /// ## Representations:
 /// - int tuple `(176, 78, 15)`
 /// - float tuple `(0.69, 0.31, 0.06)`
@@ -8895,7 +9053,7 @@ pub const XKCD_BURNT_SIENNA: Color = Color(176, 78, 15);
 
 /// Colour `(r = 160, g = 54, b = 35)`
 ///
-/// Colour `XKCD_BRICK` from the set `XKCD_COLORS`. (Colour number `984`)
+/// Colour `XKCD_BRICK` from the set `XKCD_COLORS`. (Colour number `984`, name `xkcd:brick`)
 /// ## Representations:
 /// - int tuple `(160, 54, 35)`
 /// - float tuple `(0.63, 0.21, 0.14)`
@@ -8904,7 +9062,7 @@ pub const XKCD_BRICK: Color = Color(160, 54, 35);
 
 /// Colour `(r = 135, g = 174, b = 115)`
 ///
-/// Colour `XKCD_SAGE` from the set `XKCD_COLORS`. (Colour number `985`)
+/// Colour `XKCD_SAGE` from the set `XKCD_COLORS`. (Colour number `985`, name `xkcd:sage`)
 /// ## Representations:
 /// - int tuple `(135, 174, 115)`
 /// - float tuple `(0.53, 0.68, 0.45)`
@@ -8913,7 +9071,7 @@ pub const XKCD_SAGE: Color = Color(135, 174, 115);
 
 /// Colour `(r = 120, g = 155, b = 115)`
 ///
-/// Colour `XKCD_GREY_GREEN` from the set `XKCD_COLORS`. (Colour number `986`)
+/// Colour `XKCD_GREY_GREEN` from the set `XKCD_COLORS`. (Colour number `986`, name `xkcd:grey green`)
 /// ## Representations:
 /// - int tuple `(120, 155, 115)`
 /// - float tuple `(0.47, 0.61, 0.45)`
@@ -8922,7 +9080,7 @@ pub const XKCD_GREY_GREEN: Color = Color(120, 155, 115);
 
 /// Colour `(r = 255, g = 255, b = 255)`
 ///
-/// Colour `XKCD_WHITE` from the set `XKCD_COLORS`. (Colour number `987`)
+/// Colour `XKCD_WHITE` from the set `XKCD_COLORS`. (Colour number `987`, name `xkcd:white`)
 /// ## Representations:
 /// - int tuple `(255, 255, 255)`
 /// - float tuple `(1.0, 1.0, 1.0)`
@@ -8931,7 +9089,7 @@ pub const XKCD_WHITE: Color = Color(255, 255, 255);
 
 /// Colour `(r = 152, g = 239, b = 249)`
 ///
-/// Colour `XKCD_ROBIN_S_EGG_BLUE` from the set `XKCD_COLORS`. (Colour number `988`)
+/// Colour `XKCD_ROBIN_S_EGG_BLUE` from the set `XKCD_COLORS`. (Colour number `988`, name `xkcd:robin's egg blue`)
 /// ## Representations:
 /// - int tuple `(152, 239, 249)`
 /// - float tuple `(0.6, 0.94, 0.98)`
@@ -8940,7 +9098,7 @@ pub const XKCD_ROBIN_S_EGG_BLUE: Color = Color(152, 239, 249);
 
 /// Colour `(r = 101, g = 139, b = 56)`
 ///
-/// Colour `XKCD_MOSS_GREEN` from the set `XKCD_COLORS`. (Colour number `989`)
+/// Colour `XKCD_MOSS_GREEN` from the set `XKCD_COLORS`. (Colour number `989`, name `xkcd:moss green`)
 /// ## Representations:
 /// - int tuple `(101, 139, 56)`
 /// - float tuple `(0.4, 0.55, 0.22)`
@@ -8949,7 +9107,7 @@ pub const XKCD_MOSS_GREEN: Color = Color(101, 139, 56);
 
 /// Colour `(r = 90, g = 125, b = 154)`
 ///
-/// Colour `XKCD_STEEL_BLUE` from the set `XKCD_COLORS`. (Colour number `990`)
+/// Colour `XKCD_STEEL_BLUE` from the set `XKCD_COLORS`. (Colour number `990`, name `xkcd:steel blue`)
 /// ## Representations:
 /// - int tuple `(90, 125, 154)`
 /// - float tuple `(0.35, 0.49, 0.6)`
@@ -8958,7 +9116,7 @@ pub const XKCD_STEEL_BLUE: Color = Color(90, 125, 154);
 
 /// Colour `(r = 56, g = 8, b = 53)`
 ///
-/// Colour `XKCD_EGGPLANT` from the set `XKCD_COLORS`. (Colour number `991`)
+/// Colour `XKCD_EGGPLANT` from the set `XKCD_COLORS`. (Colour number `991`, name `xkcd:eggplant`)
 /// ## Representations:
 /// - int tuple `(56, 8, 53)`
 /// - float tuple `(0.22, 0.03, 0.21)`
@@ -8967,7 +9125,7 @@ pub const XKCD_EGGPLANT: Color = Color(56, 8, 53);
 
 /// Colour `(r = 255, g = 254, b = 122)`
 ///
-/// Colour `XKCD_LIGHT_YELLOW` from the set `XKCD_COLORS`. (Colour number `992`)
+/// Colour `XKCD_LIGHT_YELLOW` from the set `XKCD_COLORS`. (Colour number `992`, name `xkcd:light yellow`)
 /// ## Representations:
 /// - int tuple `(255, 254, 122)`
 /// - float tuple `(1.0, 1.0, 0.48)`
@@ -8976,7 +9134,7 @@ pub const XKCD_LIGHT_YELLOW: Color = Color(255, 254, 122);
 
 /// Colour `(r = 92, g = 169, b = 4)`
 ///
-/// Colour `XKCD_LEAF_GREEN` from the set `XKCD_COLORS`. (Colour number `993`)
+/// Colour `XKCD_LEAF_GREEN` from the set `XKCD_COLORS`. (Colour number `993`, name `xkcd:leaf green`)
 /// ## Representations:
 /// - int tuple `(92, 169, 4)`
 /// - float tuple `(0.36, 0.66, 0.02)`
@@ -8985,7 +9143,7 @@ pub const XKCD_LEAF_GREEN: Color = Color(92, 169, 4);
 
 /// Colour `(r = 216, g = 220, b = 214)`
 ///
-/// Colour `XKCD_LIGHT_GREY` from the set `XKCD_COLORS`. (Colour number `994`)
+/// Colour `XKCD_LIGHT_GREY` from the set `XKCD_COLORS`. (Colour number `994`, name `xkcd:light grey`)
 /// ## Representations:
 /// - int tuple `(216, 220, 214)`
 /// - float tuple `(0.85, 0.86, 0.84)`
@@ -8994,7 +9152,7 @@ pub const XKCD_LIGHT_GREY: Color = Color(216, 220, 214);
 
 /// Colour `(r = 165, g = 165, b = 2)`
 ///
-/// Colour `XKCD_PUKE` from the set `XKCD_COLORS`. (Colour number `995`)
+/// Colour `XKCD_PUKE` from the set `XKCD_COLORS`. (Colour number `995`, name `xkcd:puke`)
 /// ## Representations:
 /// - int tuple `(165, 165, 2)`
 /// - float tuple `(0.65, 0.65, 0.01)`
@@ -9003,7 +9161,7 @@ pub const XKCD_PUKE: Color = Color(165, 165, 2);
 
 /// Colour `(r = 214, g = 72, b = 215)`
 ///
-/// Colour `XKCD_PINKISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `996`)
+/// Colour `XKCD_PINKISH_PURPLE` from the set `XKCD_COLORS`. (Colour number `996`, name `xkcd:pinkish purple`)
 /// ## Representations:
 /// - int tuple `(214, 72, 215)`
 /// - float tuple `(0.84, 0.28, 0.84)`
@@ -9012,7 +9170,7 @@ pub const XKCD_PINKISH_PURPLE: Color = Color(214, 72, 215);
 
 /// Colour `(r = 4, g = 116, b = 149)`
 ///
-/// Colour `XKCD_SEA_BLUE` from the set `XKCD_COLORS`. (Colour number `997`)
+/// Colour `XKCD_SEA_BLUE` from the set `XKCD_COLORS`. (Colour number `997`, name `xkcd:sea blue`)
 /// ## Representations:
 /// - int tuple `(4, 116, 149)`
 /// - float tuple `(0.02, 0.45, 0.58)`
@@ -9021,7 +9179,7 @@ pub const XKCD_SEA_BLUE: Color = Color(4, 116, 149);
 
 /// Colour `(r = 183, g = 144, b = 212)`
 ///
-/// Colour `XKCD_PALE_PURPLE` from the set `XKCD_COLORS`. (Colour number `998`)
+/// Colour `XKCD_PALE_PURPLE` from the set `XKCD_COLORS`. (Colour number `998`, name `xkcd:pale purple`)
 /// ## Representations:
 /// - int tuple `(183, 144, 212)`
 /// - float tuple `(0.72, 0.56, 0.83)`
@@ -9030,7 +9188,7 @@ pub const XKCD_PALE_PURPLE: Color = Color(183, 144, 212);
 
 /// Colour `(r = 91, g = 124, b = 153)`
 ///
-/// Colour `XKCD_SLATE_BLUE` from the set `XKCD_COLORS`. (Colour number `999`)
+/// Colour `XKCD_SLATE_BLUE` from the set `XKCD_COLORS`. (Colour number `999`, name `xkcd:slate blue`)
 /// ## Representations:
 /// - int tuple `(91, 124, 153)`
 /// - float tuple `(0.36, 0.49, 0.6)`
@@ -9039,7 +9197,7 @@ pub const XKCD_SLATE_BLUE: Color = Color(91, 124, 153);
 
 /// Colour `(r = 96, g = 124, b = 142)`
 ///
-/// Colour `XKCD_BLUE_GREY` from the set `XKCD_COLORS`. (Colour number `1000`)
+/// Colour `XKCD_BLUE_GREY` from the set `XKCD_COLORS`. (Colour number `1000`, name `xkcd:blue grey`)
 /// ## Representations:
 /// - int tuple `(96, 124, 142)`
 /// - float tuple `(0.38, 0.49, 0.56)`
@@ -9048,7 +9206,7 @@ pub const XKCD_BLUE_GREY: Color = Color(96, 124, 142);
 
 /// Colour `(r = 11, g = 64, b = 8)`
 ///
-/// Colour `XKCD_HUNTER_GREEN` from the set `XKCD_COLORS`. (Colour number `1001`)
+/// Colour `XKCD_HUNTER_GREEN` from the set `XKCD_COLORS`. (Colour number `1001`, name `xkcd:hunter green`)
 /// ## Representations:
 /// - int tuple `(11, 64, 8)`
 /// - float tuple `(0.04, 0.25, 0.03)`
@@ -9057,7 +9215,7 @@ pub const XKCD_HUNTER_GREEN: Color = Color(11, 64, 8);
 
 /// Colour `(r = 237, g = 13, b = 217)`
 ///
-/// Colour `XKCD_FUCHSIA` from the set `XKCD_COLORS`. (Colour number `1002`)
+/// Colour `XKCD_FUCHSIA` from the set `XKCD_COLORS`. (Colour number `1002`, name `xkcd:fuchsia`)
 /// ## Representations:
 /// - int tuple `(237, 13, 217)`
 /// - float tuple `(0.93, 0.05, 0.85)`
@@ -9066,7 +9224,7 @@ pub const XKCD_FUCHSIA: Color = Color(237, 13, 217);
 
 /// Colour `(r = 140, g = 0, b = 15)`
 ///
-/// Colour `XKCD_CRIMSON` from the set `XKCD_COLORS`. (Colour number `1003`)
+/// Colour `XKCD_CRIMSON` from the set `XKCD_COLORS`. (Colour number `1003`, name `xkcd:crimson`)
 /// ## Representations:
 /// - int tuple `(140, 0, 15)`
 /// - float tuple `(0.55, 0.0, 0.06)`
@@ -9075,7 +9233,7 @@ pub const XKCD_CRIMSON: Color = Color(140, 0, 15);
 
 /// Colour `(r = 255, g = 255, b = 132)`
 ///
-/// Colour `XKCD_PALE_YELLOW` from the set `XKCD_COLORS`. (Colour number `1004`)
+/// Colour `XKCD_PALE_YELLOW` from the set `XKCD_COLORS`. (Colour number `1004`, name `xkcd:pale yellow`)
 /// ## Representations:
 /// - int tuple `(255, 255, 132)`
 /// - float tuple `(1.0, 1.0, 0.52)`
@@ -9084,7 +9242,7 @@ pub const XKCD_PALE_YELLOW: Color = Color(255, 255, 132);
 
 /// Colour `(r = 191, g = 144, b = 5)`
 ///
-/// Colour `XKCD_OCHRE` from the set `XKCD_COLORS`. (Colour number `1005`)
+/// Colour `XKCD_OCHRE` from the set `XKCD_COLORS`. (Colour number `1005`, name `xkcd:ochre`)
 /// ## Representations:
 /// - int tuple `(191, 144, 5)`
 /// - float tuple `(0.75, 0.56, 0.02)`
@@ -9093,7 +9251,7 @@ pub const XKCD_OCHRE: Color = Color(191, 144, 5);
 
 /// Colour `(r = 210, g = 189, b = 10)`
 ///
-/// Colour `XKCD_MUSTARD_YELLOW` from the set `XKCD_COLORS`. (Colour number `1006`)
+/// Colour `XKCD_MUSTARD_YELLOW` from the set `XKCD_COLORS`. (Colour number `1006`, name `xkcd:mustard yellow`)
 /// ## Representations:
 /// - int tuple `(210, 189, 10)`
 /// - float tuple `(0.82, 0.74, 0.04)`
@@ -9102,7 +9260,7 @@ pub const XKCD_MUSTARD_YELLOW: Color = Color(210, 189, 10);
 
 /// Colour `(r = 255, g = 71, b = 76)`
 ///
-/// Colour `XKCD_LIGHT_RED` from the set `XKCD_COLORS`. (Colour number `1007`)
+/// Colour `XKCD_LIGHT_RED` from the set `XKCD_COLORS`. (Colour number `1007`, name `xkcd:light red`)
 /// ## Representations:
 /// - int tuple `(255, 71, 76)`
 /// - float tuple `(1.0, 0.28, 0.3)`
@@ -9111,7 +9269,7 @@ pub const XKCD_LIGHT_RED: Color = Color(255, 71, 76);
 
 /// Colour `(r = 4, g = 133, b = 209)`
 ///
-/// Colour `XKCD_CERULEAN` from the set `XKCD_COLORS`. (Colour number `1008`)
+/// Colour `XKCD_CERULEAN` from the set `XKCD_COLORS`. (Colour number `1008`, name `xkcd:cerulean`)
 /// ## Representations:
 /// - int tuple `(4, 133, 209)`
 /// - float tuple `(0.02, 0.52, 0.82)`
@@ -9120,7 +9278,7 @@ pub const XKCD_CERULEAN: Color = Color(4, 133, 209);
 
 /// Colour `(r = 255, g = 207, b = 220)`
 ///
-/// Colour `XKCD_PALE_PINK` from the set `XKCD_COLORS`. (Colour number `1009`)
+/// Colour `XKCD_PALE_PINK` from the set `XKCD_COLORS`. (Colour number `1009`, name `xkcd:pale pink`)
 /// ## Representations:
 /// - int tuple `(255, 207, 220)`
 /// - float tuple `(1.0, 0.81, 0.86)`
@@ -9129,7 +9287,7 @@ pub const XKCD_PALE_PINK: Color = Color(255, 207, 220);
 
 /// Colour `(r = 4, g = 2, b = 115)`
 ///
-/// Colour `XKCD_DEEP_BLUE` from the set `XKCD_COLORS`. (Colour number `1010`)
+/// Colour `XKCD_DEEP_BLUE` from the set `XKCD_COLORS`. (Colour number `1010`, name `xkcd:deep blue`)
 /// ## Representations:
 /// - int tuple `(4, 2, 115)`
 /// - float tuple `(0.02, 0.01, 0.45)`
@@ -9138,7 +9296,7 @@ pub const XKCD_DEEP_BLUE: Color = Color(4, 2, 115);
 
 /// Colour `(r = 168, g = 60, b = 9)`
 ///
-/// Colour `XKCD_RUST` from the set `XKCD_COLORS`. (Colour number `1011`)
+/// Colour `XKCD_RUST` from the set `XKCD_COLORS`. (Colour number `1011`, name `xkcd:rust`)
 /// ## Representations:
 /// - int tuple `(168, 60, 9)`
 /// - float tuple `(0.66, 0.24, 0.04)`
@@ -9147,7 +9305,7 @@ pub const XKCD_RUST: Color = Color(168, 60, 9);
 
 /// Colour `(r = 144, g = 228, b = 193)`
 ///
-/// Colour `XKCD_LIGHT_TEAL` from the set `XKCD_COLORS`. (Colour number `1012`)
+/// Colour `XKCD_LIGHT_TEAL` from the set `XKCD_COLORS`. (Colour number `1012`, name `xkcd:light teal`)
 /// ## Representations:
 /// - int tuple `(144, 228, 193)`
 /// - float tuple `(0.56, 0.89, 0.76)`
@@ -9156,7 +9314,7 @@ pub const XKCD_LIGHT_TEAL: Color = Color(144, 228, 193);
 
 /// Colour `(r = 81, g = 101, b = 114)`
 ///
-/// Colour `XKCD_SLATE` from the set `XKCD_COLORS`. (Colour number `1013`)
+/// Colour `XKCD_SLATE` from the set `XKCD_COLORS`. (Colour number `1013`, name `xkcd:slate`)
 /// ## Representations:
 /// - int tuple `(81, 101, 114)`
 /// - float tuple `(0.32, 0.4, 0.45)`
@@ -9165,7 +9323,7 @@ pub const XKCD_SLATE: Color = Color(81, 101, 114);
 
 /// Colour `(r = 250, g = 194, b = 5)`
 ///
-/// Colour `XKCD_GOLDENROD` from the set `XKCD_COLORS`. (Colour number `1014`)
+/// Colour `XKCD_GOLDENROD` from the set `XKCD_COLORS`. (Colour number `1014`, name `xkcd:goldenrod`)
 /// ## Representations:
 /// - int tuple `(250, 194, 5)`
 /// - float tuple `(0.98, 0.76, 0.02)`
@@ -9174,7 +9332,7 @@ pub const XKCD_GOLDENROD: Color = Color(250, 194, 5);
 
 /// Colour `(r = 213, g = 182, b = 10)`
 ///
-/// Colour `XKCD_DARK_YELLOW` from the set `XKCD_COLORS`. (Colour number `1015`)
+/// Colour `XKCD_DARK_YELLOW` from the set `XKCD_COLORS`. (Colour number `1015`, name `xkcd:dark yellow`)
 /// ## Representations:
 /// - int tuple `(213, 182, 10)`
 /// - float tuple `(0.84, 0.71, 0.04)`
@@ -9183,7 +9341,7 @@ pub const XKCD_DARK_YELLOW: Color = Color(213, 182, 10);
 
 /// Colour `(r = 54, g = 55, b = 55)`
 ///
-/// Colour `XKCD_DARK_GREY` from the set `XKCD_COLORS`. (Colour number `1016`)
+/// Colour `XKCD_DARK_GREY` from the set `XKCD_COLORS`. (Colour number `1016`, name `xkcd:dark grey`)
 /// ## Representations:
 /// - int tuple `(54, 55, 55)`
 /// - float tuple `(0.21, 0.22, 0.22)`
@@ -9192,7 +9350,7 @@ pub const XKCD_DARK_GREY: Color = Color(54, 55, 55);
 
 /// Colour `(r = 75, g = 93, b = 22)`
 ///
-/// Colour `XKCD_ARMY_GREEN` from the set `XKCD_COLORS`. (Colour number `1017`)
+/// Colour `XKCD_ARMY_GREEN` from the set `XKCD_COLORS`. (Colour number `1017`, name `xkcd:army green`)
 /// ## Representations:
 /// - int tuple `(75, 93, 22)`
 /// - float tuple `(0.29, 0.36, 0.09)`
@@ -9201,7 +9359,7 @@ pub const XKCD_ARMY_GREEN: Color = Color(75, 93, 22);
 
 /// Colour `(r = 107, g = 139, b = 164)`
 ///
-/// Colour `XKCD_GREY_BLUE` from the set `XKCD_COLORS`. (Colour number `1018`)
+/// Colour `XKCD_GREY_BLUE` from the set `XKCD_COLORS`. (Colour number `1018`, name `xkcd:grey blue`)
 /// ## Representations:
 /// - int tuple `(107, 139, 164)`
 /// - float tuple `(0.42, 0.55, 0.64)`
@@ -9210,7 +9368,7 @@ pub const XKCD_GREY_BLUE: Color = Color(107, 139, 164);
 
 /// Colour `(r = 128, g = 249, b = 173)`
 ///
-/// Colour `XKCD_SEAFOAM` from the set `XKCD_COLORS`. (Colour number `1019`)
+/// Colour `XKCD_SEAFOAM` from the set `XKCD_COLORS`. (Colour number `1019`, name `xkcd:seafoam`)
 /// ## Representations:
 /// - int tuple `(128, 249, 173)`
 /// - float tuple `(0.5, 0.98, 0.68)`
@@ -9219,7 +9377,7 @@ pub const XKCD_SEAFOAM: Color = Color(128, 249, 173);
 
 /// Colour `(r = 165, g = 126, b = 82)`
 ///
-/// Colour `XKCD_PUCE` from the set `XKCD_COLORS`. (Colour number `1020`)
+/// Colour `XKCD_PUCE` from the set `XKCD_COLORS`. (Colour number `1020`, name `xkcd:puce`)
 /// ## Representations:
 /// - int tuple `(165, 126, 82)`
 /// - float tuple `(0.65, 0.49, 0.32)`
@@ -9228,7 +9386,7 @@ pub const XKCD_PUCE: Color = Color(165, 126, 82);
 
 /// Colour `(r = 169, g = 249, b = 113)`
 ///
-/// Colour `XKCD_SPRING_GREEN` from the set `XKCD_COLORS`. (Colour number `1021`)
+/// Colour `XKCD_SPRING_GREEN` from the set `XKCD_COLORS`. (Colour number `1021`, name `xkcd:spring green`)
 /// ## Representations:
 /// - int tuple `(169, 249, 113)`
 /// - float tuple `(0.66, 0.98, 0.44)`
@@ -9237,7 +9395,7 @@ pub const XKCD_SPRING_GREEN: Color = Color(169, 249, 113);
 
 /// Colour `(r = 198, g = 81, b = 2)`
 ///
-/// Colour `XKCD_DARK_ORANGE` from the set `XKCD_COLORS`. (Colour number `1022`)
+/// Colour `XKCD_DARK_ORANGE` from the set `XKCD_COLORS`. (Colour number `1022`, name `xkcd:dark orange`)
 /// ## Representations:
 /// - int tuple `(198, 81, 2)`
 /// - float tuple `(0.78, 0.32, 0.01)`
@@ -9246,7 +9404,7 @@ pub const XKCD_DARK_ORANGE: Color = Color(198, 81, 2);
 
 /// Colour `(r = 226, g = 202, b = 118)`
 ///
-/// Colour `XKCD_SAND` from the set `XKCD_COLORS`. (Colour number `1023`)
+/// Colour `XKCD_SAND` from the set `XKCD_COLORS`. (Colour number `1023`, name `xkcd:sand`)
 /// ## Representations:
 /// - int tuple `(226, 202, 118)`
 /// - float tuple `(0.89, 0.79, 0.46)`
@@ -9255,7 +9413,7 @@ pub const XKCD_SAND: Color = Color(226, 202, 118);
 
 /// Colour `(r = 176, g = 255, b = 157)`
 ///
-/// Colour `XKCD_PASTEL_GREEN` from the set `XKCD_COLORS`. (Colour number `1024`)
+/// Colour `XKCD_PASTEL_GREEN` from the set `XKCD_COLORS`. (Colour number `1024`, name `xkcd:pastel green`)
 /// ## Representations:
 /// - int tuple `(176, 255, 157)`
 /// - float tuple `(0.69, 1.0, 0.62)`
@@ -9264,7 +9422,7 @@ pub const XKCD_PASTEL_GREEN: Color = Color(176, 255, 157);
 
 /// Colour `(r = 159, g = 254, b = 176)`
 ///
-/// Colour `XKCD_MINT` from the set `XKCD_COLORS`. (Colour number `1025`)
+/// Colour `XKCD_MINT` from the set `XKCD_COLORS`. (Colour number `1025`, name `xkcd:mint`)
 /// ## Representations:
 /// - int tuple `(159, 254, 176)`
 /// - float tuple `(0.62, 1.0, 0.69)`
@@ -9273,7 +9431,7 @@ pub const XKCD_MINT: Color = Color(159, 254, 176);
 
 /// Colour `(r = 253, g = 170, b = 72)`
 ///
-/// Colour `XKCD_LIGHT_ORANGE` from the set `XKCD_COLORS`. (Colour number `1026`)
+/// Colour `XKCD_LIGHT_ORANGE` from the set `XKCD_COLORS`. (Colour number `1026`, name `xkcd:light orange`)
 /// ## Representations:
 /// - int tuple `(253, 170, 72)`
 /// - float tuple `(0.99, 0.67, 0.28)`
@@ -9282,7 +9440,7 @@ pub const XKCD_LIGHT_ORANGE: Color = Color(253, 170, 72);
 
 /// Colour `(r = 254, g = 1, b = 177)`
 ///
-/// Colour `XKCD_BRIGHT_PINK` from the set `XKCD_COLORS`. (Colour number `1027`)
+/// Colour `XKCD_BRIGHT_PINK` from the set `XKCD_COLORS`. (Colour number `1027`, name `xkcd:bright pink`)
 /// ## Representations:
 /// - int tuple `(254, 1, 177)`
 /// - float tuple `(1.0, 0.0, 0.69)`
@@ -9291,7 +9449,7 @@ pub const XKCD_BRIGHT_PINK: Color = Color(254, 1, 177);
 
 /// Colour `(r = 193, g = 248, b = 10)`
 ///
-/// Colour `XKCD_CHARTREUSE` from the set `XKCD_COLORS`. (Colour number `1028`)
+/// Colour `XKCD_CHARTREUSE` from the set `XKCD_COLORS`. (Colour number `1028`, name `xkcd:chartreuse`)
 /// ## Representations:
 /// - int tuple `(193, 248, 10)`
 /// - float tuple `(0.76, 0.97, 0.04)`
@@ -9300,7 +9458,7 @@ pub const XKCD_CHARTREUSE: Color = Color(193, 248, 10);
 
 /// Colour `(r = 54, g = 1, b = 63)`
 ///
-/// Colour `XKCD_DEEP_PURPLE` from the set `XKCD_COLORS`. (Colour number `1029`)
+/// Colour `XKCD_DEEP_PURPLE` from the set `XKCD_COLORS`. (Colour number `1029`, name `xkcd:deep purple`)
 /// ## Representations:
 /// - int tuple `(54, 1, 63)`
 /// - float tuple `(0.21, 0.0, 0.25)`
@@ -9309,7 +9467,7 @@ pub const XKCD_DEEP_PURPLE: Color = Color(54, 1, 63);
 
 /// Colour `(r = 52, g = 28, b = 2)`
 ///
-/// Colour `XKCD_DARK_BROWN` from the set `XKCD_COLORS`. (Colour number `1030`)
+/// Colour `XKCD_DARK_BROWN` from the set `XKCD_COLORS`. (Colour number `1030`, name `xkcd:dark brown`)
 /// ## Representations:
 /// - int tuple `(52, 28, 2)`
 /// - float tuple `(0.2, 0.11, 0.01)`
@@ -9318,7 +9476,7 @@ pub const XKCD_DARK_BROWN: Color = Color(52, 28, 2);
 
 /// Colour `(r = 185, g = 162, b = 129)`
 ///
-/// Colour `XKCD_TAUPE` from the set `XKCD_COLORS`. (Colour number `1031`)
+/// Colour `XKCD_TAUPE` from the set `XKCD_COLORS`. (Colour number `1031`, name `xkcd:taupe`)
 /// ## Representations:
 /// - int tuple `(185, 162, 129)`
 /// - float tuple `(0.73, 0.64, 0.51)`
@@ -9327,7 +9485,7 @@ pub const XKCD_TAUPE: Color = Color(185, 162, 129);
 
 /// Colour `(r = 142, g = 171, b = 18)`
 ///
-/// Colour `XKCD_PEA_GREEN` from the set `XKCD_COLORS`. (Colour number `1032`)
+/// Colour `XKCD_PEA_GREEN` from the set `XKCD_COLORS`. (Colour number `1032`, name `xkcd:pea green`)
 /// ## Representations:
 /// - int tuple `(142, 171, 18)`
 /// - float tuple `(0.56, 0.67, 0.07)`
@@ -9336,7 +9494,7 @@ pub const XKCD_PEA_GREEN: Color = Color(142, 171, 18);
 
 /// Colour `(r = 154, g = 174, b = 7)`
 ///
-/// Colour `XKCD_PUKE_GREEN` from the set `XKCD_COLORS`. (Colour number `1033`)
+/// Colour `XKCD_PUKE_GREEN` from the set `XKCD_COLORS`. (Colour number `1033`, name `xkcd:puke green`)
 /// ## Representations:
 /// - int tuple `(154, 174, 7)`
 /// - float tuple `(0.6, 0.68, 0.03)`
@@ -9345,7 +9503,7 @@ pub const XKCD_PUKE_GREEN: Color = Color(154, 174, 7);
 
 /// Colour `(r = 2, g = 171, b = 46)`
 ///
-/// Colour `XKCD_KELLY_GREEN` from the set `XKCD_COLORS`. (Colour number `1034`)
+/// Colour `XKCD_KELLY_GREEN` from the set `XKCD_COLORS`. (Colour number `1034`, name `xkcd:kelly green`)
 /// ## Representations:
 /// - int tuple `(2, 171, 46)`
 /// - float tuple `(0.01, 0.67, 0.18)`
@@ -9354,7 +9512,7 @@ pub const XKCD_KELLY_GREEN: Color = Color(2, 171, 46);
 
 /// Colour `(r = 122, g = 249, b = 171)`
 ///
-/// Colour `XKCD_SEAFOAM_GREEN` from the set `XKCD_COLORS`. (Colour number `1035`)
+/// Colour `XKCD_SEAFOAM_GREEN` from the set `XKCD_COLORS`. (Colour number `1035`, name `xkcd:seafoam green`)
 /// ## Representations:
 /// - int tuple `(122, 249, 171)`
 /// - float tuple `(0.48, 0.98, 0.67)`
@@ -9363,7 +9521,7 @@ pub const XKCD_SEAFOAM_GREEN: Color = Color(122, 249, 171);
 
 /// Colour `(r = 19, g = 126, b = 109)`
 ///
-/// Colour `XKCD_BLUE_GREEN` from the set `XKCD_COLORS`. (Colour number `1036`)
+/// Colour `XKCD_BLUE_GREEN` from the set `XKCD_COLORS`. (Colour number `1036`, name `xkcd:blue green`)
 /// ## Representations:
 /// - int tuple `(19, 126, 109)`
 /// - float tuple `(0.07, 0.49, 0.43)`
@@ -9372,7 +9530,7 @@ pub const XKCD_BLUE_GREEN: Color = Color(19, 126, 109);
 
 /// Colour `(r = 170, g = 166, b = 98)`
 ///
-/// Colour `XKCD_KHAKI` from the set `XKCD_COLORS`. (Colour number `1037`)
+/// Colour `XKCD_KHAKI` from the set `XKCD_COLORS`. (Colour number `1037`, name `xkcd:khaki`)
 /// ## Representations:
 /// - int tuple `(170, 166, 98)`
 /// - float tuple `(0.67, 0.65, 0.38)`
@@ -9381,7 +9539,7 @@ pub const XKCD_KHAKI: Color = Color(170, 166, 98);
 
 /// Colour `(r = 97, g = 0, b = 35)`
 ///
-/// Colour `XKCD_BURGUNDY` from the set `XKCD_COLORS`. (Colour number `1038`)
+/// Colour `XKCD_BURGUNDY` from the set `XKCD_COLORS`. (Colour number `1038`, name `xkcd:burgundy`)
 /// ## Representations:
 /// - int tuple `(97, 0, 35)`
 /// - float tuple `(0.38, 0.0, 0.14)`
@@ -9390,7 +9548,7 @@ pub const XKCD_BURGUNDY: Color = Color(97, 0, 35);
 
 /// Colour `(r = 1, g = 77, b = 78)`
 ///
-/// Colour `XKCD_DARK_TEAL` from the set `XKCD_COLORS`. (Colour number `1039`)
+/// Colour `XKCD_DARK_TEAL` from the set `XKCD_COLORS`. (Colour number `1039`, name `xkcd:dark teal`)
 /// ## Representations:
 /// - int tuple `(1, 77, 78)`
 /// - float tuple `(0.0, 0.3, 0.31)`
@@ -9399,7 +9557,7 @@ pub const XKCD_DARK_TEAL: Color = Color(1, 77, 78);
 
 /// Colour `(r = 143, g = 20, b = 2)`
 ///
-/// Colour `XKCD_BRICK_RED` from the set `XKCD_COLORS`. (Colour number `1040`)
+/// Colour `XKCD_BRICK_RED` from the set `XKCD_COLORS`. (Colour number `1040`, name `xkcd:brick red`)
 /// ## Representations:
 /// - int tuple `(143, 20, 2)`
 /// - float tuple `(0.56, 0.08, 0.01)`
@@ -9408,7 +9566,7 @@ pub const XKCD_BRICK_RED: Color = Color(143, 20, 2);
 
 /// Colour `(r = 75, g = 0, b = 110)`
 ///
-/// Colour `XKCD_ROYAL_PURPLE` from the set `XKCD_COLORS`. (Colour number `1041`)
+/// Colour `XKCD_ROYAL_PURPLE` from the set `XKCD_COLORS`. (Colour number `1041`, name `xkcd:royal purple`)
 /// ## Representations:
 /// - int tuple `(75, 0, 110)`
 /// - float tuple `(0.29, 0.0, 0.43)`
@@ -9417,7 +9575,7 @@ pub const XKCD_ROYAL_PURPLE: Color = Color(75, 0, 110);
 
 /// Colour `(r = 88, g = 15, b = 65)`
 ///
-/// Colour `XKCD_PLUM` from the set `XKCD_COLORS`. (Colour number `1042`)
+/// Colour `XKCD_PLUM` from the set `XKCD_COLORS`. (Colour number `1042`, name `xkcd:plum`)
 /// ## Representations:
 /// - int tuple `(88, 15, 65)`
 /// - float tuple `(0.35, 0.06, 0.25)`
@@ -9426,7 +9584,7 @@ pub const XKCD_PLUM: Color = Color(88, 15, 65);
 
 /// Colour `(r = 143, g = 255, b = 159)`
 ///
-/// Colour `XKCD_MINT_GREEN` from the set `XKCD_COLORS`. (Colour number `1043`)
+/// Colour `XKCD_MINT_GREEN` from the set `XKCD_COLORS`. (Colour number `1043`, name `xkcd:mint green`)
 /// ## Representations:
 /// - int tuple `(143, 255, 159)`
 /// - float tuple `(0.56, 1.0, 0.62)`
@@ -9435,7 +9593,7 @@ pub const XKCD_MINT_GREEN: Color = Color(143, 255, 159);
 
 /// Colour `(r = 219, g = 180, b = 12)`
 ///
-/// Colour `XKCD_GOLD` from the set `XKCD_COLORS`. (Colour number `1044`)
+/// Colour `XKCD_GOLD` from the set `XKCD_COLORS`. (Colour number `1044`, name `xkcd:gold`)
 /// ## Representations:
 /// - int tuple `(219, 180, 12)`
 /// - float tuple `(0.86, 0.71, 0.05)`
@@ -9444,7 +9602,7 @@ pub const XKCD_GOLD: Color = Color(219, 180, 12);
 
 /// Colour `(r = 162, g = 207, b = 254)`
 ///
-/// Colour `XKCD_BABY_BLUE` from the set `XKCD_COLORS`. (Colour number `1045`)
+/// Colour `XKCD_BABY_BLUE` from the set `XKCD_COLORS`. (Colour number `1045`, name `xkcd:baby blue`)
 /// ## Representations:
 /// - int tuple `(162, 207, 254)`
 /// - float tuple `(0.64, 0.81, 1.0)`
@@ -9453,7 +9611,7 @@ pub const XKCD_BABY_BLUE: Color = Color(162, 207, 254);
 
 /// Colour `(r = 192, g = 251, b = 45)`
 ///
-/// Colour `XKCD_YELLOW_GREEN` from the set `XKCD_COLORS`. (Colour number `1046`)
+/// Colour `XKCD_YELLOW_GREEN` from the set `XKCD_COLORS`. (Colour number `1046`, name `xkcd:yellow green`)
 /// ## Representations:
 /// - int tuple `(192, 251, 45)`
 /// - float tuple `(0.75, 0.98, 0.18)`
@@ -9462,7 +9620,7 @@ pub const XKCD_YELLOW_GREEN: Color = Color(192, 251, 45);
 
 /// Colour `(r = 190, g = 3, b = 253)`
 ///
-/// Colour `XKCD_BRIGHT_PURPLE` from the set `XKCD_COLORS`. (Colour number `1047`)
+/// Colour `XKCD_BRIGHT_PURPLE` from the set `XKCD_COLORS`. (Colour number `1047`, name `xkcd:bright purple`)
 /// ## Representations:
 /// - int tuple `(190, 3, 253)`
 /// - float tuple `(0.75, 0.01, 0.99)`
@@ -9471,7 +9629,7 @@ pub const XKCD_BRIGHT_PURPLE: Color = Color(190, 3, 253);
 
 /// Colour `(r = 132, g = 0, b = 0)`
 ///
-/// Colour `XKCD_DARK_RED` from the set `XKCD_COLORS`. (Colour number `1048`)
+/// Colour `XKCD_DARK_RED` from the set `XKCD_COLORS`. (Colour number `1048`, name `xkcd:dark red`)
 /// ## Representations:
 /// - int tuple `(132, 0, 0)`
 /// - float tuple `(0.52, 0.0, 0.0)`
@@ -9480,7 +9638,7 @@ pub const XKCD_DARK_RED: Color = Color(132, 0, 0);
 
 /// Colour `(r = 208, g = 254, b = 254)`
 ///
-/// Colour `XKCD_PALE_BLUE` from the set `XKCD_COLORS`. (Colour number `1049`)
+/// Colour `XKCD_PALE_BLUE` from the set `XKCD_COLORS`. (Colour number `1049`, name `xkcd:pale blue`)
 /// ## Representations:
 /// - int tuple `(208, 254, 254)`
 /// - float tuple `(0.82, 1.0, 1.0)`
@@ -9489,7 +9647,7 @@ pub const XKCD_PALE_BLUE: Color = Color(208, 254, 254);
 
 /// Colour `(r = 63, g = 155, b = 11)`
 ///
-/// Colour `XKCD_GRASS_GREEN` from the set `XKCD_COLORS`. (Colour number `1050`)
+/// Colour `XKCD_GRASS_GREEN` from the set `XKCD_COLORS`. (Colour number `1050`, name `xkcd:grass green`)
 /// ## Representations:
 /// - int tuple `(63, 155, 11)`
 /// - float tuple `(0.25, 0.61, 0.04)`
@@ -9498,7 +9656,7 @@ pub const XKCD_GRASS_GREEN: Color = Color(63, 155, 11);
 
 /// Colour `(r = 1, g = 21, b = 62)`
 ///
-/// Colour `XKCD_NAVY` from the set `XKCD_COLORS`. (Colour number `1051`)
+/// Colour `XKCD_NAVY` from the set `XKCD_COLORS`. (Colour number `1051`, name `xkcd:navy`)
 /// ## Representations:
 /// - int tuple `(1, 21, 62)`
 /// - float tuple `(0.0, 0.08, 0.24)`
@@ -9507,7 +9665,7 @@ pub const XKCD_NAVY: Color = Color(1, 21, 62);
 
 /// Colour `(r = 4, g = 216, b = 178)`
 ///
-/// Colour `XKCD_AQUAMARINE` from the set `XKCD_COLORS`. (Colour number `1052`)
+/// Colour `XKCD_AQUAMARINE` from the set `XKCD_COLORS`. (Colour number `1052`, name `xkcd:aquamarine`)
 /// ## Representations:
 /// - int tuple `(4, 216, 178)`
 /// - float tuple `(0.02, 0.85, 0.7)`
@@ -9516,7 +9674,7 @@ pub const XKCD_AQUAMARINE: Color = Color(4, 216, 178);
 
 /// Colour `(r = 192, g = 78, b = 1)`
 ///
-/// Colour `XKCD_BURNT_ORANGE` from the set `XKCD_COLORS`. (Colour number `1053`)
+/// Colour `XKCD_BURNT_ORANGE` from the set `XKCD_COLORS`. (Colour number `1053`, name `xkcd:burnt orange`)
 /// ## Representations:
 /// - int tuple `(192, 78, 1)`
 /// - float tuple `(0.75, 0.31, 0.0)`
@@ -9525,7 +9683,7 @@ pub const XKCD_BURNT_ORANGE: Color = Color(192, 78, 1);
 
 /// Colour `(r = 12, g = 255, b = 12)`
 ///
-/// Colour `XKCD_NEON_GREEN` from the set `XKCD_COLORS`. (Colour number `1054`)
+/// Colour `XKCD_NEON_GREEN` from the set `XKCD_COLORS`. (Colour number `1054`, name `xkcd:neon green`)
 /// ## Representations:
 /// - int tuple `(12, 255, 12)`
 /// - float tuple `(0.05, 1.0, 0.05)`
@@ -9534,7 +9692,7 @@ pub const XKCD_NEON_GREEN: Color = Color(12, 255, 12);
 
 /// Colour `(r = 1, g = 101, b = 252)`
 ///
-/// Colour `XKCD_BRIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `1055`)
+/// Colour `XKCD_BRIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `1055`, name `xkcd:bright blue`)
 /// ## Representations:
 /// - int tuple `(1, 101, 252)`
 /// - float tuple `(0.0, 0.4, 0.99)`
@@ -9543,7 +9701,7 @@ pub const XKCD_BRIGHT_BLUE: Color = Color(1, 101, 252);
 
 /// Colour `(r = 207, g = 98, b = 117)`
 ///
-/// Colour `XKCD_ROSE` from the set `XKCD_COLORS`. (Colour number `1056`)
+/// Colour `XKCD_ROSE` from the set `XKCD_COLORS`. (Colour number `1056`, name `xkcd:rose`)
 /// ## Representations:
 /// - int tuple `(207, 98, 117)`
 /// - float tuple `(0.81, 0.38, 0.46)`
@@ -9552,7 +9710,7 @@ pub const XKCD_ROSE: Color = Color(207, 98, 117);
 
 /// Colour `(r = 255, g = 209, b = 223)`
 ///
-/// Colour `XKCD_LIGHT_PINK` from the set `XKCD_COLORS`. (Colour number `1057`)
+/// Colour `XKCD_LIGHT_PINK` from the set `XKCD_COLORS`. (Colour number `1057`, name `xkcd:light pink`)
 /// ## Representations:
 /// - int tuple `(255, 209, 223)`
 /// - float tuple `(1.0, 0.82, 0.87)`
@@ -9561,7 +9719,7 @@ pub const XKCD_LIGHT_PINK: Color = Color(255, 209, 223);
 
 /// Colour `(r = 206, g = 179, b = 1)`
 ///
-/// Colour `XKCD_MUSTARD` from the set `XKCD_COLORS`. (Colour number `1058`)
+/// Colour `XKCD_MUSTARD` from the set `XKCD_COLORS`. (Colour number `1058`, name `xkcd:mustard`)
 /// ## Representations:
 /// - int tuple `(206, 179, 1)`
 /// - float tuple `(0.81, 0.7, 0.0)`
@@ -9570,7 +9728,7 @@ pub const XKCD_MUSTARD: Color = Color(206, 179, 1);
 
 /// Colour `(r = 56, g = 2, b = 130)`
 ///
-/// Colour `XKCD_INDIGO` from the set `XKCD_COLORS`. (Colour number `1059`)
+/// Colour `XKCD_INDIGO` from the set `XKCD_COLORS`. (Colour number `1059`, name `xkcd:indigo`)
 /// ## Representations:
 /// - int tuple `(56, 2, 130)`
 /// - float tuple `(0.22, 0.01, 0.51)`
@@ -9579,7 +9737,7 @@ pub const XKCD_INDIGO: Color = Color(56, 2, 130);
 
 /// Colour `(r = 170, g = 255, b = 50)`
 ///
-/// Colour `XKCD_LIME` from the set `XKCD_COLORS`. (Colour number `1060`)
+/// Colour `XKCD_LIME` from the set `XKCD_COLORS`. (Colour number `1060`, name `xkcd:lime`)
 /// ## Representations:
 /// - int tuple `(170, 255, 50)`
 /// - float tuple `(0.67, 1.0, 0.2)`
@@ -9588,7 +9746,7 @@ pub const XKCD_LIME: Color = Color(170, 255, 50);
 
 /// Colour `(r = 83, g = 252, b = 161)`
 ///
-/// Colour `XKCD_SEA_GREEN` from the set `XKCD_COLORS`. (Colour number `1061`)
+/// Colour `XKCD_SEA_GREEN` from the set `XKCD_COLORS`. (Colour number `1061`, name `xkcd:sea green`)
 /// ## Representations:
 /// - int tuple `(83, 252, 161)`
 /// - float tuple `(0.33, 0.99, 0.63)`
@@ -9597,7 +9755,7 @@ pub const XKCD_SEA_GREEN: Color = Color(83, 252, 161);
 
 /// Colour `(r = 142, g = 130, b = 254)`
 ///
-/// Colour `XKCD_PERIWINKLE` from the set `XKCD_COLORS`. (Colour number `1062`)
+/// Colour `XKCD_PERIWINKLE` from the set `XKCD_COLORS`. (Colour number `1062`, name `xkcd:periwinkle`)
 /// ## Representations:
 /// - int tuple `(142, 130, 254)`
 /// - float tuple `(0.56, 0.51, 1.0)`
@@ -9606,7 +9764,7 @@ pub const XKCD_PERIWINKLE: Color = Color(142, 130, 254);
 
 /// Colour `(r = 203, g = 65, b = 107)`
 ///
-/// Colour `XKCD_DARK_PINK` from the set `XKCD_COLORS`. (Colour number `1063`)
+/// Colour `XKCD_DARK_PINK` from the set `XKCD_COLORS`. (Colour number `1063`, name `xkcd:dark pink`)
 /// ## Representations:
 /// - int tuple `(203, 65, 107)`
 /// - float tuple `(0.8, 0.25, 0.42)`
@@ -9615,7 +9773,7 @@ pub const XKCD_DARK_PINK: Color = Color(203, 65, 107);
 
 /// Colour `(r = 103, g = 122, b = 4)`
 ///
-/// Colour `XKCD_OLIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `1064`)
+/// Colour `XKCD_OLIVE_GREEN` from the set `XKCD_COLORS`. (Colour number `1064`, name `xkcd:olive green`)
 /// ## Representations:
 /// - int tuple `(103, 122, 4)`
 /// - float tuple `(0.4, 0.48, 0.02)`
@@ -9624,7 +9782,7 @@ pub const XKCD_OLIVE_GREEN: Color = Color(103, 122, 4);
 
 /// Colour `(r = 255, g = 176, b = 124)`
 ///
-/// Colour `XKCD_PEACH` from the set `XKCD_COLORS`. (Colour number `1065`)
+/// Colour `XKCD_PEACH` from the set `XKCD_COLORS`. (Colour number `1065`, name `xkcd:peach`)
 /// ## Representations:
 /// - int tuple `(255, 176, 124)`
 /// - float tuple `(1.0, 0.69, 0.49)`
@@ -9633,7 +9791,7 @@ pub const XKCD_PEACH: Color = Color(255, 176, 124);
 
 /// Colour `(r = 199, g = 253, b = 181)`
 ///
-/// Colour `XKCD_PALE_GREEN` from the set `XKCD_COLORS`. (Colour number `1066`)
+/// Colour `XKCD_PALE_GREEN` from the set `XKCD_COLORS`. (Colour number `1066`, name `xkcd:pale green`)
 /// ## Representations:
 /// - int tuple `(199, 253, 181)`
 /// - float tuple `(0.78, 0.99, 0.71)`
@@ -9642,7 +9800,7 @@ pub const XKCD_PALE_GREEN: Color = Color(199, 253, 181);
 
 /// Colour `(r = 173, g = 129, b = 80)`
 ///
-/// Colour `XKCD_LIGHT_BROWN` from the set `XKCD_COLORS`. (Colour number `1067`)
+/// Colour `XKCD_LIGHT_BROWN` from the set `XKCD_COLORS`. (Colour number `1067`, name `xkcd:light brown`)
 /// ## Representations:
 /// - int tuple `(173, 129, 80)`
 /// - float tuple `(0.68, 0.51, 0.31)`
@@ -9651,7 +9809,7 @@ pub const XKCD_LIGHT_BROWN: Color = Color(173, 129, 80);
 
 /// Colour `(r = 255, g = 2, b = 141)`
 ///
-/// Colour `XKCD_HOT_PINK` from the set `XKCD_COLORS`. (Colour number `1068`)
+/// Colour `XKCD_HOT_PINK` from the set `XKCD_COLORS`. (Colour number `1068`, name `xkcd:hot pink`)
 /// ## Representations:
 /// - int tuple `(255, 2, 141)`
 /// - float tuple `(1.0, 0.01, 0.55)`
@@ -9660,7 +9818,7 @@ pub const XKCD_HOT_PINK: Color = Color(255, 2, 141);
 
 /// Colour `(r = 0, g = 0, b = 0)`
 ///
-/// Colour `XKCD_BLACK` from the set `XKCD_COLORS`. (Colour number `1069`)
+/// Colour `XKCD_BLACK` from the set `XKCD_COLORS`. (Colour number `1069`, name `xkcd:black`)
 /// ## Representations:
 /// - int tuple `(0, 0, 0)`
 /// - float tuple `(0.0, 0.0, 0.0)`
@@ -9669,7 +9827,7 @@ pub const XKCD_BLACK: Color = Color(0, 0, 0);
 
 /// Colour `(r = 206, g = 162, b = 253)`
 ///
-/// Colour `XKCD_LILAC` from the set `XKCD_COLORS`. (Colour number `1070`)
+/// Colour `XKCD_LILAC` from the set `XKCD_COLORS`. (Colour number `1070`, name `xkcd:lilac`)
 /// ## Representations:
 /// - int tuple `(206, 162, 253)`
 /// - float tuple `(0.81, 0.64, 0.99)`
@@ -9678,7 +9836,7 @@ pub const XKCD_LILAC: Color = Color(206, 162, 253);
 
 /// Colour `(r = 0, g = 17, b = 70)`
 ///
-/// Colour `XKCD_NAVY_BLUE` from the set `XKCD_COLORS`. (Colour number `1071`)
+/// Colour `XKCD_NAVY_BLUE` from the set `XKCD_COLORS`. (Colour number `1071`, name `xkcd:navy blue`)
 /// ## Representations:
 /// - int tuple `(0, 17, 70)`
 /// - float tuple `(0.0, 0.07, 0.27)`
@@ -9687,7 +9845,7 @@ pub const XKCD_NAVY_BLUE: Color = Color(0, 17, 70);
 
 /// Colour `(r = 5, g = 4, b = 170)`
 ///
-/// Colour `XKCD_ROYAL_BLUE` from the set `XKCD_COLORS`. (Colour number `1072`)
+/// Colour `XKCD_ROYAL_BLUE` from the set `XKCD_COLORS`. (Colour number `1072`, name `xkcd:royal blue`)
 /// ## Representations:
 /// - int tuple `(5, 4, 170)`
 /// - float tuple `(0.02, 0.02, 0.67)`
@@ -9696,7 +9854,7 @@ pub const XKCD_ROYAL_BLUE: Color = Color(5, 4, 170);
 
 /// Colour `(r = 230, g = 218, b = 166)`
 ///
-/// Colour `XKCD_BEIGE` from the set `XKCD_COLORS`. (Colour number `1073`)
+/// Colour `XKCD_BEIGE` from the set `XKCD_COLORS`. (Colour number `1073`, name `xkcd:beige`)
 /// ## Representations:
 /// - int tuple `(230, 218, 166)`
 /// - float tuple `(0.9, 0.85, 0.65)`
@@ -9705,7 +9863,7 @@ pub const XKCD_BEIGE: Color = Color(230, 218, 166);
 
 /// Colour `(r = 255, g = 121, b = 108)`
 ///
-/// Colour `XKCD_SALMON` from the set `XKCD_COLORS`. (Colour number `1074`)
+/// Colour `XKCD_SALMON` from the set `XKCD_COLORS`. (Colour number `1074`, name `xkcd:salmon`)
 /// ## Representations:
 /// - int tuple `(255, 121, 108)`
 /// - float tuple `(1.0, 0.47, 0.42)`
@@ -9714,7 +9872,7 @@ pub const XKCD_SALMON: Color = Color(255, 121, 108);
 
 /// Colour `(r = 110, g = 117, b = 14)`
 ///
-/// Colour `XKCD_OLIVE` from the set `XKCD_COLORS`. (Colour number `1075`)
+/// Colour `XKCD_OLIVE` from the set `XKCD_COLORS`. (Colour number `1075`, name `xkcd:olive`)
 /// ## Representations:
 /// - int tuple `(110, 117, 14)`
 /// - float tuple `(0.43, 0.46, 0.05)`
@@ -9723,7 +9881,7 @@ pub const XKCD_OLIVE: Color = Color(110, 117, 14);
 
 /// Colour `(r = 101, g = 0, b = 33)`
 ///
-/// Colour `XKCD_MAROON` from the set `XKCD_COLORS`. (Colour number `1076`)
+/// Colour `XKCD_MAROON` from the set `XKCD_COLORS`. (Colour number `1076`, name `xkcd:maroon`)
 /// ## Representations:
 /// - int tuple `(101, 0, 33)`
 /// - float tuple `(0.4, 0.0, 0.13)`
@@ -9732,7 +9890,7 @@ pub const XKCD_MAROON: Color = Color(101, 0, 33);
 
 /// Colour `(r = 1, g = 255, b = 7)`
 ///
-/// Colour `XKCD_BRIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `1077`)
+/// Colour `XKCD_BRIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `1077`, name `xkcd:bright green`)
 /// ## Representations:
 /// - int tuple `(1, 255, 7)`
 /// - float tuple `(0.0, 1.0, 0.03)`
@@ -9741,7 +9899,7 @@ pub const XKCD_BRIGHT_GREEN: Color = Color(1, 255, 7);
 
 /// Colour `(r = 53, g = 6, b = 62)`
 ///
-/// Colour `XKCD_DARK_PURPLE` from the set `XKCD_COLORS`. (Colour number `1078`)
+/// Colour `XKCD_DARK_PURPLE` from the set `XKCD_COLORS`. (Colour number `1078`, name `xkcd:dark purple`)
 /// ## Representations:
 /// - int tuple `(53, 6, 62)`
 /// - float tuple `(0.21, 0.02, 0.24)`
@@ -9750,7 +9908,7 @@ pub const XKCD_DARK_PURPLE: Color = Color(53, 6, 62);
 
 /// Colour `(r = 174, g = 113, b = 129)`
 ///
-/// Colour `XKCD_MAUVE` from the set `XKCD_COLORS`. (Colour number `1079`)
+/// Colour `XKCD_MAUVE` from the set `XKCD_COLORS`. (Colour number `1079`, name `xkcd:mauve`)
 /// ## Representations:
 /// - int tuple `(174, 113, 129)`
 /// - float tuple `(0.68, 0.44, 0.51)`
@@ -9759,7 +9917,7 @@ pub const XKCD_MAUVE: Color = Color(174, 113, 129);
 
 /// Colour `(r = 6, g = 71, b = 12)`
 ///
-/// Colour `XKCD_FOREST_GREEN` from the set `XKCD_COLORS`. (Colour number `1080`)
+/// Colour `XKCD_FOREST_GREEN` from the set `XKCD_COLORS`. (Colour number `1080`, name `xkcd:forest green`)
 /// ## Representations:
 /// - int tuple `(6, 71, 12)`
 /// - float tuple `(0.02, 0.28, 0.05)`
@@ -9768,7 +9926,7 @@ pub const XKCD_FOREST_GREEN: Color = Color(6, 71, 12);
 
 /// Colour `(r = 19, g = 234, b = 201)`
 ///
-/// Colour `XKCD_AQUA` from the set `XKCD_COLORS`. (Colour number `1081`)
+/// Colour `XKCD_AQUA` from the set `XKCD_COLORS`. (Colour number `1081`, name `xkcd:aqua`)
 /// ## Representations:
 /// - int tuple `(19, 234, 201)`
 /// - float tuple `(0.07, 0.92, 0.79)`
@@ -9777,7 +9935,7 @@ pub const XKCD_AQUA: Color = Color(19, 234, 201);
 
 /// Colour `(r = 0, g = 255, b = 255)`
 ///
-/// Colour `XKCD_CYAN` from the set `XKCD_COLORS`. (Colour number `1082`)
+/// Colour `XKCD_CYAN` from the set `XKCD_COLORS`. (Colour number `1082`, name `xkcd:cyan`)
 /// ## Representations:
 /// - int tuple `(0, 255, 255)`
 /// - float tuple `(0.0, 1.0, 1.0)`
@@ -9786,7 +9944,7 @@ pub const XKCD_CYAN: Color = Color(0, 255, 255);
 
 /// Colour `(r = 209, g = 178, b = 111)`
 ///
-/// Colour `XKCD_TAN` from the set `XKCD_COLORS`. (Colour number `1083`)
+/// Colour `XKCD_TAN` from the set `XKCD_COLORS`. (Colour number `1083`, name `xkcd:tan`)
 /// ## Representations:
 /// - int tuple `(209, 178, 111)`
 /// - float tuple `(0.82, 0.7, 0.44)`
@@ -9795,7 +9953,7 @@ pub const XKCD_TAN: Color = Color(209, 178, 111);
 
 /// Colour `(r = 0, g = 3, b = 91)`
 ///
-/// Colour `XKCD_DARK_BLUE` from the set `XKCD_COLORS`. (Colour number `1084`)
+/// Colour `XKCD_DARK_BLUE` from the set `XKCD_COLORS`. (Colour number `1084`, name `xkcd:dark blue`)
 /// ## Representations:
 /// - int tuple `(0, 3, 91)`
 /// - float tuple `(0.0, 0.01, 0.36)`
@@ -9804,7 +9962,7 @@ pub const XKCD_DARK_BLUE: Color = Color(0, 3, 91);
 
 /// Colour `(r = 199, g = 159, b = 239)`
 ///
-/// Colour `XKCD_LAVENDER` from the set `XKCD_COLORS`. (Colour number `1085`)
+/// Colour `XKCD_LAVENDER` from the set `XKCD_COLORS`. (Colour number `1085`, name `xkcd:lavender`)
 /// ## Representations:
 /// - int tuple `(199, 159, 239)`
 /// - float tuple `(0.78, 0.62, 0.94)`
@@ -9813,7 +9971,7 @@ pub const XKCD_LAVENDER: Color = Color(199, 159, 239);
 
 /// Colour `(r = 6, g = 194, b = 172)`
 ///
-/// Colour `XKCD_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `1086`)
+/// Colour `XKCD_TURQUOISE` from the set `XKCD_COLORS`. (Colour number `1086`, name `xkcd:turquoise`)
 /// ## Representations:
 /// - int tuple `(6, 194, 172)`
 /// - float tuple `(0.02, 0.76, 0.67)`
@@ -9822,7 +9980,7 @@ pub const XKCD_TURQUOISE: Color = Color(6, 194, 172);
 
 /// Colour `(r = 3, g = 53, b = 0)`
 ///
-/// Colour `XKCD_DARK_GREEN` from the set `XKCD_COLORS`. (Colour number `1087`)
+/// Colour `XKCD_DARK_GREEN` from the set `XKCD_COLORS`. (Colour number `1087`, name `xkcd:dark green`)
 /// ## Representations:
 /// - int tuple `(3, 53, 0)`
 /// - float tuple `(0.01, 0.21, 0.0)`
@@ -9831,7 +9989,7 @@ pub const XKCD_DARK_GREEN: Color = Color(3, 53, 0);
 
 /// Colour `(r = 154, g = 14, b = 234)`
 ///
-/// Colour `XKCD_VIOLET` from the set `XKCD_COLORS`. (Colour number `1088`)
+/// Colour `XKCD_VIOLET` from the set `XKCD_COLORS`. (Colour number `1088`, name `xkcd:violet`)
 /// ## Representations:
 /// - int tuple `(154, 14, 234)`
 /// - float tuple `(0.6, 0.05, 0.92)`
@@ -9840,7 +9998,7 @@ pub const XKCD_VIOLET: Color = Color(154, 14, 234);
 
 /// Colour `(r = 191, g = 119, b = 246)`
 ///
-/// Colour `XKCD_LIGHT_PURPLE` from the set `XKCD_COLORS`. (Colour number `1089`)
+/// Colour `XKCD_LIGHT_PURPLE` from the set `XKCD_COLORS`. (Colour number `1089`, name `xkcd:light purple`)
 /// ## Representations:
 /// - int tuple `(191, 119, 246)`
 /// - float tuple `(0.75, 0.47, 0.96)`
@@ -9849,7 +10007,7 @@ pub const XKCD_LIGHT_PURPLE: Color = Color(191, 119, 246);
 
 /// Colour `(r = 137, g = 254, b = 5)`
 ///
-/// Colour `XKCD_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `1090`)
+/// Colour `XKCD_LIME_GREEN` from the set `XKCD_COLORS`. (Colour number `1090`, name `xkcd:lime green`)
 /// ## Representations:
 /// - int tuple `(137, 254, 5)`
 /// - float tuple `(0.54, 1.0, 0.02)`
@@ -9858,7 +10016,7 @@ pub const XKCD_LIME_GREEN: Color = Color(137, 254, 5);
 
 /// Colour `(r = 146, g = 149, b = 145)`
 ///
-/// Colour `XKCD_GREY` from the set `XKCD_COLORS`. (Colour number `1091`)
+/// Colour `XKCD_GREY` from the set `XKCD_COLORS`. (Colour number `1091`, name `xkcd:grey`)
 /// ## Representations:
 /// - int tuple `(146, 149, 145)`
 /// - float tuple `(0.57, 0.58, 0.57)`
@@ -9867,7 +10025,7 @@ pub const XKCD_GREY: Color = Color(146, 149, 145);
 
 /// Colour `(r = 117, g = 187, b = 253)`
 ///
-/// Colour `XKCD_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `1092`)
+/// Colour `XKCD_SKY_BLUE` from the set `XKCD_COLORS`. (Colour number `1092`, name `xkcd:sky blue`)
 /// ## Representations:
 /// - int tuple `(117, 187, 253)`
 /// - float tuple `(0.46, 0.73, 0.99)`
@@ -9876,7 +10034,7 @@ pub const XKCD_SKY_BLUE: Color = Color(117, 187, 253);
 
 /// Colour `(r = 255, g = 255, b = 20)`
 ///
-/// Colour `XKCD_YELLOW` from the set `XKCD_COLORS`. (Colour number `1093`)
+/// Colour `XKCD_YELLOW` from the set `XKCD_COLORS`. (Colour number `1093`, name `xkcd:yellow`)
 /// ## Representations:
 /// - int tuple `(255, 255, 20)`
 /// - float tuple `(1.0, 1.0, 0.08)`
@@ -9885,7 +10043,7 @@ pub const XKCD_YELLOW: Color = Color(255, 255, 20);
 
 /// Colour `(r = 194, g = 0, b = 120)`
 ///
-/// Colour `XKCD_MAGENTA` from the set `XKCD_COLORS`. (Colour number `1094`)
+/// Colour `XKCD_MAGENTA` from the set `XKCD_COLORS`. (Colour number `1094`, name `xkcd:magenta`)
 /// ## Representations:
 /// - int tuple `(194, 0, 120)`
 /// - float tuple `(0.76, 0.0, 0.47)`
@@ -9894,7 +10052,7 @@ pub const XKCD_MAGENTA: Color = Color(194, 0, 120);
 
 /// Colour `(r = 150, g = 249, b = 123)`
 ///
-/// Colour `XKCD_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `1095`)
+/// Colour `XKCD_LIGHT_GREEN` from the set `XKCD_COLORS`. (Colour number `1095`, name `xkcd:light green`)
 /// ## Representations:
 /// - int tuple `(150, 249, 123)`
 /// - float tuple `(0.59, 0.98, 0.48)`
@@ -9903,7 +10061,7 @@ pub const XKCD_LIGHT_GREEN: Color = Color(150, 249, 123);
 
 /// Colour `(r = 249, g = 115, b = 6)`
 ///
-/// Colour `XKCD_ORANGE` from the set `XKCD_COLORS`. (Colour number `1096`)
+/// Colour `XKCD_ORANGE` from the set `XKCD_COLORS`. (Colour number `1096`, name `xkcd:orange`)
 /// ## Representations:
 /// - int tuple `(249, 115, 6)`
 /// - float tuple `(0.98, 0.45, 0.02)`
@@ -9912,7 +10070,7 @@ pub const XKCD_ORANGE: Color = Color(249, 115, 6);
 
 /// Colour `(r = 2, g = 147, b = 134)`
 ///
-/// Colour `XKCD_TEAL` from the set `XKCD_COLORS`. (Colour number `1097`)
+/// Colour `XKCD_TEAL` from the set `XKCD_COLORS`. (Colour number `1097`, name `xkcd:teal`)
 /// ## Representations:
 /// - int tuple `(2, 147, 134)`
 /// - float tuple `(0.01, 0.58, 0.53)`
@@ -9921,7 +10079,7 @@ pub const XKCD_TEAL: Color = Color(2, 147, 134);
 
 /// Colour `(r = 149, g = 208, b = 252)`
 ///
-/// Colour `XKCD_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `1098`)
+/// Colour `XKCD_LIGHT_BLUE` from the set `XKCD_COLORS`. (Colour number `1098`, name `xkcd:light blue`)
 /// ## Representations:
 /// - int tuple `(149, 208, 252)`
 /// - float tuple `(0.58, 0.82, 0.99)`
@@ -9930,7 +10088,7 @@ pub const XKCD_LIGHT_BLUE: Color = Color(149, 208, 252);
 
 /// Colour `(r = 229, g = 0, b = 0)`
 ///
-/// Colour `XKCD_RED` from the set `XKCD_COLORS`. (Colour number `1099`)
+/// Colour `XKCD_RED` from the set `XKCD_COLORS`. (Colour number `1099`, name `xkcd:red`)
 /// ## Representations:
 /// - int tuple `(229, 0, 0)`
 /// - float tuple `(0.9, 0.0, 0.0)`
@@ -9939,7 +10097,7 @@ pub const XKCD_RED: Color = Color(229, 0, 0);
 
 /// Colour `(r = 101, g = 55, b = 0)`
 ///
-/// Colour `XKCD_BROWN` from the set `XKCD_COLORS`. (Colour number `1100`)
+/// Colour `XKCD_BROWN` from the set `XKCD_COLORS`. (Colour number `1100`, name `xkcd:brown`)
 /// ## Representations:
 /// - int tuple `(101, 55, 0)`
 /// - float tuple `(0.4, 0.22, 0.0)`
@@ -9948,7 +10106,7 @@ pub const XKCD_BROWN: Color = Color(101, 55, 0);
 
 /// Colour `(r = 255, g = 129, b = 192)`
 ///
-/// Colour `XKCD_PINK` from the set `XKCD_COLORS`. (Colour number `1101`)
+/// Colour `XKCD_PINK` from the set `XKCD_COLORS`. (Colour number `1101`, name `xkcd:pink`)
 /// ## Representations:
 /// - int tuple `(255, 129, 192)`
 /// - float tuple `(1.0, 0.51, 0.75)`
@@ -9957,7 +10115,7 @@ pub const XKCD_PINK: Color = Color(255, 129, 192);
 
 /// Colour `(r = 3, g = 67, b = 223)`
 ///
-/// Colour `XKCD_BLUE` from the set `XKCD_COLORS`. (Colour number `1102`)
+/// Colour `XKCD_BLUE` from the set `XKCD_COLORS`. (Colour number `1102`, name `xkcd:blue`)
 /// ## Representations:
 /// - int tuple `(3, 67, 223)`
 /// - float tuple `(0.01, 0.26, 0.87)`
@@ -9966,7 +10124,7 @@ pub const XKCD_BLUE: Color = Color(3, 67, 223);
 
 /// Colour `(r = 21, g = 176, b = 26)`
 ///
-/// Colour `XKCD_GREEN` from the set `XKCD_COLORS`. (Colour number `1103`)
+/// Colour `XKCD_GREEN` from the set `XKCD_COLORS`. (Colour number `1103`, name `xkcd:green`)
 /// ## Representations:
 /// - int tuple `(21, 176, 26)`
 /// - float tuple `(0.08, 0.69, 0.1)`
@@ -9975,9 +10133,99 @@ pub const XKCD_GREEN: Color = Color(21, 176, 26);
 
 /// Colour `(r = 126, g = 30, b = 156)`
 ///
-/// Colour `XKCD_PURPLE` from the set `XKCD_COLORS`. (Colour number `1104`)
+/// Colour `XKCD_PURPLE` from the set `XKCD_COLORS`. (Colour number `1104`, name `xkcd:purple`)
 /// ## Representations:
 /// - int tuple `(126, 30, 156)`
 /// - float tuple `(0.49, 0.12, 0.61)`
 /// - hex: `#7E1E9C`
 pub const XKCD_PURPLE: Color = Color(126, 30, 156);
+
+/// Colour `(r = 31, g = 119, b = 180)`
+///
+/// Colour `TAB_BLUE` from the set `TABLEAU_COLORS`. (Colour number `1105`, name `tab:blue`)
+/// ## Representations:
+/// - int tuple `(31, 119, 180)`
+/// - float tuple `(0.12, 0.47, 0.71)`
+/// - hex: `#1F77B4`
+pub const TAB_BLUE: Color = Color(31, 119, 180);
+
+/// Colour `(r = 255, g = 127, b = 14)`
+///
+/// Colour `TAB_ORANGE` from the set `TABLEAU_COLORS`. (Colour number `1106`, name `tab:orange`)
+/// ## Representations:
+/// - int tuple `(255, 127, 14)`
+/// - float tuple `(1.0, 0.5, 0.05)`
+/// - hex: `#FF7F0E`
+pub const TAB_ORANGE: Color = Color(255, 127, 14);
+
+/// Colour `(r = 44, g = 160, b = 44)`
+///
+/// Colour `TAB_GREEN` from the set `TABLEAU_COLORS`. (Colour number `1107`, name `tab:green`)
+/// ## Representations:
+/// - int tuple `(44, 160, 44)`
+/// - float tuple `(0.17, 0.63, 0.17)`
+/// - hex: `#2CA02C`
+pub const TAB_GREEN: Color = Color(44, 160, 44);
+
+/// Colour `(r = 214, g = 39, b = 40)`
+///
+/// Colour `TAB_RED` from the set `TABLEAU_COLORS`. (Colour number `1108`, name `tab:red`)
+/// ## Representations:
+/// - int tuple `(214, 39, 40)`
+/// - float tuple `(0.84, 0.15, 0.16)`
+/// - hex: `#D62728`
+pub const TAB_RED: Color = Color(214, 39, 40);
+
+/// Colour `(r = 148, g = 103, b = 189)`
+///
+/// Colour `TAB_PURPLE` from the set `TABLEAU_COLORS`. (Colour number `1109`, name `tab:purple`)
+/// ## Representations:
+/// - int tuple `(148, 103, 189)`
+/// - float tuple `(0.58, 0.4, 0.74)`
+/// - hex: `#9467BD`
+pub const TAB_PURPLE: Color = Color(148, 103, 189);
+
+/// Colour `(r = 140, g = 86, b = 75)`
+///
+/// Colour `TAB_BROWN` from the set `TABLEAU_COLORS`. (Colour number `1110`, name `tab:brown`)
+/// ## Representations:
+/// - int tuple `(140, 86, 75)`
+/// - float tuple `(0.55, 0.34, 0.29)`
+/// - hex: `#8C564B`
+pub const TAB_BROWN: Color = Color(140, 86, 75);
+
+/// Colour `(r = 227, g = 119, b = 194)`
+///
+/// Colour `TAB_PINK` from the set `TABLEAU_COLORS`. (Colour number `1111`, name `tab:pink`)
+/// ## Representations:
+/// - int tuple `(227, 119, 194)`
+/// - float tuple `(0.89, 0.47, 0.76)`
+/// - hex: `#E377C2`
+pub const TAB_PINK: Color = Color(227, 119, 194);
+
+/// Colour `(r = 127, g = 127, b = 127)`
+///
+/// Colour `TAB_GRAY` from the set `TABLEAU_COLORS`. (Colour number `1112`, name `tab:gray`)
+/// ## Representations:
+/// - int tuple `(127, 127, 127)`
+/// - float tuple `(0.5, 0.5, 0.5)`
+/// - hex: `#7F7F7F`
+pub const TAB_GRAY: Color = Color(127, 127, 127);
+
+/// Colour `(r = 188, g = 189, b = 34)`
+///
+/// Colour `TAB_OLIVE` from the set `TABLEAU_COLORS`. (Colour number `1113`, name `tab:olive`)
+/// ## Representations:
+/// - int tuple `(188, 189, 34)`
+/// - float tuple `(0.74, 0.74, 0.13)`
+/// - hex: `#BCBD22`
+pub const TAB_OLIVE: Color = Color(188, 189, 34);
+
+/// Colour `(r = 23, g = 190, b = 207)`
+///
+/// Colour `TAB_CYAN` from the set `TABLEAU_COLORS`. (Colour number `1114`, name `tab:cyan`)
+/// ## Representations:
+/// - int tuple `(23, 190, 207)`
+/// - float tuple `(0.09, 0.75, 0.81)`
+/// - hex: `#17BECF`
+pub const TAB_CYAN: Color = Color(23, 190, 207);
@@ -0,0 +1,282 @@
+//! CMYK colours for print, with the naive, profile-free conversion from and to sRGB.
+//!
+//! Real print separations depend on inks, paper and press and are made with ICC profiles. This
+//! module uses none: it treats cyan, magenta and yellow as the exact complements of red, green and
+//! blue and black as a neutral filter, `r = (1 - c)(1 - k)`, which is also what CSS uses for
+//! `device-cmyk()` without a colour profile. The values are a starting point for print, not a
+//! colour managed conversion.
+//!
+//! A `Separation` decides how much of the grey component of a colour (the part cyan, magenta and
+//! yellow have in common) is printed with black instead:
+//! - the naive conversion replaces all of it, `k = 1 - max(r, g, b)`
+//! - grey component replacement (GCR) replaces a share of it, set with the black generation
+//! - under colour removal (UCR) replaces it only in the shadows, from the black start onwards
+//!
+//! Black generation does not change the colour in this model. The total ink limit does: if the
+//! four inks add up to more than it, cyan, magenta and yellow are reduced.
+//!
+//! `Cmyk` parses and serializes the CSS
+//! [`device-cmyk()`](https://www.w3.org/TR/css-color-5/#device-cmyk) function, and `Color` accepts
+//! it too.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, ROYALBLUE};
+//! use colors::cmyk::{Cmyk, Separation};
+//!
+//! let cmyk = Cmyk::from(ROYALBLUE);
+//! assert_eq!(format!("{:.3}", cmyk), "(0.711, 0.533, 0.000, 0.118)");
+//! assert_eq!(Color::from(cmyk), ROYALBLUE);
+//!
+//! // half of the grey component in black, at most 250% ink
+//! let separation = Separation::NAIVE
+//!     .with_black_generation(0.5)
+//!     .with_ink_limit(2.5);
+//! let cmyk = separation.separate(Color(40, 20, 10));
+//! assert_eq!(cmyk.to_css(), "device-cmyk(0.599816 0.711409 0.767206 0.421569)");
+//! assert!((cmyk.total_ink() - 2.5).abs() < 1e-6);
+//!
+//! let cmyk: Cmyk = "device-cmyk(0 81% 81% 30%)".parse().unwrap();
+//! assert_eq!(Color::from(cmyk), Color(179, 34, 34));
+//! ```
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::clr::Color;
+use crate::css::format_number;
+use crate::parse::{parse, Cursor, Number, ParseColorError, ParseColorErrorKind};
+
+/// A colour as amounts of cyan, magenta, yellow and black ink, each in `[0, 1]`.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Cmyk {
+    pub c: f32,
+    pub m: f32,
+    pub y: f32,
+    pub k: f32,
+}
+
+impl_fmt!(Cmyk { c, m, y, k });
+
+impl Cmyk {
+    /// Creates a new CMYK colour.
+    pub fn new(c: f32, m: f32, y: f32, k: f32) -> Self {
+        Cmyk { c, m, y, k }
+    }
+
+    /// The sum of the four inks, e.g. `3.0` for 300% coverage.
+    pub fn total_ink(&self) -> f32 {
+        self.c + self.m + self.y + self.k
+    }
+
+    /// Serializes the colour as CSS `device-cmyk()` with the components as numbers.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::RED;
+    /// # use colors::cmyk::Cmyk;
+    /// # fn main() {
+    /// assert_eq!(Cmyk::from(RED).to_css(), "device-cmyk(0 1 1 0)");
+    /// # }
+    /// ```
+    pub fn to_css(&self) -> String {
+        let components: Vec<String> = [self.c, self.m, self.y, self.k]
+            .iter()
+            .map(|&value| format_number(value))
+            .collect();
+        format!("device-cmyk({})", components.join(" "))
+    }
+}
+
+/// How a colour is separated into CMYK inks, see the module documentation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Separation {
+    black_generation: f32,
+    black_start: f32,
+    ink_limit: f32,
+}
+
+impl Default for Separation {
+    fn default() -> Self {
+        Separation::NAIVE
+    }
+}
+
+impl Separation {
+    /// The naive conversion: all of the grey component in black and no ink limit.
+    pub const NAIVE: Separation = Separation {
+        black_generation: 1.0,
+        black_start: 0.0,
+        ink_limit: 4.0,
+    };
+
+    /// Sets the share of the grey component printed with black in `[0, 1]`, `0` uses no black at
+    /// all and `1` as much as possible.
+    pub fn with_black_generation(self, black_generation: f32) -> Self {
+        Separation {
+            black_generation: black_generation.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Sets the grey component in `[0, 1]` from which black is used. Above it the share printed
+    /// with black rises linearly and reaches the black generation for black itself.
+    pub fn with_black_start(self, black_start: f32) -> Self {
+        Separation {
+            black_start: black_start.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Sets the total ink limit in `[0, 4]`, e.g. `3.0` for 300%.
+    pub fn with_ink_limit(self, ink_limit: f32) -> Self {
+        Separation {
+            ink_limit: ink_limit.clamp(0.0, 4.0),
+            ..self
+        }
+    }
+
+    /// The share of the grey component printed with black.
+    pub fn black_generation(&self) -> f32 {
+        self.black_generation
+    }
+
+    /// The grey component from which black is used.
+    pub fn black_start(&self) -> f32 {
+        self.black_start
+    }
+
+    /// The total ink limit.
+    pub fn ink_limit(&self) -> f32 {
+        self.ink_limit
+    }
+
+    /// Separates `color` into CMYK inks.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::Color;
+    /// # use colors::cmyk::{Cmyk, Separation};
+    /// # fn main() {
+    /// let grey = Color(102, 102, 102);
+    /// assert_eq!(Separation::NAIVE.separate(grey), Cmyk::new(0.0, 0.0, 0.0, 0.6));
+    /// // under colour removal leaves the mid tones to cyan, magenta and yellow
+    /// let ucr = Separation::NAIVE.with_black_start(0.8);
+    /// assert_eq!(ucr.separate(grey), Cmyk::new(0.6, 0.6, 0.6, 0.0));
+    /// # }
+    /// ```
+    pub fn separate(&self, color: Color) -> Cmyk {
+        let rgb = [color.0, color.1, color.2].map(|c| f32::from(c) / 255.0);
+        let grey = 1.0 - rgb[0].max(rgb[1]).max(rgb[2]);
+        let ramp = if self.black_start < 1.0 {
+            ((grey - self.black_start) / (1.0 - self.black_start)).max(0.0)
+        } else {
+            0.0
+        };
+        let mut k = self.black_generation * ramp;
+        let mut cmy = if k < 1.0 {
+            rgb.map(|c| (1.0 - c / (1.0 - k)).clamp(0.0, 1.0))
+        } else {
+            [0.0; 3]
+        };
+
+        let sum: f32 = cmy.iter().sum();
+        if sum + k > self.ink_limit {
+            if k >= self.ink_limit {
+                k = self.ink_limit;
+                cmy = [0.0; 3];
+            } else {
+                let scale = (self.ink_limit - k) / sum;
+                cmy = cmy.map(|c| c * scale);
+            }
+        }
+        let [c, m, y] = cmy;
+        Cmyk { c, m, y, k }
+    }
+}
+
+impl From<Color> for Cmyk {
+    /// The naive conversion, see `Separation::NAIVE`.
+    fn from(color: Color) -> Self {
+        Separation::NAIVE.separate(color)
+    }
+}
+
+impl From<Cmyk> for Color {
+    /// The naive conversion, as used by CSS for `device-cmyk()` without a colour profile.
+    fn from(cmyk: Cmyk) -> Self {
+        let channel = |ink: f32| {
+            let value = (1.0 - ink.clamp(0.0, 1.0)) * (1.0 - cmyk.k.clamp(0.0, 1.0));
+            (value * 255.0).round() as u8
+        };
+        Color(channel(cmyk.c), channel(cmyk.m), channel(cmyk.y))
+    }
+}
+
+/// Converts a `device-cmyk()` component given as number or percentage to `[0, 1]`.
+fn component(cursor: &mut Cursor, allow_none: bool) -> Result<f32, ParseColorError> {
+    if allow_none && cursor.keyword("none") {
+        return Ok(0.0);
+    }
+    match cursor.number()? {
+        Number::Plain(value) => Ok(value.clamp(0.0, 1.0)),
+        Number::Percent(percent) => Ok((percent / 100.0).clamp(0.0, 1.0)),
+        Number::Unit(..) => Err(cursor.error(ParseColorErrorKind::ExpectedNumber)),
+    }
+}
+
+/// Parses the arguments of `device-cmyk()`, starting after the opening parenthesis, in the space
+/// separated syntax with an optional `/ alpha` or the legacy comma separated syntax.
+pub(crate) fn parse_device_cmyk_arguments(
+    cursor: &mut Cursor,
+) -> Result<(Cmyk, f32), ParseColorError> {
+    let c = component(cursor, true)?;
+    let legacy = cursor.eat(',');
+    let m = component(cursor, !legacy)?;
+    if legacy {
+        cursor.expect(',')?;
+    }
+    let y = component(cursor, !legacy)?;
+    if legacy {
+        cursor.expect(',')?;
+    }
+    let k = component(cursor, !legacy)?;
+    let alpha = if !legacy && cursor.eat('/') {
+        if cursor.keyword("none") {
+            0.0
+        } else {
+            crate::parse::alpha(cursor.number()?, cursor)?
+        }
+    } else {
+        1.0
+    };
+    cursor.expect(')')?;
+    Ok((Cmyk { c, m, y, k }, alpha))
+}
+
+/// Parses CSS `device-cmyk()`, and converts the other notations understood by `Color` naively.
+/// The alpha value is validated but discarded.
+impl FromStr for Cmyk {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cursor = Cursor::new(s);
+        cursor.skip_whitespace();
+        if !cursor.ident().eq_ignore_ascii_case("device-cmyk") || !cursor.eat('(') {
+            return parse(s).map(|(color, _)| Cmyk::from(color));
+        }
+        let (cmyk, _) = parse_device_cmyk_arguments(&mut cursor)?;
+        cursor.skip_whitespace();
+        match cursor.peek() {
+            Some(_) => Err(cursor.unexpected()),
+            None => Ok(cmyk),
+        }
+    }
+}
+
+impl TryFrom<&str> for Cmyk {
+    type Error = ParseColorError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
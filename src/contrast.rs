@@ -0,0 +1,99 @@
+//! Contrast and relative luminance as defined by the
+//! [Web Content Accessibility Guidelines (WCAG) 2.x](https://www.w3.org/TR/WCAG22/#dfn-contrast-ratio).
+//!
+//! The contrast ratio of two colours ranges from `1` (no contrast) to `21` (black on white).
+//! WCAG requires a minimum ratio depending on the conformance level and the text size; large text
+//! is at least 18 point, or 14 point bold.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{BLACK, GOLDENROD, WHITE};
+//! use colors::contrast::{contrast_ratio, passes, readable_text_color, TextSize, WcagLevel};
+//!
+//! assert_eq!(format!("{:.2}", contrast_ratio(WHITE, GOLDENROD)), "2.24");
+//! assert!(!passes(WHITE, GOLDENROD, WcagLevel::Aa, TextSize::Large));
+//! assert!(passes(BLACK, GOLDENROD, WcagLevel::Aa, TextSize::Normal));
+//! assert_eq!(readable_text_color(GOLDENROD), BLACK);
+//! ```
+use crate::clr::{Color, BLACK, WHITE};
+use crate::rgb::color_to_linear;
+
+/// A WCAG conformance level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WcagLevel {
+    /// Level AA, the level usually required
+    Aa,
+    /// Level AAA, the enhanced contrast level
+    Aaa,
+}
+
+/// The size class of text, which determines the contrast it needs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TextSize {
+    /// Text below 18 point (14 point if bold)
+    Normal,
+    /// Text of at least 18 point, or 14 point if bold
+    Large,
+}
+
+impl WcagLevel {
+    /// The minimum contrast ratio text of the given size needs to conform to the level.
+    pub fn min_ratio(self, size: TextSize) -> f32 {
+        match (self, size) {
+            (WcagLevel::Aa, TextSize::Normal) => 4.5,
+            (WcagLevel::Aa, TextSize::Large) => 3.0,
+            (WcagLevel::Aaa, TextSize::Normal) => 7.0,
+            (WcagLevel::Aaa, TextSize::Large) => 4.5,
+        }
+    }
+}
+
+impl Color {
+    /// The relative luminance in `[0, 1]` as defined by WCAG: the luminance of the linear light
+    /// colour, `0` for black and `1` for white.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::{Color, WHITE};
+    /// # fn main() {
+    /// assert_eq!(WHITE.relative_luminance(), 1.0);
+    /// assert_eq!(format!("{:.4}", Color(128, 128, 128).relative_luminance()), "0.2159");
+    /// # }
+    /// ```
+    pub fn relative_luminance(&self) -> f32 {
+        let [r, g, b] = color_to_linear(*self);
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+}
+
+/// The WCAG contrast ratio of two colours in `[1, 21]`. The order of the colours does not matter.
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (a.relative_luminance(), b.relative_luminance());
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Whether text in `foreground` on `background` has enough contrast for the level and text size.
+pub fn passes(foreground: Color, background: Color, level: WcagLevel, size: TextSize) -> bool {
+    contrast_ratio(foreground, background) >= level.min_ratio(size)
+}
+
+/// Black or white, whichever has the higher contrast as text on `background`.
+pub fn readable_text_color(background: Color) -> Color {
+    if contrast_ratio(BLACK, background) >= contrast_ratio(WHITE, background) {
+        BLACK
+    } else {
+        WHITE
+    }
+}
+
+/// The candidate with the highest contrast as text on `background`, `None` if there are no
+/// candidates. Ties go to the earlier candidate.
+pub fn most_readable(background: Color, candidates: &[Color]) -> Option<Color> {
+    let mut best = *candidates.first()?;
+    for &candidate in &candidates[1..] {
+        if contrast_ratio(candidate, background) > contrast_ratio(best, background) {
+            best = candidate;
+        }
+    }
+    Some(best)
+}
@@ -0,0 +1,537 @@
+//! Parsing and serializing colours in the syntax of
+//! [CSS Color Level 4](https://www.w3.org/TR/css-color-4/).
+//!
+//! `CssColor` keeps the colour in the space it was specified in, so that `lab(…)` stays a Lab colour
+//! and `color(display-p3 …)` a Display P3 colour. Missing components given as `none` are kept as
+//! `None`. Parsing supports hex notation, the CSS named colours, `transparent` and the functions
+//! `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()` and `color()`,
+//! in the space separated syntax with an optional `/ alpha` as well as the legacy comma separated
+//! syntax where CSS allows it.
+//!
+//! The `Display` implementation serializes the colour as described in the
+//! [specification](https://www.w3.org/TR/css-color-4/#serializing-color-values): the sRGB based
+//! syntaxes become `rgb()`/`rgba()`, everything else keeps its function.
+//!
+//! # Examples
+//! ```
+//! use colors::css::{CssColor, CssSpace};
+//!
+//! let color: CssColor = "lab(52.2345% 40.1645 59.9971 / .5)".parse().unwrap();
+//! assert_eq!(color.space, CssSpace::Lab);
+//! assert_eq!(color.to_string(), "lab(52.2345 40.1645 59.9971 / 0.5)");
+//!
+//! let color: CssColor = "hsl(120deg 100% 25%)".parse().unwrap();
+//! assert_eq!(color.to_string(), "rgb(0, 128, 0)");
+//! ```
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::cie::{Adaptation, Lab, Lch, WhitePoint, Xyz};
+use crate::clr::{self, Color, ColorSet};
+use crate::gamut::GamutMapping;
+use crate::hsl::{hsl_to_rgb, hwb_to_rgb};
+use crate::oklab::{Oklab, Oklch};
+use crate::parse::{parse_hex, Cursor, Number, ParseColorError, ParseColorErrorKind, Unit};
+use crate::rgba::Rgba;
+use crate::space::RgbSpace;
+
+/// The predefined colour spaces usable in `color(…)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PredefinedSpace {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    A98Rgb,
+    ProphotoRgb,
+    Rec2020,
+    XyzD50,
+    XyzD65,
+}
+
+impl PredefinedSpace {
+    /// The name of the space as used in CSS, e.g. `"display-p3"`.
+    pub fn name(self) -> &'static str {
+        use PredefinedSpace::*;
+        match self {
+            Srgb => "srgb",
+            SrgbLinear => "srgb-linear",
+            DisplayP3 => "display-p3",
+            A98Rgb => "a98-rgb",
+            ProphotoRgb => "prophoto-rgb",
+            Rec2020 => "rec2020",
+            XyzD50 => "xyz-d50",
+            XyzD65 => "xyz-d65",
+        }
+    }
+
+    /// Looks up a space by its CSS name, ignoring case. `xyz` is an alias for `xyz-d65`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        use PredefinedSpace::*;
+        let space = match name.to_ascii_lowercase().as_str() {
+            "srgb" => Srgb,
+            "srgb-linear" => SrgbLinear,
+            "display-p3" => DisplayP3,
+            "a98-rgb" => A98Rgb,
+            "prophoto-rgb" => ProphotoRgb,
+            "rec2020" => Rec2020,
+            "xyz-d50" => XyzD50,
+            "xyz" | "xyz-d65" => XyzD65,
+            _ => return None,
+        };
+        Some(space)
+    }
+}
+
+/// The syntax a `CssColor` was given in, which also determines the meaning of its components.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CssSpace {
+    /// `rgb()`, hex notation and named colours: red, green and blue in `[0, 255]`
+    Rgb,
+    /// `hsl()`: hue in degrees, saturation and lightness in percent
+    Hsl,
+    /// `hwb()`: hue in degrees, whiteness and blackness in percent
+    Hwb,
+    /// `lab()`: lightness in `[0, 100]` and the `a` and `b` axes
+    Lab,
+    /// `lch()`: lightness in `[0, 100]`, chroma and hue in degrees
+    Lch,
+    /// `oklab()`: lightness in `[0, 1]` and the `a` and `b` axes
+    Oklab,
+    /// `oklch()`: lightness in `[0, 1]`, chroma and hue in degrees
+    Oklch,
+    /// `color()` with one of the predefined spaces, components usually in `[0, 1]`
+    Predefined(PredefinedSpace),
+}
+
+/// How a component is read: either a hue, or a number where `100%` corresponds to the given value.
+#[derive(Copy, Clone)]
+enum Component {
+    Hue,
+    Scaled(f32),
+}
+
+impl CssSpace {
+    fn components(self) -> [Component; 3] {
+        use Component::*;
+        match self {
+            CssSpace::Rgb => [Scaled(255.0), Scaled(255.0), Scaled(255.0)],
+            CssSpace::Hsl | CssSpace::Hwb => [Hue, Scaled(100.0), Scaled(100.0)],
+            CssSpace::Lab => [Scaled(100.0), Scaled(125.0), Scaled(125.0)],
+            CssSpace::Lch => [Scaled(100.0), Scaled(150.0), Hue],
+            CssSpace::Oklab => [Scaled(1.0), Scaled(0.4), Scaled(0.4)],
+            CssSpace::Oklch => [Scaled(1.0), Scaled(0.4), Hue],
+            CssSpace::Predefined(_) => [Scaled(1.0), Scaled(1.0), Scaled(1.0)],
+        }
+    }
+}
+
+/// A colour parsed from CSS, in the space it was specified in.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CssColor {
+    /// The syntax, and with it the colour space, of the colour
+    pub space: CssSpace,
+    /// The three components in the units described at `CssSpace`, `None` for `none`
+    pub components: [Option<f32>; 3],
+    /// The alpha value in `[0, 1]`, `None` for `none`
+    pub alpha: Option<f32>,
+}
+
+impl CssColor {
+    /// Creates an opaque colour with the given components.
+    pub fn new(space: CssSpace, components: [f32; 3]) -> Self {
+        CssColor {
+            space,
+            components: [
+                Some(components[0]),
+                Some(components[1]),
+                Some(components[2]),
+            ],
+            alpha: Some(1.0),
+        }
+    }
+
+    /// For the sRGB based syntaxes (`rgb()`, `hsl()`, `hwb()`), returns red, green and blue in
+    /// `[0, 255]`, treating missing components as zero.
+    fn srgb_channels(&self) -> Option<[f32; 3]> {
+        match self.space {
+            CssSpace::Rgb | CssSpace::Hsl | CssSpace::Hwb => Some(self.legacy_channels()),
+            _ => None,
+        }
+    }
+
+    /// Red, green and blue in `[0, 255]` of a colour in `hsl()` or `hwb()` syntax, the components
+    /// of all other spaces are taken as those of `rgb()`.
+    fn legacy_channels(&self) -> [f32; 3] {
+        let [c0, c1, c2] = self.components.map(|c| c.unwrap_or(0.0));
+        let rgb = match self.space {
+            CssSpace::Hsl => hsl_to_rgb(c0, c1 / 100.0, c2 / 100.0),
+            CssSpace::Hwb => hwb_to_rgb(c0, c1 / 100.0, c2 / 100.0),
+            _ => return [c0, c1, c2],
+        };
+        rgb.map(|c| c * 255.0)
+    }
+
+    /// Converts a colour given in one of the sRGB based syntaxes (`rgb()`, `hsl()`, `hwb()`, hex
+    /// notation and named colours) to a `Color`, discarding the alpha value. Returns `None` for
+    /// the other spaces.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr;
+    /// # use colors::css::CssColor;
+    /// # fn main() {
+    /// let color: CssColor = "hwb(0 0% 0%)".parse().unwrap();
+    /// assert_eq!(color.to_srgb(), Some(clr::R));
+    /// # }
+    /// ```
+    pub fn to_srgb(&self) -> Option<Color> {
+        self.srgb_channels()
+            .map(|[r, g, b]| Color(to_byte(r), to_byte(g), to_byte(b)))
+    }
+}
+
+impl PredefinedSpace {
+    /// The RGB space behind the name, `None` for the XYZ spaces.
+    pub fn rgb_space(self) -> Option<RgbSpace> {
+        use PredefinedSpace::*;
+        let space = match self {
+            Srgb => RgbSpace::SRGB,
+            SrgbLinear => RgbSpace::SRGB_LINEAR,
+            DisplayP3 => RgbSpace::DISPLAY_P3,
+            A98Rgb => RgbSpace::ADOBE_RGB,
+            ProphotoRgb => RgbSpace::PROPHOTO_RGB,
+            Rec2020 => RgbSpace::REC2020,
+            XyzD50 | XyzD65 => return None,
+        };
+        Some(space)
+    }
+}
+
+impl CssColor {
+    /// Converts the colour to XYZ relative to D65, treating missing components as zero. As in
+    /// CSS, `lab()` and `lch()` are relative to D50 and adapted with the Bradford transform.
+    pub fn to_xyz(&self) -> Xyz {
+        let [c0, c1, c2] = self.components.map(|c| c.unwrap_or(0.0));
+        let from_d50 = |xyz: Xyz| xyz.adapt(WhitePoint::D50, WhitePoint::D65, Adaptation::Bradford);
+        match self.space {
+            CssSpace::Rgb | CssSpace::Hsl | CssSpace::Hwb => {
+                let rgb = self.legacy_channels();
+                RgbSpace::SRGB.to_xyz(rgb.map(|c| c / 255.0))
+            }
+            CssSpace::Lab => from_d50(Lab::new(c0, c1, c2).to_xyz(WhitePoint::D50)),
+            CssSpace::Lch => from_d50(Lab::from(Lch::new(c0, c1, c2)).to_xyz(WhitePoint::D50)),
+            CssSpace::Oklab => Xyz::from(Oklab::new(c0, c1, c2)),
+            CssSpace::Oklch => Xyz::from(Oklch::new(c0, c1, c2)),
+            CssSpace::Predefined(PredefinedSpace::XyzD65) => Xyz::new(c0, c1, c2),
+            CssSpace::Predefined(PredefinedSpace::XyzD50) => from_d50(Xyz::new(c0, c1, c2)),
+            CssSpace::Predefined(space) => space
+                .rgb_space()
+                .map_or(Xyz::new(c0, c1, c2), |rgb| rgb.to_xyz([c0, c1, c2])),
+        }
+    }
+
+    /// Converts a colour in any space to a `Color`, discarding the alpha value and bringing
+    /// colours outside sRGB into its gamut with `method`. CSS itself uses `GamutMapping::Css`.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::Color;
+    /// # use colors::css::CssColor;
+    /// # use colors::gamut::GamutMapping;
+    /// # fn main() {
+    /// let color: CssColor = "color(display-p3 1 0 0)".parse().unwrap();
+    /// assert_eq!(color.to_color(GamutMapping::Clip), Color(255, 0, 0));
+    /// assert_eq!(color.to_color(GamutMapping::Css), Color(255, 11, 12));
+    /// let color: CssColor = "lab(50 -80 0)".parse().unwrap();
+    /// assert_eq!(color.to_color(GamutMapping::Clip), Color(0, 147, 116));
+    /// # }
+    /// ```
+    pub fn to_color(&self, method: GamutMapping) -> Color {
+        if let Some(color) = self.to_srgb() {
+            return color;
+        }
+        method.map(self.to_xyz())
+    }
+}
+
+impl From<Color> for CssColor {
+    fn from(color: Color) -> Self {
+        CssColor::new(
+            CssSpace::Rgb,
+            [color.0.into(), color.1.into(), color.2.into()],
+        )
+    }
+}
+
+impl From<Rgba> for CssColor {
+    fn from(rgba: Rgba) -> Self {
+        CssColor {
+            alpha: Some(f32::from(rgba.3) / 255.0),
+            ..CssColor::from(rgba.color())
+        }
+    }
+}
+
+fn to_byte(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+/// Converts a hue given as a number (degrees) or an angle to degrees.
+fn hue(number: Number, cursor: &Cursor) -> Result<f32, ParseColorError> {
+    match number {
+        Number::Plain(degrees) | Number::Unit(degrees, Unit::Deg) => Ok(degrees),
+        Number::Unit(radians, Unit::Rad) => Ok(radians.to_degrees()),
+        Number::Unit(gradians, Unit::Grad) => Ok(gradians * 0.9),
+        Number::Unit(turns, Unit::Turn) => Ok(turns * 360.0),
+        Number::Percent(_) => Err(cursor.error(ParseColorErrorKind::ExpectedNumber)),
+    }
+}
+
+/// Reads one component, returning `None` for `none` if allowed.
+fn component(
+    cursor: &mut Cursor,
+    kind: Component,
+    allow_none: bool,
+) -> Result<Option<f32>, ParseColorError> {
+    if allow_none && cursor.keyword("none") {
+        return Ok(None);
+    }
+    let number = cursor.number()?;
+    let value = match (kind, number) {
+        (Component::Hue, number) => hue(number, cursor)?,
+        (Component::Scaled(_), Number::Plain(value)) => value,
+        (Component::Scaled(scale), Number::Percent(percent)) => percent / 100.0 * scale,
+        (Component::Scaled(_), Number::Unit(..)) => {
+            return Err(cursor.error(ParseColorErrorKind::ExpectedNumber))
+        }
+    };
+    Ok(Some(value))
+}
+
+fn alpha(cursor: &mut Cursor, allow_none: bool) -> Result<Option<f32>, ParseColorError> {
+    if allow_none && cursor.keyword("none") {
+        return Ok(None);
+    }
+    let number = cursor.number()?;
+    crate::parse::alpha(number, cursor).map(Some)
+}
+
+/// Parses the arguments of a colour function after the opening parenthesis, up to and including
+/// the closing one.
+fn parse_arguments(
+    cursor: &mut Cursor,
+    space: CssSpace,
+    legacy_allowed: bool,
+) -> Result<CssColor, ParseColorError> {
+    let kinds = space.components();
+    let mut components = [None; 3];
+
+    components[0] = component(cursor, kinds[0], true)?;
+    let legacy = legacy_allowed && components[0].is_some() && cursor.eat(',');
+    components[1] = component(cursor, kinds[1], !legacy)?;
+    if legacy {
+        cursor.expect(',')?;
+    }
+    components[2] = component(cursor, kinds[2], !legacy)?;
+
+    let separator = if legacy { ',' } else { '/' };
+    let alpha = if cursor.eat(separator) {
+        alpha(cursor, !legacy)?
+    } else {
+        Some(1.0)
+    };
+    cursor.expect(')')?;
+
+    // clamp the values that CSS clamps at parse time
+    match space {
+        CssSpace::Lab | CssSpace::Lch => {
+            components[0] = components[0].map(|l| l.clamp(0.0, 100.0));
+        }
+        CssSpace::Oklab | CssSpace::Oklch => {
+            components[0] = components[0].map(|l| l.clamp(0.0, 1.0));
+        }
+        _ => {}
+    }
+    if let CssSpace::Lch | CssSpace::Oklch = space {
+        components[1] = components[1].map(|c| c.max(0.0));
+    }
+
+    Ok(CssColor {
+        space,
+        components,
+        alpha,
+    })
+}
+
+/// Parses a colour function, starting at its name.
+fn parse_function(cursor: &mut Cursor) -> Result<CssColor, ParseColorError> {
+    let start = cursor.position();
+    let name = cursor.ident();
+    if !cursor.eat('(') {
+        return Err(cursor.unexpected());
+    }
+    let (space, legacy_allowed) = match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => (CssSpace::Rgb, true),
+        "hsl" | "hsla" => (CssSpace::Hsl, true),
+        "hwb" => (CssSpace::Hwb, false),
+        "lab" => (CssSpace::Lab, false),
+        "lch" => (CssSpace::Lch, false),
+        "oklab" => (CssSpace::Oklab, false),
+        "oklch" => (CssSpace::Oklch, false),
+        "color" => {
+            cursor.skip_whitespace();
+            let space_start = cursor.position();
+            let space_name = cursor.ident();
+            let space = PredefinedSpace::from_name(space_name).ok_or_else(|| {
+                ParseColorError::new(
+                    space_start,
+                    ParseColorErrorKind::UnknownColorSpace(space_name.to_string()),
+                )
+            })?;
+            (CssSpace::Predefined(space), false)
+        }
+        _ => {
+            return Err(ParseColorError::new(
+                start,
+                ParseColorErrorKind::UnknownFunction(name.to_string()),
+            ))
+        }
+    };
+    parse_arguments(cursor, space, legacy_allowed)
+}
+
+impl FromStr for CssColor {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cursor = Cursor::new(s);
+        cursor.skip_whitespace();
+        let start = cursor.position();
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(cursor.error(ParseColorErrorKind::Empty));
+        }
+
+        if let Some(digits) = trimmed.strip_prefix('#') {
+            let (color, alpha) = parse_hex(digits, start + 1)?;
+            return Ok(CssColor {
+                alpha: Some(alpha),
+                ..CssColor::from(color)
+            });
+        }
+
+        let name = cursor.ident();
+        cursor.skip_whitespace();
+        if cursor.peek() == Some('(') {
+            cursor = Cursor::new(s);
+            cursor.skip_whitespace();
+            let color = parse_function(&mut cursor)?;
+            cursor.skip_whitespace();
+            return match cursor.peek() {
+                Some(_) => Err(cursor.unexpected()),
+                None => Ok(color),
+            };
+        }
+        if cursor.peek().is_some() {
+            return Err(cursor.unexpected());
+        }
+
+        if name.eq_ignore_ascii_case("transparent") {
+            return Ok(CssColor {
+                alpha: Some(0.0),
+                ..CssColor::new(CssSpace::Rgb, [0.0; 3])
+            });
+        }
+        match clr::lookup(name) {
+            Some(named) if named.set == ColorSet::Css4 => Ok(CssColor::from(named.color)),
+            _ => Err(ParseColorError::new(
+                start,
+                ParseColorErrorKind::UnknownName(name.to_string()),
+            )),
+        }
+    }
+}
+
+/// Formats a number with at most six significant digits and without trailing zeros.
+pub(crate) fn format_number(value: f32) -> String {
+    let value = f64::from(value);
+    if value == 0.0 || !value.is_finite() {
+        return "0".to_string();
+    }
+    let decimals = (5 - value.abs().log10().floor() as i32).max(0);
+    let scale = 10f64.powi(decimals);
+    let rounded = (value * scale).round() / scale;
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        rounded.to_string()
+    }
+}
+
+fn write_number(f: &mut Formatter<'_>, value: f32) -> fmt::Result {
+    write!(f, "{}", format_number(value))
+}
+
+/// Writes an alpha value. An alpha that is an 8 bit value, e.g. from hex notation or `Rgba`, is
+/// written like browsers do: with the shortest decimal of two or three places that maps back to the
+/// same byte, so `#ff000080` becomes `0.5` rather than `0.501961`.
+fn write_alpha(f: &mut Formatter<'_>, alpha: f32) -> fmt::Result {
+    let byte = alpha * 255.0;
+    if (byte - byte.round()).abs() < 1e-3 {
+        for decimals in 2..=3 {
+            let scale = 10f32.powi(decimals);
+            let rounded = (alpha * scale).round() / scale;
+            if (rounded * 255.0).round() == byte.round() {
+                return write_number(f, rounded);
+            }
+        }
+    }
+    write_number(f, alpha)
+}
+
+fn write_component(f: &mut Formatter<'_>, value: Option<f32>) -> fmt::Result {
+    match value {
+        Some(value) => write_number(f, value),
+        None => write!(f, "none"),
+    }
+}
+
+/// Serializes the colour following CSS Color 4.
+impl Display for CssColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.space {
+            CssSpace::Rgb | CssSpace::Hsl | CssSpace::Hwb => {
+                let [r, g, b] = self.legacy_channels();
+                let alpha = self.alpha.unwrap_or(0.0);
+                let name = if alpha == 1.0 { "rgb" } else { "rgba" };
+                write!(f, "{}({}, {}, {}", name, to_byte(r), to_byte(g), to_byte(b))?;
+                if alpha != 1.0 {
+                    write!(f, ", ")?;
+                    write_alpha(f, alpha)?;
+                }
+                return write!(f, ")");
+            }
+            CssSpace::Lab => write!(f, "lab(")?,
+            CssSpace::Lch => write!(f, "lch(")?,
+            CssSpace::Oklab => write!(f, "oklab(")?,
+            CssSpace::Oklch => write!(f, "oklch(")?,
+            CssSpace::Predefined(space) => write!(f, "color({} ", space.name())?,
+        }
+        for (i, &value) in self.components.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write_component(f, value)?;
+        }
+        match self.alpha {
+            Some(1.0) => {}
+            Some(alpha) => {
+                write!(f, " / ")?;
+                write_alpha(f, alpha)?;
+            }
+            None => write!(f, " / none")?,
+        }
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,288 @@
+//! Simulation of colour vision deficiencies (CVD) and daltonization.
+//!
+//! Three simulation methods are available, all working on linear light sRGB:
+//!
+//! * `Method::Brettel`: [Brettel, Viénot and Mollon (1997)](https://doi.org/10.1364/JOSAA.14.002647),
+//!   projecting the cone responses onto two half-planes,
+//! * `Method::Vienot`: [Viénot, Brettel and Mollon (1999)](https://doi.org/10.1002/(SICI)1520-6378(199908)24:4%3C243::AID-COL5%3E3.0.CO;2-3),
+//!   a single plane approximation for protanopia and deuteranopia; tritanopia falls back to Brettel,
+//! * `Method::Machado`: [Machado, Oliveira and Fernandes (2009)](https://doi.org/10.1109/TVCG.2009.113),
+//!   which models anomalous trichromacy with a severity in `[0, 1]`.
+//!
+//! For Brettel and Viénot a severity below `1` blends the simulated colour with the original one.
+//! Achromatopsia is simulated as the luminance of the colour, independent of the method.
+//!
+//! Daltonization (after Fidaner, Lin and Ozguven) takes the part of a colour that is lost for the
+//! viewer and shifts it into the channels they can still distinguish.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, G, R};
+//! use colors::cvd::{Deficiency, Method, Simulation};
+//! use colors::distance::{ColorDistance, DeltaE};
+//!
+//! let deutan = Simulation::new(Deficiency::Deuteranopia, Method::Machado);
+//! assert_eq!(deutan.simulate(R), Color(163, 144, 0));
+//!
+//! let before = deutan.simulate(R).delta_e(&deutan.simulate(G), DeltaE::Oklab);
+//! let fixed = deutan.daltonize_palette(&[R, G]);
+//! let after = deutan.simulate(fixed[0]).delta_e(&deutan.simulate(fixed[1]), DeltaE::Oklab);
+//! assert!(after > before);
+//! ```
+use crate::cie::SRGB_TO_XYZ;
+use crate::clr::Color;
+use crate::matrix::{invert, multiply, transform, Matrix};
+use crate::rgb::{color_to_linear, linear_to_color};
+
+/// A colour vision deficiency.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// Missing or anomalous long wavelength (red) cones
+    Protanopia,
+    /// Missing or anomalous medium wavelength (green) cones
+    Deuteranopia,
+    /// Missing or anomalous short wavelength (blue) cones
+    Tritanopia,
+    /// No colour vision at all, only luminance is seen
+    Achromatopsia,
+}
+
+/// A method of simulating a deficiency.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Method {
+    Brettel,
+    Vienot,
+    Machado,
+}
+
+/// The CIE 1931 chromaticities of the monochromatic anchor stimuli of Brettel: 475 nm and 575 nm
+/// for protanopia and deuteranopia, 485 nm and 660 nm for tritanopia.
+const ANCHORS_475_575: [(f32, f32); 2] = [(0.109_6, 0.086_8), (0.478_8, 0.520_2)];
+const ANCHORS_485_660: [(f32, f32); 2] = [(0.068_7, 0.200_7), (0.730_0, 0.270_0)];
+
+/// XYZ to LMS, the Smith & Pokorny cone fundamentals used by Brettel and Viénot.
+const XYZ_TO_LMS: Matrix = [
+    [0.155_14, 0.543_12, -0.032_86],
+    [-0.155_14, 0.456_84, 0.032_86],
+    [0.0, 0.0, 0.016_08],
+];
+
+/// The simulation matrices of Machado et al. for the severities `0.1` to `1.0`, applied to linear
+/// RGB.
+#[rustfmt::skip]
+const MACHADO_PROTAN: [Matrix; 10] = [
+    [[0.856167, 0.182038, -0.038205], [0.029342, 0.955115, 0.015544], [-0.002880, -0.001563, 1.004443]],
+    [[0.734766, 0.334872, -0.069637], [0.051840, 0.919198, 0.028963], [-0.004928, -0.004209, 1.009137]],
+    [[0.630323, 0.465641, -0.095964], [0.069181, 0.890046, 0.040773], [-0.006308, -0.007724, 1.014032]],
+    [[0.539009, 0.579343, -0.118352], [0.082546, 0.866121, 0.051332], [-0.007136, -0.011959, 1.019095]],
+    [[0.458064, 0.679578, -0.137642], [0.092785, 0.846313, 0.060902], [-0.007494, -0.016807, 1.024301]],
+    [[0.385450, 0.769005, -0.154455], [0.100526, 0.829802, 0.069673], [-0.007442, -0.022190, 1.029632]],
+    [[0.319627, 0.849633, -0.169261], [0.106241, 0.815969, 0.077790], [-0.007025, -0.028051, 1.035076]],
+    [[0.259411, 0.923008, -0.182420], [0.110296, 0.804340, 0.085364], [-0.006276, -0.034346, 1.040622]],
+    [[0.203876, 0.990338, -0.194214], [0.112975, 0.794542, 0.092483], [-0.005222, -0.041043, 1.046265]],
+    [[0.152286, 1.052583, -0.204868], [0.114503, 0.786281, 0.099216], [-0.003882, -0.048116, 1.051998]],
+];
+#[rustfmt::skip]
+const MACHADO_DEUTAN: [Matrix; 10] = [
+    [[0.866435, 0.177704, -0.044139], [0.049567, 0.939063, 0.011370], [-0.003453, 0.007233, 0.996220]],
+    [[0.760729, 0.319078, -0.079807], [0.090568, 0.889315, 0.020117], [-0.006027, 0.013325, 0.992702]],
+    [[0.675425, 0.433850, -0.109275], [0.125303, 0.847755, 0.026942], [-0.007950, 0.018572, 0.989378]],
+    [[0.605511, 0.528560, -0.134071], [0.155318, 0.812366, 0.032316], [-0.009376, 0.023176, 0.986200]],
+    [[0.547494, 0.607765, -0.155259], [0.181692, 0.781742, 0.036566], [-0.010410, 0.027275, 0.983136]],
+    [[0.498864, 0.674741, -0.173604], [0.205199, 0.754872, 0.039929], [-0.011131, 0.030969, 0.980162]],
+    [[0.457771, 0.731899, -0.189670], [0.226409, 0.731012, 0.042579], [-0.011595, 0.034333, 0.977261]],
+    [[0.422823, 0.781057, -0.203881], [0.245752, 0.709602, 0.044646], [-0.011843, 0.037423, 0.974421]],
+    [[0.392952, 0.823610, -0.216562], [0.263559, 0.690210, 0.046232], [-0.011910, 0.040281, 0.971630]],
+    [[0.367322, 0.860646, -0.227968], [0.280085, 0.672501, 0.047413], [-0.011820, 0.042940, 0.968881]],
+];
+#[rustfmt::skip]
+const MACHADO_TRITAN: [Matrix; 10] = [
+    [[0.926670, 0.092514, -0.019184], [0.021191, 0.964503, 0.014306], [0.008437, 0.054813, 0.936750]],
+    [[0.895720, 0.133330, -0.029050], [0.029997, 0.945400, 0.024603], [0.013027, 0.104707, 0.882266]],
+    [[0.905871, 0.127791, -0.033662], [0.026856, 0.941251, 0.031893], [0.013410, 0.148296, 0.838294]],
+    [[0.948035, 0.089490, -0.037526], [0.014364, 0.946792, 0.038844], [0.010853, 0.193991, 0.795156]],
+    [[1.017277, 0.027029, -0.044306], [-0.006113, 0.958479, 0.047634], [0.006379, 0.248708, 0.744913]],
+    [[1.104996, -0.046633, -0.058363], [-0.032137, 0.971635, 0.060503], [0.001336, 0.317922, 0.680742]],
+    [[1.193214, -0.109812, -0.083402], [-0.058496, 0.979410, 0.079086], [-0.002346, 0.403492, 0.598854]],
+    [[1.257728, -0.139648, -0.118081], [-0.078003, 0.975409, 0.102594], [-0.003316, 0.501214, 0.502102]],
+    [[1.278864, -0.125333, -0.153531], [-0.084748, 0.957674, 0.127074], [-0.000989, 0.601151, 0.399838]],
+    [[1.255528, -0.076749, -0.178779], [-0.078411, 0.930809, 0.147602], [0.004733, 0.691367, 0.303900]],
+];
+
+/// Linear sRGB to LMS.
+fn srgb_to_lms() -> Matrix {
+    multiply(&XYZ_TO_LMS, &SRGB_TO_XYZ)
+}
+
+const IDENTITY: Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// The cross product of two vectors.
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// The LMS response of a stimulus with the given chromaticity.
+fn anchor_lms((x, y): (f32, f32)) -> [f32; 3] {
+    transform(&XYZ_TO_LMS, [x / y, 1.0, (1.0 - x - y) / y])
+}
+
+/// Projects `lms` along the axis of the missing cone onto the plane with the given normal.
+fn project(lms: [f32; 3], normal: [f32; 3], missing: usize) -> [f32; 3] {
+    let mut projected = lms;
+    let others: f32 = (0..3)
+        .filter(|&i| i != missing)
+        .map(|i| normal[i] * lms[i])
+        .sum();
+    projected[missing] = -others / normal[missing];
+    projected
+}
+
+/// The index of the missing cone and of the two cones whose ratio decides the half-plane of
+/// Brettel, together with the anchor stimuli.
+fn cones(deficiency: Deficiency) -> (usize, usize, usize, [(f32, f32); 2]) {
+    match deficiency {
+        Deficiency::Protanopia => (0, 2, 1, ANCHORS_475_575),
+        Deficiency::Deuteranopia => (1, 2, 0, ANCHORS_475_575),
+        _ => (2, 1, 0, ANCHORS_485_660),
+    }
+}
+
+/// Brettel's simulation of a dichromat: the projection onto the half-plane through white and
+/// the anchor stimulus on the same side as the colour.
+fn brettel(rgb: [f32; 3], deficiency: Deficiency) -> [f32; 3] {
+    let (missing, numerator, denominator, anchors) = cones(deficiency);
+    let to_lms = srgb_to_lms();
+    let lms = transform(&to_lms, rgb);
+    let white = transform(&to_lms, [1.0; 3]);
+    // the first anchor lies on the side of white with the larger ratio
+    let ratio = |v: [f32; 3]| v[numerator] / v[denominator];
+    let anchor = if lms[denominator] == 0.0 || ratio(lms) >= ratio(white) {
+        anchors[0]
+    } else {
+        anchors[1]
+    };
+    let normal = cross(white, anchor_lms(anchor));
+    transform(&invert(&to_lms), project(lms, normal, missing))
+}
+
+/// Viénot's single plane through black, white and the blue primary for protanopia and
+/// deuteranopia.
+fn vienot(rgb: [f32; 3], deficiency: Deficiency) -> [f32; 3] {
+    let (missing, ..) = cones(deficiency);
+    let to_lms = srgb_to_lms();
+    let white = transform(&to_lms, [1.0; 3]);
+    let blue = transform(&to_lms, [0.0, 0.0, 1.0]);
+    let normal = cross(white, blue);
+    let lms = transform(&to_lms, rgb);
+    transform(&invert(&to_lms), project(lms, normal, missing))
+}
+
+/// The Machado matrix for a severity in `[0, 1]`, interpolated between the tabulated ones.
+fn machado_matrix(deficiency: Deficiency, severity: f32) -> Matrix {
+    let table = match deficiency {
+        Deficiency::Protanopia => &MACHADO_PROTAN,
+        Deficiency::Deuteranopia => &MACHADO_DEUTAN,
+        _ => &MACHADO_TRITAN,
+    };
+    let position = severity.clamp(0.0, 1.0) * 10.0;
+    let index = (position.floor() as usize).min(9);
+    let lower = if index == 0 {
+        &IDENTITY
+    } else {
+        &table[index - 1]
+    };
+    let upper = &table[index];
+    let t = position - index as f32;
+    let mut matrix = [[0.0; 3]; 3];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = lower[i][j] + (upper[i][j] - lower[i][j]) * t;
+        }
+    }
+    matrix
+}
+
+/// The shift of the lost information into the remaining channels used for daltonization.
+fn error_shift(deficiency: Deficiency) -> Matrix {
+    match deficiency {
+        Deficiency::Protanopia => [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]],
+        Deficiency::Deuteranopia => [[1.0, 0.7, 0.0], [0.0, 0.0, 0.0], [0.0, 0.7, 1.0]],
+        _ => [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]],
+    }
+}
+
+/// A simulation of a colour vision deficiency of a given severity.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Simulation {
+    pub deficiency: Deficiency,
+    pub method: Method,
+    /// The severity in `[0, 1]`, `1` being a complete loss of the cone type
+    pub severity: f32,
+}
+
+impl Simulation {
+    /// A simulation of the complete deficiency (dichromacy or monochromacy).
+    pub fn new(deficiency: Deficiency, method: Method) -> Self {
+        Simulation {
+            deficiency,
+            method,
+            severity: 1.0,
+        }
+    }
+
+    /// The same simulation with another severity in `[0, 1]`.
+    pub fn with_severity(self, severity: f32) -> Self {
+        Simulation {
+            severity: severity.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Simulates linear light sRGB components.
+    fn simulate_linear(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let full = match (self.deficiency, self.method) {
+            (Deficiency::Achromatopsia, _) => {
+                [0.2126 * rgb[0] + 0.7152 * rgb[1] + 0.0722 * rgb[2]; 3]
+            }
+            (deficiency, Method::Machado) => {
+                return transform(&machado_matrix(deficiency, self.severity), rgb)
+            }
+            (Deficiency::Tritanopia, _) | (_, Method::Brettel) => brettel(rgb, self.deficiency),
+            (deficiency, Method::Vienot) => vienot(rgb, deficiency),
+        };
+        let t = self.severity;
+        [0, 1, 2].map(|i| rgb[i] + (full[i] - rgb[i]) * t)
+    }
+
+    /// How a colour appears to a viewer with the deficiency.
+    pub fn simulate(&self, color: Color) -> Color {
+        linear_to_color(self.simulate_linear(color_to_linear(color)))
+    }
+
+    /// Simulates every colour of a palette.
+    pub fn simulate_palette(&self, colors: &[Color]) -> Vec<Color> {
+        colors.iter().map(|&color| self.simulate(color)).collect()
+    }
+
+    /// Shifts the information of a colour lost to the viewer into the channels they can see.
+    /// Achromatopsia cannot be corrected by changing hues; the colour is returned unchanged.
+    pub fn daltonize(&self, color: Color) -> Color {
+        if self.deficiency == Deficiency::Achromatopsia {
+            return color;
+        }
+        let rgb = color_to_linear(color);
+        let simulated = self.simulate_linear(rgb);
+        let error = [0, 1, 2].map(|i| rgb[i] - simulated[i]);
+        let shift = transform(&error_shift(self.deficiency), error);
+        linear_to_color([0, 1, 2].map(|i| rgb[i] + shift[i]))
+    }
+
+    /// Daltonizes every colour of a palette.
+    pub fn daltonize_palette(&self, colors: &[Color]) -> Vec<Color> {
+        colors.iter().map(|&color| self.daltonize(color)).collect()
+    }
+}
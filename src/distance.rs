@@ -0,0 +1,203 @@
+//! Colour difference metrics (ΔE).
+//!
+//! All CIE metrics work on L\*a\*b\*; for `Color` it is taken relative to D65. `DeltaE::Oklab` is
+//! the Euclidean distance in Oklab, which is cheap and perceptually fairly uniform. A ΔE of about
+//! `1` (`0.02` for ΔEOK) is roughly the smallest difference that can be noticed.
+//!
+//! ΔE94 and CMC are not symmetric: `self` is the reference (standard) colour and `other` the sample.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, GOLDENROD};
+//! use colors::distance::{ColorDistance, DeltaE};
+//!
+//! let sample = Color(220, 160, 40);
+//! assert_eq!(format!("{:.2}", GOLDENROD.delta_e(&sample, DeltaE::Cie76)), "4.96");
+//! assert_eq!(format!("{:.2}", GOLDENROD.delta_e(&sample, DeltaE::Ciede2000)), "2.80");
+//! assert!(GOLDENROD.delta_e(&GOLDENROD, DeltaE::CMC_ACCEPTABILITY) == 0.0);
+//! ```
+use crate::cie::{Lab, Xyz};
+use crate::clr::Color;
+use crate::oklab::Oklab;
+
+/// The weighting constants of ΔE94, which differ between industries.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Cie94Application {
+    GraphicArts,
+    Textiles,
+}
+
+/// A colour difference metric.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DeltaE {
+    /// CIE 1976: the Euclidean distance in L\*a\*b\*
+    Cie76,
+    /// CIE 1994, weighting chroma and hue differences by the chroma of the reference
+    Cie94(Cie94Application),
+    /// CIEDE2000, the current CIE recommendation
+    Ciede2000,
+    /// CMC l:c (1984) with the lightness and chroma weights `l` and `c`
+    Cmc { l: f32, c: f32 },
+    /// ΔEOK: the Euclidean distance in Oklab
+    Oklab,
+}
+
+impl DeltaE {
+    /// CMC 2:1, used to judge whether a difference is acceptable.
+    pub const CMC_ACCEPTABILITY: DeltaE = DeltaE::Cmc { l: 2.0, c: 1.0 };
+    /// CMC 1:1, used to judge whether a difference is perceptible.
+    pub const CMC_PERCEPTIBILITY: DeltaE = DeltaE::Cmc { l: 1.0, c: 1.0 };
+}
+
+/// Colour types that a colour difference can be computed for.
+pub trait ColorDistance {
+    /// The difference between `self` as the reference and `other` in the given metric.
+    fn delta_e(&self, other: &Self, metric: DeltaE) -> f32;
+}
+
+impl ColorDistance for Lab {
+    fn delta_e(&self, other: &Lab, metric: DeltaE) -> f32 {
+        match metric {
+            DeltaE::Cie76 => cie76(self, other),
+            DeltaE::Cie94(application) => cie94(self, other, application),
+            DeltaE::Ciede2000 => ciede2000(self, other),
+            DeltaE::Cmc { l, c } => cmc(self, other, l, c),
+            DeltaE::Oklab => {
+                Oklab::from(Xyz::from(*self)).distance(&Oklab::from(Xyz::from(*other)))
+            }
+        }
+    }
+}
+
+impl ColorDistance for Color {
+    /// Compares the colours in L\*a\*b\* relative to D65, or in Oklab.
+    fn delta_e(&self, other: &Color, metric: DeltaE) -> f32 {
+        match metric {
+            DeltaE::Oklab => Oklab::from(*self).distance(&Oklab::from(*other)),
+            _ => Lab::from(*self).delta_e(&Lab::from(*other), metric),
+        }
+    }
+}
+
+/// The components of a Lab colour in double precision, which the more involved formulas need to
+/// reproduce the published test data.
+fn components(lab: &Lab) -> (f64, f64, f64) {
+    (f64::from(lab.l), f64::from(lab.a), f64::from(lab.b))
+}
+
+/// CIE 1976 ΔE\*ab.
+pub fn cie76(reference: &Lab, sample: &Lab) -> f32 {
+    let (dl, da, db) = (
+        reference.l - sample.l,
+        reference.a - sample.a,
+        reference.b - sample.b,
+    );
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// The squared hue difference `ΔH²`, derived from the differences in a, b and chroma.
+fn hue_difference_squared(da: f64, db: f64, dc: f64) -> f64 {
+    (da * da + db * db - dc * dc).max(0.0)
+}
+
+/// CIE 1994 ΔE\*94.
+pub fn cie94(reference: &Lab, sample: &Lab, application: Cie94Application) -> f32 {
+    let (l1, a1, b1) = components(reference);
+    let (l2, a2, b2) = components(sample);
+    let (kl, k1, k2) = match application {
+        Cie94Application::GraphicArts => (1.0, 0.045, 0.015),
+        Cie94Application::Textiles => (2.0, 0.048, 0.014),
+    };
+    let c1 = a1.hypot(b1);
+    let dc = c1 - a2.hypot(b2);
+    let dh2 = hue_difference_squared(a1 - a2, b1 - b2, dc);
+    let (sc, sh) = (1.0 + k1 * c1, 1.0 + k2 * c1);
+    let dl = (l1 - l2) / kl;
+    (dl * dl + (dc / sc).powi(2) + dh2 / (sh * sh)).sqrt() as f32
+}
+
+/// CMC l:c ΔE.
+pub fn cmc(reference: &Lab, sample: &Lab, l: f32, c: f32) -> f32 {
+    let (l1, a1, b1) = components(reference);
+    let (l2, a2, b2) = components(sample);
+    let c1 = a1.hypot(b1);
+    let dc = c1 - a2.hypot(b2);
+    let dh2 = hue_difference_squared(a1 - a2, b1 - b2, dc);
+    let h1 = b1.atan2(a1).to_degrees().rem_euclid(360.0);
+
+    let sl = if l1 < 16.0 {
+        0.511
+    } else {
+        0.040_975 * l1 / (1.0 + 0.017_65 * l1)
+    };
+    let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+    let f = (c1.powi(4) / (c1.powi(4) + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let sh = sc * (f * t + 1.0 - f);
+    let dl = (l1 - l2) / (f64::from(l) * sl);
+    let dc = dc / (f64::from(c) * sc);
+    (dl * dl + dc * dc + dh2 / (sh * sh)).sqrt() as f32
+}
+
+/// CIEDE2000 ΔE00 with all parametric factors at `1`, following
+/// [Sharma et al.](https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/).
+pub fn ciede2000(reference: &Lab, sample: &Lab) -> f32 {
+    let (l1, a1, b1) = components(reference);
+    let (l2, a2, b2) = components(sample);
+
+    let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt());
+    let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |a: f64, b: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_mean).to_radians().cos()
+        + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+    let d_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt();
+    let sl = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let sc = 1.0 + 0.045 * c_mean;
+    let sh = 1.0 + 0.015 * c_mean * t;
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+    let (dl, dc, dh) = (dl / sl, dc / sc, dh / sh);
+    (dl * dl + dc * dc + dh * dh + rt * dc * dh).sqrt() as f32
+}
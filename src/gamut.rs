@@ -0,0 +1,154 @@
+//! Gamut mapping: bringing colours that lie outside sRGB into the gamut of `Color`.
+//!
+//! Colours in Lab, Oklch or a wide gamut RGB space can easily describe colours an sRGB display
+//! cannot show. Converting them with `From` simply clips the linear RGB components, which is fast
+//! but can shift the hue and lightness noticeably. `GamutMapping` offers the alternatives:
+//!
+//! * `Clip` clips each linear RGB component to `[0, 1]`,
+//! * `Css` reduces the Oklch chroma at constant lightness and hue until clipping the result changes
+//!   it by less than a just noticeable difference, as specified in
+//!   [CSS Color 4](https://www.w3.org/TR/css-color-4/#gamut-mapping),
+//! * `Closest` searches for the sRGB colour with the smallest ΔEOK to the original.
+//!
+//! All colour types that can be converted to `Xyz` (relative to D65) can be mapped.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::Color;
+//! use colors::gamut::{in_gamut, GamutMapping};
+//! use colors::oklab::Oklch;
+//! use colors::space::RgbSpace;
+//!
+//! let vivid = Oklch::new(0.7, 0.4, 150.0);
+//! assert!(!in_gamut(vivid));
+//! assert_eq!(GamutMapping::Clip.map(vivid), Color(0, 214, 0));
+//! assert_eq!(GamutMapping::Css.map(vivid), Color(0, 194, 72));
+//!
+//! let p3_green = RgbSpace::DISPLAY_P3.to_xyz([0.0, 1.0, 0.0]);
+//! assert_eq!(GamutMapping::Css.map(p3_green), Color(0, 251, 41));
+//! ```
+use crate::cie::Xyz;
+use crate::clr::Color;
+use crate::oklab::{linear_in_gamut, Oklab, Oklch};
+use crate::rgb::linear_to_color;
+
+/// The just noticeable difference in ΔEOK used by the CSS algorithm.
+const JND: f32 = 0.02;
+/// The precision of the chroma search of the CSS algorithm.
+const CHROMA_EPSILON: f32 = 1e-4;
+
+/// A strategy to map colours outside the sRGB gamut into it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GamutMapping {
+    /// Clip the linear RGB components to `[0, 1]`
+    Clip,
+    /// Reduce the Oklch chroma as specified by CSS Color 4
+    #[default]
+    Css,
+    /// Find the in-gamut colour with the smallest ΔEOK
+    Closest,
+}
+
+/// Whether a colour lies within the sRGB gamut, up to rounding errors.
+pub fn in_gamut<C: Into<Xyz>>(color: C) -> bool {
+    linear_in_gamut(color.into().to_linear_srgb())
+}
+
+fn clip(rgb: [f32; 3]) -> [f32; 3] {
+    rgb.map(|c| c.clamp(0.0, 1.0))
+}
+
+impl GamutMapping {
+    /// Maps a colour into the sRGB gamut and rounds it to 8 bit. Colours already in gamut are only
+    /// rounded.
+    pub fn map<C: Into<Xyz>>(self, color: C) -> Color {
+        linear_to_color(self.map_linear(color.into().to_linear_srgb()))
+    }
+
+    /// Maps linear light sRGB components into `[0, 1]`.
+    pub(crate) fn map_linear(self, rgb: [f32; 3]) -> [f32; 3] {
+        if linear_in_gamut(rgb) {
+            return clip(rgb);
+        }
+        match self {
+            GamutMapping::Clip => clip(rgb),
+            GamutMapping::Css => css(rgb),
+            GamutMapping::Closest => closest(rgb),
+        }
+    }
+}
+
+/// The CSS Color 4 gamut mapping algorithm: a binary search for the largest Oklch chroma whose
+/// clipped colour is within a just noticeable difference of it.
+fn css(rgb: [f32; 3]) -> [f32; 3] {
+    let origin = Oklch::from(Oklab::from_linear_srgb(rgb));
+    if origin.l >= 1.0 {
+        return [1.0; 3];
+    }
+    if origin.l <= 0.0 {
+        return [0.0; 3];
+    }
+
+    let clipped_distance = |current: Oklch| {
+        let lab = Oklab::from(current);
+        let clipped = clip(lab.to_linear_srgb());
+        (clipped, Oklab::from_linear_srgb(clipped).distance(&lab))
+    };
+
+    let (mut clipped, distance) = clipped_distance(origin);
+    if distance < JND {
+        return clipped;
+    }
+    let mut current = origin;
+    let (mut min, mut max) = (0.0, origin.c);
+    let mut min_in_gamut = true;
+    while max - min > CHROMA_EPSILON {
+        current.c = (min + max) / 2.0;
+        if min_in_gamut && current.in_gamut() {
+            min = current.c;
+            continue;
+        }
+        let distance;
+        (clipped, distance) = clipped_distance(current);
+        if distance < JND {
+            if JND - distance < CHROMA_EPSILON {
+                break;
+            }
+            min_in_gamut = false;
+            min = current.c;
+        } else {
+            max = current.c;
+        }
+    }
+    clipped
+}
+
+/// A pattern search over the sRGB cube for the colour with the smallest ΔEOK, starting from the
+/// clipped colour. The distance is not convex in linear RGB, but close enough for the search to
+/// find the minimum in practice.
+fn closest(rgb: [f32; 3]) -> [f32; 3] {
+    let target = Oklab::from_linear_srgb(rgb);
+    let distance = |candidate: [f32; 3]| Oklab::from_linear_srgb(candidate).distance(&target);
+    let mut best = clip(rgb);
+    let mut best_distance = distance(best);
+    let mut step = 0.25;
+    while step > 1e-6 {
+        let mut improved = false;
+        for channel in 0..3 {
+            for sign in [-1.0, 1.0] {
+                let mut candidate = best;
+                candidate[channel] = (candidate[channel] + sign * step).clamp(0.0, 1.0);
+                let candidate_distance = distance(candidate);
+                if candidate_distance < best_distance {
+                    best = candidate;
+                    best_distance = candidate_distance;
+                    improved = true;
+                }
+            }
+        }
+        if !improved {
+            step /= 2.0;
+        }
+    }
+    best
+}
@@ -0,0 +1,260 @@
+//! Multi-stop colour gradients, e.g. for progress bars and heat maps.
+//!
+//! A gradient is built from `(position, Color)` stops and sampled at any position `t`. Like in CSS,
+//! stops whose position is smaller than that of an earlier stop are moved up to it, two stops at the
+//! same position give a hard transition, and before the first and after the last stop the gradient
+//! keeps the colour of that stop. Between two stops the colours are mixed in the selected space
+//! (Oklab by default), optionally with an easing function and a midpoint hint.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, BLUE, RED, WHITE};
+//! use colors::gradient::{Easing, Gradient};
+//! use colors::mix::MixSpace;
+//!
+//! let gradient = Gradient::new(&[(0.0, BLUE), (0.5, WHITE), (1.0, RED)])
+//!     .unwrap()
+//!     .with_space(MixSpace::LinearRgb);
+//! assert_eq!(gradient.at(0.25), Color(188, 188, 255));
+//! assert_eq!(gradient.at(2.0), RED);
+//!
+//! let colors: Vec<Color> = gradient.take(5).collect();
+//! assert_eq!(colors, vec![BLUE, Color(188, 188, 255), WHITE, Color(255, 188, 188), RED]);
+//!
+//! // ease the way from blue to white and move its midpoint towards white
+//! let gradient = gradient.with_segment_easing(0, Easing::EASE_IN).with_hint(0, 0.4);
+//! assert_eq!(gradient.at(0.25), Color(41, 41, 255));
+//! ```
+use crate::clr::Color;
+use crate::hue::HueInterpolation;
+use crate::mix::{mix_with_hue, MixSpace};
+
+/// An easing function, mapping the progress between two stops to the mixing ratio.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Easing {
+    /// Mix proportionally to the progress
+    #[default]
+    Linear,
+    /// Ease in and out with the Hermite polynomial `3t² - 2t³`
+    Smoothstep,
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)` with the control points `(x1, y1)` and
+    /// `(x2, y2)`, as the CSS `cubic-bezier()` function. `x1` and `x2` are clamped to `[0, 1]`.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// The CSS `ease` timing function
+    pub const EASE: Easing = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+    /// The CSS `ease-in` timing function
+    pub const EASE_IN: Easing = Easing::CubicBezier(0.42, 0.0, 1.0, 1.0);
+    /// The CSS `ease-out` timing function
+    pub const EASE_OUT: Easing = Easing::CubicBezier(0.0, 0.0, 0.58, 1.0);
+    /// The CSS `ease-in-out` timing function
+    pub const EASE_IN_OUT: Easing = Easing::CubicBezier(0.42, 0.0, 0.58, 1.0);
+
+    /// Applies the easing to the progress `t` in `[0, 1]`.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::gradient::Easing;
+    /// # fn main() {
+    /// assert_eq!(Easing::Smoothstep.apply(0.25), 0.15625);
+    /// assert_eq!(format!("{:.4}", Easing::EASE.apply(0.5)), "0.8024");
+    /// # }
+    /// ```
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::Smoothstep => t * t * (3.0 - 2.0 * t),
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2, t)
+            }
+        }
+    }
+}
+
+/// The y value of the cubic Bézier curve from `(0, 0)` to `(1, 1)` at the x value `x`.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    // the polynomial coefficients of a coordinate with the control values p1 and p2
+    let coefficients = |p1: f32, p2: f32| {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        (1.0 - c - b, b, c)
+    };
+    let (ax, bx, cx) = coefficients(x1, x2);
+    let (ay, by, cy) = coefficients(y1, y2);
+    let curve_x = |s: f32| ((ax * s + bx) * s + cx) * s;
+
+    // Newton's method, falling back to bisection where the slope is too flat
+    let mut s = x;
+    for _ in 0..8 {
+        let error = curve_x(s) - x;
+        if error.abs() < 1e-6 {
+            return ((ay * s + by) * s + cy) * s;
+        }
+        let slope = (3.0 * ax * s + 2.0 * bx) * s + cx;
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        s -= error / slope;
+    }
+    let (mut low, mut high) = (0.0, 1.0);
+    s = x;
+    for _ in 0..32 {
+        let value = curve_x(s);
+        if (value - x).abs() < 1e-6 {
+            break;
+        }
+        if value < x {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    ((ay * s + by) * s + cy) * s
+}
+
+/// How the colours between two neighbouring stops are mixed.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Segment {
+    pub easing: Easing,
+    /// The position between the two stops at which the colours are mixed half and half, as the
+    /// colour hints of CSS gradients. `None` for the middle.
+    pub hint: Option<f32>,
+}
+
+/// A gradient through a list of colour stops.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, Color)>,
+    segments: Vec<Segment>,
+    space: MixSpace,
+    hue: HueInterpolation,
+}
+
+impl Gradient {
+    /// Creates a gradient mixing linearly in Oklab from `(position, Color)` stops, usually with
+    /// positions in `[0, 1]`. Positions smaller than that of an earlier stop are raised to it.
+    /// Returns `None` if there are no stops.
+    pub fn new(stops: &[(f32, Color)]) -> Option<Self> {
+        let (&first, rest) = stops.split_first()?;
+        let mut fixed = Vec::with_capacity(stops.len());
+        fixed.push(first);
+        for &(position, color) in rest {
+            let previous = fixed[fixed.len() - 1].0;
+            fixed.push((position.max(previous), color));
+        }
+        Some(Gradient {
+            segments: vec![Segment::default(); stops.len() - 1],
+            stops: fixed,
+            space: MixSpace::default(),
+            hue: HueInterpolation::default(),
+        })
+    }
+
+    /// Creates a gradient with the colours evenly spaced from `0` to `1`. Returns `None` if there
+    /// are no colours.
+    pub fn evenly_spaced(colors: &[Color]) -> Option<Self> {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        let stops: Vec<(f32, Color)> = colors
+            .iter()
+            .enumerate()
+            .map(|(i, &color)| (i as f32 / last, color))
+            .collect();
+        Gradient::new(&stops)
+    }
+
+    /// The gradient mixing in `space`.
+    pub fn with_space(self, space: MixSpace) -> Self {
+        Gradient { space, ..self }
+    }
+
+    /// The gradient interpolating hues with `hue`, for the spaces LCh and Oklch.
+    pub fn with_hue_interpolation(self, hue: HueInterpolation) -> Self {
+        Gradient { hue, ..self }
+    }
+
+    /// The gradient with `easing` for all segments.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        for segment in &mut self.segments {
+            segment.easing = easing;
+        }
+        self
+    }
+
+    /// The gradient with `easing` between the stops `index` and `index + 1`. Indices past the last
+    /// segment are ignored.
+    pub fn with_segment_easing(mut self, index: usize, easing: Easing) -> Self {
+        if let Some(segment) = self.segments.get_mut(index) {
+            segment.easing = easing;
+        }
+        self
+    }
+
+    /// The gradient with a midpoint hint at `position` between the stops `index` and `index + 1`.
+    /// The position is clamped to the stops. Indices past the last segment are ignored.
+    pub fn with_hint(mut self, index: usize, position: f32) -> Self {
+        if let Some(segment) = self.segments.get_mut(index) {
+            let (from, to) = (self.stops[index].0, self.stops[index + 1].0);
+            segment.hint = Some(position.clamp(from, to));
+        }
+        self
+    }
+
+    /// The stops, with their positions fixed up to be non-decreasing.
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// The segments between the stops.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// The space the colours are mixed in.
+    pub fn space(&self) -> MixSpace {
+        self.space
+    }
+
+    /// The colour at position `t`.
+    pub fn at(&self, t: f32) -> Color {
+        let (first, last) = (self.stops[0], self.stops[self.stops.len() - 1]);
+        if t.is_nan() || t < first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+        // the last segment starting at or before t; it has a positive length as t is below its end
+        let index = self.stops.partition_point(|&(position, _)| position <= t) - 1;
+        let ((from, a), (to, b)) = (self.stops[index], self.stops[index + 1]);
+        let segment = self.segments[index];
+        let mut progress = (t - from) / (to - from);
+        if let Some(hint) = segment.hint {
+            progress = apply_hint(progress, (hint - from) / (to - from));
+        }
+        let ratio = segment.easing.apply(progress);
+        mix_with_hue(a, b, ratio, self.space, self.hue)
+    }
+
+    /// `n` colours evenly spaced from `0` to `1`, including both ends. A single colour is taken at
+    /// `0`.
+    pub fn take(&self, n: usize) -> impl Iterator<Item = Color> + '_ {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(move |i| self.at(i as f32 / last))
+    }
+}
+
+/// Applies a CSS colour hint at the relative position `hint` to the progress `t`, such that the
+/// colours are mixed half and half at the hint.
+fn apply_hint(t: f32, hint: f32) -> f32 {
+    if hint <= 0.0 {
+        1.0
+    } else if hint >= 1.0 {
+        0.0
+    } else {
+        t.powf(0.5f32.ln() / hint.ln())
+    }
+}
@@ -0,0 +1,126 @@
+//! Colour harmony schemes, seeding a palette from a single base colour.
+//!
+//! The hue based schemes rotate the base colour around the Oklch hue circle, keeping its lightness
+//! and, as far as the sRGB gamut allows, its chroma; the monochromatic scheme varies the lightness
+//! instead. The base colour always comes first. The colours can be snapped to the closest distinct
+//! named colours of a set, e.g. to use `clr` constants in a theme.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, ColorSet, ROYALBLUE};
+//! use colors::harmony::Scheme;
+//!
+//! let triadic = Scheme::Triadic.generate(ROYALBLUE);
+//! assert_eq!(triadic, vec![ROYALBLUE, Color(203, 53, 50), Color(0, 143, 32)]);
+//!
+//! let named = Scheme::Triadic.generate_named(ROYALBLUE, &[ColorSet::Css4]);
+//! let names: Vec<&str> = named.iter().map(|named| named.name).collect();
+//! assert_eq!(names, vec!["royalblue", "crimson", "forestgreen"]);
+//! ```
+use crate::clr::{Color, ColorSet, NamedColor};
+use crate::gamut::GamutMapping;
+use crate::nearest::nearest_k;
+use crate::oklab::Oklch;
+
+/// A colour harmony scheme.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Scheme {
+    /// The base colour and the one opposite to it
+    Complementary,
+    /// The base colour and the two colours next to its complement, 30° apart from it
+    SplitComplementary,
+    /// The base colour and its neighbours 30° to either side
+    Analogous,
+    /// Three colours evenly spaced around the hue circle
+    Triadic,
+    /// Two pairs of complementary colours, 60° apart (a rectangle on the hue circle)
+    Tetradic,
+    /// Four colours evenly spaced around the hue circle
+    Square,
+    /// Five colours of the base hue: the base colour, two lighter and two darker ones
+    Monochromatic,
+}
+
+impl Scheme {
+    /// All schemes
+    pub const ALL: [Scheme; 7] = [
+        Scheme::Complementary,
+        Scheme::SplitComplementary,
+        Scheme::Analogous,
+        Scheme::Triadic,
+        Scheme::Tetradic,
+        Scheme::Square,
+        Scheme::Monochromatic,
+    ];
+
+    /// The hue rotations in degrees from the base colour to the colours of the scheme. All
+    /// colours of the monochromatic scheme have the base hue.
+    pub fn hue_offsets(self) -> &'static [f32] {
+        match self {
+            Scheme::Complementary => &[0.0, 180.0],
+            Scheme::SplitComplementary => &[0.0, 150.0, 210.0],
+            Scheme::Analogous => &[0.0, -30.0, 30.0],
+            Scheme::Triadic => &[0.0, 120.0, 240.0],
+            Scheme::Tetradic => &[0.0, 60.0, 180.0, 240.0],
+            Scheme::Square => &[0.0, 90.0, 180.0, 270.0],
+            Scheme::Monochromatic => &[0.0; 5],
+        }
+    }
+
+    /// The colours of the scheme for `base`, starting with `base`.
+    pub fn generate(self, base: Color) -> Vec<Color> {
+        if self == Scheme::Monochromatic {
+            let lch = Oklch::from(base);
+            // a quarter and half of the way towards white and black
+            let lightnesses = [
+                lch.l + (1.0 - lch.l) * 0.25,
+                lch.l + (1.0 - lch.l) * 0.5,
+                lch.l * 0.75,
+                lch.l * 0.5,
+            ];
+            let mut colors = vec![base];
+            colors.extend(
+                lightnesses
+                    .iter()
+                    .map(|&l| GamutMapping::Css.map(Oklch { l, ..lch })),
+            );
+            return colors;
+        }
+        let mut colors = vec![base];
+        colors.extend(
+            self.hue_offsets()[1..]
+                .iter()
+                .map(|&offset| base.rotate_hue(offset)),
+        );
+        colors
+    }
+
+    /// The colours of the scheme for `base`, each replaced by the closest named colour from
+    /// `sets` that has not been used for an earlier colour of the scheme. Returns fewer colours if
+    /// the sets run out of colours and none if `sets` is empty.
+    pub fn generate_named(self, base: Color, sets: &[ColorSet]) -> Vec<NamedColor> {
+        let colors = self.generate(base);
+        let mut named: Vec<NamedColor> = Vec::with_capacity(colors.len());
+        for color in colors {
+            // colours can have several names, so widen the search until an unused colour turns up
+            // or the sets are exhausted
+            let mut k = named.len() + 1;
+            loop {
+                let candidates = nearest_k(color, k, sets);
+                let exhausted = candidates.len() < k;
+                if let Some((closest, _)) = candidates
+                    .into_iter()
+                    .find(|(candidate, _)| named.iter().all(|used| used.color != candidate.color))
+                {
+                    named.push(closest);
+                    break;
+                }
+                if exhausted {
+                    break;
+                }
+                k *= 2;
+            }
+        }
+        named
+    }
+}
@@ -0,0 +1,181 @@
+//! The cylindrical sRGB colour spaces HSL, HSV and HWB.
+//!
+//! All three share the same hue, given in degrees in `[0, 360)`. The other components are in
+//! `[0, 1]`. Greys have no defined hue; they are given a hue (and saturation) of `0`, so that
+//! converting a grey back and forth always gives the same result.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, GOLDENROD};
+//! use colors::hsl::{Hsl, Hsv, Hwb};
+//!
+//! let hsl = Hsl::from(GOLDENROD);
+//! assert_eq!(format!("{:.2}", hsl), "(42.90, 0.74, 0.49)");
+//! assert_eq!(Color::from(hsl), GOLDENROD);
+//!
+//! let hsv = Hsv::new(120.0, 1.0, 0.5);
+//! assert_eq!(Color::from(hsv), Color(0, 128, 0));
+//! assert_eq!(Color::from(Hwb::new(0.0, 0.5, 0.5)), Color(128, 128, 128));
+//! ```
+use crate::clr::Color;
+
+/// A colour in the HSL (hue, saturation, lightness) space.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+/// A colour in the HSV (hue, saturation, value) space, also known as HSB.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+/// A colour in the HWB (hue, whiteness, blackness) space.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Hwb {
+    pub h: f32,
+    pub w: f32,
+    pub b: f32,
+}
+
+impl_fmt!(Hsl { h, s, l });
+impl_fmt!(Hsv { h, s, v });
+impl_fmt!(Hwb { h, w, b });
+
+impl Hsl {
+    /// Creates a new HSL colour.
+    pub fn new(h: f32, s: f32, l: f32) -> Self {
+        Hsl { h, s, l }
+    }
+}
+
+impl Hsv {
+    /// Creates a new HSV colour.
+    pub fn new(h: f32, s: f32, v: f32) -> Self {
+        Hsv { h, s, v }
+    }
+}
+
+impl Hwb {
+    /// Creates a new HWB colour.
+    pub fn new(h: f32, w: f32, b: f32) -> Self {
+        Hwb { h, w, b }
+    }
+}
+
+/// The RGB components of a colour in `[0, 1]`.
+fn unit_rgb(color: Color) -> [f32; 3] {
+    [
+        f32::from(color.0) / 255.0,
+        f32::from(color.1) / 255.0,
+        f32::from(color.2) / 255.0,
+    ]
+}
+
+fn to_color([r, g, b]: [f32; 3]) -> Color {
+    let byte = |c: f32| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+    Color(byte(r), byte(g), byte(b))
+}
+
+/// The hue in degrees together with the largest and smallest component, the hue is `0` for greys.
+fn hue_max_min([r, g, b]: [f32; 3]) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (hue.rem_euclid(360.0), max, min)
+}
+
+/// Converts HSL (hue in degrees, saturation and lightness in `[0, 1]`) to RGB in `[0, 1]`, following
+/// the algorithm in the CSS specification.
+pub(crate) fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [f32; 3] {
+    let hue = hue.rem_euclid(360.0);
+    let a = saturation * lightness.min(1.0 - lightness);
+    let f = |n: f32| {
+        let k = (n + hue / 30.0) % 12.0;
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+/// Converts HSV (hue in degrees, saturation and value in `[0, 1]`) to RGB in `[0, 1]`.
+pub(crate) fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> [f32; 3] {
+    let hue = hue.rem_euclid(360.0);
+    let f = |n: f32| {
+        let k = (n + hue / 60.0) % 6.0;
+        value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+    [f(5.0), f(3.0), f(1.0)]
+}
+
+/// Converts HWB (hue in degrees, whiteness and blackness in `[0, 1]`) to RGB in `[0, 1]`.
+pub(crate) fn hwb_to_rgb(hue: f32, whiteness: f32, blackness: f32) -> [f32; 3] {
+    if whiteness + blackness >= 1.0 {
+        let grey = whiteness / (whiteness + blackness);
+        return [grey; 3];
+    }
+    hsl_to_rgb(hue, 1.0, 0.5).map(|c| c * (1.0 - whiteness - blackness) + whiteness)
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let (h, max, min) = hue_max_min(unit_rgb(color));
+        let l = (max + min) / 2.0;
+        let s = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl { h, s, l }
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(color: Color) -> Self {
+        let (h, max, min) = hue_max_min(unit_rgb(color));
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Hsv { h, s, v: max }
+    }
+}
+
+impl From<Color> for Hwb {
+    fn from(color: Color) -> Self {
+        let (h, max, min) = hue_max_min(unit_rgb(color));
+        Hwb {
+            h,
+            w: min,
+            b: 1.0 - max,
+        }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        to_color(hsl_to_rgb(hsl.h, hsl.s, hsl.l))
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        to_color(hsv_to_rgb(hsv.h, hsv.s, hsv.v))
+    }
+}
+
+impl From<Hwb> for Color {
+    fn from(hwb: Hwb) -> Self {
+        to_color(hwb_to_rgb(hwb.h, hwb.w, hwb.b))
+    }
+}
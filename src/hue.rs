@@ -0,0 +1,62 @@
+//! Interpolation of hue angles, following the hue interpolation methods of CSS Color 4.
+
+/// How to go from one hue to another. Hues are angles, so there are always two ways around the
+/// colour wheel.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HueInterpolation {
+    /// Take the shorter way (at most 180°), the default
+    #[default]
+    Shorter,
+    /// Take the longer way (at least 180°)
+    Longer,
+    /// Always go in the direction of increasing hue
+    Increasing,
+    /// Always go in the direction of decreasing hue
+    Decreasing,
+}
+
+/// Interpolates between the hues `from` and `to` (in degrees) at `t` in `[0, 1]`. The result is
+/// normalised to `[0, 360)`.
+///
+/// Example
+/// ```
+/// # use colors::hue::{interpolate_hue, HueInterpolation};
+/// # fn main() {
+/// assert_eq!(interpolate_hue(350.0, 30.0, 0.5, HueInterpolation::Shorter), 10.0);
+/// assert_eq!(interpolate_hue(350.0, 30.0, 0.5, HueInterpolation::Longer), 190.0);
+/// assert_eq!(interpolate_hue(30.0, 350.0, 0.5, HueInterpolation::Increasing), 190.0);
+/// assert_eq!(interpolate_hue(350.0, 30.0, 0.5, HueInterpolation::Decreasing), 190.0);
+/// # }
+/// ```
+pub fn interpolate_hue(from: f32, to: f32, t: f32, method: HueInterpolation) -> f32 {
+    let from = from.rem_euclid(360.0);
+    let mut to = to.rem_euclid(360.0);
+    let difference = to - from;
+    match method {
+        HueInterpolation::Shorter => {
+            if difference > 180.0 {
+                to -= 360.0;
+            } else if difference < -180.0 {
+                to += 360.0;
+            }
+        }
+        HueInterpolation::Longer => {
+            if 0.0 < difference && difference < 180.0 {
+                to -= 360.0;
+            } else if -180.0 < difference && difference <= 0.0 {
+                to += 360.0;
+            }
+        }
+        HueInterpolation::Increasing => {
+            if difference < 0.0 {
+                to += 360.0;
+            }
+        }
+        HueInterpolation::Decreasing => {
+            if difference > 0.0 {
+                to -= 360.0;
+            }
+        }
+    }
+    (from + (to - from) * t).rem_euclid(360.0)
+}
@@ -10,9 +10,28 @@
 //!
 //! ## Colour representation
 //!
-//! For each colour there is detailled information on how to represent it in different ways (for now hex,
-//! int/float tuple in RGB space). Other colour spaces (HUV, L\*AB, maybe also XYZ and spectrum samples)
-//! to come).
+//! For each colour there is detailled information on how to represent it in different ways (hex,
+//! int/float tuple in RGB space). Further representations and tools live in their own modules:
+//!
+//! - `rgb`, `rgba`: float colours, gamma encoded or linear light, and 8-bit colours with alpha
+//! - `hsl`: HSL, HSV and HWB
+//! - `cie`: CIE XYZ, L\*a\*b\*, L\*u\*v\* and LCh
+//! - `oklab`: Oklab and Oklch
+//! - `space`: wide gamut and custom RGB spaces
+//! - `css`, `parse`: CSS Color 4 parsing and serialization
+//! - `gamut`: gamut mapping into sRGB
+//! - `nearest`: the named colours closest to a colour
+//! - `distance`: ΔE colour differences
+//! - `contrast`, `apca`: WCAG 2 and APCA contrast
+//! - `cvd`: colour vision deficiency simulation and daltonization
+//! - `palette`: whether the colours of a palette can be told apart
+//! - `mix`, `gradient`: CSS `color-mix()` and multi-stop gradients
+//! - `adjust`: tints, shades and lighten/darken style adjustments
+//! - `harmony`: colour schemes from a base colour
+//! - `temperature`: colour temperatures
+//! - `spectrum`: colours of spectra
+//! - `pigment`: mixing like paints
+//! - `cmyk`: CMYK for print
 //!
 //! ## Basic Usage
 //!
@@ -25,9 +44,65 @@
 //! println!("Make the output {}red{}, {}bold{}", Ansi::ColorEscape(RED), Ansi::Reset, Ansi::Bold, Ansi::Reset);
 //!
 use std::fmt;
-use std::fmt::{Debug, Display, Formatter};
+use std::fmt::{Display, Formatter};
+
+/// Implements `Debug` as `Name(x=.., y=..)` and `Display` as `(x, y)` for a struct with the given
+/// fields, in the style of `Color`. Formatting options like the precision are passed on to the fields.
+macro_rules! impl_fmt {
+    ($ty:ident { $first:ident $(, $field:ident)* }) => {
+        impl std::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!(stringify!($ty), "(", stringify!($first), "="))?;
+                std::fmt::Display::fmt(&self.$first, f)?;
+                $(
+                    write!(f, concat!(", ", stringify!($field), "="))?;
+                    std::fmt::Display::fmt(&self.$field, f)?;
+                )*
+                write!(f, ")")
+            }
+        }
+
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "(")?;
+                std::fmt::Display::fmt(&self.$first, f)?;
+                $(
+                    write!(f, ", ")?;
+                    std::fmt::Display::fmt(&self.$field, f)?;
+                )*
+                write!(f, ")")
+            }
+        }
+    };
+}
 
+pub mod adjust;
+pub mod apca;
+pub mod cie;
 pub mod clr;
+mod cmf;
+pub mod cmyk;
+pub mod contrast;
+pub mod css;
+pub mod cvd;
+pub mod distance;
+pub mod gamut;
+pub mod gradient;
+pub mod harmony;
+pub mod hsl;
+pub mod hue;
+mod matrix;
+pub mod mix;
+pub mod nearest;
+pub mod oklab;
+pub mod palette;
+pub mod parse;
+pub mod pigment;
+pub mod rgb;
+pub mod rgba;
+pub mod space;
+pub mod spectrum;
+pub mod temperature;
 use clr::Color;
 
 /// A trait that transforms an objects into an ANSI escape sequence.
@@ -140,4 +215,1609 @@ mod tests {
             "\x1B[38;2;255;0;0m"
         );
     }
+
+    #[test]
+    fn lookup_by_name() {
+        assert_eq!(clr::by_name("b"), Some(clr::B));
+        assert_eq!(clr::by_name("K"), Some(clr::K));
+        assert_eq!(clr::by_name("CornflowerBlue"), Some(clr::CORNFLOWERBLUE));
+        assert_eq!(clr::by_name("dark goldenrod"), Some(clr::DARKGOLDENROD));
+        assert_eq!(clr::by_name("DARK_GOLDENROD"), Some(clr::DARKGOLDENROD));
+        assert_eq!(
+            clr::by_name("xkcd:cloudy blue"),
+            Some(clr::XKCD_CLOUDY_BLUE)
+        );
+        assert_eq!(
+            clr::by_name("XKCD_CLOUDY_BLUE"),
+            Some(clr::XKCD_CLOUDY_BLUE)
+        );
+        assert_eq!(clr::by_name("xkcd:blue green"), Some(clr::XKCD_BLUE_GREEN));
+        assert_eq!(clr::by_name("xkcd:bluegreen"), Some(clr::XKCD_BLUEGREEN));
+        assert_eq!(
+            clr::by_name("xkcd:blue/green"),
+            Some(clr::XKCD_BLUE_SLASH_GREEN)
+        );
+        assert_eq!(
+            clr::by_name("xkcd:robin's egg"),
+            Some(clr::XKCD_ROBIN_S_EGG)
+        );
+        assert_eq!(clr::by_name("tab:gray"), Some(clr::TAB_GRAY));
+        assert_eq!(clr::by_name("xkcd:"), None);
+        assert_eq!(clr::by_name(""), None);
+    }
+
+    #[test]
+    fn nearest_named_colors() {
+        use clr::ColorSet;
+        use nearest::{nearest, nearest_k};
+
+        assert_eq!(
+            nearest(clr::GOLDENROD, &[ColorSet::Css4]).unwrap().name,
+            "goldenrod"
+        );
+        assert_eq!(
+            nearest(clr::XKCD_DUSTY_TEAL, &[ColorSet::Xkcd])
+                .unwrap()
+                .name,
+            "xkcd:dusty teal"
+        );
+        assert_eq!(
+            nearest(Color(254, 1, 2), &[ColorSet::Base]).unwrap().name,
+            "r"
+        );
+        assert!(nearest(clr::GOLDENROD, &[]).is_none());
+        assert!(nearest_k(clr::GOLDENROD, 0, &ColorSet::ALL).is_empty());
+
+        // the k-d tree agrees with a linear scan
+        let all: Vec<_> = ColorSet::ALL
+            .iter()
+            .flat_map(|set| set.as_slice())
+            .collect();
+        for i in 0..200u32 {
+            let color = Color(
+                (i * 37 % 256) as u8,
+                (i * 91 % 256) as u8,
+                (i * 13 % 256) as u8,
+            );
+            let lab = oklab::Oklab::from(color);
+            let mut expected: Vec<f32> = all
+                .iter()
+                .map(|named| lab.distance(&oklab::Oklab::from(named.color)))
+                .collect();
+            expected.sort_by(f32::total_cmp);
+            let found = nearest_k(color, 5, &ColorSet::ALL);
+            for (j, (_, distance)) in found.iter().enumerate() {
+                assert!((distance - expected[j]).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn walk_all_named_colors() {
+        use clr::ColorSet;
+
+        let lengths: Vec<usize> = ColorSet::ALL.iter().map(|set| set.len()).collect();
+        assert_eq!(lengths, vec![8, 148, 949, 10]);
+        assert_eq!(clr::named_colors().len(), lengths.iter().sum::<usize>());
+
+        let mut number = 0;
+        for set in ColorSet::ALL.iter() {
+            for (i, named) in set.iter().enumerate() {
+                assert_eq!(named.index, number);
+                assert_eq!(named.set, *set);
+                assert_eq!(set.get(i), Some(*named));
+                assert_eq!(clr::lookup(named.name), Some(*named));
+                number += 1;
+            }
+        }
+
+        assert_eq!(format!("{}", ColorSet::Xkcd), "XKCD_COLORS");
+        assert_eq!(
+            format!("{}", clr::lookup("goldenrod").unwrap()),
+            "goldenrod (218, 165, 32)"
+        );
+    }
+
+    #[test]
+    fn parse_colors() {
+        use parse::ParseColorErrorKind::*;
+        use std::convert::TryFrom;
+
+        let parse = |s: &str| s.parse::<Color>();
+        assert_eq!(parse("#F00"), Ok(clr::R));
+        assert_eq!(parse("#f008"), Ok(clr::R));
+        assert_eq!(parse("#DAA520"), Ok(clr::GOLDENROD));
+        assert_eq!(parse("  daa520ff "), Ok(clr::GOLDENROD));
+        assert_eq!(parse("rgb(218, 165, 32)"), Ok(clr::GOLDENROD));
+        assert_eq!(parse("RGBA(218,165,32,0.5)"), Ok(clr::GOLDENROD));
+        assert_eq!(parse("rgb(218 165 32 / 50%)"), Ok(clr::GOLDENROD));
+        assert_eq!(parse("rgb(100%, 0%, 0%)"), Ok(clr::R));
+        assert_eq!(parse("rgb(300, -20, 127.6)"), Ok(Color(255, 0, 128)));
+        assert_eq!(parse("Goldenrod"), Ok(clr::GOLDENROD));
+        assert_eq!(parse("xkcd:cloudy blue"), Ok(clr::XKCD_CLOUDY_BLUE));
+        assert_eq!(Color::try_from("tab:blue"), Ok(clr::TAB_BLUE));
+
+        let error = |s: &str| {
+            let e = parse(s).unwrap_err();
+            (e.position(), e.kind().clone())
+        };
+        assert_eq!(error("   "), (3, Empty));
+        assert_eq!(error("#12345"), (1, InvalidHexLength(5)));
+        assert_eq!(error(" #12x456"), (4, InvalidHexDigit('x')));
+        assert_eq!(
+            error("notacolour"),
+            (0, UnknownName("notacolour".to_string()))
+        );
+        assert_eq!(
+            error("hsv(1, 2, 3)"),
+            (0, UnknownFunction("hsv".to_string()))
+        );
+        assert_eq!(error("rgb(1, 2 3)"), (9, Expected(',')));
+        assert_eq!(error("rgb(1, x, 3)"), (7, ExpectedNumber));
+        assert_eq!(error("rgb(1, 2, 3"), (11, UnexpectedEnd));
+        assert_eq!(error("rgb(1 2 3) x"), (11, UnexpectedCharacter('x')));
+        assert_eq!(error("rgb(1px 2 3)"), (5, UnexpectedCharacter('p')));
+    }
+
+    #[test]
+    fn css_serialization() {
+        let serialize = |s: &str| s.parse::<css::CssColor>().unwrap().to_string();
+
+        // examples from the serialization section of CSS Color 4
+        assert_eq!(
+            serialize("rgb(29 164 192 / 95%)"),
+            "rgba(29, 164, 192, 0.95)"
+        );
+        assert_eq!(serialize("hsl(120 100% 50%)"), "rgb(0, 255, 0)");
+        assert_eq!(
+            serialize("lab(29.2345% 39.3825 20.0664)"),
+            "lab(29.2345 39.3825 20.0664)"
+        );
+        assert_eq!(
+            serialize("lab(52.2345% 40.1645 59.9971 / .5)"),
+            "lab(52.2345 40.1645 59.9971 / 0.5)"
+        );
+        assert_eq!(
+            serialize("lch(52.2345% 72.2 56.2 / 1)"),
+            "lch(52.2345 72.2 56.2)"
+        );
+        assert_eq!(
+            serialize("oklab(40.101% 0.1147 0.0453)"),
+            "oklab(0.40101 0.1147 0.0453)"
+        );
+        assert_eq!(
+            serialize("oklch(59.686% 0.15619 49.7694 / 0.5)"),
+            "oklch(0.59686 0.15619 49.7694 / 0.5)"
+        );
+        assert_eq!(
+            serialize("color(display-p3 0.6 0.3 0.2)"),
+            "color(display-p3 0.6 0.3 0.2)"
+        );
+        assert_eq!(
+            serialize("color(srgb 50% 25% 10% / 50%)"),
+            "color(srgb 0.5 0.25 0.1 / 0.5)"
+        );
+        assert_eq!(
+            serialize("color(xyz 0.1 0.2 0.3)"),
+            "color(xyz-d65 0.1 0.2 0.3)"
+        );
+
+        // legacy syntax, named colours and hex notation
+        assert_eq!(
+            serialize("hsla(120, 100%, 25%, 0.5)"),
+            "rgba(0, 128, 0, 0.5)"
+        );
+        assert_eq!(serialize("hwb(0 100% 100%)"), "rgb(128, 128, 128)");
+        assert_eq!(serialize("#DAA52080"), "rgba(218, 165, 32, 0.5)");
+        assert_eq!(serialize("#DAA52081"), "rgba(218, 165, 32, 0.506)");
+        assert_eq!(serialize("#DAA52001"), "rgba(218, 165, 32, 0.004)");
+        assert_eq!(serialize("lab(50 0 0 / 0.25)"), "lab(50 0 0 / 0.25)");
+        assert_eq!(
+            serialize("oklab(0.5 0 0 / 0.1234)"),
+            "oklab(0.5 0 0 / 0.1234)"
+        );
+        assert_eq!(serialize("Goldenrod"), "rgb(218, 165, 32)");
+        assert_eq!(serialize("transparent"), "rgba(0, 0, 0, 0)");
+
+        // missing components, angles and clamping
+        assert_eq!(serialize("lch(none 20 30)"), "lch(none 20 30)");
+        assert_eq!(
+            serialize("oklch(0.5 none 120 / none)"),
+            "oklch(0.5 none 120 / none)"
+        );
+        assert_eq!(serialize("rgb(none 255 0)"), "rgb(0, 255, 0)");
+        assert_eq!(serialize("lch(50 30 0.5turn)"), "lch(50 30 180)");
+        assert_eq!(serialize("lab(120 -20 0)"), "lab(100 -20 0)");
+        assert_eq!(serialize("oklch(0.5 -0.1 90)"), "oklch(0.5 0 90)");
+        assert_eq!(serialize("rgb(300 -5 12.4)"), "rgb(255, 0, 12)");
+
+        let error = |s: &str| {
+            let e = s.parse::<css::CssColor>().unwrap_err();
+            (e.position(), e.kind().clone())
+        };
+        use parse::ParseColorErrorKind::*;
+        assert_eq!(error("rgb(1, none, 3)"), (7, ExpectedNumber));
+        assert_eq!(
+            error("color(foo 1 2 3)"),
+            (6, UnknownColorSpace("foo".to_string()))
+        );
+        assert_eq!(error("lab(50 20 30deg)"), (15, ExpectedNumber));
+        assert_eq!(error("hwb(0, 0%, 0%)"), (5, ExpectedNumber));
+        assert_eq!(error("xkcd:blue"), (4, UnexpectedCharacter(':')));
+        assert_eq!(error(" r"), (1, UnknownName("r".to_string())));
+    }
+
+    #[test]
+    fn rgba_compositing() {
+        use rgba::{CompositeOp, PremultipliedRgba, Rgba};
+
+        let red = Rgba(255, 0, 0, 128);
+        let blue = Rgba(0, 0, 255, 255);
+        let clear = Rgba(0, 0, 0, 0);
+
+        assert_eq!(format!("{}", red), "(255, 0, 0, 128)");
+        assert_eq!(format!("{:?}", red), "Rgba(r=255, g=0, b=0, a=128)");
+        assert_eq!(red.to_hex(), "#FF000080");
+        assert_eq!(Rgba::from(clr::GOLDENROD), Rgba(218, 165, 32, 255));
+        assert_eq!(Color::from(red), clr::R);
+
+        assert_eq!(red.premultiply(), PremultipliedRgba(128, 0, 0, 128));
+        assert_eq!(red.premultiply().unpremultiply(), red);
+        assert_eq!(PremultipliedRgba(0, 0, 0, 0).unpremultiply(), clear);
+
+        assert_eq!(red.over(blue), Rgba(128, 0, 127, 255));
+        assert_eq!(red.over(clear), red);
+        assert_eq!(clear.over(blue), blue);
+        assert_eq!(red.in_(blue), red);
+        assert_eq!(red.in_(clear), clear);
+        assert_eq!(red.out(blue), clear);
+        assert_eq!(red.out(clear), red);
+        assert_eq!(red.atop(blue), Rgba(128, 0, 127, 255));
+        assert_eq!(red.atop(clear), clear);
+        assert_eq!(red.xor(blue), Rgba(0, 0, 255, 127));
+        assert_eq!(red.composite(blue, CompositeOp::Over), red.over(blue));
+        assert_eq!(red.flatten(clr::WHITE), Color(255, 127, 127));
+
+        assert_eq!("#FF000080".parse::<Rgba>(), Ok(red));
+        assert_eq!(
+            "rgb(0 0 255 / 50%)".parse::<Rgba>(),
+            Ok(Rgba(0, 0, 255, 128))
+        );
+        assert_eq!("goldenrod".parse::<Rgba>(), Ok(Rgba(218, 165, 32, 255)));
+        assert_eq!(css::CssColor::from(red).to_string(), "rgba(255, 0, 0, 0.5)");
+
+        // every 8 bit alpha survives serializing and parsing
+        for alpha in 0..=255 {
+            let rgba = Rgba(218, 165, 32, alpha);
+            let css = css::CssColor::from(rgba).to_string();
+            assert_eq!(css.parse::<Rgba>(), Ok(rgba), "{}", css);
+            assert!(css.len() <= "rgba(218, 165, 32, 0.004)".len(), "{}", css);
+        }
+    }
+
+    #[test]
+    fn cylindrical_round_trips() {
+        use hsl::{Hsl, Hsv, Hwb};
+
+        let close = |a: Color, b: Color| {
+            (a.0 as i32 - b.0 as i32).abs() <= 1
+                && (a.1 as i32 - b.1 as i32).abs() <= 1
+                && (a.2 as i32 - b.2 as i32).abs() <= 1
+        };
+        for named in clr::named_colors() {
+            let color = named.color;
+            assert!(close(Color::from(Hsl::from(color)), color), "{}", named);
+            assert!(close(Color::from(Hsv::from(color)), color), "{}", named);
+            assert!(close(Color::from(Hwb::from(color)), color), "{}", named);
+        }
+
+        // greys have hue and saturation 0
+        for &grey in [clr::BLACK, clr::GREY, clr::WHITE].iter() {
+            let hsl = Hsl::from(grey);
+            assert_eq!((hsl.h, hsl.s), (0.0, 0.0));
+            let hsv = Hsv::from(grey);
+            assert_eq!((hsv.h, hsv.s), (0.0, 0.0));
+            assert_eq!(Hwb::from(grey).h, 0.0);
+        }
+
+        assert_eq!(format!("{:?}", Hsl::from(clr::R)), "Hsl(h=0, s=1, l=0.5)");
+        assert_eq!(format!("{}", Hsv::from(clr::B)), "(240, 1, 1)");
+        assert_eq!(format!("{}", Hwb::from(clr::WHITE)), "(0, 1, 0)");
+        assert_eq!(Color::from(Hsl::new(-240.0, 1.0, 0.5)), Color(0, 255, 0));
+        assert_eq!(Color::from(Hsv::new(300.0, 1.0, 1.0)), Color(255, 0, 255));
+    }
+
+    #[test]
+    fn cie_spaces() {
+        use cie::{Adaptation, Lab, Lch, LchUv, Luv, WhitePoint, Xyz};
+
+        let near = |a: f32, b: f32| (a - b).abs() < 0.01;
+
+        let xyz = Xyz::from(clr::R);
+        assert!(near(xyz.x, 0.4124) && near(xyz.y, 0.2126) && near(xyz.z, 0.0193));
+        let white = Lab::from(clr::WHITE);
+        assert!(near(white.l, 100.0) && near(white.a, 0.0) && near(white.b, 0.0));
+        let luv = Luv::from(clr::R);
+        assert!(
+            near(luv.l, 53.24) && (luv.u - 175.01).abs() < 0.05 && (luv.v - 37.76).abs() < 0.05
+        );
+        // a chromaticity v′ of zero or below has no XYZ values
+        let (_, vn) = Xyz::from(clr::WHITE).uv_prime();
+        for &v in &[-13.0 * 50.0 * vn, -500.0] {
+            let xyz = Luv::new(50.0, 20.0, v).to_xyz(WhitePoint::D65);
+            assert_eq!((xyz.x, xyz.y, xyz.z), (0.0, 0.0, 0.0));
+        }
+        let lch = Lch::from(clr::R);
+        assert!(near(lch.c, 104.55) && near(lch.h, 40.0));
+        let lch_uv = LchUv::from(clr::WHITE);
+        assert!(near(lch_uv.c, 0.0) && lch_uv.h == 0.0);
+
+        // adaptation there and back
+        for &method in [
+            Adaptation::Bradford,
+            Adaptation::Cat02,
+            Adaptation::VonKries,
+        ]
+        .iter()
+        {
+            let adapted = xyz.adapt(WhitePoint::D65, WhitePoint::D50, method);
+            let back = adapted.adapt(WhitePoint::D50, WhitePoint::D65, method);
+            assert!((back.x - xyz.x).abs() < 1e-5 && (back.z - xyz.z).abs() < 1e-5);
+            let white = Lab::from_color(clr::WHITE, WhitePoint::D50, method);
+            assert!(near(white.l, 100.0) && near(white.a, 0.0) && near(white.b, 0.0));
+            let white = Lch::from_color(clr::WHITE, WhitePoint::D50, method);
+            assert!(near(white.l, 100.0) && near(white.c, 0.0));
+            let white = LchUv::from_color(clr::WHITE, WhitePoint::D50, method);
+            assert!(near(white.l, 100.0) && near(white.c, 0.0));
+        }
+
+        let close = |a: Color, b: Color| {
+            (a.0 as i32 - b.0 as i32).abs() <= 1
+                && (a.1 as i32 - b.1 as i32).abs() <= 1
+                && (a.2 as i32 - b.2 as i32).abs() <= 1
+        };
+        let a = WhitePoint::A;
+        for named in clr::named_colors() {
+            let color = named.color;
+            assert!(close(Color::from(Lab::from(color)), color), "{}", named);
+            assert!(close(Color::from(Luv::from(color)), color), "{}", named);
+            assert!(close(Color::from(Lch::from(color)), color), "{}", named);
+            assert!(close(Color::from(LchUv::from(color)), color), "{}", named);
+            let lab = Lab::from_color(color, a, Adaptation::Cat02);
+            assert!(
+                close(lab.to_color(a, Adaptation::Cat02), color),
+                "{}",
+                named
+            );
+            let luv = Luv::from_color(color, a, Adaptation::VonKries);
+            assert!(
+                close(luv.to_color(a, Adaptation::VonKries), color),
+                "{}",
+                named
+            );
+            let d50 = WhitePoint::D50;
+            let lab = Lab::from_color(color, d50, Adaptation::Bradford);
+            let lch = Lch::from_color(color, d50, Adaptation::Bradford);
+            assert_eq!(lch, Lch::from(lab), "{}", named);
+            assert!(
+                close(lch.to_color(d50, Adaptation::Bradford), color),
+                "{}",
+                named
+            );
+            let luv = Luv::from_color(color, d50, Adaptation::Bradford);
+            let lch_uv = LchUv::from_color(color, d50, Adaptation::Bradford);
+            assert_eq!(lch_uv, LchUv::from(luv), "{}", named);
+            assert!(
+                close(lch_uv.to_color(d50, Adaptation::Bradford), color),
+                "{}",
+                named
+            );
+        }
+    }
+
+    #[test]
+    fn oklab_spaces() {
+        use hue::HueInterpolation;
+        use oklab::{Oklab, Oklch};
+
+        let near = |a: f32, b: f32| (a - b).abs() < 1e-4;
+        let red = Oklab::from(clr::R);
+        assert!(near(red.l, 0.62796) && near(red.a, 0.22486) && near(red.b, 0.12585));
+        let red = Oklch::from(clr::R);
+        assert!(near(red.c, 0.25768) && (red.h - 29.2339).abs() < 1e-2);
+        assert_eq!(Oklch::from(clr::GREY).h, 0.0);
+
+        for named in clr::named_colors() {
+            let color = named.color;
+            assert!(Oklab::from(color).in_gamut(), "{}", named);
+            assert_eq!(Oklab::from(color).try_to_color(), Some(color), "{}", named);
+            assert_eq!(Color::from(Oklch::from(color)), color, "{}", named);
+        }
+
+        // greys take the hue of the other colour
+        let white = Oklch::from(clr::WHITE);
+        let blue = Oklch::from(clr::B);
+        let middle = white.interpolate(&blue, 0.5, HueInterpolation::Shorter);
+        assert!(near(middle.h, blue.h));
+        assert!(near(middle.c, blue.c / 2.0));
+
+        let mid = Oklab::from(clr::BLACK).interpolate(&Oklab::from(clr::WHITE), 0.5);
+        assert!(near(mid.l, 0.5) && mid.in_gamut());
+        assert!(!Oklab::new(0.5, 0.4, 0.0).in_gamut());
+        assert_eq!(Color::from(Oklab::new(1.5, 0.0, 0.0)), clr::WHITE);
+    }
+
+    #[test]
+    fn float_rgb() {
+        use rgb::{decode, encode, LinearRgb, Rgb};
+
+        for named in clr::named_colors() {
+            let color = named.color;
+            assert_eq!(Rgb::<f32>::from(color).to_color(), color);
+            assert_eq!(Rgb::<f64>::from(color).to_color(), color);
+            assert_eq!(LinearRgb::<f32>::from(color).to_color(), color);
+            assert_eq!(LinearRgb::<f64>::from(color).encode().to_color(), color);
+            let lab = oklab::Oklab::from(LinearRgb::<f64>::from(color));
+            assert_eq!(LinearRgb::<f64>::from(lab).to_color(), color);
+        }
+
+        let grey: Rgb<f64> = Rgb::new(0.5, 0.5, 0.5);
+        assert!((grey.decode().r - 0.214_041_14).abs() < 1e-8);
+        assert_eq!(decode(-0.5f64), -decode(0.5f64));
+        assert_eq!(encode(-0.25f32), -encode(0.25f32));
+        assert!((encode(decode(0.75f64)) - 0.75).abs() < 1e-12);
+
+        let wide = LinearRgb::new(1.2f32, -0.1, 0.5);
+        assert!(!wide.in_gamut());
+        assert_eq!(wide.clamp(), LinearRgb::new(1.0, 0.0, 0.5));
+        assert_eq!(wide.to_color(), Color(255, 0, 188));
+        assert_eq!(
+            LinearRgb::new(0.25f32, 0.5, 1.0) + LinearRgb::new(0.25, 0.25, 0.0),
+            LinearRgb::new(0.5, 0.75, 1.0)
+        );
+        assert_eq!(
+            LinearRgb::new(0.25f32, 0.5, 1.0) * 0.5,
+            LinearRgb::new(0.125, 0.25, 0.5)
+        );
+        assert_eq!(
+            format!("{:?}", Rgb::new(1.0f32, 0.5, 0.0)),
+            "Rgb(r=1, g=0.5, b=0)"
+        );
+        assert_eq!(
+            format!("{:.1}", LinearRgb::new(1.0f64, 0.5, 0.0)),
+            "(1.0, 0.5, 0.0)"
+        );
+    }
+
+    #[test]
+    fn rgb_spaces() {
+        use space::{RgbSpace, TransferFunction};
+
+        let spaces = [
+            RgbSpace::SRGB,
+            RgbSpace::DISPLAY_P3,
+            RgbSpace::REC709,
+            RgbSpace::REC2020,
+            RgbSpace::ADOBE_RGB,
+            RgbSpace::PROPHOTO_RGB,
+        ];
+        for named in clr::named_colors() {
+            for space in &spaces {
+                let rgb = space.from_color(named.color);
+                assert!(space.contains(rgb), "{} in {}", named, space);
+                assert_eq!(space.to_color(rgb), named.color, "{} in {}", named, space);
+            }
+        }
+
+        let close = |a: [f32; 3], b: [f32; 3]| a.iter().zip(&b).all(|(x, y)| (x - y).abs() < 2e-4);
+        let srgb = RgbSpace::SRGB.from_color(clr::GOLDENROD);
+        assert!(close(srgb, [218.0 / 255.0, 165.0 / 255.0, 32.0 / 255.0]));
+        assert!(close(
+            RgbSpace::REC2020.from_color(clr::R),
+            [0.7920, 0.2310, 0.0738]
+        ));
+        // green and blue are zero, which the Adobe RGB gamma amplifies any rounding error of
+        let red = RgbSpace::ADOBE_RGB.from_color(clr::R);
+        assert!((red[0] - 0.8587).abs() < 2e-4);
+        assert!(close(RgbSpace::ADOBE_RGB.decode(red), [0.7152, 0.0, 0.0]));
+        assert!(close(
+            RgbSpace::PROPHOTO_RGB.from_color(clr::R),
+            [0.7023, 0.2757, 0.1035]
+        ));
+        assert!(close(
+            RgbSpace::ADOBE_RGB.from_color(clr::WHITE),
+            [1.0, 1.0, 1.0]
+        ));
+
+        let green = RgbSpace::REC2020.convert([0.0, 1.0, 0.0], &RgbSpace::DISPLAY_P3);
+        assert!(!RgbSpace::DISPLAY_P3.contains(green));
+        assert!(close(
+            RgbSpace::DISPLAY_P3.convert(green, &RgbSpace::REC2020),
+            [0.0, 1.0, 0.0]
+        ));
+
+        for transfer in [
+            TransferFunction::Linear,
+            TransferFunction::Srgb,
+            TransferFunction::Gamma(2.2),
+            TransferFunction::Bt709,
+            TransferFunction::ProPhoto,
+        ] {
+            for value in [-0.5, 0.0, 0.001, 0.01, 0.2, 0.5, 1.0] {
+                let round_trip = transfer.encode(transfer.decode(value));
+                assert!(
+                    (round_trip - value).abs() < 1e-5,
+                    "{:?} {}",
+                    transfer,
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn gamut_mapping() {
+        use cie::{Lab, Xyz};
+        use css::CssColor;
+        use gamut::{in_gamut, GamutMapping};
+        use oklab::{Oklab, Oklch};
+        use space::RgbSpace;
+
+        let methods = [GamutMapping::Clip, GamutMapping::Css, GamutMapping::Closest];
+        for named in clr::named_colors() {
+            assert!(in_gamut(named.color));
+            for method in methods {
+                assert_eq!(method.map(Oklch::from(named.color)), named.color);
+            }
+        }
+
+        let vivid = Oklch::new(0.7, 0.4, 150.0);
+        assert!(!in_gamut(vivid));
+        assert_eq!(GamutMapping::Clip.map(vivid), Color(0, 214, 0));
+        // the CSS algorithm keeps the hue much closer than clipping
+        let mapped = Oklch::from(GamutMapping::Css.map(vivid));
+        let clipped = Oklch::from(GamutMapping::Clip.map(vivid));
+        assert!((mapped.h - vivid.h).abs() < (clipped.h - vivid.h).abs());
+        assert!((mapped.l - vivid.l).abs() < 0.02);
+        // no mapping ends up further away than the closest one
+        let closest = Oklab::from(GamutMapping::Closest.map(vivid));
+        for method in methods {
+            let distance = Oklab::from(method.map(vivid)).distance(&Oklab::from(vivid));
+            assert!(closest.distance(&Oklab::from(vivid)) <= distance + 2e-3);
+        }
+
+        // beyond white and black
+        assert_eq!(
+            GamutMapping::Css.map(Oklch::new(1.2, 0.1, 30.0)),
+            clr::WHITE
+        );
+        assert_eq!(GamutMapping::Css.map(Lab::new(-5.0, 20.0, 0.0)), clr::BLACK);
+        assert_eq!(GamutMapping::Css.map(Xyz::new(0.0, 0.0, 0.0)), clr::BLACK);
+
+        let p3 = RgbSpace::DISPLAY_P3.to_xyz([0.0, 1.0, 0.0]);
+        assert!(!in_gamut(p3));
+        assert!(in_gamut(
+            RgbSpace::DISPLAY_P3.to_xyz(RgbSpace::DISPLAY_P3.from_color(clr::G))
+        ));
+
+        let parse = |s: &str| s.parse::<CssColor>().unwrap().to_color(GamutMapping::Css);
+        assert_eq!(parse("lab(54.29 80.80 69.89)"), clr::R);
+        assert_eq!(parse("lch(54.29 106.84 40.85)"), clr::R);
+        assert_eq!(parse("oklch(62.8% 0.2577 29.23)"), clr::R);
+        assert_eq!(parse("color(xyz 0.4124 0.2126 0.0193)"), clr::R);
+        assert_eq!(parse("color(srgb-linear 1 0 0)"), clr::R);
+        assert_eq!(parse("color(rec2020 0.7920 0.2310 0.0738)"), clr::R);
+        assert_eq!(parse("hsl(120 100% 25%)"), Color(0, 128, 0));
+    }
+
+    #[test]
+    fn delta_e_metrics() {
+        use cie::Lab;
+        use distance::{Cie94Application, ColorDistance, DeltaE};
+
+        // the CIEDE2000 test data of Sharma, Wu and Dalal
+        #[rustfmt::skip]
+        let sharma = [
+            ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+            ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+            ([50.0, 2.8361, -74.0200], [50.0, 0.0, -82.7485], 3.4412),
+            ([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, -1.1848, -84.8006], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, -0.9009, -85.5211], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+            ([50.0, -1.0, 2.0], [50.0, 0.0, 0.0], 2.3669),
+            ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0009], 7.1792),
+            ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0010], 7.1792),
+            ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0011], 7.2195),
+            ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0012], 7.2195),
+            ([50.0, -0.0010, 2.4900], [50.0, 0.0009, -2.4900], 4.8045),
+            ([50.0, -0.0010, 2.4900], [50.0, 0.0010, -2.4900], 4.8045),
+            ([50.0, -0.0010, 2.4900], [50.0, 0.0011, -2.4900], 4.7461),
+            ([50.0, 2.5000, 0.0000], [50.0, 0.0000, -2.5000], 4.3065),
+            ([50.0, 2.5000, 0.0000], [73.0, 25.0000, -18.0000], 27.1492),
+            ([50.0, 2.5000, 0.0000], [61.0, -5.0000, 29.0000], 22.8977),
+            ([50.0, 2.5000, 0.0000], [56.0, -27.0000, -3.0000], 31.9030),
+            ([50.0, 2.5000, 0.0000], [58.0, 24.0000, 15.0000], 19.4535),
+            ([50.0, 2.5000, 0.0000], [50.0, 3.1736, 0.5854], 1.0000),
+            ([50.0, 2.5000, 0.0000], [50.0, 3.2972, 0.0000], 1.0000),
+            ([50.0, 2.5000, 0.0000], [50.0, 1.8634, 0.5757], 1.0000),
+            ([50.0, 2.5000, 0.0000], [50.0, 3.2592, 0.3350], 1.0000),
+            ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+            ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+            ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+            ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+            ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+            ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+            ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+            ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+            ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+            ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+        ];
+        let lab = |[l, a, b]: [f32; 3]| Lab::new(l, a, b);
+        for (first, second, expected) in sharma {
+            let (first, second) = (lab(first), lab(second));
+            for (reference, sample) in [(first, second), (second, first)] {
+                let delta = reference.delta_e(&sample, DeltaE::Ciede2000);
+                assert!(
+                    (delta - expected).abs() < 1e-4,
+                    "{:?} {:?}",
+                    reference,
+                    sample
+                );
+            }
+        }
+
+        let (reference, sample) = (lab([50.0, 2.6772, -79.7751]), lab([50.0, 0.0, -82.7485]));
+        let metrics = [
+            (DeltaE::Cie76, 4.0010),
+            (DeltaE::Cie94(Cie94Application::GraphicArts), 1.3950),
+            (DeltaE::Cie94(Cie94Application::Textiles), 1.4230),
+            (DeltaE::CMC_ACCEPTABILITY, 1.7387),
+            (DeltaE::CMC_PERCEPTIBILITY, 1.7387),
+        ];
+        for (metric, expected) in metrics {
+            let delta = reference.delta_e(&sample, metric);
+            assert!((delta - expected).abs() < 1e-4, "{:?}: {}", metric, delta);
+        }
+        let (reference, sample) = (
+            lab([60.2574, -34.0099, 36.2677]),
+            lab([60.4626, -34.1751, 39.4387]),
+        );
+        assert!((reference.delta_e(&sample, DeltaE::CMC_ACCEPTABILITY) - 1.4205).abs() < 1e-4);
+        assert!((reference.delta_e(&sample, DeltaE::CMC_PERCEPTIBILITY) - 1.4282).abs() < 1e-4);
+
+        for named in &clr::named_colors()[..200] {
+            let color = named.color;
+            for metric in [DeltaE::Cie76, DeltaE::Ciede2000, DeltaE::Oklab] {
+                assert!(color.delta_e(&color, metric).abs() < 1e-6);
+                let other = clr::WHITE;
+                let there = color.delta_e(&other, metric);
+                assert!((there - other.delta_e(&color, metric)).abs() < 1e-3);
+            }
+        }
+        let ok = clr::BLACK.delta_e(&clr::WHITE, DeltaE::Oklab);
+        assert!((ok - 1.0).abs() < 1e-3);
+        assert!(
+            (Lab::from(clr::BLACK).delta_e(&Lab::from(clr::WHITE), DeltaE::Oklab) - ok).abs()
+                < 1e-3
+        );
+    }
+
+    #[test]
+    fn wcag_contrast() {
+        use contrast::{contrast_ratio, most_readable, passes, readable_text_color};
+        use contrast::{TextSize, WcagLevel};
+
+        assert_eq!(clr::BLACK.relative_luminance(), 0.0);
+        assert_eq!(clr::WHITE.relative_luminance(), 1.0);
+        assert!((clr::R.relative_luminance() - 0.2126).abs() < 1e-6);
+        assert!((clr::LIME.relative_luminance() - 0.7152).abs() < 1e-6);
+        assert!((clr::B.relative_luminance() - 0.0722).abs() < 1e-6);
+
+        assert!((contrast_ratio(clr::BLACK, clr::WHITE) - 21.0).abs() < 1e-5);
+        assert_eq!(contrast_ratio(clr::GOLDENROD, clr::GOLDENROD), 1.0);
+        assert_eq!(
+            contrast_ratio(clr::ROYALBLUE, clr::WHITE),
+            contrast_ratio(clr::WHITE, clr::ROYALBLUE)
+        );
+        // #767676 is the lightest grey passing AA on white
+        assert!(passes(
+            Color(0x76, 0x76, 0x76),
+            clr::WHITE,
+            WcagLevel::Aa,
+            TextSize::Normal
+        ));
+        assert!(!passes(
+            Color(0x77, 0x77, 0x77),
+            clr::WHITE,
+            WcagLevel::Aa,
+            TextSize::Normal
+        ));
+        assert!(passes(
+            Color(0x77, 0x77, 0x77),
+            clr::WHITE,
+            WcagLevel::Aa,
+            TextSize::Large
+        ));
+        assert!(!passes(
+            Color(0x77, 0x77, 0x77),
+            clr::WHITE,
+            WcagLevel::Aaa,
+            TextSize::Large
+        ));
+        assert!(passes(
+            Color(0x59, 0x59, 0x59),
+            clr::WHITE,
+            WcagLevel::Aaa,
+            TextSize::Normal
+        ));
+
+        for named in clr::named_colors() {
+            let text = readable_text_color(named.color);
+            assert!(contrast_ratio(text, named.color) >= 21f32.sqrt() - 1e-3);
+        }
+        assert_eq!(readable_text_color(clr::NAVY), clr::WHITE);
+        assert_eq!(readable_text_color(clr::YELLOW), clr::BLACK);
+
+        let candidates = [clr::GOLDENROD, clr::ROYALBLUE, clr::NAVY];
+        assert_eq!(most_readable(clr::WHITE, &candidates), Some(clr::NAVY));
+        assert_eq!(most_readable(clr::BLACK, &candidates), Some(clr::GOLDENROD));
+        assert_eq!(most_readable(clr::BLACK, &[]), None);
+    }
+
+    #[test]
+    fn apca_contrast() {
+        use apca::{adjust_lightness, contrast, min_font_size};
+
+        // reference values of the APCA 0.0.98G-4g implementation
+        let cases = [
+            (0x888888, 0xffffff, 63.056),
+            (0xffffff, 0x888888, -68.541),
+            (0x000000, 0xaaaaaa, 58.146),
+            (0xaaaaaa, 0x000000, -56.241),
+            (0x112233, 0xddeeff, 91.668),
+            (0xddeeff, 0x112233, -93.068),
+        ];
+        let hex = |value: u32| Color((value >> 16) as u8, (value >> 8) as u8, value as u8);
+        for (text, background, expected) in cases {
+            let lc = contrast(hex(text), hex(background));
+            assert!((lc - expected).abs() < 2e-3, "{} != {}", lc, expected);
+        }
+        assert_eq!(contrast(clr::GOLDENROD, clr::GOLDENROD), 0.0);
+        assert!(contrast(clr::BLACK, clr::WHITE) > 106.0);
+        assert!(contrast(clr::WHITE, clr::BLACK) < -107.0);
+
+        assert_eq!(min_font_size(10.0, 400), None);
+        assert_eq!(min_font_size(20.0, 900), None);
+        assert_eq!(min_font_size(75.0, 400), Some(18.0));
+        assert_eq!(min_font_size(-77.0, 450), Some(18.0));
+        assert_eq!(min_font_size(90.0, 300), Some(21.0));
+        assert_eq!(min_font_size(200.0, 100), Some(32.0));
+        assert_eq!(min_font_size(60.0, 0), Some(72.0));
+
+        let background = Color(0x1e, 0x1e, 0x1e);
+        for named in &clr::named_colors()[..100] {
+            for target in [45.0, 60.0, 75.0] {
+                if let Some(text) = adjust_lightness(named.color, background, target) {
+                    assert!(contrast(text, background).abs() >= target);
+                }
+            }
+        }
+        // light text on a dark background stays light
+        let text = adjust_lightness(clr::ROYALBLUE, background, 75.0).unwrap();
+        assert!(contrast(text, background) <= -75.0);
+        assert_eq!(
+            adjust_lightness(clr::WHITE, background, 75.0),
+            Some(clr::WHITE)
+        );
+        // dark text that cannot get dark enough is flipped
+        let text = adjust_lightness(Color(0x20, 0x20, 0x20), background, 60.0).unwrap();
+        assert!(contrast(text, background) <= -60.0);
+        assert_eq!(
+            adjust_lightness(clr::GOLDENROD, clr::GOLDENROD, 110.0),
+            None
+        );
+    }
+
+    #[test]
+    fn colour_vision_deficiencies() {
+        use cvd::{Deficiency, Method, Simulation};
+        use distance::{ColorDistance, DeltaE};
+
+        let deficiencies = [
+            Deficiency::Protanopia,
+            Deficiency::Deuteranopia,
+            Deficiency::Tritanopia,
+            Deficiency::Achromatopsia,
+        ];
+        let methods = [Method::Brettel, Method::Vienot, Method::Machado];
+        let greys = [
+            clr::BLACK,
+            clr::WHITE,
+            Color(128, 128, 128),
+            Color(30, 30, 30),
+        ];
+        for deficiency in deficiencies {
+            for method in methods {
+                let full = Simulation::new(deficiency, method);
+                for grey in greys {
+                    let simulated = full.simulate(grey);
+                    assert!(simulated.delta_e(&grey, DeltaE::Oklab) < 0.01, "{:?}", full);
+                    assert_eq!(full.daltonize(grey), grey);
+                }
+                let none = full.with_severity(0.0);
+                for named in clr::named_colors() {
+                    assert_eq!(none.simulate(named.color), named.color);
+                }
+            }
+        }
+
+        // red and green are hard to tell apart for protans and deutans, easy for tritans
+        let apart = |simulation: Simulation, colors: &[Color]| {
+            let simulated = simulation.simulate_palette(colors);
+            simulated[0].delta_e(&simulated[1], DeltaE::Oklab)
+        };
+        let pair = [clr::R, clr::G];
+        let normal = clr::R.delta_e(&clr::G, DeltaE::Oklab);
+        for method in methods {
+            for deficiency in [Deficiency::Protanopia, Deficiency::Deuteranopia] {
+                let simulation = Simulation::new(deficiency, method);
+                assert!(apart(simulation, &pair) < normal / 2.0, "{:?}", simulation);
+                let daltonized = simulation.daltonize_palette(&pair);
+                assert!(apart(simulation, &daltonized) > apart(simulation, &pair));
+                let mild = simulation.with_severity(0.5);
+                assert!(apart(mild, &pair) > apart(simulation, &pair));
+            }
+        }
+        let tritan = Simulation::new(Deficiency::Tritanopia, Method::Brettel);
+        assert!(apart(tritan, &pair) > normal / 2.0);
+        let blue_yellow = [clr::B, clr::LIME];
+        assert!(apart(tritan, &blue_yellow) < clr::B.delta_e(&clr::LIME, DeltaE::Oklab));
+
+        let achromat = Simulation::new(Deficiency::Achromatopsia, Method::Brettel);
+        let grey = achromat.simulate(clr::GOLDENROD);
+        assert!(grey.0 == grey.1 && grey.1 == grey.2);
+        assert_eq!(achromat.daltonize(clr::GOLDENROD), clr::GOLDENROD);
+    }
+
+    #[test]
+    fn palette_check() {
+        use cvd::Deficiency;
+        use distance::DeltaE;
+        use palette::{check_palette, PaletteCheck, Vision};
+
+        let tableau: Vec<Color> = clr::ColorSet::Tableau.iter().map(|n| n.color).collect();
+        let report = check_palette(&tableau[..4]);
+        assert_eq!(report.summaries.len(), 4);
+        assert_eq!(report.summaries[0].vision, Vision::Normal);
+        assert!(report.summaries[0].min_delta_e >= 10.0);
+
+        let report = check_palette(&[clr::R, clr::G, clr::B, clr::GOLDENROD]);
+        assert!(!report.is_distinguishable());
+        assert!(report.summaries[0].min_delta_e >= 10.0);
+        for conflict in &report.conflicts {
+            assert_ne!(conflict.vision, Vision::Normal);
+            assert!(conflict.delta_e < 10.0);
+            assert!(conflict.first < conflict.second);
+        }
+        let protan = report
+            .summaries
+            .iter()
+            .find(|s| s.vision == Vision::Deficient(Deficiency::Protanopia))
+            .unwrap();
+        assert!(protan.min_delta_e < 10.0);
+        assert_eq!(protan.closest_pair, (0, 1));
+        assert!(!report.suggestions.is_empty());
+        for suggestion in &report.suggestions {
+            assert!(suggestion.min_delta_e >= 10.0);
+        }
+
+        // applying all suggestions one after the other resolves the conflicts
+        let mut palette = vec![clr::R, clr::G, clr::B, clr::GOLDENROD];
+        let check = PaletteCheck::default();
+        for _ in 0..palette.len() {
+            let report = check.check(&palette);
+            match report.suggestions.first() {
+                Some(suggestion) => palette[suggestion.index] = suggestion.color.color,
+                None => break,
+            }
+        }
+        assert!(check.check(&palette).is_distinguishable());
+        assert!(check.suggest(&palette, 3).is_some());
+        assert!(check.suggest(&palette, palette.len()).is_none());
+        assert!(check.suggest(&[], 0).is_none());
+
+        let strict = PaletteCheck {
+            metric: DeltaE::Oklab,
+            threshold: 0.5,
+            visions: vec![Vision::Normal],
+            ..PaletteCheck::default()
+        };
+        let report = strict.check(&[clr::BLACK, clr::WHITE]);
+        assert!(report.is_distinguishable());
+        let report = strict.check(&[clr::BLACK, clr::NAVY]);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.suggestions[0].index, 1);
+        assert!(check_palette(&[]).is_distinguishable());
+        assert!(check_palette(&[clr::R]).summaries[0]
+            .min_delta_e
+            .is_infinite());
+    }
+
+    #[test]
+    fn mixing() {
+        use hue::HueInterpolation;
+        use mix::{color_mix, interpolate, mix, mix_with_hue, MixSpace};
+        use oklab::Oklch;
+        use rgba::Rgba;
+
+        let spaces = [
+            MixSpace::Srgb,
+            MixSpace::LinearRgb,
+            MixSpace::Lab,
+            MixSpace::Lch,
+            MixSpace::Oklab,
+            MixSpace::Oklch,
+        ];
+        for space in spaces {
+            for named in &clr::named_colors()[..150] {
+                let color = named.color;
+                assert_eq!(mix(color, clr::ROYALBLUE, 0.0, space), color, "{:?}", space);
+                assert_eq!(mix(color, clr::ROYALBLUE, 1.0, space), clr::ROYALBLUE);
+                assert_eq!(
+                    mix(color, color, 0.3, space),
+                    color,
+                    "{} {:?}",
+                    named,
+                    space
+                );
+            }
+            // mixing with a grey keeps the hue of the colour
+            let middle = mix(clr::GOLDENROD, clr::WHITE, 0.5, space);
+            let hue = Oklch::from(middle).h - Oklch::from(clr::GOLDENROD).h;
+            assert!(hue.abs() < 12.0, "{:?}: {}", space, hue);
+        }
+        assert_eq!(
+            mix(clr::BLACK, clr::WHITE, 0.5, MixSpace::Srgb),
+            Color(128, 128, 128)
+        );
+        assert_eq!(
+            mix(clr::BLACK, clr::WHITE, 0.5, MixSpace::LinearRgb),
+            Color(188, 188, 188)
+        );
+        assert_eq!(
+            mix(clr::BLACK, clr::WHITE, 0.5, MixSpace::Oklab),
+            Color(99, 99, 99)
+        );
+
+        // hue interpolation in the cylindrical spaces
+        let (red, blue) = (clr::RED, clr::BLUE);
+        let shorter = Oklch::from(mix_with_hue(
+            red,
+            blue,
+            0.5,
+            MixSpace::Oklch,
+            HueInterpolation::Shorter,
+        ));
+        let longer = Oklch::from(mix_with_hue(
+            red,
+            blue,
+            0.5,
+            MixSpace::Oklch,
+            HueInterpolation::Longer,
+        ));
+        assert!(shorter.h > 300.0 && shorter.h < 350.0, "{}", shorter.h);
+        assert!(longer.h > 100.0 && longer.h < 180.0, "{}", longer.h);
+        // from red to blue, increasing hues is the longer way and decreasing hues the shorter one
+        for space in [MixSpace::Lch, MixSpace::Oklch] {
+            let at = |method| mix_with_hue(red, blue, 0.5, space, method);
+            assert_eq!(
+                at(HueInterpolation::Increasing),
+                at(HueInterpolation::Longer)
+            );
+            assert_eq!(
+                at(HueInterpolation::Decreasing),
+                at(HueInterpolation::Shorter)
+            );
+        }
+
+        // premultiplied alpha: transparent colours do not tint the mix
+        let transparent = Rgba(0, 0, 0, 0);
+        for space in spaces {
+            let mixed = interpolate(
+                Rgba::from(clr::GOLDENROD),
+                transparent,
+                0.5,
+                space,
+                HueInterpolation::Shorter,
+            );
+            assert_eq!(mixed, Rgba::new(clr::GOLDENROD, 128), "{:?}", space);
+        }
+        assert_eq!(
+            interpolate(
+                transparent,
+                transparent,
+                0.5,
+                MixSpace::Oklab,
+                HueInterpolation::Shorter
+            ),
+            transparent
+        );
+
+        // color-mix() percentages
+        let (a, b) = (Rgba::from(clr::RED), Rgba::from(clr::BLUE));
+        let mixed = |pa, pb| color_mix(a, pa, b, pb, MixSpace::Srgb, HueInterpolation::Shorter);
+        assert_eq!(mixed(None, None), Some(Rgba(128, 0, 128, 255)));
+        assert_eq!(mixed(Some(25.0), None), Some(Rgba(64, 0, 191, 255)));
+        assert_eq!(mixed(None, Some(75.0)), mixed(Some(25.0), None));
+        assert_eq!(mixed(Some(50.0), Some(150.0)), None);
+        assert_eq!(mixed(Some(150.0), None), None);
+        assert_eq!(mixed(None, Some(-10.0)), None);
+        assert_eq!(mixed(Some(20.0), Some(30.0)), Some(Rgba(102, 0, 153, 128)));
+        assert_eq!(mixed(Some(0.0), Some(0.0)), None);
+        // the alpha is rounded once, after scaling: 127.5 · 0.84 = 107.1
+        let transparent = Rgba(0, 0, 255, 0);
+        assert_eq!(
+            color_mix(
+                a,
+                Some(42.0),
+                transparent,
+                Some(42.0),
+                MixSpace::Srgb,
+                HueInterpolation::Shorter
+            ),
+            Some(Rgba(255, 0, 0, 107))
+        );
+    }
+
+    #[test]
+    fn gradients() {
+        use gradient::{Easing, Gradient};
+        use mix::{mix, MixSpace};
+
+        assert_eq!(Gradient::new(&[]), None);
+        let single = Gradient::new(&[(0.3, clr::TEAL)]).unwrap();
+        assert!(single.take(3).all(|color| color == clr::TEAL));
+
+        let stops = [
+            (0.0, clr::NAVY),
+            (0.3, clr::TEAL),
+            (0.2, clr::GOLD),
+            (1.0, clr::CRIMSON),
+        ];
+        let gradient = Gradient::new(&stops).unwrap();
+        let positions: Vec<f32> = gradient.stops().iter().map(|stop| stop.0).collect();
+        assert_eq!(positions, vec![0.0, 0.3, 0.3, 1.0]);
+        // a hard transition at 0.3
+        assert_eq!(gradient.at(-1.0), clr::NAVY);
+        assert_eq!(
+            gradient.at(0.2999),
+            mix(clr::NAVY, clr::TEAL, 0.2999 / 0.3, MixSpace::Oklab)
+        );
+        assert_eq!(gradient.at(0.3), clr::GOLD);
+        assert_eq!(
+            gradient.at(0.65),
+            mix(clr::GOLD, clr::CRIMSON, 0.5, MixSpace::Oklab)
+        );
+        assert_eq!(gradient.at(1.0), clr::CRIMSON);
+        assert_eq!(gradient.at(f32::NAN), clr::NAVY);
+
+        let colors: Vec<Color> = Gradient::evenly_spaced(&[clr::BLACK, clr::WHITE])
+            .unwrap()
+            .with_space(MixSpace::Srgb)
+            .take(3)
+            .collect();
+        assert_eq!(colors, vec![clr::BLACK, Color(128, 128, 128), clr::WHITE]);
+        assert_eq!(gradient.take(0).count(), 0);
+        assert_eq!(gradient.take(1).collect::<Vec<_>>(), vec![clr::NAVY]);
+
+        // easings keep their ends and are monotonic for monotonic curves
+        let easings = [
+            Easing::Linear,
+            Easing::Smoothstep,
+            Easing::EASE,
+            Easing::EASE_IN,
+            Easing::EASE_OUT,
+            Easing::EASE_IN_OUT,
+            Easing::CubicBezier(0.0, 0.0, 1.0, 1.0),
+        ];
+        for easing in easings {
+            assert!(easing.apply(0.0).abs() < 1e-5, "{:?}", easing);
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-5, "{:?}", easing);
+            let mut previous = 0.0;
+            for i in 1..=100 {
+                let value = easing.apply(i as f32 / 100.0);
+                assert!(value >= previous - 1e-5, "{:?} at {}", easing, i);
+                previous = value;
+            }
+        }
+        assert!((Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).apply(0.3) - 0.3).abs() < 1e-4);
+        assert!(Easing::EASE_IN.apply(0.5) < 0.5 && Easing::EASE_OUT.apply(0.5) > 0.5);
+        assert!((Easing::EASE_IN_OUT.apply(0.5) - 0.5).abs() < 1e-5);
+
+        // a midpoint hint mixes half and half at its position
+        let bw = Gradient::evenly_spaced(&[clr::BLACK, clr::WHITE])
+            .unwrap()
+            .with_space(MixSpace::Srgb);
+        let half = bw.at(0.5);
+        assert_eq!(bw.clone().with_hint(0, 0.2).at(0.2), half);
+        assert_eq!(bw.clone().with_hint(0, 0.9).at(0.9), half);
+        assert_eq!(bw.clone().with_hint(0, 0.0).at(0.01), clr::WHITE);
+        assert_eq!(bw.clone().with_hint(5, 0.2), bw);
+    }
+
+    #[test]
+    fn adjustments() {
+        use adjust::sass;
+        use oklab::Oklch;
+
+        let hex = |s: &str| s.parse::<Color>().unwrap();
+        // the examples of the Sass documentation
+        let cases: [(Color, &str); 22] = [
+            (sass::lighten(hex("#6b717f"), 20.0), "#a1a5af"),
+            (sass::lighten(hex("#036"), 60.0), "#99ccff"),
+            (sass::lighten(hex("#e1d7d2"), 30.0), "#ffffff"),
+            (sass::darken(hex("#b37399"), 20.0), "#7c4465"),
+            (sass::darken(hex("#f2ece4"), 40.0), "#b08b5a"),
+            (sass::darken(hex("#036"), 30.0), "#000000"),
+            (sass::saturate(hex("#c69"), 20.0), "#e05299"),
+            (sass::saturate(hex("#0e4982"), 30.0), "#004990"),
+            (sass::desaturate(hex("#036"), 20.0), "#0a335c"),
+            (sass::desaturate(hex("#f2ece4"), 20.0), "#eeebe8"),
+            (sass::adjust_hue(hex("#6b717f"), 60.0), "#796b7f"),
+            (sass::adjust_hue(hex("#d2e1dd"), -60.0), "#d6e1d2"),
+            (sass::adjust_hue(hex("#036"), 45.0), "#1a0066"),
+            (sass::complement(hex("#6b717f")), "#7f796b"),
+            (sass::complement(hex("#d2e1dd")), "#e1d2d6"),
+            (sass::complement(hex("#036")), "#663300"),
+            (sass::invert(hex("#b37399"), 100.0), "#4c8c66"),
+            (sass::invert(hex("#550e0c"), 20.0), "#663b3a"),
+            (sass::mix(hex("#036"), hex("#d2e1dd"), 50.0), "#698aa2"),
+            (sass::mix(hex("#036"), hex("#d2e1dd"), 75.0), "#355f84"),
+            (sass::mix(hex("#036"), hex("#d2e1dd"), 25.0), "#9eb6bf"),
+            (sass::grayscale(hex("#6b717f")), "#757575"),
+        ];
+        for (i, &(color, expected)) in cases.iter().enumerate() {
+            assert_eq!(color, hex(expected), "case {}", i);
+        }
+
+        // the Oklch operations change only what they say
+        for named in &clr::named_colors()[..150] {
+            let color = named.color;
+            assert_eq!(color.lighten(0.0), color, "{}", named);
+            assert_eq!(color.rotate_hue(360.0), color, "{}", named);
+            assert_eq!(color.tint(0.0), color);
+            assert_eq!(color.shade(1.0), clr::BLACK);
+            assert_eq!(color.tint(1.0), clr::WHITE);
+            assert_eq!(color.invert().invert(), color);
+
+            let (lch, lighter) = (Oklch::from(color), Oklch::from(color.lighten(0.05)));
+            assert!(lighter.l >= lch.l - 1e-3, "{}", named);
+            // the gamut mapping of saturated colours may change the lightness a bit
+            if lch.l > 0.2 && lch.l < 0.9 && lch.c < 0.02 {
+                assert!((lighter.l - lch.l - 0.05).abs() < 0.01, "{}", named);
+            }
+            let darker = Oklch::from(color.darken(0.05));
+            assert!(darker.l <= lch.l + 1e-3, "{}", named);
+            let grey = Oklch::from(color.grayscale());
+            assert!(grey.c < 1e-3 && (grey.l - lch.l).abs() < 5e-3, "{}", named);
+            let toned = Oklch::from(color.tone(0.5));
+            assert!(
+                toned.c <= lch.c + 1e-3 && (toned.l - lch.l).abs() < 5e-3,
+                "{}",
+                named
+            );
+            assert!(Oklch::from(color.desaturate(0.02)).c <= lch.c + 1e-3);
+            if lch.c > 0.1 {
+                let complement = Oklch::from(color.complement());
+                let difference = (complement.h - lch.h).rem_euclid(360.0);
+                assert!(
+                    (difference - 180.0).abs() < 10.0,
+                    "{} {}",
+                    named,
+                    difference
+                );
+            }
+        }
+        assert_eq!(
+            clr::GREY.saturate(0.1),
+            clr::GREY.rotate_hue(10.0).saturate(0.1)
+        );
+    }
+
+    #[test]
+    fn harmonies() {
+        use clr::ColorSet;
+        use harmony::Scheme;
+        use oklab::Oklch;
+
+        let bases = [
+            clr::ROYALBLUE,
+            clr::GOLDENROD,
+            clr::CRIMSON,
+            clr::SEAGREEN,
+            clr::ORCHID,
+        ];
+        for scheme in Scheme::ALL {
+            for base in bases {
+                let colors = scheme.generate(base);
+                assert_eq!(colors.len(), scheme.hue_offsets().len());
+                assert_eq!(colors[0], base);
+                let lch = Oklch::from(base);
+                for (&color, &offset) in colors.iter().zip(scheme.hue_offsets()) {
+                    let other = Oklch::from(color);
+                    let hue = (other.h - lch.h - offset).rem_euclid(360.0);
+                    assert!(
+                        hue.min(360.0 - hue) < 10.0,
+                        "{:?} {:?} {}",
+                        scheme,
+                        base,
+                        hue
+                    );
+                    if scheme != Scheme::Monochromatic {
+                        assert!((other.l - lch.l).abs() < 0.05, "{:?} {:?}", scheme, base);
+                    }
+                }
+                for (i, a) in colors.iter().enumerate() {
+                    assert!(!colors[i + 1..].contains(a), "{:?} {:?}", scheme, base);
+                }
+
+                let named = scheme.generate_named(base, &[ColorSet::Css4]);
+                assert_eq!(named.len(), colors.len());
+                for (i, a) in named.iter().enumerate() {
+                    assert!(named[i + 1..].iter().all(|b| b.color != a.color));
+                }
+                let named = scheme.generate_named(base, &ColorSet::ALL);
+                assert_eq!(named.len(), colors.len(), "{:?} {:?}", scheme, base);
+                for (i, a) in named.iter().enumerate() {
+                    assert!(named[i + 1..].iter().all(|b| b.color != a.color));
+                }
+                assert!(scheme.generate_named(base, &[]).is_empty());
+            }
+        }
+        // white has three names across the sets, which must all be skipped
+        let named = Scheme::Monochromatic.generate_named(clr::WHITE, &ColorSet::ALL);
+        assert_eq!(named.len(), 5);
+        for (i, a) in named.iter().enumerate() {
+            assert!(named[i + 1..].iter().all(|b| b.color != a.color));
+        }
+        let mono = Scheme::Monochromatic.generate(clr::ROYALBLUE);
+        let lightness: Vec<f32> = mono.iter().map(|&color| Oklch::from(color).l).collect();
+        assert!(lightness[2] > lightness[1] && lightness[1] > lightness[0]);
+        assert!(lightness[0] > lightness[3] && lightness[3] > lightness[4]);
+        assert_eq!(
+            Scheme::Complementary.generate(clr::ROYALBLUE)[1],
+            clr::ROYALBLUE.complement()
+        );
+    }
+
+    #[test]
+    fn colour_temperature() {
+        use temperature::{
+            cct, daylight_chromaticity, kelvin_to_color, planckian_chromaticity, CctMethod, Locus,
+        };
+
+        // the CIE illuminants A, D50, D55, D65 and D75 (at today's value of c2)
+        let (x, y) = planckian_chromaticity(2856.0);
+        assert!(
+            (x - 0.44757).abs() < 1e-4 && (y - 0.40745).abs() < 1e-4,
+            "{} {}",
+            x,
+            y
+        );
+        let daylight = [
+            (5003.0, 0.34567, 0.35851),
+            (5503.0, 0.33243, 0.34744),
+            (6504.0, 0.31271, 0.32902),
+            (7504.0, 0.29902, 0.31485),
+        ];
+        for &(kelvin, cx, cy) in &daylight {
+            // the published values are computed from the spectra, not the locus formula
+            let (x, y) = daylight_chromaticity(kelvin);
+            assert!(
+                (x - cx).abs() < 2e-4 && (y - cy).abs() < 2e-4,
+                "{}: {} {}",
+                kelvin,
+                x,
+                y
+            );
+        }
+
+        // Robertson's table of the Planckian locus in the CIE 1960 UCS
+        let robertson = [
+            (10.0, 0.18066, 0.26589),
+            (50.0, 0.18388, 0.27709),
+            (100.0, 0.19032, 0.29326),
+            (200.0, 0.21142, 0.32312),
+            (300.0, 0.24010, 0.34308),
+            (400.0, 0.27218, 0.35407),
+            (500.0, 0.30505, 0.35907),
+            (600.0, 0.33724, 0.36051),
+        ];
+        for &(mired, cu, cv) in &robertson {
+            let (x, y) = planckian_chromaticity(1e6 / mired);
+            let denominator = -2.0 * x + 12.0 * y + 3.0;
+            let (u, v) = (4.0 * x / denominator, 6.0 * y / denominator);
+            assert!(
+                (u - cu).abs() < 2e-4 && (v - cv).abs() < 2e-4,
+                "{}: {} {}",
+                mired,
+                u,
+                v
+            );
+
+            // points on the locus have their own temperature and no Duv
+            let estimate = cct(x, y, CctMethod::Robertson).unwrap();
+            let error = (1e6 / estimate.kelvin - mired).abs();
+            assert!(error < 0.5, "{}: {:?}", mired, estimate);
+            assert!(estimate.duv.abs() < 1e-4, "{}: {:?}", mired, estimate);
+        }
+        for &(kelvin, x, y) in &daylight {
+            for method in [CctMethod::McCamy, CctMethod::Robertson] {
+                let estimate = cct(x, y, method).unwrap();
+                assert!((estimate.kelvin - kelvin).abs() < 10.0, "{:?}", estimate);
+                assert!(estimate.duv > 0.0 && estimate.duv < 0.006, "{:?}", estimate);
+            }
+        }
+        let a = cct(0.44757, 0.40745, CctMethod::McCamy).unwrap();
+        assert!((a.kelvin - 2856.0).abs() < 5.0, "{:?}", a);
+        assert_eq!(cct(0.6, 0.3, CctMethod::Robertson), None);
+
+        // warmer is redder, colder is bluer
+        let mut previous = kelvin_to_color(temperature::MIN_KELVIN, Locus::Planckian);
+        assert_eq!(previous, kelvin_to_color(500.0, Locus::Planckian));
+        for kelvin in (1500..=40000).step_by(500) {
+            let color = kelvin_to_color(kelvin as f32, Locus::Planckian);
+            assert!(
+                color.2 >= previous.2 && color.0 <= previous.0,
+                "{}: {}",
+                kelvin,
+                color
+            );
+            previous = color;
+            // the colours are rounded and, for low temperatures, clipped
+            if (2500..12000).contains(&kelvin) {
+                let round_trip = color.cct(CctMethod::Robertson).unwrap();
+                let error = (1e6 / round_trip.kelvin - 1e6 / kelvin as f32).abs();
+                assert!(error < 5.0, "{}: {:?}", kelvin, round_trip);
+            }
+        }
+    }
+
+    #[test]
+    fn spectra() {
+        use spectrum::{wavelength_to_color, Illuminant, Observer, Spectrum};
+
+        // the white points of the illuminants as published by the CIE
+        let white_points = [
+            (Illuminant::A, Observer::Cie1931, [1.09850, 0.35585]),
+            (Illuminant::D50, Observer::Cie1931, [0.96422, 0.82521]),
+            (Illuminant::D65, Observer::Cie1931, [0.95047, 1.08883]),
+            (Illuminant::F2, Observer::Cie1931, [0.99186, 0.67393]),
+            (Illuminant::F7, Observer::Cie1931, [0.95041, 1.08747]),
+            (Illuminant::F11, Observer::Cie1931, [1.00962, 0.64350]),
+            (Illuminant::A, Observer::Cie1964, [1.11144, 0.35200]),
+            (Illuminant::D50, Observer::Cie1964, [0.96720, 0.81427]),
+            (Illuminant::D65, Observer::Cie1964, [0.94811, 1.07304]),
+            (Illuminant::E, Observer::Cie1931, [1.0, 1.0]),
+            (Illuminant::E, Observer::Cie1964, [1.0, 1.0]),
+        ];
+        for &(illuminant, observer, [x, z]) in &white_points {
+            let white = illuminant.white_point(observer);
+            let error = (white.x - x).abs().max((white.z - z).abs());
+            assert!(error < 1e-3, "{:?} {:?}: {}", illuminant, observer, white);
+        }
+        let a = Illuminant::A.white_point(Observer::Cie1931);
+        let blackbody = Illuminant::Blackbody(2856.0).white_point(Observer::Cie1931);
+        assert!((a.x - blackbody.x).abs() < 1e-3 && (a.z - blackbody.z).abs() < 1e-3);
+        let (x, y) = Illuminant::Blackbody(4000.0)
+            .spectrum()
+            .to_xyz(Observer::Cie1931)
+            .chromaticity();
+        let (px, py) = temperature::planckian_chromaticity(4000.0);
+        assert!((x - px).abs() < 1e-5 && (y - py).abs() < 1e-5);
+        assert_eq!(Illuminant::D65.spectrum().at(560.0), 100.0);
+        assert_eq!(Illuminant::A.spectrum().at(560.0), 100.0);
+
+        // reflectances
+        for illuminant in [
+            Illuminant::A,
+            Illuminant::D50,
+            Illuminant::D65,
+            Illuminant::F2,
+            Illuminant::F11,
+        ] {
+            for observer in [Observer::Cie1931, Observer::Cie1964] {
+                let white = Spectrum::constant(1.0).reflectance_to_xyz(illuminant, observer);
+                assert!((white.y - 1.0).abs() < 1e-5);
+                let color = Spectrum::constant(1.0).reflectance_to_color(illuminant, observer);
+                assert_eq!(color, clr::WHITE, "{:?} {:?}", illuminant, observer);
+                let grey = Spectrum::constant(0.2).reflectance_to_color(illuminant, observer);
+                assert!(
+                    grey.0.abs_diff(grey.2) <= 1 && grey.0.abs_diff(grey.1) <= 1,
+                    "{}",
+                    grey
+                );
+            }
+        }
+        assert_eq!(
+            Spectrum::constant(0.0).reflectance_to_color(Illuminant::D65, Observer::Cie1931),
+            clr::BLACK
+        );
+
+        // spectrum arithmetic and resampling
+        let ramp = Spectrum::from_fn(|nm| (nm - 380.0) / 400.0);
+        let samples: Vec<(f32, f32)> = (0..=40)
+            .map(|i| (380.0 + 10.0 * i as f32, i as f32 / 40.0))
+            .collect();
+        let resampled = Spectrum::from_samples(&samples).unwrap();
+        for (a, b) in ramp.values().iter().zip(resampled.values()) {
+            assert!((a - b).abs() < 1e-6);
+        }
+        assert_eq!(Spectrum::from_samples(&[]), None);
+        assert_eq!(ramp * Spectrum::constant(2.0), ramp + ramp);
+        assert_eq!(ramp * 0.5 + ramp * 0.5, ramp);
+        assert_eq!(ramp.at(382.5), 0.00625);
+        assert_eq!(ramp.at(800.0), 0.0);
+
+        // the rainbow goes from violet through blue, green and yellow to red
+        let hue = |nm: f32| oklab::Oklch::from(wavelength_to_color(nm)).h;
+        let blue = wavelength_to_color(460.0);
+        assert!(blue.2 == 255 && blue.0 < 60, "{}", blue);
+        let green = wavelength_to_color(530.0);
+        assert!(
+            green.1 == 255 && green.0 < 100 && green.2 < 100,
+            "{}",
+            green
+        );
+        let yellow = wavelength_to_color(575.0);
+        assert!(
+            yellow.0 > 200 && yellow.1 > 200 && yellow.2 < 50,
+            "{}",
+            yellow
+        );
+        let red = wavelength_to_color(650.0);
+        assert!(red.0 == 255 && red.1 < 30 && red.2 < 30, "{}", red);
+        assert!(hue(480.0) < hue(460.0) && hue(530.0) < hue(480.0) && hue(600.0) < hue(530.0));
+        assert_eq!(wavelength_to_color(300.0), clr::BLACK);
+    }
+
+    #[test]
+    fn pigment_mixing() {
+        use clr::{Color, BLACK, BLUE, RED, WHITE, YELLOW};
+        use distance::{ColorDistance, DeltaE};
+        use oklab::Oklch;
+        use spectrum::{Illuminant, Observer};
+
+        // painter's mixes: yellow and blue give green, not grey
+        let green = pigment::mix(YELLOW, BLUE, 0.5);
+        assert!(green.1 > green.0 && green.1 > green.2, "{}", green);
+        let hue = Oklch::from(green).h;
+        assert!(hue > 120.0 && hue < 170.0, "{}", hue);
+        let orange = pigment::mix(RED, YELLOW, 0.5);
+        assert!(
+            orange.0 == 255 && orange.1 > 40 && orange.2 < 40,
+            "{}",
+            orange
+        );
+        let pink = pigment::mix(RED, WHITE, 0.5);
+        assert!(pink.0 == 255 && pink.1 > 40 && pink.2 > 40, "{}", pink);
+        let grey = pigment::mix(BLACK, WHITE, 0.5);
+        assert!(grey.0 == grey.1 && grey.1 == grey.2 && grey.0 > 100 && grey.0 < 200);
+
+        // unmixed paints keep their colour
+        for &color in &[
+            RED,
+            BLUE,
+            YELLOW,
+            WHITE,
+            BLACK,
+            Color(12, 200, 99),
+            clr::GOLDENROD,
+        ] {
+            assert_eq!(pigment::mix(color, color, 0.3), color);
+            assert_eq!(pigment::mix(color, BLUE, 0.0), color);
+            assert_eq!(pigment::mix_all(&[(color, 3.0)]), Some(color));
+        }
+        assert_eq!(pigment::mix(RED, BLUE, 1.0), BLUE);
+        assert_eq!(pigment::mix_all(&[]), None);
+        assert_eq!(pigment::mix_all(&[(RED, -1.0), (BLUE, 0.0)]), None);
+        assert_eq!(
+            pigment::mix_all(&[(RED, 1.0), (BLUE, 1.0)]),
+            Some(pigment::mix(RED, BLUE, 0.5))
+        );
+
+        // reflectances are between 0 and 1 and have the colour under D65
+        for &color in &[RED, clr::GOLDENROD, clr::TEAL, Color(200, 180, 190)] {
+            let curve = pigment::reflectance(color);
+            assert!(curve.values().iter().all(|&r| r > 0.0 && r < 1.0));
+            let back = curve.reflectance_to_color(Illuminant::D65, Observer::Cie1931);
+            assert!(
+                back.delta_e(&color, DeltaE::Ciede2000) < 1.0,
+                "{} {}",
+                back,
+                color
+            );
+        }
+    }
+
+    #[test]
+    fn cmyk_conversion() {
+        use clr::{Color, BLACK, RED, WHITE};
+        use cmyk::{Cmyk, Separation};
+        use parse::ParseColorErrorKind;
+        use rgba::Rgba;
+
+        assert_eq!(Cmyk::from(RED), Cmyk::new(0.0, 1.0, 1.0, 0.0));
+        assert_eq!(Cmyk::from(BLACK), Cmyk::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(Cmyk::from(WHITE), Cmyk::new(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(Separation::default(), Separation::NAIVE);
+
+        // black generation does not change the colour, the ink limit does
+        let separations = [
+            Separation::NAIVE,
+            Separation::NAIVE.with_black_generation(0.0),
+            Separation::NAIVE.with_black_generation(0.6),
+            Separation::NAIVE.with_black_start(0.4),
+        ];
+        let limited = Separation::NAIVE
+            .with_black_generation(0.3)
+            .with_ink_limit(2.8);
+        for named in clr::named_colors() {
+            for separation in &separations {
+                let cmyk = separation.separate(named.color);
+                assert_eq!(Color::from(cmyk), named.color, "{} {:?}", named.name, cmyk);
+            }
+            let cmyk = limited.separate(named.color);
+            assert!(cmyk.total_ink() <= 2.8 + 1e-5, "{} {:?}", named.name, cmyk);
+            for &ink in &[cmyk.c, cmyk.m, cmyk.y, cmyk.k] {
+                assert!((0.0..=1.0).contains(&ink));
+            }
+        }
+
+        // rich black without black generation, limited to 240%
+        let rich = Separation::NAIVE.with_black_generation(0.0);
+        assert_eq!(rich.separate(BLACK), Cmyk::new(1.0, 1.0, 1.0, 0.0));
+        let cmyk = rich.with_ink_limit(2.4).separate(BLACK);
+        assert_eq!(cmyk, Cmyk::new(0.8, 0.8, 0.8, 0.0));
+        assert_eq!(
+            Separation::NAIVE.with_ink_limit(0.5).separate(BLACK),
+            Cmyk::new(0.0, 0.0, 0.0, 0.5)
+        );
+        let ucr = Separation::NAIVE.with_black_start(0.5);
+        assert_eq!(ucr.separate(Color(153, 153, 153)).k, 0.0);
+        assert_eq!(ucr.separate(BLACK), Cmyk::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(
+            Separation::NAIVE.with_ink_limit(9.0).ink_limit(),
+            Separation::NAIVE.ink_limit()
+        );
+
+        // device-cmyk()
+        let cmyk: Cmyk = "device-cmyk(0 81% 81% 30%)".parse().unwrap();
+        assert_eq!(cmyk, Cmyk::new(0.0, 0.81, 0.81, 0.3));
+        assert_eq!("device-cmyk(0, 0.81, 0.81, 0.3)".parse(), Ok(cmyk));
+        assert_eq!("DEVICE-CMYK(none 0.81 0.81 0.3 / 1)".parse(), Ok(cmyk));
+        assert_eq!(cmyk.to_css(), "device-cmyk(0 0.81 0.81 0.3)");
+        assert_eq!(cmyk.to_css().parse(), Ok(cmyk));
+        assert_eq!(
+            "device-cmyk(150% -1 0 0)".parse(),
+            Ok(Cmyk::new(1.0, 0.0, 0.0, 0.0))
+        );
+        assert_eq!("#FF0000".parse(), Ok(Cmyk::from(RED)));
+        assert_eq!("device-cmyk(0 1 1 0)".parse(), Ok(RED));
+        assert_eq!(
+            "device-cmyk(0 1 1 0 / 50%)".parse(),
+            Ok(Rgba(255, 0, 0, 128))
+        );
+
+        let error = "device-cmyk(0 0 0)".parse::<Cmyk>().unwrap_err();
+        assert_eq!(error.kind(), &ParseColorErrorKind::ExpectedNumber);
+        assert_eq!(error.position(), 17);
+        let error = "device-cmyk(0, 0 0 0)".parse::<Color>().unwrap_err();
+        assert_eq!(error.kind(), &ParseColorErrorKind::Expected(','));
+        let error = "device-cmyk(0, 0, 0, 0 / 1)".parse::<Cmyk>().unwrap_err();
+        assert_eq!(error.kind(), &ParseColorErrorKind::Expected(')'));
+        let error = "device-cmyk(0 0 0 0) x".parse::<Cmyk>().unwrap_err();
+        assert_eq!(error.kind(), &ParseColorErrorKind::UnexpectedCharacter('x'));
+    }
 }
@@ -0,0 +1,249 @@
+//! Mixing and interpolating colours in a chosen colour space, following the semantics of the CSS
+//! [`color-mix()`](https://www.w3.org/TR/css-color-5/#color-mix) function.
+//!
+//! Interpolating the bytes of two `Color`s directly mixes gamma encoded values, which makes the
+//! middle of most mixes too dark. Linear light RGB gives physically correct mixes of light, the
+//! perceptual spaces Lab, LCh, Oklab and Oklch give evenly looking transitions. In the cylindrical
+//! spaces LCh and Oklch the hue is interpolated with a `HueInterpolation` method; the hue of a grey
+//! is ignored in favour of the hue of the other colour, as CSS treats it as missing.
+//!
+//! Colours with alpha are mixed with premultiplied alpha, so that a transparent colour does not
+//! contribute its (invisible) colour to the mix. Results outside the sRGB gamut are mapped into it
+//! with the CSS gamut mapping. As in CSS, Lab and LCh are relative to D50. To mix colours like
+//! paints instead of light, see `pigment`.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, BLUE, WHITE};
+//! use colors::hue::HueInterpolation;
+//! use colors::mix::{color_mix, mix, MixSpace};
+//! use colors::rgba::Rgba;
+//!
+//! assert_eq!(mix(BLUE, WHITE, 0.5, MixSpace::Srgb), Color(128, 128, 255));
+//! assert_eq!(mix(BLUE, WHITE, 0.5, MixSpace::LinearRgb), Color(188, 188, 255));
+//! assert_eq!(mix(BLUE, WHITE, 0.5, MixSpace::Oklab), Color(116, 163, 255));
+//!
+//! // color-mix(in oklch, blue 40%, transparent)
+//! let mixed = color_mix(
+//!     Rgba::from(BLUE),
+//!     Some(40.0),
+//!     Rgba(0, 0, 0, 0),
+//!     None,
+//!     MixSpace::Oklch,
+//!     HueInterpolation::Shorter,
+//! );
+//! assert_eq!(mixed, Some(Rgba(0, 0, 255, 102)));
+//! ```
+use crate::cie::{Adaptation, Lab, Lch, WhitePoint, Xyz};
+use crate::clr::Color;
+use crate::gamut::GamutMapping;
+use crate::hue::{interpolate_hue, HueInterpolation};
+use crate::oklab::{Oklab, Oklch};
+use crate::rgb::{color_to_linear, linear_to_color};
+use crate::rgba::Rgba;
+
+/// The colour space to mix in.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MixSpace {
+    /// Gamma encoded sRGB, i.e. the plain components of `Color`
+    Srgb,
+    /// Linear light sRGB
+    LinearRgb,
+    /// CIE L\*a\*b\* relative to D50
+    Lab,
+    /// CIE LCh(ab) relative to D50
+    Lch,
+    /// Oklab, the default of CSS
+    #[default]
+    Oklab,
+    /// Oklch
+    Oklch,
+}
+
+impl MixSpace {
+    /// The chroma below which a colour counts as grey and its hue is ignored, `None` for the
+    /// spaces without a hue.
+    fn achromatic_chroma(self) -> Option<f32> {
+        match self {
+            MixSpace::Lch => Some(1e-2),
+            MixSpace::Oklch => Some(1e-4),
+            _ => None,
+        }
+    }
+
+    /// The components of a colour in this space; for LCh and Oklch the hue comes last.
+    fn components(self, color: Color) -> [f32; 3] {
+        match self {
+            MixSpace::Srgb => [color.0, color.1, color.2].map(|c| f32::from(c) / 255.0),
+            MixSpace::LinearRgb => color_to_linear(color),
+            MixSpace::Lab => {
+                let lab = Lab::from_color(color, WhitePoint::D50, Adaptation::Bradford);
+                [lab.l, lab.a, lab.b]
+            }
+            MixSpace::Lch => {
+                let lch = Lch::from(Lab::from_color(
+                    color,
+                    WhitePoint::D50,
+                    Adaptation::Bradford,
+                ));
+                [lch.l, lch.c, lch.h]
+            }
+            MixSpace::Oklab => {
+                let lab = Oklab::from(color);
+                [lab.l, lab.a, lab.b]
+            }
+            MixSpace::Oklch => {
+                let lch = Oklch::from(color);
+                [lch.l, lch.c, lch.h]
+            }
+        }
+    }
+
+    /// Converts components of this space back to a `Color`, mapping them into the sRGB gamut.
+    fn to_color(self, [c0, c1, c2]: [f32; 3]) -> Color {
+        let from_d50 = |lab: Lab| {
+            lab.to_xyz(WhitePoint::D50).adapt(
+                WhitePoint::D50,
+                WhitePoint::D65,
+                Adaptation::Bradford,
+            )
+        };
+        let xyz = match self {
+            MixSpace::Srgb => {
+                let byte = |c: f32| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+                return Color(byte(c0), byte(c1), byte(c2));
+            }
+            MixSpace::LinearRgb => return linear_to_color([c0, c1, c2]),
+            MixSpace::Lab => from_d50(Lab::new(c0, c1, c2)),
+            MixSpace::Lch => from_d50(Lab::from(Lch::new(c0, c1, c2))),
+            MixSpace::Oklab => Xyz::from(Oklab::new(c0, c1, c2)),
+            MixSpace::Oklch => Xyz::from(Oklch::new(c0, c1, c2)),
+        };
+        GamutMapping::Css.map(xyz)
+    }
+}
+
+/// Interpolates the components of two colours at `t`, with the alpha values `alpha_a` and `alpha_b`
+/// in `[0, 1]`. Returns the unpremultiplied components and the interpolated alpha.
+fn interpolate_components(
+    space: MixSpace,
+    a: [f32; 3],
+    alpha_a: f32,
+    b: [f32; 3],
+    alpha_b: f32,
+    t: f32,
+    hue: HueInterpolation,
+) -> ([f32; 3], f32) {
+    let (mut a, mut b) = (a, b);
+    let hue_index = space.achromatic_chroma().map(|achromatic| {
+        // a grey has no hue, it takes the hue of the other colour
+        if a[1] < achromatic {
+            a[2] = b[2];
+        } else if b[1] < achromatic {
+            b[2] = a[2];
+        }
+        2
+    });
+    let alpha = alpha_a + (alpha_b - alpha_a) * t;
+    let mut mixed = [0.0; 3];
+    for i in 0..3 {
+        mixed[i] = if hue_index == Some(i) {
+            interpolate_hue(a[i], b[i], t, hue)
+        } else {
+            let (pa, pb) = (a[i] * alpha_a, b[i] * alpha_b);
+            let premultiplied = pa + (pb - pa) * t;
+            if alpha > 0.0 {
+                premultiplied / alpha
+            } else {
+                0.0
+            }
+        };
+    }
+    (mixed, alpha)
+}
+
+/// Mixes two opaque colours in `space`, giving `a` at `t = 0` and `b` at `t = 1`. Hues are
+/// interpolated the shorter way.
+pub fn mix(a: Color, b: Color, t: f32, space: MixSpace) -> Color {
+    mix_with_hue(a, b, t, space, HueInterpolation::Shorter)
+}
+
+/// Mixes two opaque colours in `space` with the given hue interpolation method.
+pub fn mix_with_hue(a: Color, b: Color, t: f32, space: MixSpace, hue: HueInterpolation) -> Color {
+    let (a, b) = (space.components(a), space.components(b));
+    let (mixed, _) = interpolate_components(space, a, 1.0, b, 1.0, t, hue);
+    space.to_color(mixed)
+}
+
+/// Interpolates two colours with alpha in `space`, using premultiplied alpha, with the alpha of
+/// the result in `[0, 1]`.
+fn interpolate_alpha(
+    a: Rgba,
+    b: Rgba,
+    t: f32,
+    space: MixSpace,
+    hue: HueInterpolation,
+) -> (Color, f32) {
+    let (alpha_a, alpha_b) = (f32::from(a.3) / 255.0, f32::from(b.3) / 255.0);
+    let (mixed, alpha) = interpolate_components(
+        space,
+        space.components(a.color()),
+        alpha_a,
+        space.components(b.color()),
+        alpha_b,
+        t,
+        hue,
+    );
+    if alpha <= 0.0 {
+        return (Color(0, 0, 0), 0.0);
+    }
+    (space.to_color(mixed), alpha)
+}
+
+/// Converts a colour and an alpha in `[0, 1]` to `Rgba`.
+fn to_rgba(color: Color, alpha: f32) -> Rgba {
+    Rgba::new(color, (alpha.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// Interpolates two colours with alpha in `space`, using premultiplied alpha.
+pub fn interpolate(a: Rgba, b: Rgba, t: f32, space: MixSpace, hue: HueInterpolation) -> Rgba {
+    let (color, alpha) = interpolate_alpha(a, b, t, space, hue);
+    to_rgba(color, alpha)
+}
+
+/// Mixes two colours like the CSS `color-mix()` function, with the optional percentages `pa` and
+/// `pb` in `[0, 100]`. A missing percentage is the complement of the other one, both missing means
+/// 50% each. Percentages that do not add up to 100 are normalised, and if their sum is below 100
+/// the alpha of the result is scaled by it. Returns `None` if a percentage lies outside
+/// `[0, 100]` or both are zero, which makes the mix invalid in CSS.
+pub fn color_mix(
+    a: Rgba,
+    pa: Option<f32>,
+    b: Rgba,
+    pb: Option<f32>,
+    space: MixSpace,
+    hue: HueInterpolation,
+) -> Option<Rgba> {
+    if [pa, pb]
+        .iter()
+        .flatten()
+        .any(|p| !(0.0..=100.0).contains(p))
+    {
+        return None;
+    }
+    let (pa, pb) = match (pa, pb) {
+        (None, None) => (50.0, 50.0),
+        (Some(pa), None) => (pa, 100.0 - pa),
+        (None, Some(pb)) => (100.0 - pb, pb),
+        (Some(pa), Some(pb)) => (pa, pb),
+    };
+    let sum = pa + pb;
+    if sum <= 0.0 {
+        return None;
+    }
+    let (color, mut alpha) = interpolate_alpha(a, b, pb / sum, space, hue);
+    if sum < 100.0 {
+        alpha *= sum / 100.0;
+    }
+    Some(to_rgba(color, alpha))
+}
@@ -0,0 +1,31 @@
+// Shared by `build.rs`, which generates the lookup keys, and `clr.rs`, which normalises names at
+// runtime: both must agree on every key, so there is only this one definition.
+
+/// Normalises a colour name for lookup, mirroring how the constant names are built: lowercase,
+/// spaces, dashes, apostrophes and colons turned into single underscores and `/` spelled out as
+/// `slash`. XKCD distinguishes names like `"blue green"` and `"bluegreen"`, so the separators are only
+/// dropped for the other sets.
+fn normalize_name(name: &str) -> String {
+    let mut key = String::with_capacity(name.len());
+    let mut separator = false;
+    for c in name.chars() {
+        match c {
+            ' ' | '_' | '-' | '\'' | ':' => separator = true,
+            '/' => {
+                key.push_str("_slash");
+                separator = true;
+            }
+            c => {
+                if separator && !key.is_empty() {
+                    key.push('_');
+                }
+                separator = false;
+                key.extend(c.to_lowercase());
+            }
+        }
+    }
+    if !key.starts_with("xkcd_") {
+        key.retain(|c| c != '_');
+    }
+    key
+}
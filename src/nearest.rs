@@ -0,0 +1,130 @@
+//! Reverse lookup: finds the named colours closest to an arbitrary `Color`.
+//!
+//! Colours are compared by their distance in the perceptual [Oklab](crate::oklab) space (ΔEOK). Each
+//! colour set is indexed by a k-d tree that is built on first use, so queries stay cheap enough to
+//! be run per pixel.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, ColorSet};
+//! use colors::nearest::{nearest, nearest_k};
+//!
+//! let named = nearest(Color(100, 149, 230), &[ColorSet::Css4]).unwrap();
+//! assert_eq!(named.name, "cornflowerblue");
+//!
+//! let matches = nearest_k(Color(100, 149, 230), 3, &ColorSet::ALL);
+//! assert_eq!(matches.len(), 3);
+//! assert!(matches[0].1 <= matches[1].1 && matches[1].1 <= matches[2].1);
+//! ```
+use std::sync::OnceLock;
+
+use crate::clr::{Color, ColorSet, NamedColor};
+use crate::oklab::Oklab;
+
+/// Returns the named colour closest to `color` from the given sets, or `None` if `sets` is empty.
+pub fn nearest(color: Color, sets: &[ColorSet]) -> Option<NamedColor> {
+    nearest_k(color, 1, sets).pop().map(|(named, _)| named)
+}
+
+/// Returns the `k` named colours closest to `color` from the given sets together with their
+/// distance (ΔEOK), closest first.
+pub fn nearest_k(color: Color, k: usize, sets: &[ColorSet]) -> Vec<(NamedColor, f32)> {
+    let target = point(Oklab::from(color));
+    let trees = trees();
+    let mut best = Vec::with_capacity(k + 1);
+    if k > 0 {
+        for &set in sets {
+            trees[set as usize].search(&target, k, &mut best);
+        }
+    }
+    best.into_iter()
+        .map(|(distance, named)| (*named, distance.sqrt()))
+        .collect()
+}
+
+fn point(lab: Oklab) -> [f32; 3] {
+    [lab.l, lab.a, lab.b]
+}
+
+/// The k-d trees of all colour sets, indexed by `ColorSet`.
+fn trees() -> &'static [KdTree; 4] {
+    static TREES: OnceLock<[KdTree; 4]> = OnceLock::new();
+    TREES.get_or_init(|| ColorSet::ALL.map(|set| KdTree::new(set.as_slice())))
+}
+
+struct Node {
+    point: [f32; 3],
+    named: &'static NamedColor,
+}
+
+/// A balanced k-d tree stored implicitly in a slice: the node of each subtree sits at the middle of
+/// its slice, with the left and right subtrees to either side. The splitting axis cycles through
+/// `l`, `a` and `b` with the depth.
+struct KdTree {
+    nodes: Vec<Node>,
+}
+
+impl KdTree {
+    fn new(colors: &'static [NamedColor]) -> Self {
+        let mut nodes: Vec<Node> = colors
+            .iter()
+            .map(|named| Node {
+                point: point(Oklab::from(named.color)),
+                named,
+            })
+            .collect();
+        build(&mut nodes, 0);
+        KdTree { nodes }
+    }
+
+    /// Merges the `k` nearest neighbours of `target` into `best`, which holds
+    /// `(squared distance, colour)` pairs sorted by distance.
+    fn search(&self, target: &[f32; 3], k: usize, best: &mut Vec<(f32, &'static NamedColor)>) {
+        search(&self.nodes, 0, target, k, best);
+    }
+}
+
+fn build(nodes: &mut [Node], depth: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+    let axis = depth % 3;
+    let mid = nodes.len() / 2;
+    nodes.select_nth_unstable_by(mid, |a, b| a.point[axis].total_cmp(&b.point[axis]));
+    let (left, right) = nodes.split_at_mut(mid);
+    build(left, depth + 1);
+    build(&mut right[1..], depth + 1);
+}
+
+fn search(
+    nodes: &[Node],
+    depth: usize,
+    target: &[f32; 3],
+    k: usize,
+    best: &mut Vec<(f32, &'static NamedColor)>,
+) {
+    if nodes.is_empty() {
+        return;
+    }
+    let mid = nodes.len() / 2;
+    let node = &nodes[mid];
+
+    let distance: f32 = (0..3).map(|i| (node.point[i] - target[i]).powi(2)).sum();
+    if best.len() < k || distance < best[best.len() - 1].0 {
+        let position = best.partition_point(|&(d, _)| d <= distance);
+        best.insert(position, (distance, node.named));
+        best.truncate(k);
+    }
+
+    let axis = depth % 3;
+    let diff = target[axis] - node.point[axis];
+    let (near, far) = if diff < 0.0 {
+        (&nodes[..mid], &nodes[mid + 1..])
+    } else {
+        (&nodes[mid + 1..], &nodes[..mid])
+    };
+    search(near, depth + 1, target, k, best);
+    if best.len() < k || diff * diff < best[best.len() - 1].0 {
+        search(far, depth + 1, target, k, best);
+    }
+}
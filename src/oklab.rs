@@ -0,0 +1,250 @@
+//! The [Oklab](https://bottosson.github.io/posts/oklab/) perceptual colour space.
+//!
+//! Euclidean distances in Oklab approximate perceived colour differences, which makes it a good fit
+//! for comparing and searching colours. `Oklch` is its cylindrical form (lightness, chroma, hue),
+//! suited for gradients and palettes.
+//!
+//! Not every Oklab colour can be shown in sRGB. Converting to `Color` with `From` clips the linear
+//! RGB components; use `in_gamut` or `try_to_color` to detect such colours and
+//! `gamut::GamutMapping` to bring them into the gamut.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, GOLDENROD, ROYALBLUE};
+//! use colors::hue::HueInterpolation;
+//! use colors::oklab::Oklch;
+//!
+//! let from = Oklch::from(GOLDENROD);
+//! let to = Oklch::from(ROYALBLUE);
+//! let middle = from.interpolate(&to, 0.5, HueInterpolation::Shorter);
+//! assert!(middle.in_gamut());
+//! assert_eq!(Color::from(from.interpolate(&to, 1.0, HueInterpolation::Longer)), ROYALBLUE);
+//!
+//! let vivid = Oklch::new(0.7, 0.4, 150.0);
+//! assert!(!vivid.in_gamut());
+//! assert_eq!(vivid.try_to_color(), None);
+//! ```
+use crate::cie::{from_polar, to_polar, Xyz};
+use crate::clr::Color;
+use crate::hue::{interpolate_hue, HueInterpolation};
+use crate::rgb::{color_to_linear, linear_to_color, Float, LinearRgb};
+
+/// How far linear RGB components may lie outside `[0, 1]` and still count as in gamut, to allow for
+/// rounding errors.
+pub(crate) const GAMUT_TOLERANCE: f32 = 1e-4;
+
+/// Whether linear RGB components lie within `[0, 1]`, i.e. the gamut of their RGB space, which is
+/// sRGB unless stated otherwise.
+pub(crate) fn linear_in_gamut(rgb: [f32; 3]) -> bool {
+    rgb.iter()
+        .all(|&c| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(&c))
+}
+
+/// A colour in the Oklab colour space. `l` is the perceived lightness in `[0, 1]`, `a` and `b` are the
+/// green/red and blue/yellow opponent axes.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Oklab {
+    /// Creates a new Oklab colour.
+    pub fn new(l: f32, a: f32, b: f32) -> Self {
+        Oklab { l, a, b }
+    }
+
+    /// Converts linear light sRGB components to Oklab.
+    pub(crate) fn from_linear_srgb([r, g, b]: [f32; 3]) -> Self {
+        let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+        Oklab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+
+    /// Converts Oklab to linear light sRGB components, which may lie outside `[0, 1]`.
+    pub(crate) fn to_linear_srgb(self) -> [f32; 3] {
+        let l = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+
+    /// Whether the colour lies within the sRGB gamut.
+    pub fn in_gamut(&self) -> bool {
+        linear_in_gamut(self.to_linear_srgb())
+    }
+
+    /// Converts to `Color` if the colour lies within the sRGB gamut, `None` otherwise.
+    pub fn try_to_color(&self) -> Option<Color> {
+        let rgb = self.to_linear_srgb();
+        if linear_in_gamut(rgb) {
+            Some(linear_to_color(rgb))
+        } else {
+            None
+        }
+    }
+
+    /// Linear interpolation towards `other` at `t` in `[0, 1]`.
+    pub fn interpolate(&self, other: &Oklab, t: f32) -> Oklab {
+        Oklab {
+            l: self.l + (other.l - self.l) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    /// The Euclidean distance to `other` (ΔEOK).
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::{Color, BLACK, WHITE};
+    /// # use colors::oklab::Oklab;
+    /// # fn main() {
+    /// let d = Oklab::from(BLACK).distance(&Oklab::from(WHITE));
+    /// assert!((d - 1.0).abs() < 1e-3);
+    /// # }
+    /// ```
+    pub fn distance(&self, other: &Oklab) -> f32 {
+        let (dl, da, db) = (self.l - other.l, self.a - other.a, self.b - other.b);
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        Oklab::from_linear_srgb(color_to_linear(color))
+    }
+}
+
+impl From<Oklab> for Color {
+    /// Converts to sRGB, clipping colours outside the gamut.
+    fn from(lab: Oklab) -> Self {
+        linear_to_color(lab.to_linear_srgb())
+    }
+}
+
+impl From<Oklab> for Xyz {
+    fn from(lab: Oklab) -> Self {
+        Xyz::from_linear_srgb(lab.to_linear_srgb())
+    }
+}
+
+impl From<Xyz> for Oklab {
+    fn from(xyz: Xyz) -> Self {
+        Oklab::from_linear_srgb(xyz.to_linear_srgb())
+    }
+}
+
+impl<T: Float> From<LinearRgb<T>> for Oklab {
+    fn from(linear: LinearRgb<T>) -> Self {
+        Oklab::from_linear_srgb(linear.cast::<f32>().to_array())
+    }
+}
+
+impl<T: Float> From<Oklab> for LinearRgb<T> {
+    /// Converts to linear light sRGB without clipping.
+    fn from(lab: Oklab) -> Self {
+        LinearRgb::from_array(lab.to_linear_srgb()).cast()
+    }
+}
+
+impl_fmt!(Oklab { l, a, b });
+
+/// A colour in the Oklch colour space, the cylindrical form of `Oklab`: lightness in `[0, 1]`,
+/// chroma and hue in degrees. Greys have a hue of `0`.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl Oklch {
+    /// Creates a new Oklch colour.
+    pub fn new(l: f32, c: f32, h: f32) -> Self {
+        Oklch { l, c, h }
+    }
+
+    /// Whether the colour lies within the sRGB gamut.
+    pub fn in_gamut(&self) -> bool {
+        Oklab::from(*self).in_gamut()
+    }
+
+    /// Converts to `Color` if the colour lies within the sRGB gamut, `None` otherwise.
+    pub fn try_to_color(&self) -> Option<Color> {
+        Oklab::from(*self).try_to_color()
+    }
+
+    /// Interpolates towards `other` at `t` in `[0, 1]`, going around the hue circle as given by
+    /// `method`. If one of the colours is grey, its hue is ignored and the hue of the other is used.
+    pub fn interpolate(&self, other: &Oklch, t: f32, method: HueInterpolation) -> Oklch {
+        const ACHROMATIC: f32 = 1e-4;
+        let (from_hue, to_hue) = match (self.c < ACHROMATIC, other.c < ACHROMATIC) {
+            (true, false) => (other.h, other.h),
+            (false, true) => (self.h, self.h),
+            _ => (self.h, other.h),
+        };
+        Oklch {
+            l: self.l + (other.l - self.l) * t,
+            c: self.c + (other.c - self.c) * t,
+            h: interpolate_hue(from_hue, to_hue, t, method),
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        let (c, h) = to_polar(lab.a, lab.b);
+        Oklch { l: lab.l, c, h }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let (a, b) = from_polar(lch.c, lch.h);
+        Oklab { l: lch.l, a, b }
+    }
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        Oklch::from(Oklab::from(color))
+    }
+}
+
+impl From<Oklch> for Color {
+    /// Converts to sRGB, clipping colours outside the gamut.
+    fn from(lch: Oklch) -> Self {
+        Color::from(Oklab::from(lch))
+    }
+}
+
+impl From<Oklch> for Xyz {
+    fn from(lch: Oklch) -> Self {
+        Xyz::from(Oklab::from(lch))
+    }
+}
+
+impl From<Xyz> for Oklch {
+    fn from(xyz: Xyz) -> Self {
+        Oklch::from(Oklab::from(xyz))
+    }
+}
+
+impl_fmt!(Oklch { l, c, h });
@@ -0,0 +1,246 @@
+//! Checks whether the colours of a palette, e.g. the series of a graph, can be told apart, also by
+//! viewers with a colour vision deficiency.
+//!
+//! Every pair of colours is compared under normal vision and under the simulated deficiencies. Pairs
+//! whose difference falls below a threshold are reported, and for the offending colours a
+//! replacement from the named colour sets is suggested that is as close as possible to the original
+//! while being distinguishable from the rest of the palette.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{self, G, R};
+//! use colors::cvd::Deficiency;
+//! use colors::palette::{check_palette, Vision};
+//!
+//! let report = check_palette(&[R, G, clr::B]);
+//! assert!(!report.is_distinguishable());
+//! let conflict = report.conflicts[0];
+//! assert_eq!((conflict.first, conflict.second), (0, 1));
+//! assert_eq!(conflict.vision, Vision::Deficient(Deficiency::Protanopia));
+//!
+//! let replacement = report.suggestions[0];
+//! assert_eq!(replacement.index, 1);
+//! assert!(check_palette(&[R, replacement.color.color, clr::B]).is_distinguishable());
+//! ```
+use crate::cie::Lab;
+use crate::clr::{Color, ColorSet, NamedColor};
+use crate::cvd::{Deficiency, Method, Simulation};
+use crate::distance::{ColorDistance, DeltaE};
+
+/// The vision a palette is checked for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Vision {
+    Normal,
+    Deficient(Deficiency),
+}
+
+impl Vision {
+    /// How the colour appears with this vision.
+    fn see(self, color: Color, method: Method) -> Color {
+        match self {
+            Vision::Normal => color,
+            Vision::Deficient(deficiency) => Simulation::new(deficiency, method).simulate(color),
+        }
+    }
+}
+
+/// The smallest difference found for one kind of vision.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VisionSummary {
+    pub vision: Vision,
+    /// The smallest difference between two colours of the palette
+    pub min_delta_e: f32,
+    /// The indices of the pair with the smallest difference
+    pub closest_pair: (usize, usize),
+}
+
+/// A pair of colours that cannot be told apart well enough.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Conflict {
+    /// The index of the first colour of the pair
+    pub first: usize,
+    /// The index of the second colour of the pair
+    pub second: usize,
+    /// The vision for which the difference is too small
+    pub vision: Vision,
+    pub delta_e: f32,
+}
+
+/// A suggested replacement for a colour of the palette.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    /// The index of the colour to replace
+    pub index: usize,
+    /// The named colour to replace it with
+    pub color: NamedColor,
+    /// The smallest difference to the rest of the palette across all checked visions
+    pub min_delta_e: f32,
+}
+
+/// The result of checking a palette.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteReport {
+    /// The smallest difference for every checked vision, in the order of `PaletteCheck::visions`
+    pub summaries: Vec<VisionSummary>,
+    /// All pairs below the threshold, for every vision
+    pub conflicts: Vec<Conflict>,
+    /// Replacements for the second colour of each conflicting pair
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl PaletteReport {
+    /// Whether all colours can be told apart with all checked visions.
+    pub fn is_distinguishable(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// The parameters of a palette check.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteCheck {
+    /// The metric colours are compared with
+    pub metric: DeltaE,
+    /// The smallest difference two colours need to be told apart
+    pub threshold: f32,
+    /// The visions to check for
+    pub visions: Vec<Vision>,
+    /// The simulation method for the deficiencies
+    pub method: Method,
+    /// The sets replacements are taken from
+    pub sets: Vec<ColorSet>,
+}
+
+impl Default for PaletteCheck {
+    /// Compares with CIEDE2000 against a threshold of `10`, for normal vision and the three
+    /// dichromacies simulated with Machado's method, suggesting replacements from the CSS and
+    /// Tableau colours.
+    fn default() -> Self {
+        PaletteCheck {
+            metric: DeltaE::Ciede2000,
+            threshold: 10.0,
+            visions: vec![
+                Vision::Normal,
+                Vision::Deficient(Deficiency::Protanopia),
+                Vision::Deficient(Deficiency::Deuteranopia),
+                Vision::Deficient(Deficiency::Tritanopia),
+            ],
+            method: Method::Machado,
+            sets: vec![ColorSet::Css4, ColorSet::Tableau],
+        }
+    }
+}
+
+/// Checks a palette with the default parameters, see `PaletteCheck::default`.
+pub fn check_palette(palette: &[Color]) -> PaletteReport {
+    PaletteCheck::default().check(palette)
+}
+
+impl PaletteCheck {
+    /// The difference of two colours as seen with `vision`.
+    fn delta_e(&self, vision: Vision, a: Color, b: Color) -> f32 {
+        let (a, b) = (vision.see(a, self.method), vision.see(b, self.method));
+        Lab::from(a).delta_e(&Lab::from(b), self.metric)
+    }
+
+    /// Compares all pairs of colours for all visions.
+    pub fn check(&self, palette: &[Color]) -> PaletteReport {
+        let mut summaries = Vec::with_capacity(self.visions.len());
+        let mut conflicts = Vec::new();
+        for &vision in &self.visions {
+            let mut summary = VisionSummary {
+                vision,
+                min_delta_e: f32::INFINITY,
+                closest_pair: (0, 0),
+            };
+            for first in 0..palette.len() {
+                for second in first + 1..palette.len() {
+                    let delta_e = self.delta_e(vision, palette[first], palette[second]);
+                    if delta_e < summary.min_delta_e {
+                        summary.min_delta_e = delta_e;
+                        summary.closest_pair = (first, second);
+                    }
+                    if delta_e < self.threshold {
+                        conflicts.push(Conflict {
+                            first,
+                            second,
+                            vision,
+                            delta_e,
+                        });
+                    }
+                }
+            }
+            summaries.push(summary);
+        }
+
+        let mut replace: Vec<usize> = conflicts.iter().map(|conflict| conflict.second).collect();
+        replace.sort_unstable();
+        replace.dedup();
+        let suggestions = replace
+            .into_iter()
+            .filter_map(|index| self.suggest(palette, index))
+            .collect();
+
+        PaletteReport {
+            summaries,
+            conflicts,
+            suggestions,
+        }
+    }
+
+    /// Suggests a named colour to replace `palette[index]`: the one closest to the original that
+    /// is distinguishable from all other colours, or, if there is none, the one that is the most
+    /// distinguishable. Returns `None` if `index` is out of range or the sets are empty.
+    pub fn suggest(&self, palette: &[Color], index: usize) -> Option<Suggestion> {
+        let original = *palette.get(index)?;
+        let others: Vec<Color> = palette
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != index)
+            .map(|(_, &color)| color)
+            .collect();
+        // the smallest difference of a candidate to the other colours across all visions
+        let min_delta_e = |candidate: Color| {
+            self.visions
+                .iter()
+                .flat_map(|&vision| {
+                    others
+                        .iter()
+                        .map(move |&other| self.delta_e(vision, candidate, other))
+                })
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        let mut best: Option<(Suggestion, f32)> = None;
+        for &set in &self.sets {
+            for named in set.iter() {
+                if palette.contains(&named.color) {
+                    continue;
+                }
+                let distinct = min_delta_e(named.color);
+                let change = self.delta_e(Vision::Normal, original, named.color);
+                let suggestion = Suggestion {
+                    index,
+                    color: *named,
+                    min_delta_e: distinct,
+                };
+                let better = match best {
+                    None => true,
+                    Some((current, current_change)) => {
+                        let passes = distinct >= self.threshold;
+                        let current_passes = current.min_delta_e >= self.threshold;
+                        match (passes, current_passes) {
+                            (true, false) => true,
+                            (true, true) => change < current_change,
+                            (false, false) => distinct > current.min_delta_e,
+                            (false, true) => false,
+                        }
+                    }
+                };
+                if better {
+                    best = Some((suggestion, change));
+                }
+            }
+        }
+        best.map(|(suggestion, _)| suggestion)
+    }
+}
@@ -0,0 +1,418 @@
+//! Parsing colours from strings.
+//!
+//! `Color` implements `FromStr` and `TryFrom<&str>` and accepts
+//! - hex notation: `#RGB`, `#RRGGBB`, `#RGBA` and `#RRGGBBAA`, with or without the leading `#`
+//! - the CSS functions `rgb(…)` and `rgba(…)`, both in the legacy comma separated syntax
+//!   `rgb(255, 0, 0)` and the space separated syntax `rgb(255 0 0 / 50%)`, with integer or
+//!   percentage channels
+//! - the CSS function `device-cmyk(…)`, converted naively as described in
+//!   [`cmyk`](crate::cmyk)
+//! - all names understood by [`clr::by_name`](crate::clr::by_name)
+//!
+//! `Color` has no alpha channel, so an alpha component is validated but discarded. Parse into
+//! [`Rgba`](crate::rgba::Rgba) to keep it.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{self, Color};
+//!
+//! assert_eq!("#DAA520".parse::<Color>(), Ok(clr::GOLDENROD));
+//! assert_eq!("rgb(100%, 0%, 0%)".parse::<Color>(), Ok(clr::R));
+//! assert_eq!("xkcd:dusty teal".parse::<Color>(), Ok(clr::XKCD_DUSTY_TEAL));
+//!
+//! let error = "#12G456".parse::<Color>().unwrap_err();
+//! assert_eq!(error.position(), 3);
+//! assert_eq!(error.to_string(), "invalid hex digit 'G' at position 3");
+//! ```
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::clr::{self, Color};
+use crate::cmyk::parse_device_cmyk_arguments;
+
+/// The reason why a colour string could not be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseColorErrorKind {
+    /// The string is empty or only contains whitespace
+    Empty,
+    /// A character that is not a hex digit was found in hex notation
+    InvalidHexDigit(char),
+    /// Hex notation with a number of digits other than 3, 4, 6 or 8
+    InvalidHexLength(usize),
+    /// The string is neither hex notation, a function nor a known colour name
+    UnknownName(String),
+    /// A function other than the supported ones, e.g. `foo(…)`
+    UnknownFunction(String),
+    /// An unknown colour space in `color(…)`
+    UnknownColorSpace(String),
+    /// A number was expected
+    ExpectedNumber,
+    /// A specific character was expected, e.g. `,` or `)`
+    Expected(char),
+    /// An unexpected character was found
+    UnexpectedCharacter(char),
+    /// The string ended in the middle of a function
+    UnexpectedEnd,
+}
+
+/// The error returned when parsing a colour from a string fails. It records the byte position in
+/// the input at which parsing failed and why.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseColorError {
+    position: usize,
+    kind: ParseColorErrorKind,
+}
+
+impl ParseColorError {
+    pub(crate) fn new(position: usize, kind: ParseColorErrorKind) -> Self {
+        ParseColorError { position, kind }
+    }
+
+    /// The byte position in the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The reason why parsing failed.
+    pub fn kind(&self) -> &ParseColorErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use ParseColorErrorKind::*;
+        match &self.kind {
+            Empty => write!(f, "empty colour string")?,
+            InvalidHexDigit(c) => write!(f, "invalid hex digit '{}'", c)?,
+            InvalidHexLength(n) => {
+                write!(f, "hex colour with {} digits, expected 3, 4, 6 or 8", n)?
+            }
+            UnknownName(name) => write!(f, "unknown colour name '{}'", name)?,
+            UnknownFunction(name) => write!(f, "unknown colour function '{}'", name)?,
+            UnknownColorSpace(name) => write!(f, "unknown colour space '{}'", name)?,
+            ExpectedNumber => write!(f, "expected a number")?,
+            Expected(c) => write!(f, "expected '{}'", c)?,
+            UnexpectedCharacter(c) => write!(f, "unexpected character '{}'", c)?,
+            UnexpectedEnd => write!(f, "unexpected end of input")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// A number read from a colour string, remembering whether it was given as a percentage or with a
+/// unit like `deg`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum Number {
+    Plain(f32),
+    Percent(f32),
+    Unit(f32, Unit),
+}
+
+/// The units a number can be given in.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum Unit {
+    Deg,
+    Rad,
+    Grad,
+    Turn,
+}
+
+/// A cursor over a colour string that keeps track of the current position for error reporting.
+pub(crate) struct Cursor<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Cursor { input, position: 0 }
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    pub(crate) fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub(crate) fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.position += c.len_utf8();
+        }
+    }
+
+    pub(crate) fn error(&self, kind: ParseColorErrorKind) -> ParseColorError {
+        ParseColorError::new(self.position, kind)
+    }
+
+    /// An error for the character at the current position (or the end of the input).
+    pub(crate) fn unexpected(&self) -> ParseColorError {
+        match self.peek() {
+            Some(c) => self.error(ParseColorErrorKind::UnexpectedCharacter(c)),
+            None => self.error(ParseColorErrorKind::UnexpectedEnd),
+        }
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Consumes `c` (after optional whitespace) if it is the next character.
+    pub(crate) fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn expect(&mut self, c: char) -> Result<(), ParseColorError> {
+        if self.eat(c) {
+            Ok(())
+        } else if self.peek().is_none() {
+            Err(self.error(ParseColorErrorKind::UnexpectedEnd))
+        } else {
+            Err(self.error(ParseColorErrorKind::Expected(c)))
+        }
+    }
+
+    /// Consumes the keyword `word` (after optional whitespace, ignoring case) if it comes next.
+    pub(crate) fn keyword(&mut self, word: &str) -> bool {
+        self.skip_whitespace();
+        let start = self.position;
+        if self.ident().eq_ignore_ascii_case(word) {
+            true
+        } else {
+            self.position = start;
+            false
+        }
+    }
+
+    /// Reads an identifier made of letters, digits and dashes.
+    pub(crate) fn ident(&mut self) -> &'a str {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            self.bump();
+        }
+        &self.input[start..self.position]
+    }
+
+    /// Reads a number, optionally followed by `%` or an angle unit, after optional whitespace.
+    pub(crate) fn number(&mut self) -> Result<Number, ParseColorError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let bytes = self.input.as_bytes();
+        let mut end = start;
+        let digits = |end: &mut usize| {
+            let from = *end;
+            while *end < bytes.len() && bytes[*end].is_ascii_digit() {
+                *end += 1;
+            }
+            *end > from
+        };
+
+        if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+            end += 1;
+        }
+        let mut any = digits(&mut end);
+        if end < bytes.len() && bytes[end] == b'.' {
+            end += 1;
+            any |= digits(&mut end);
+        }
+        if !any {
+            return Err(self.error(ParseColorErrorKind::ExpectedNumber));
+        }
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut exponent = end + 1;
+            if exponent < bytes.len() && (bytes[exponent] == b'+' || bytes[exponent] == b'-') {
+                exponent += 1;
+            }
+            if digits(&mut exponent) {
+                end = exponent;
+            }
+        }
+
+        let value: f32 = self.input[start..end]
+            .parse()
+            .map_err(|_| self.error(ParseColorErrorKind::ExpectedNumber))?;
+        self.position = end;
+
+        if self.peek() == Some('%') {
+            self.bump();
+            return Ok(Number::Percent(value));
+        }
+        let unit_start = self.position;
+        let unit = match self.ident().to_ascii_lowercase().as_str() {
+            "" => return Ok(Number::Plain(value)),
+            "deg" => Unit::Deg,
+            "rad" => Unit::Rad,
+            "grad" => Unit::Grad,
+            "turn" => Unit::Turn,
+            _ => {
+                self.position = unit_start;
+                return Err(self.unexpected());
+            }
+        };
+        Ok(Number::Unit(value, unit))
+    }
+}
+
+fn hex_value(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        _ => c - b'A' + 10,
+    }
+}
+
+/// Parses hex notation without the leading `#`. `offset` is the position of `digits` in the input.
+pub(crate) fn parse_hex(digits: &str, offset: usize) -> Result<(Color, f32), ParseColorError> {
+    if let Some((i, c)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        return Err(ParseColorError::new(
+            offset + i,
+            ParseColorErrorKind::InvalidHexDigit(c),
+        ));
+    }
+    let bytes = digits.as_bytes();
+    let channels: Vec<u8> = match bytes.len() {
+        3 | 4 => bytes.iter().map(|&c| hex_value(c) * 17).collect(),
+        6 | 8 => bytes
+            .chunks(2)
+            .map(|pair| hex_value(pair[0]) * 16 + hex_value(pair[1]))
+            .collect(),
+        n => {
+            return Err(ParseColorError::new(
+                offset,
+                ParseColorErrorKind::InvalidHexLength(n),
+            ))
+        }
+    };
+    Ok((
+        Color(channels[0], channels[1], channels[2]),
+        channels.get(3).map_or(1.0, |&a| f32::from(a) / 255.0),
+    ))
+}
+
+/// Converts an `rgb()` channel to 8 bit, clamping out of range values like CSS does.
+fn channel(number: Number, cursor: &Cursor) -> Result<u8, ParseColorError> {
+    let value = match number {
+        Number::Plain(value) => value,
+        Number::Percent(percent) => percent * 2.55,
+        Number::Unit(..) => return Err(cursor.error(ParseColorErrorKind::ExpectedNumber)),
+    };
+    Ok(value.round().clamp(0.0, 255.0) as u8)
+}
+
+/// Converts an alpha value given as number or percentage to `[0, 1]`.
+pub(crate) fn alpha(number: Number, cursor: &Cursor) -> Result<f32, ParseColorError> {
+    match number {
+        Number::Plain(value) => Ok(value.clamp(0.0, 1.0)),
+        Number::Percent(percent) => Ok((percent / 100.0).clamp(0.0, 1.0)),
+        Number::Unit(..) => Err(cursor.error(ParseColorErrorKind::ExpectedNumber)),
+    }
+}
+
+/// Parses the arguments of `rgb()`/`rgba()`, starting after the opening parenthesis.
+fn parse_rgb_arguments(cursor: &mut Cursor) -> Result<(Color, f32), ParseColorError> {
+    let red = channel(cursor.number()?, cursor)?;
+    let legacy = cursor.eat(',');
+    let green = channel(cursor.number()?, cursor)?;
+    if legacy {
+        cursor.expect(',')?;
+    }
+    let blue = channel(cursor.number()?, cursor)?;
+    let separator = if legacy { ',' } else { '/' };
+    let alpha = if cursor.eat(separator) {
+        alpha(cursor.number()?, cursor)?
+    } else {
+        1.0
+    };
+    cursor.expect(')')?;
+    Ok((Color(red, green, blue), alpha))
+}
+
+/// Parses any of the supported notations, see the module documentation, into the colour and an
+/// alpha value in `[0, 1]`.
+pub(crate) fn parse(input: &str) -> Result<(Color, f32), ParseColorError> {
+    let mut cursor = Cursor::new(input);
+    cursor.skip_whitespace();
+    let start = cursor.position();
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(cursor.error(ParseColorErrorKind::Empty));
+    }
+
+    if let Some(digits) = trimmed.strip_prefix('#') {
+        return parse_hex(digits, start + 1);
+    }
+
+    if trimmed.contains('(') {
+        let name = cursor.ident();
+        if !cursor.eat('(') {
+            return Err(cursor.unexpected());
+        }
+        let parsed = match name.to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => parse_rgb_arguments(&mut cursor)?,
+            "device-cmyk" => {
+                let (cmyk, alpha) = parse_device_cmyk_arguments(&mut cursor)?;
+                (Color::from(cmyk), alpha)
+            }
+            _ => {
+                return Err(ParseColorError::new(
+                    start,
+                    ParseColorErrorKind::UnknownFunction(name.to_string()),
+                ))
+            }
+        };
+        cursor.skip_whitespace();
+        if cursor.peek().is_some() {
+            return Err(cursor.unexpected());
+        }
+        return Ok(parsed);
+    }
+
+    if let Some(color) = clr::by_name(trimmed) {
+        return Ok((color, 1.0));
+    }
+    if trimmed.chars().all(|c| c.is_ascii_hexdigit()) && [3, 4, 6, 8].contains(&trimmed.len()) {
+        return parse_hex(trimmed, start);
+    }
+    Err(ParseColorError::new(
+        start,
+        ParseColorErrorKind::UnknownName(trimmed.to_string()),
+    ))
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(|(color, _)| color)
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ParseColorError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
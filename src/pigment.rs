@@ -0,0 +1,220 @@
+//! Subtractive mixing of colours as if they were paints.
+//!
+//! Mixing light adds up spectra, which is why yellow and blue mixed in RGB give grey. Paints
+//! absorb light instead: each pigment removes part of the spectrum, and a mix reflects only what
+//! none of them absorbs. To mix colours like paints, each colour is turned into a plausible
+//! reflectance spectrum, the spectra are mixed with the Kubelka–Munk model and the mix is converted
+//! back.
+//!
+//! The reflectance of a colour is found with the least hyperbolic tangent slope squared method of
+//! [Scott Burns](http://scottburns.us/reflectance-curves-from-srgb/): the smoothest reflectance curve
+//! between `0` and `1` that has the colour under D65 for the CIE 1931 observer. Kubelka–Munk mixes
+//! the ratios of absorption to scattering `K/S = (1 - R)² / 2R` of the paints. Light paints cover
+//! better than dark ones, so the amount of each paint is weighted by its luminance (its tinting
+//! strength) and squared. A paint mixed with itself keeps its colour.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, BLUE, YELLOW};
+//! use colors::mix::{mix, MixSpace};
+//! use colors::pigment;
+//!
+//! assert_eq!(mix(YELLOW, BLUE, 0.5, MixSpace::LinearRgb), Color(188, 188, 188));
+//! assert_eq!(pigment::mix(YELLOW, BLUE, 0.5), Color(56, 143, 84));
+//! ```
+use std::sync::OnceLock;
+
+use crate::cie::Xyz;
+use crate::clr::{Color, WHITE};
+use crate::cmf::CIE1931;
+use crate::rgb::{color_to_linear, linear_to_color};
+use crate::spectrum::{Illuminant, Spectrum, SAMPLES};
+
+/// How close the linear RGB components of a colour may come to `0` and `1`: reflectances strictly
+/// between `0` and `1` cannot reproduce them exactly.
+const MARGIN: f64 = 1e-4;
+/// The largest error of the linear RGB components accepted from the solver.
+const TOLERANCE: f64 = 1e-9;
+const MAX_ITERATIONS: usize = 100;
+
+/// The matrix turning a reflectance curve into linear sRGB under D65, scaled such that a perfect
+/// white reflector is exactly white.
+fn reflectance_to_rgb() -> &'static [[f64; SAMPLES]; 3] {
+    static MATRIX: OnceLock<[[f64; SAMPLES]; 3]> = OnceLock::new();
+    MATRIX.get_or_init(|| {
+        let light = Illuminant::D65.spectrum();
+        let mut matrix = [[0.0; SAMPLES]; 3];
+        for (i, (cmf, &power)) in CIE1931.iter().zip(light.values()).enumerate() {
+            let [x, y, z] = cmf.map(|weight| (weight * f64::from(power)) as f32);
+            let rgb = Xyz::new(x, y, z).to_linear_srgb();
+            for (row, value) in matrix.iter_mut().zip(rgb) {
+                row[i] = f64::from(value);
+            }
+        }
+        for row in &mut matrix {
+            let sum: f64 = row.iter().sum();
+            row.iter_mut().for_each(|value| *value /= sum);
+        }
+        matrix
+    })
+}
+
+/// Linear sRGB of a reflectance curve.
+fn to_rgb(reflectance: &[f64; SAMPLES]) -> [f64; 3] {
+    reflectance_to_rgb().map(|row| row.iter().zip(reflectance).map(|(t, r)| t * r).sum())
+}
+
+/// Solves `a · x = b` by Gaussian elimination with partial pivoting, `None` if `a` is singular.
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for column in 0..n {
+        let pivot =
+            (column..n).max_by(|&i, &j| a[i][column].abs().total_cmp(&a[j][column].abs()))?;
+        if a[pivot][column].abs() < 1e-300 {
+            return None;
+        }
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+        let (upper, lower) = a.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for (i, row) in lower.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            if factor != 0.0 {
+                for (value, pivot) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                    *value -= factor * pivot;
+                }
+                b[column + 1 + i] -= factor * b[column];
+            }
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+/// The reflectance curve of a colour by the least hyperbolic tangent slope squared method: with
+/// the reflectance written as `(tanh(z) + 1) / 2`, it minimises the squared differences of
+/// neighbouring `z` under the constraint of reproducing the colour, by Newton's method on the
+/// Lagrangian.
+fn reflectance_curve(color: Color) -> [f64; SAMPLES] {
+    if color == WHITE {
+        return [1.0; SAMPLES];
+    }
+    let rgb = color_to_linear(color).map(|c| f64::from(c).clamp(MARGIN, 1.0 - MARGIN));
+    let t = reflectance_to_rgb();
+    let n = SAMPLES + 3;
+    let mut z = [0.0; SAMPLES];
+    let mut lambda = [0.0; 3];
+    for _ in 0..MAX_ITERATIONS {
+        let tanh = z.map(f64::tanh);
+        // the first and second derivatives of the reflectance
+        let d1 = tanh.map(|tanh| (1.0 - tanh * tanh) / 2.0);
+        let d2 = tanh.map(|tanh| -(1.0 - tanh * tanh) * tanh);
+        let mut jacobian = vec![vec![0.0; n]; n];
+        let mut f = vec![0.0; n];
+        for i in 0..SAMPLES {
+            let weighted: f64 = (0..3).map(|k| t[k][i] * lambda[k]).sum();
+            // the slopes to both neighbours, the ends have only one
+            let mut slope = 0.0;
+            let mut diagonal = 0.0;
+            for j in [i.wrapping_sub(1), i + 1] {
+                if j < SAMPLES {
+                    slope += 2.0 * (z[i] - z[j]);
+                    diagonal += 2.0;
+                    jacobian[i][j] = -2.0;
+                }
+            }
+            f[i] = slope + d1[i] * weighted;
+            jacobian[i][i] = diagonal + d2[i] * weighted;
+            for k in 0..3 {
+                jacobian[i][SAMPLES + k] = d1[i] * t[k][i];
+                jacobian[SAMPLES + k][i] = t[k][i] * d1[i];
+            }
+        }
+        let current = to_rgb(&tanh.map(|tanh| (tanh + 1.0) / 2.0));
+        for k in 0..3 {
+            f[SAMPLES + k] = current[k] - rgb[k];
+        }
+        if f.iter().all(|value| value.abs() < TOLERANCE) {
+            break;
+        }
+        let step = match solve(jacobian, f.iter().map(|value| -value).collect()) {
+            Some(step) => step,
+            None => break,
+        };
+        for i in 0..SAMPLES {
+            z[i] += step[i];
+        }
+        for k in 0..3 {
+            lambda[k] += step[SAMPLES + k];
+        }
+    }
+    z.map(|z| (z.tanh() + 1.0) / 2.0)
+}
+
+/// A smooth reflectance curve with the colour `color` under D65 for the CIE 1931 observer, with
+/// values between `0` and `1`.
+///
+/// Example
+/// ```
+/// # use colors::clr::{GOLDENROD, WHITE};
+/// # use colors::pigment::reflectance;
+/// # fn main() {
+/// assert!(reflectance(WHITE).values().iter().all(|&value| value == 1.0));
+/// let goldenrod = reflectance(GOLDENROD);
+/// assert!(goldenrod.at(650.0) > 0.5 && goldenrod.at(450.0) < 0.05);
+/// # }
+/// ```
+pub fn reflectance(color: Color) -> Spectrum {
+    Spectrum::new(reflectance_curve(color).map(|value| value as f32))
+}
+
+/// Mixes two colours like paints, with `t` of `b`: `0` is `a`, `1` is `b`.
+pub fn mix(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    mix_all(&[(a, 1.0 - t), (b, t)]).unwrap_or(a)
+}
+
+/// Mixes colours like paints, each with its amount. Amounts are relative, they do not need to add
+/// up to `1`. Returns `None` if no amount is positive.
+///
+/// Example
+/// ```
+/// # use colors::clr::{Color, BLUE, RED, WHITE, YELLOW};
+/// # use colors::pigment::mix_all;
+/// # fn main() {
+/// let brown = mix_all(&[(RED, 2.0), (YELLOW, 1.0), (BLUE, 1.0)]).unwrap();
+/// assert_eq!(brown, Color(144, 43, 36));
+/// assert_eq!(mix_all(&[(WHITE, 0.0)]), None);
+/// # }
+/// ```
+pub fn mix_all(colors: &[(Color, f32)]) -> Option<Color> {
+    let mut mixed = [0.0; SAMPLES];
+    let mut total = 0.0;
+    for &(color, amount) in colors {
+        if amount <= 0.0 {
+            continue;
+        }
+        let curve = reflectance_curve(color);
+        // the luminance of the curve, as that of black is not zero
+        let [r, g, b] = to_rgb(&curve);
+        let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let strength = f64::from(amount).powi(2) * luminance;
+        total += strength;
+        for (sum, value) in mixed.iter_mut().zip(curve) {
+            *sum += strength * (1.0 - value).powi(2) / (2.0 * value);
+        }
+    }
+    if total <= 0.0 {
+        return None;
+    }
+    let reflectance = mixed.map(|ks| {
+        let ks = ks / total;
+        1.0 + ks - (ks * ks + 2.0 * ks).sqrt()
+    });
+    let rgb = to_rgb(&reflectance);
+    Some(linear_to_color(rgb.map(|c| c.clamp(0.0, 1.0) as f32)))
+}
@@ -0,0 +1,315 @@
+//! Floating point RGB colours, keeping gamma encoded sRGB and linear light RGB apart.
+//!
+//! `Color` stores 8 bit per channel, which loses precision every time a result is rounded. `Rgb<T>`
+//! holds the same gamma encoded sRGB values as floating point numbers in `[0, 1]` (`f32` or `f64`),
+//! `LinearRgb<T>` holds linear light values, which is what blending and colour space conversions
+//! should operate on. Going from one to the other is always explicit via `decode` and `encode`.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, GOLDENROD, WHITE};
+//! use colors::rgb::{LinearRgb, Rgb};
+//!
+//! let encoded: Rgb<f64> = Rgb::from(GOLDENROD);
+//! assert_eq!(encoded.to_color(), GOLDENROD);
+//!
+//! // blend in linear light and only round at the very end
+//! let linear = encoded.decode().lerp(&LinearRgb::from(WHITE), 0.5);
+//! assert_eq!(linear.encode().to_color(), Color(237, 216, 189));
+//! ```
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::clr::Color;
+
+/// The floating point types the RGB types can be used with, i.e. `f32` and `f64`.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Converts from `f64`, rounding if necessary.
+    fn from_f64(value: f64) -> Self;
+    /// Converts to `f64`.
+    fn to_f64(self) -> f64;
+    /// Raises the value to the power `exponent`.
+    fn powf(self, exponent: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($ty:ident),*) => {
+        $(
+            impl Float for $ty {
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
+                }
+
+                fn to_f64(self) -> f64 {
+                    self.into()
+                }
+
+                fn powf(self, exponent: Self) -> Self {
+                    $ty::powf(self, exponent)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// The sRGB transfer function: converts a gamma encoded value to linear light. Values outside
+/// `[0, 1]` are extended symmetrically around zero, as in CSS.
+///
+/// Example
+/// ```
+/// # use colors::rgb::{decode, encode};
+/// # fn main() {
+/// assert!((decode(0.5f64) - 0.214_041).abs() < 1e-6);
+/// assert!((encode(decode(0.5f64)) - 0.5).abs() < 1e-12);
+/// # }
+/// ```
+pub fn decode<T: Float>(value: T) -> T {
+    let zero = T::from_f64(0.0);
+    if value < zero {
+        return -decode(-value);
+    }
+    if value <= T::from_f64(0.04045) {
+        value / T::from_f64(12.92)
+    } else {
+        ((value + T::from_f64(0.055)) / T::from_f64(1.055)).powf(T::from_f64(2.4))
+    }
+}
+
+/// The inverse sRGB transfer function: converts a linear light value to gamma encoded sRGB. Values
+/// outside `[0, 1]` are extended symmetrically around zero, as in CSS.
+pub fn encode<T: Float>(value: T) -> T {
+    let zero = T::from_f64(0.0);
+    if value < zero {
+        return -encode(-value);
+    }
+    if value <= T::from_f64(0.003_130_8) {
+        value * T::from_f64(12.92)
+    } else {
+        T::from_f64(1.055) * value.powf(T::from_f64(1.0 / 2.4)) - T::from_f64(0.055)
+    }
+}
+
+/// Converts a value in `[0, 1]` to 8 bit, clipping values outside.
+fn to_byte<T: Float>(value: T) -> u8 {
+    (value.to_f64() * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+fn from_byte<T: Float>(value: u8) -> T {
+    T::from_f64(f64::from(value) / 255.0)
+}
+
+/// A gamma encoded sRGB colour with floating point components, nominally in `[0, 1]`.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Rgb<T = f32> {
+    pub r: T,
+    pub g: T,
+    pub b: T,
+}
+
+/// A linear light RGB colour with the sRGB primaries and floating point components, nominally in
+/// `[0, 1]`.
+#[derive(Copy, Clone, PartialEq)]
+pub struct LinearRgb<T = f32> {
+    pub r: T,
+    pub g: T,
+    pub b: T,
+}
+
+macro_rules! impl_rgb {
+    ($ty:ident) => {
+        impl<T: Float> $ty<T> {
+            /// Creates a new colour.
+            pub fn new(r: T, g: T, b: T) -> Self {
+                $ty { r, g, b }
+            }
+
+            /// Applies `f` to each component.
+            pub fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+                $ty {
+                    r: f(self.r),
+                    g: f(self.g),
+                    b: f(self.b),
+                }
+            }
+
+            /// Linear interpolation towards `other` at `t` in `[0, 1]`.
+            pub fn lerp(&self, other: &Self, t: T) -> Self {
+                $ty {
+                    r: self.r + (other.r - self.r) * t,
+                    g: self.g + (other.g - self.g) * t,
+                    b: self.b + (other.b - self.b) * t,
+                }
+            }
+
+            /// Whether all components lie within `[0, 1]`.
+            pub fn in_gamut(&self) -> bool {
+                let range = T::from_f64(0.0)..=T::from_f64(1.0);
+                range.contains(&self.r) && range.contains(&self.g) && range.contains(&self.b)
+            }
+
+            /// Clips all components to `[0, 1]`.
+            pub fn clamp(&self) -> Self {
+                let (zero, one) = (T::from_f64(0.0), T::from_f64(1.0));
+                self.map(|c| {
+                    if c < zero {
+                        zero
+                    } else if c > one {
+                        one
+                    } else {
+                        c
+                    }
+                })
+            }
+
+            /// Converts the components to another floating point type.
+            pub fn cast<U: Float>(&self) -> $ty<U> {
+                $ty {
+                    r: U::from_f64(self.r.to_f64()),
+                    g: U::from_f64(self.g.to_f64()),
+                    b: U::from_f64(self.b.to_f64()),
+                }
+            }
+        }
+
+        impl<T: Float> Add for $ty<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $ty::new(self.r + other.r, self.g + other.g, self.b + other.b)
+            }
+        }
+
+        impl<T: Float> Sub for $ty<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $ty::new(self.r - other.r, self.g - other.g, self.b - other.b)
+            }
+        }
+
+        impl<T: Float> Mul<T> for $ty<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                self.map(|c| c * factor)
+            }
+        }
+
+        impl<T: Float> Debug for $ty<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, concat!(stringify!($ty), "(r="))?;
+                Display::fmt(&self.r, f)?;
+                write!(f, ", g=")?;
+                Display::fmt(&self.g, f)?;
+                write!(f, ", b=")?;
+                Display::fmt(&self.b, f)?;
+                write!(f, ")")
+            }
+        }
+
+        impl<T: Float> Display for $ty<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "(")?;
+                Display::fmt(&self.r, f)?;
+                write!(f, ", ")?;
+                Display::fmt(&self.g, f)?;
+                write!(f, ", ")?;
+                Display::fmt(&self.b, f)?;
+                write!(f, ")")
+            }
+        }
+    };
+}
+
+impl_rgb!(Rgb);
+impl_rgb!(LinearRgb);
+
+impl<T: Float> Rgb<T> {
+    /// Converts to linear light by applying the sRGB transfer function.
+    pub fn decode(&self) -> LinearRgb<T> {
+        LinearRgb::new(decode(self.r), decode(self.g), decode(self.b))
+    }
+
+    /// Rounds to an 8-bit `Color`, clipping components outside `[0, 1]`.
+    pub fn to_color(&self) -> Color {
+        Color(to_byte(self.r), to_byte(self.g), to_byte(self.b))
+    }
+}
+
+impl<T: Float> LinearRgb<T> {
+    /// Converts to gamma encoded sRGB by applying the inverse sRGB transfer function.
+    pub fn encode(&self) -> Rgb<T> {
+        Rgb::new(encode(self.r), encode(self.g), encode(self.b))
+    }
+
+    /// Encodes and rounds to an 8-bit `Color`, clipping components outside `[0, 1]`.
+    pub fn to_color(&self) -> Color {
+        self.encode().to_color()
+    }
+
+    pub(crate) fn to_array(self) -> [T; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    pub(crate) fn from_array([r, g, b]: [T; 3]) -> Self {
+        LinearRgb { r, g, b }
+    }
+}
+
+impl<T: Float> From<Color> for Rgb<T> {
+    fn from(color: Color) -> Self {
+        Rgb::new(from_byte(color.0), from_byte(color.1), from_byte(color.2))
+    }
+}
+
+impl<T: Float> From<Color> for LinearRgb<T> {
+    fn from(color: Color) -> Self {
+        Rgb::from(color).decode()
+    }
+}
+
+impl<T: Float> From<Rgb<T>> for LinearRgb<T> {
+    fn from(rgb: Rgb<T>) -> Self {
+        rgb.decode()
+    }
+}
+
+impl<T: Float> From<LinearRgb<T>> for Rgb<T> {
+    fn from(linear: LinearRgb<T>) -> Self {
+        linear.encode()
+    }
+}
+
+impl<T: Float> From<Rgb<T>> for Color {
+    fn from(rgb: Rgb<T>) -> Self {
+        rgb.to_color()
+    }
+}
+
+impl<T: Float> From<LinearRgb<T>> for Color {
+    fn from(linear: LinearRgb<T>) -> Self {
+        linear.to_color()
+    }
+}
+
+/// Returns the linear light components of an 8-bit sRGB colour.
+pub(crate) fn color_to_linear(color: Color) -> [f32; 3] {
+    LinearRgb::from(color).to_array()
+}
+
+/// Converts linear light components to an 8-bit sRGB colour, clipping values outside `[0, 1]`.
+pub(crate) fn linear_to_color(rgb: [f32; 3]) -> Color {
+    LinearRgb::from_array(rgb).to_color()
+}
@@ -0,0 +1,258 @@
+//! Colours with an alpha channel and [Porter-Duff](https://en.wikipedia.org/wiki/Alpha_compositing)
+//! compositing.
+//!
+//! `Rgba` stores straight (unassociated) alpha, i.e. the colour channels are independent of the
+//! alpha value. `PremultipliedRgba` stores the colour channels already multiplied by alpha, which is
+//! the form compositing works in.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, WHITE};
+//! use colors::rgba::Rgba;
+//!
+//! let overlay = Rgba(255, 0, 0, 128);
+//! assert_eq!(overlay.to_hex(), "#FF000080");
+//! assert_eq!(overlay.over(Rgba::from(WHITE)), Rgba(255, 127, 127, 255));
+//! assert_eq!(overlay.flatten(WHITE), Color(255, 127, 127));
+//! ```
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::clr::Color;
+use crate::parse::{parse, ParseColorError};
+
+/// An RGBA32 colour with straight alpha. Each channel has a depth of 8 bit, an alpha of `255` is
+/// fully opaque.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rgba(pub u8, pub u8, pub u8, pub u8);
+
+/// An RGBA32 colour with premultiplied alpha: the colour channels are scaled by the alpha value and
+/// can never exceed it.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PremultipliedRgba(pub u8, pub u8, pub u8, pub u8);
+
+/// The Porter-Duff compositing operators, describing how a source colour is combined with a
+/// destination (backdrop) colour.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompositeOp {
+    /// The source is placed over the destination
+    Over,
+    /// The part of the source inside the destination, the destination is discarded
+    In,
+    /// The part of the source outside the destination, the destination is discarded
+    Out,
+    /// The part of the source inside the destination is placed over the destination
+    Atop,
+    /// The parts of source and destination that do not overlap
+    Xor,
+}
+
+impl CompositeOp {
+    /// The fractions of source and destination contributing to the result.
+    fn fractions(self, alpha_src: f32, alpha_dst: f32) -> (f32, f32) {
+        match self {
+            CompositeOp::Over => (1.0, 1.0 - alpha_src),
+            CompositeOp::In => (alpha_dst, 0.0),
+            CompositeOp::Out => (1.0 - alpha_dst, 0.0),
+            CompositeOp::Atop => (alpha_dst, 1.0 - alpha_src),
+            CompositeOp::Xor => (1.0 - alpha_dst, 1.0 - alpha_src),
+        }
+    }
+}
+
+fn to_unit(value: u8) -> f32 {
+    f32::from(value) / 255.0
+}
+
+fn to_byte(value: f32) -> u8 {
+    (value * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+impl Rgba {
+    /// Creates a colour from a `Color` and an alpha value.
+    pub fn new(color: Color, alpha: u8) -> Self {
+        Rgba(color.0, color.1, color.2, alpha)
+    }
+
+    /// The colour without its alpha channel.
+    pub fn color(&self) -> Color {
+        Color(self.0, self.1, self.2)
+    }
+
+    /// The alpha channel.
+    pub fn alpha(&self) -> u8 {
+        self.3
+    }
+
+    /// Prints out a hex representation `#RRGGBBAA` of the colour.
+    /// Example
+    /// ```
+    /// # use colors::rgba::Rgba;
+    /// # fn main() {
+    /// assert_eq!(Rgba(255, 0, 0, 255).to_hex(), "#FF0000FF");
+    /// # }
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("#{:02X}{:02X}{:02X}{:02X}", self.0, self.1, self.2, self.3)
+    }
+
+    /// Converts to premultiplied alpha.
+    pub fn premultiply(&self) -> PremultipliedRgba {
+        let alpha = to_unit(self.3);
+        PremultipliedRgba(
+            to_byte(to_unit(self.0) * alpha),
+            to_byte(to_unit(self.1) * alpha),
+            to_byte(to_unit(self.2) * alpha),
+            self.3,
+        )
+    }
+
+    /// Composites `self` as the source onto `destination` with the given operator. The computation
+    /// is done on premultiplied floating point values, only the result is rounded to 8 bit.
+    pub fn composite(&self, destination: Rgba, op: CompositeOp) -> Rgba {
+        let (alpha_src, alpha_dst) = (to_unit(self.3), to_unit(destination.3));
+        let (fraction_src, fraction_dst) = op.fractions(alpha_src, alpha_dst);
+        let alpha = alpha_src * fraction_src + alpha_dst * fraction_dst;
+        if alpha <= 0.0 {
+            return Rgba(0, 0, 0, 0);
+        }
+        let channel = |src: u8, dst: u8| {
+            let premultiplied =
+                to_unit(src) * alpha_src * fraction_src + to_unit(dst) * alpha_dst * fraction_dst;
+            to_byte(premultiplied / alpha)
+        };
+        Rgba(
+            channel(self.0, destination.0),
+            channel(self.1, destination.1),
+            channel(self.2, destination.2),
+            to_byte(alpha),
+        )
+    }
+
+    /// Porter-Duff source over destination.
+    pub fn over(&self, destination: Rgba) -> Rgba {
+        self.composite(destination, CompositeOp::Over)
+    }
+
+    /// Porter-Duff source in destination (named `in_` as `in` is a keyword).
+    pub fn in_(&self, destination: Rgba) -> Rgba {
+        self.composite(destination, CompositeOp::In)
+    }
+
+    /// Porter-Duff source out destination.
+    pub fn out(&self, destination: Rgba) -> Rgba {
+        self.composite(destination, CompositeOp::Out)
+    }
+
+    /// Porter-Duff source atop destination.
+    pub fn atop(&self, destination: Rgba) -> Rgba {
+        self.composite(destination, CompositeOp::Atop)
+    }
+
+    /// Porter-Duff source xor destination.
+    pub fn xor(&self, destination: Rgba) -> Rgba {
+        self.composite(destination, CompositeOp::Xor)
+    }
+
+    /// Places the colour over an opaque background, giving the colour that is actually seen.
+    pub fn flatten(&self, background: Color) -> Color {
+        self.over(Rgba::from(background)).color()
+    }
+}
+
+impl PremultipliedRgba {
+    /// Converts back to straight alpha. Fully transparent colours become transparent black.
+    pub fn unpremultiply(&self) -> Rgba {
+        if self.3 == 0 {
+            return Rgba(0, 0, 0, 0);
+        }
+        let alpha = to_unit(self.3);
+        Rgba(
+            to_byte(to_unit(self.0) / alpha),
+            to_byte(to_unit(self.1) / alpha),
+            to_byte(to_unit(self.2) / alpha),
+            self.3,
+        )
+    }
+}
+
+impl From<Color> for Rgba {
+    /// An opaque colour.
+    fn from(color: Color) -> Self {
+        Rgba::new(color, 255)
+    }
+}
+
+impl From<Rgba> for Color {
+    /// Drops the alpha channel.
+    fn from(rgba: Rgba) -> Self {
+        rgba.color()
+    }
+}
+
+impl From<Rgba> for PremultipliedRgba {
+    fn from(rgba: Rgba) -> Self {
+        rgba.premultiply()
+    }
+}
+
+impl From<PremultipliedRgba> for Rgba {
+    fn from(premultiplied: PremultipliedRgba) -> Self {
+        premultiplied.unpremultiply()
+    }
+}
+
+/// Parses the same notations as `Color`, keeping the alpha value.
+impl FromStr for Rgba {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(|(color, alpha)| Rgba::new(color, to_byte(alpha)))
+    }
+}
+
+impl TryFrom<&str> for Rgba {
+    type Error = ParseColorError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Debug formatting for Rgba
+impl Debug for Rgba {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Rgba(r={}, g={}, b={}, a={})",
+            self.0, self.1, self.2, self.3
+        )
+    }
+}
+
+/// Display formatting for Rgba, will print in the format `(r, g, b, a)`.
+impl Display for Rgba {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.0, self.1, self.2, self.3)
+    }
+}
+
+/// Debug formatting for PremultipliedRgba
+impl Debug for PremultipliedRgba {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PremultipliedRgba(r={}, g={}, b={}, a={})",
+            self.0, self.1, self.2, self.3
+        )
+    }
+}
+
+/// Display formatting for PremultipliedRgba, will print in the format `(r, g, b, a)`.
+impl Display for PremultipliedRgba {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.0, self.1, self.2, self.3)
+    }
+}
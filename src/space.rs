@@ -0,0 +1,293 @@
+//! RGB colour spaces beyond sRGB, defined by their primaries, white point and transfer function.
+//!
+//! `RgbSpace` describes an additive RGB space. Colours are converted between spaces through CIE XYZ,
+//! adapting between white points with the Bradford transform as CSS does. Components are given as
+//! gamma encoded `[r, g, b]` arrays, nominally in `[0, 1]`; colours outside the gamut of a space
+//! have components outside this range, which are kept by all conversions except the ones to
+//! `Color`.
+//!
+//! Built in are sRGB, Display P3, Rec. 709, Rec. 2020, Adobe RGB (1998) and ProPhoto RGB, custom
+//! spaces can be created with `RgbSpace::new`.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, GOLDENROD, R};
+//! use colors::space::RgbSpace;
+//!
+//! let p3 = RgbSpace::DISPLAY_P3.from_color(R);
+//! assert_eq!(format!("{:.4?}", p3), "[0.9175, 0.2003, 0.1386]");
+//! assert_eq!(RgbSpace::DISPLAY_P3.to_color(p3), R);
+//!
+//! // pure Display P3 red lies outside sRGB and is clipped
+//! assert_eq!(RgbSpace::DISPLAY_P3.to_color([1.0, 0.0, 0.0]), Color(255, 0, 0));
+//! let srgb = RgbSpace::DISPLAY_P3.convert([1.0, 0.0, 0.0], &RgbSpace::SRGB);
+//! assert!(srgb[0] > 1.0 && srgb[1] < 0.0);
+//!
+//! let wide = RgbSpace::PROPHOTO_RGB.from_color(GOLDENROD);
+//! assert_eq!(RgbSpace::PROPHOTO_RGB.to_color(wide), GOLDENROD);
+//! ```
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+use crate::cie::{Adaptation, WhitePoint, Xyz};
+use crate::clr::Color;
+use crate::matrix::{invert, multiply, transform, Matrix};
+use crate::oklab::linear_in_gamut;
+use crate::rgb::{color_to_linear, linear_to_color};
+
+/// The transfer function of an RGB space, converting between gamma encoded and linear light values.
+/// All of them are extended symmetrically to negative values, as in CSS.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TransferFunction {
+    /// No encoding, the components are linear light
+    Linear,
+    /// The piecewise sRGB curve, also used by Display P3
+    Srgb,
+    /// A pure power law with the given exponent, e.g. `563 / 256` for Adobe RGB
+    Gamma(f32),
+    /// The ITU-R BT.709 curve, also used by Rec. 2020
+    Bt709,
+    /// The ROMM RGB curve of ProPhoto RGB: a power law of `1.8` with a linear segment near black
+    ProPhoto,
+}
+
+/// Applies `f` to the magnitude of `value`, keeping its sign.
+fn symmetric(value: f32, f: impl Fn(f32) -> f32) -> f32 {
+    if value < 0.0 {
+        -f(-value)
+    } else {
+        f(value)
+    }
+}
+
+/// The spaces whose conversion matrices are cached, one per distinct pair of primaries and white
+/// point.
+const BUILT_IN_SPACES: [RgbSpace; 5] = [
+    RgbSpace::SRGB,
+    RgbSpace::DISPLAY_P3,
+    RgbSpace::REC2020,
+    RgbSpace::ADOBE_RGB,
+    RgbSpace::PROPHOTO_RGB,
+];
+
+const BT709_ALPHA: f32 = 1.099_296_8;
+const BT709_BETA: f32 = 0.018_053_97;
+
+impl TransferFunction {
+    /// Converts a gamma encoded component to linear light.
+    pub fn decode(self, value: f32) -> f32 {
+        match self {
+            TransferFunction::Linear => value,
+            TransferFunction::Srgb => crate::rgb::decode(value),
+            TransferFunction::Gamma(gamma) => symmetric(value, |v| v.powf(gamma)),
+            TransferFunction::Bt709 => symmetric(value, |v| {
+                if v < BT709_BETA * 4.5 {
+                    v / 4.5
+                } else {
+                    ((v + BT709_ALPHA - 1.0) / BT709_ALPHA).powf(1.0 / 0.45)
+                }
+            }),
+            TransferFunction::ProPhoto => symmetric(value, |v| {
+                if v <= 16.0 / 512.0 {
+                    v / 16.0
+                } else {
+                    v.powf(1.8)
+                }
+            }),
+        }
+    }
+
+    /// Converts a linear light component to its gamma encoded form.
+    pub fn encode(self, value: f32) -> f32 {
+        match self {
+            TransferFunction::Linear => value,
+            TransferFunction::Srgb => crate::rgb::encode(value),
+            TransferFunction::Gamma(gamma) => symmetric(value, |v| v.powf(1.0 / gamma)),
+            TransferFunction::Bt709 => symmetric(value, |v| {
+                if v < BT709_BETA {
+                    v * 4.5
+                } else {
+                    BT709_ALPHA * v.powf(0.45) - (BT709_ALPHA - 1.0)
+                }
+            }),
+            TransferFunction::ProPhoto => symmetric(value, |v| {
+                if v < 1.0 / 512.0 {
+                    v * 16.0
+                } else {
+                    v.powf(1.0 / 1.8)
+                }
+            }),
+        }
+    }
+}
+
+/// An additive RGB colour space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RgbSpace {
+    /// A human readable name, e.g. `"Display P3"`
+    pub name: &'static str,
+    /// The `(x, y)` chromaticities of the red, green and blue primaries
+    pub primaries: [(f32, f32); 3],
+    /// The reference white, which has all components at `1`
+    pub white: WhitePoint,
+    /// The transfer function relating encoded component values to linear light
+    pub transfer: TransferFunction,
+}
+
+impl RgbSpace {
+    /// sRGB, the space of `Color`.
+    pub const SRGB: RgbSpace = RgbSpace {
+        name: "sRGB",
+        primaries: [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Srgb,
+    };
+    /// sRGB without the transfer function, i.e. linear light.
+    pub const SRGB_LINEAR: RgbSpace = RgbSpace {
+        name: "sRGB linear",
+        primaries: [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Linear,
+    };
+    /// Display P3, the wide gamut of current Apple devices and many HDR monitors: the DCI-P3
+    /// primaries with a D65 white point and the sRGB transfer function.
+    pub const DISPLAY_P3: RgbSpace = RgbSpace {
+        name: "Display P3",
+        primaries: [(0.680, 0.320), (0.265, 0.690), (0.150, 0.060)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Srgb,
+    };
+    /// ITU-R BT.709, the HDTV space: the sRGB primaries with the BT.709 transfer function.
+    pub const REC709: RgbSpace = RgbSpace {
+        name: "Rec. 709",
+        primaries: [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Bt709,
+    };
+    /// ITU-R BT.2020, the UHDTV space.
+    pub const REC2020: RgbSpace = RgbSpace {
+        name: "Rec. 2020",
+        primaries: [(0.708, 0.292), (0.170, 0.797), (0.131, 0.046)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Bt709,
+    };
+    /// Adobe RGB (1998), called `a98-rgb` in CSS.
+    pub const ADOBE_RGB: RgbSpace = RgbSpace {
+        name: "Adobe RGB (1998)",
+        primaries: [(0.64, 0.33), (0.21, 0.71), (0.15, 0.06)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Gamma(563.0 / 256.0),
+    };
+    /// ProPhoto RGB (ROMM RGB), a very large gamut relative to D50.
+    pub const PROPHOTO_RGB: RgbSpace = RgbSpace {
+        name: "ProPhoto RGB",
+        primaries: [
+            (0.734_699, 0.265_301),
+            (0.159_597, 0.840_403),
+            (0.036_598, 0.000_105),
+        ],
+        white: WhitePoint::D50,
+        transfer: TransferFunction::ProPhoto,
+    };
+
+    /// Creates a custom space.
+    pub fn new(
+        name: &'static str,
+        primaries: [(f32, f32); 3],
+        white: WhitePoint,
+        transfer: TransferFunction,
+    ) -> Self {
+        RgbSpace {
+            name,
+            primaries,
+            white,
+            transfer,
+        }
+    }
+
+    /// The matrix from linear light components to XYZ relative to the white point of the space.
+    fn xyz_matrix(&self) -> Matrix {
+        let columns = self.primaries.map(|(x, y)| [x / y, 1.0, (1.0 - x - y) / y]);
+        let primaries = [0, 1, 2].map(|row| columns.map(|column| column[row]));
+        let scale = transform(
+            &invert(&primaries),
+            [self.white.x, self.white.y, self.white.z],
+        );
+        primaries.map(|row| [row[0] * scale[0], row[1] * scale[1], row[2] * scale[2]])
+    }
+
+    /// The matrices from linear light components to XYZ relative to D65 and back, including the
+    /// Bradford adaptation from the white point of the space. They are computed once for the built
+    /// in spaces and on every call for custom ones.
+    fn matrices(&self) -> (Matrix, Matrix) {
+        static BUILT_IN: OnceLock<[(RgbSpace, Matrix, Matrix); 5]> = OnceLock::new();
+        let built_in = BUILT_IN.get_or_init(|| {
+            BUILT_IN_SPACES.map(|space| {
+                let (to_xyz, from_xyz) = space.compute_matrices();
+                (space, to_xyz, from_xyz)
+            })
+        });
+        built_in
+            .iter()
+            .find(|(space, ..)| space.primaries == self.primaries && space.white == self.white)
+            .map_or_else(
+                || self.compute_matrices(),
+                |&(_, to_xyz, from_xyz)| (to_xyz, from_xyz),
+            )
+    }
+
+    fn compute_matrices(&self) -> (Matrix, Matrix) {
+        let adaptation = Adaptation::Bradford.matrix(self.white, WhitePoint::D65);
+        let to_xyz = multiply(&adaptation, &self.xyz_matrix());
+        (to_xyz, invert(&to_xyz))
+    }
+
+    /// Applies the transfer function to gamma encoded components, giving linear light.
+    pub fn decode(&self, rgb: [f32; 3]) -> [f32; 3] {
+        rgb.map(|c| self.transfer.decode(c))
+    }
+
+    /// Applies the inverse transfer function to linear light components.
+    pub fn encode(&self, linear: [f32; 3]) -> [f32; 3] {
+        linear.map(|c| self.transfer.encode(c))
+    }
+
+    /// Converts gamma encoded components to XYZ relative to D65.
+    pub fn to_xyz(&self, rgb: [f32; 3]) -> Xyz {
+        let [x, y, z] = transform(&self.matrices().0, self.decode(rgb));
+        Xyz::new(x, y, z)
+    }
+
+    /// Converts XYZ relative to D65 to gamma encoded components, which may lie outside `[0, 1]`.
+    pub fn from_xyz(&self, xyz: Xyz) -> [f32; 3] {
+        self.encode(transform(&self.matrices().1, [xyz.x, xyz.y, xyz.z]))
+    }
+
+    /// Converts gamma encoded components of this space to the components in `target`.
+    pub fn convert(&self, rgb: [f32; 3], target: &RgbSpace) -> [f32; 3] {
+        target.from_xyz(self.to_xyz(rgb))
+    }
+
+    /// Expresses an sRGB colour in this space.
+    pub fn from_color(&self, color: Color) -> [f32; 3] {
+        self.from_xyz(Xyz::from_linear_srgb(color_to_linear(color)))
+    }
+
+    /// Converts components of this space to a `Color`, clipping colours outside the sRGB gamut.
+    pub fn to_color(&self, rgb: [f32; 3]) -> Color {
+        linear_to_color(self.to_xyz(rgb).to_linear_srgb())
+    }
+
+    /// Whether gamma encoded components lie within the gamut of this space. The check is done in
+    /// linear light, where rounding errors are not amplified by the transfer function.
+    pub fn contains(&self, rgb: [f32; 3]) -> bool {
+        linear_in_gamut(self.decode(rgb))
+    }
+}
+
+impl Display for RgbSpace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,325 @@
+//! Spectral data: spectra sampled from 380 nm to 780 nm, integrated with the CIE colour matching
+//! functions to XYZ and `Color`.
+//!
+//! A `Spectrum` holds 81 samples at 5 nm intervals. It can be the spectral power distribution of a
+//! light, turned into its colour with `to_xyz`, or the reflectance of a surface in `[0, 1]`, whose
+//! colour depends on the illuminant it is lit by (`reflectance_to_xyz`). Colours are computed for
+//! the CIE 1931 2° or the CIE 1964 10° standard observer.
+//!
+//! The built-in illuminants are CIE A (incandescent light), the daylight illuminants D50 and D65,
+//! the fluorescent illuminants F2 (cool white), F7 (broadband daylight) and F11 (narrow tri-band),
+//! which the CIE recommends as representatives of the F series, as well as the equal energy
+//! illuminant E and black bodies and daylight of any temperature.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::{Color, WHITE};
+//! use colors::spectrum::{wavelength_to_color, Illuminant, Observer, Spectrum};
+//!
+//! // a perfect white reflector is white under any illuminant, as the eye adapts to it
+//! let white = Spectrum::constant(1.0);
+//! assert_eq!(white.reflectance_to_color(Illuminant::F11, Observer::Cie1931), WHITE);
+//!
+//! // a surface reflecting only the long wavelengths
+//! let red = Spectrum::from_fn(|nm| if nm > 580.0 { 0.9 } else { 0.05 });
+//! let color = red.reflectance_to_color(Illuminant::D65, Observer::Cie1931);
+//! assert_eq!(color, Color(255, 74, 46));
+//!
+//! assert_eq!(wavelength_to_color(470.0), Color(0, 45, 255));
+//! ```
+use std::ops::{Add, Mul};
+
+use crate::cie::{Adaptation, WhitePoint, Xyz};
+use crate::clr::{Color, BLACK};
+use crate::cmf;
+use crate::cmf::{CIE1931, CIE1964, DAYLIGHT, FLUORESCENT};
+use crate::gamut::GamutMapping;
+use crate::temperature::{brightest_color, daylight_chromaticity, planck};
+
+/// The number of samples of a spectrum.
+pub const SAMPLES: usize = cmf::SAMPLES;
+/// The wavelength of the first sample in nm.
+pub const START: f32 = cmf::START as f32;
+/// The wavelength of the last sample in nm.
+pub const END: f32 = cmf::END as f32;
+/// The spacing of the samples in nm.
+pub const STEP: f32 = cmf::STEP as f32;
+
+/// A CIE standard colorimetric observer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Observer {
+    /// The CIE 1931 2° observer, for fields of view up to 4°, which sRGB and most colour spaces
+    /// are defined for
+    #[default]
+    Cie1931,
+    /// The CIE 1964 10° supplementary observer, for larger fields of view
+    Cie1964,
+}
+
+impl Observer {
+    fn table(self) -> &'static [[f64; 3]; SAMPLES] {
+        match self {
+            Observer::Cie1931 => &CIE1931,
+            Observer::Cie1964 => &CIE1964,
+        }
+    }
+
+    /// The colour matching functions x̄, ȳ, z̄ at `wavelength` in nm, interpolated linearly between
+    /// the tabulated values and zero outside `[START, END]`.
+    pub fn color_matching(self, wavelength: f32) -> Xyz {
+        let [x, y, z] = interpolate(wavelength, |i| self.table()[i]);
+        Xyz::new(x as f32, y as f32, z as f32)
+    }
+}
+
+/// Linear interpolation in a table with `SAMPLES` entries, zero outside of it.
+fn interpolate(wavelength: f32, entry: impl Fn(usize) -> [f64; 3]) -> [f64; 3] {
+    if !(START..=END).contains(&wavelength) {
+        return [0.0; 3];
+    }
+    let position = f64::from((wavelength - START) / STEP);
+    let i = (position as usize).min(SAMPLES - 2);
+    let t = position - i as f64;
+    let (a, b) = (entry(i), entry(i + 1));
+    [0, 1, 2].map(|k| a[k] + (b[k] - a[k]) * t)
+}
+
+/// A CIE illuminant.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Illuminant {
+    /// Incandescent light, a black body at 2856 K
+    A,
+    /// Horizon light at 5003 K
+    D50,
+    /// Noon daylight at 6504 K
+    D65,
+    /// Equal energy at all wavelengths
+    E,
+    /// Cool white fluorescent lamp
+    F2,
+    /// Broadband daylight fluorescent lamp
+    F7,
+    /// Narrow tri-band fluorescent lamp
+    F11,
+    /// A black body at the given temperature in Kelvin
+    Blackbody(f32),
+    /// CIE daylight at the given temperature in Kelvin, from 4000 K
+    Daylight(f32),
+}
+
+impl Illuminant {
+    /// The relative spectral power distribution, normalised to `100` at 560 nm (except for the
+    /// F illuminants, which are given as tabulated by the CIE).
+    pub fn spectrum(self) -> Spectrum {
+        match self {
+            Illuminant::A => Spectrum::from_fn(|nm| {
+                // the CIE definition, with the value of c₂ at the time
+                let c2: f64 = 1.435e7 / 2848.0;
+                let nm = f64::from(nm);
+                (100.0 * (560.0 / nm).powi(5) * (c2 / 560.0).exp_m1() / (c2 / nm).exp_m1()) as f32
+            }),
+            Illuminant::D50 => Illuminant::Daylight(5003.0).spectrum(),
+            Illuminant::D65 => Illuminant::Daylight(6504.0).spectrum(),
+            Illuminant::E => Spectrum::constant(100.0),
+            Illuminant::F2 => fluorescent(0),
+            Illuminant::F7 => fluorescent(1),
+            Illuminant::F11 => fluorescent(2),
+            Illuminant::Blackbody(kelvin) => {
+                let kelvin = f64::from(kelvin);
+                let reference = planck(560.0, kelvin);
+                Spectrum::from_fn(|nm| (100.0 * planck(f64::from(nm), kelvin) / reference) as f32)
+            }
+            Illuminant::Daylight(kelvin) => {
+                let (x, y) = daylight_chromaticity(kelvin);
+                let (x, y) = (f64::from(x), f64::from(y));
+                // the CIE rounds the weights to three decimals for its tables
+                let denominator = 0.0241 + 0.2562 * x - 0.7341 * y;
+                let m1 = ((-1.3515 - 1.7703 * x + 5.9114 * y) / denominator * 1e3).round() / 1e3;
+                let m2 = ((0.0300 - 31.4424 * x + 30.0717 * y) / denominator * 1e3).round() / 1e3;
+                // the components are tabulated every 10 nm and interpolated linearly
+                Spectrum::from_fn(|nm| {
+                    let position = f64::from((nm - START) / 10.0);
+                    let i = (position as usize).min(DAYLIGHT.len() - 2);
+                    let t = position - i as f64;
+                    let value = |[s0, s1, s2]: [f64; 3]| s0 + m1 * s1 + m2 * s2;
+                    let (a, b) = (value(DAYLIGHT[i]), value(DAYLIGHT[i + 1]));
+                    (a + (b - a) * t) as f32
+                })
+            }
+        }
+    }
+
+    /// The white point of the illuminant for `observer`: the tristimulus values of its light,
+    /// normalised to `y = 1`.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::spectrum::{Illuminant, Observer};
+    /// # fn main() {
+    /// let d65 = Illuminant::D65.white_point(Observer::Cie1931);
+    /// assert_eq!(format!("{:.4}", d65), "(0.9504, 1.0000, 1.0891)");
+    /// # }
+    /// ```
+    pub fn white_point(self, observer: Observer) -> WhitePoint {
+        let xyz = self.spectrum().to_xyz(observer);
+        WhitePoint::new(xyz.x / xyz.y, 1.0, xyz.z / xyz.y)
+    }
+}
+
+fn fluorescent(index: usize) -> Spectrum {
+    Spectrum::new(FLUORESCENT[index].map(|value| value as f32))
+}
+
+/// A spectrum sampled from `START` to `END` in steps of `STEP`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Spectrum {
+    values: [f32; SAMPLES],
+}
+
+impl Spectrum {
+    /// Creates a spectrum from its samples at `START`, `START + STEP`, … `END`.
+    pub fn new(values: [f32; SAMPLES]) -> Self {
+        Spectrum { values }
+    }
+
+    /// A spectrum with the same value at all wavelengths.
+    pub fn constant(value: f32) -> Self {
+        Spectrum::new([value; SAMPLES])
+    }
+
+    /// A spectrum with the values of `f` at the sampled wavelengths in nm.
+    pub fn from_fn(f: impl Fn(f32) -> f32) -> Self {
+        let mut values = [0.0; SAMPLES];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = f(START + i as f32 * STEP);
+        }
+        Spectrum::new(values)
+    }
+
+    /// Resamples `(wavelength, value)` pairs at any spacing, e.g. the 10 nm steps of most
+    /// spectrophotometers. Values in between are interpolated linearly, beyond the first and last
+    /// pair their values are repeated, as the CIE recommends. Returns `None` if there are no
+    /// samples.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::spectrum::Spectrum;
+    /// # fn main() {
+    /// let spectrum = Spectrum::from_samples(&[(400.0, 0.2), (700.0, 0.8)]).unwrap();
+    /// assert_eq!(spectrum.at(380.0), 0.2);
+    /// assert_eq!(spectrum.at(550.0), 0.5);
+    /// # }
+    /// ```
+    pub fn from_samples(samples: &[(f32, f32)]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let (&first, &last) = (samples.first()?, samples.last()?);
+        Some(Spectrum::from_fn(|nm| {
+            if nm <= first.0 {
+                return first.1;
+            }
+            if nm >= last.0 {
+                return last.1;
+            }
+            let i = samples.partition_point(|&(wavelength, _)| wavelength <= nm);
+            let ((w0, v0), (w1, v1)) = (samples[i - 1], samples[i]);
+            v0 + (v1 - v0) * (nm - w0) / (w1 - w0)
+        }))
+    }
+
+    /// The samples.
+    pub fn values(&self) -> &[f32; SAMPLES] {
+        &self.values
+    }
+
+    /// The value at `wavelength` in nm, interpolated linearly between the samples and zero outside
+    /// `[START, END]`.
+    pub fn at(&self, wavelength: f32) -> f32 {
+        interpolate(wavelength, |i| [f64::from(self.values[i]); 3])[0] as f32
+    }
+
+    /// Integrates the spectrum weighted with `weight` over the observer.
+    fn integrate(&self, observer: Observer, weight: impl Fn(usize) -> f64) -> [f64; 3] {
+        let mut xyz = [0.0; 3];
+        for (i, cmf) in observer.table().iter().enumerate() {
+            let value = f64::from(self.values[i]) * weight(i);
+            for (sum, &weight) in xyz.iter_mut().zip(cmf) {
+                *sum += value * weight;
+            }
+        }
+        xyz
+    }
+
+    /// The XYZ tristimulus values of light with this spectral power distribution, scaled such
+    /// that light with the value `1` at all wavelengths has `Y = 1`.
+    pub fn to_xyz(&self, observer: Observer) -> Xyz {
+        let norm: f64 = observer.table().iter().map(|cmf| cmf[1]).sum();
+        let [x, y, z] = self.integrate(observer, |_| 1.0);
+        Xyz::new((x / norm) as f32, (y / norm) as f32, (z / norm) as f32)
+    }
+
+    /// The colour of light with this spectral power distribution: the brightest sRGB colour of its
+    /// chromaticity, clipped to the gamut.
+    pub fn to_color(&self, observer: Observer) -> Color {
+        brightest_color(self.to_xyz(observer))
+    }
+
+    /// The XYZ tristimulus values of a surface with this spectral reflectance under `illuminant`,
+    /// relative to the illuminant: a perfect white reflector has the illuminant's white point.
+    pub fn reflectance_to_xyz(&self, illuminant: Illuminant, observer: Observer) -> Xyz {
+        let light = illuminant.spectrum();
+        let norm = light.integrate(observer, |_| 1.0)[1];
+        let [x, y, z] = self.integrate(observer, |i| f64::from(light.values[i]));
+        Xyz::new((x / norm) as f32, (y / norm) as f32, (z / norm) as f32)
+    }
+
+    /// The sRGB colour of a surface with this spectral reflectance under `illuminant`, adapted
+    /// from the illuminant to D65 with the Bradford transform and mapped into the sRGB gamut.
+    pub fn reflectance_to_color(&self, illuminant: Illuminant, observer: Observer) -> Color {
+        let xyz = self.reflectance_to_xyz(illuminant, observer);
+        let white = illuminant.white_point(observer);
+        GamutMapping::Css.map(xyz.adapt(white, WhitePoint::D65, Adaptation::Bradford))
+    }
+}
+
+impl Add for Spectrum {
+    type Output = Spectrum;
+
+    fn add(self, other: Spectrum) -> Spectrum {
+        let mut values = self.values;
+        for (value, other) in values.iter_mut().zip(other.values) {
+            *value += other;
+        }
+        Spectrum::new(values)
+    }
+}
+
+/// The product at each wavelength, e.g. of a reflectance and an illuminant or of two filters.
+impl Mul for Spectrum {
+    type Output = Spectrum;
+
+    fn mul(self, other: Spectrum) -> Spectrum {
+        let mut values = self.values;
+        for (value, other) in values.iter_mut().zip(other.values) {
+            *value *= other;
+        }
+        Spectrum::new(values)
+    }
+}
+
+impl Mul<f32> for Spectrum {
+    type Output = Spectrum;
+
+    fn mul(self, factor: f32) -> Spectrum {
+        Spectrum::new(self.values.map(|value| value * factor))
+    }
+}
+
+/// The colour of monochromatic light of `wavelength` in nm for rendering a rainbow: the brightest
+/// sRGB colour of its chromaticity, clipped to the gamut. Black outside `[START, END]`.
+pub fn wavelength_to_color(wavelength: f32) -> Color {
+    if !(START..=END).contains(&wavelength) {
+        return BLACK;
+    }
+    brightest_color(Observer::Cie1931.color_matching(wavelength))
+}
@@ -0,0 +1,254 @@
+//! Colour temperature: the colour of black body radiators and CIE daylight of a given temperature
+//! in Kelvin, and the correlated colour temperature (CCT) of a colour.
+//!
+//! The chromaticity of a black body (the Planckian locus) is computed from Planck's law with the
+//! CIE 1931 standard observer, that of daylight with the formula of the CIE daylight locus. As a
+//! `Color`, a temperature is rendered as the brightest sRGB colour of its chromaticity, clipped to
+//! the gamut where needed.
+//!
+//! The correlated colour temperature of a colour is the temperature of the closest point on the
+//! Planckian locus in the CIE 1960 UCS, and `Duv` the signed distance to it, positive above the
+//! locus (towards green) and negative below it (towards magenta). It can be estimated with the
+//! formula of McCamy or with Robertson's method, the latter working from 1667 K upwards.
+//!
+//! # Examples
+//! ```
+//! use colors::clr::Color;
+//! use colors::temperature::{cct, kelvin_to_color, CctMethod, Locus};
+//!
+//! assert_eq!(kelvin_to_color(2700.0, Locus::Planckian), Color(255, 173, 89));
+//! assert_eq!(kelvin_to_color(6504.0, Locus::Daylight), Color(255, 255, 255));
+//!
+//! // CIE illuminant A
+//! let a = cct(0.44757, 0.40745, CctMethod::Robertson).unwrap();
+//! assert_eq!(a.kelvin.round(), 2856.0);
+//! assert!(a.duv.abs() < 1e-4);
+//! ```
+use crate::cie::Xyz;
+use crate::clr::Color;
+use crate::cmf::{CIE1931, START, STEP};
+use crate::rgb::linear_to_color;
+
+/// The lowest temperature colours are given for, in Kelvin.
+pub const MIN_KELVIN: f32 = 1000.0;
+/// The highest temperature colours are given for, in Kelvin.
+pub const MAX_KELVIN: f32 = 40000.0;
+
+/// The second radiation constant `c₂` in nm·K, as used for the CIE illuminants.
+const C2: f64 = 1.4388e7;
+
+/// The curve the chromaticity of a temperature is taken from.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locus {
+    /// Black body radiators, e.g. incandescent light
+    #[default]
+    Planckian,
+    /// CIE daylight, the basis of the D illuminants; defined from 4000 K
+    Daylight,
+}
+
+/// The method to estimate the correlated colour temperature with.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CctMethod {
+    /// McCamy's cubic formula, accurate to a few Kelvin near the Planckian locus from 2856 K to
+    /// 6500 K
+    McCamy,
+    /// Robertson's interpolation between isotemperature lines, from 1667 K
+    #[default]
+    Robertson,
+}
+
+/// A correlated colour temperature.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cct {
+    /// The temperature in Kelvin
+    pub kelvin: f32,
+    /// The signed distance from the Planckian locus in the CIE 1960 UCS
+    pub duv: f32,
+}
+
+/// The CIE 1960 UCS coordinates of the chromaticity `(x, y)`.
+fn xy_to_uv(x: f64, y: f64) -> (f64, f64) {
+    let denominator = -2.0 * x + 12.0 * y + 3.0;
+    (4.0 * x / denominator, 6.0 * y / denominator)
+}
+
+/// The spectral radiance of a black body at `kelvin` and `wavelength` in nm, without the constant
+/// factor.
+pub(crate) fn planck(wavelength: f64, kelvin: f64) -> f64 {
+    1.0 / (wavelength.powi(5) * ((C2 / (wavelength * kelvin)).exp_m1()))
+}
+
+/// The CIE 1931 chromaticity of a black body at `kelvin`, with the spectral radiance integrated
+/// over the standard observer.
+fn planckian(kelvin: f64) -> (f64, f64) {
+    let mut xyz = [0.0f64; 3];
+    for (i, cmf) in CIE1931.iter().enumerate() {
+        let radiance = planck(START + i as f64 * STEP, kelvin);
+        for (sum, &weight) in xyz.iter_mut().zip(cmf) {
+            *sum += radiance * weight;
+        }
+    }
+    let total: f64 = xyz.iter().sum();
+    (xyz[0] / total, xyz[1] / total)
+}
+
+/// The CIE 1931 chromaticity `(x, y)` of a black body radiator at `kelvin`.
+///
+/// Example
+/// ```
+/// # use colors::temperature::planckian_chromaticity;
+/// # fn main() {
+/// // CIE illuminant A
+/// let (x, y) = planckian_chromaticity(2856.0);
+/// assert_eq!(format!("{:.4} {:.4}", x, y), "0.4475 0.4074");
+/// # }
+/// ```
+pub fn planckian_chromaticity(kelvin: f32) -> (f32, f32) {
+    let (x, y) = planckian(f64::from(kelvin));
+    (x as f32, y as f32)
+}
+
+/// The CIE 1931 chromaticity `(x, y)` of CIE daylight at `kelvin`. The daylight locus is defined
+/// from 4000 K to 25000 K; lower temperatures are raised to 4000 K and higher ones extrapolated.
+///
+/// Example
+/// ```
+/// # use colors::temperature::daylight_chromaticity;
+/// # fn main() {
+/// // D65 is daylight at 6500 K with the older value of c₂, 6504 K today
+/// let (x, y) = daylight_chromaticity(6504.0);
+/// assert_eq!(format!("{:.4} {:.4}", x, y), "0.3127 0.3291");
+/// # }
+/// ```
+pub fn daylight_chromaticity(kelvin: f32) -> (f32, f32) {
+    let t = f64::from(kelvin.max(4000.0));
+    let x = if t <= 7000.0 {
+        -4.6070e9 / t.powi(3) + 2.9678e6 / t.powi(2) + 0.09911e3 / t + 0.244063
+    } else {
+        -2.0064e9 / t.powi(3) + 1.9018e6 / t.powi(2) + 0.24748e3 / t + 0.237040
+    };
+    let y = -3.0 * x * x + 2.87 * x - 0.275;
+    (x as f32, y as f32)
+}
+
+/// The colour of light at `kelvin` on `locus`: the brightest sRGB colour of its chromaticity,
+/// clipped to the gamut. Temperatures are clamped to `[MIN_KELVIN, MAX_KELVIN]`.
+pub fn kelvin_to_color(kelvin: f32, locus: Locus) -> Color {
+    let kelvin = kelvin.clamp(MIN_KELVIN, MAX_KELVIN);
+    let (x, y) = match locus {
+        Locus::Planckian => planckian_chromaticity(kelvin),
+        Locus::Daylight => daylight_chromaticity(kelvin),
+    };
+    brightest_color(Xyz::new(x / y, 1.0, (1.0 - x - y) / y))
+}
+
+/// The brightest sRGB colour with the chromaticity of `xyz`, clipped to the gamut. Black if `xyz`
+/// has no positive component in sRGB.
+pub(crate) fn brightest_color(xyz: Xyz) -> Color {
+    let rgb = xyz.to_linear_srgb().map(|c| c.max(0.0));
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    if max <= 0.0 {
+        return Color(0, 0, 0);
+    }
+    linear_to_color(rgb.map(|c| c / max))
+}
+
+/// Robertson's isotemperature lines: reciprocal temperature in mired, `u`, `v` of the Planckian
+/// locus in the CIE 1960 UCS and the slope of the line.
+#[rustfmt::skip]
+const ROBERTSON: [[f64; 4]; 31] = [
+    [0.0, 0.18006, 0.26352, -0.24341],
+    [10.0, 0.18066, 0.26589, -0.25479],
+    [20.0, 0.18133, 0.26846, -0.26876],
+    [30.0, 0.18208, 0.27119, -0.28539],
+    [40.0, 0.18293, 0.27407, -0.30470],
+    [50.0, 0.18388, 0.27709, -0.32675],
+    [60.0, 0.18494, 0.28021, -0.35156],
+    [70.0, 0.18611, 0.28342, -0.37915],
+    [80.0, 0.18740, 0.28668, -0.40955],
+    [90.0, 0.18880, 0.28997, -0.44278],
+    [100.0, 0.19032, 0.29326, -0.47888],
+    [125.0, 0.19462, 0.30141, -0.58204],
+    [150.0, 0.19962, 0.30921, -0.70471],
+    [175.0, 0.20525, 0.31647, -0.84901],
+    [200.0, 0.21142, 0.32312, -1.0182],
+    [225.0, 0.21807, 0.32909, -1.2168],
+    [250.0, 0.22511, 0.33439, -1.4512],
+    [275.0, 0.23247, 0.33904, -1.7298],
+    [300.0, 0.24010, 0.34308, -2.0637],
+    [325.0, 0.24792, 0.34655, -2.4681],
+    [350.0, 0.25591, 0.34951, -2.9641],
+    [375.0, 0.26400, 0.35200, -3.5814],
+    [400.0, 0.27218, 0.35407, -4.3633],
+    [425.0, 0.28039, 0.35577, -5.3762],
+    [450.0, 0.28863, 0.35714, -6.7262],
+    [475.0, 0.29685, 0.35823, -8.5955],
+    [500.0, 0.30505, 0.35907, -11.324],
+    [525.0, 0.31320, 0.35968, -15.628],
+    [550.0, 0.32129, 0.36011, -23.325],
+    [575.0, 0.32931, 0.36038, -40.770],
+    [600.0, 0.33724, 0.36051, -116.45],
+];
+
+/// Robertson's method, `None` outside the table.
+fn robertson(u: f64, v: f64) -> Option<f64> {
+    let distance = |[_, ui, vi, slope]: [f64; 4]| ((v - vi) - slope * (u - ui)) / slope.hypot(1.0);
+    let mut previous = distance(ROBERTSON[0]);
+    for i in 1..ROBERTSON.len() {
+        let current = distance(ROBERTSON[i]);
+        if previous == 0.0 || (previous < 0.0) != (current < 0.0) {
+            let ratio = previous / (previous - current);
+            let mired = ROBERTSON[i - 1][0] + ratio * (ROBERTSON[i][0] - ROBERTSON[i - 1][0]);
+            return Some(1e6 / mired);
+        }
+        previous = current;
+    }
+    None
+}
+
+/// The correlated colour temperature and `Duv` of the CIE 1931 chromaticity `(x, y)`. Returns
+/// `None` if the method cannot give a temperature for it, e.g. below 1667 K for Robertson's
+/// method.
+pub fn cct(x: f32, y: f32, method: CctMethod) -> Option<Cct> {
+    let (x, y) = (f64::from(x), f64::from(y));
+    let (u, v) = xy_to_uv(x, y);
+    let kelvin = match method {
+        CctMethod::McCamy => {
+            let n = (x - 0.3320) / (0.1858 - y);
+            Some(449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33)
+        }
+        CctMethod::Robertson => robertson(u, v),
+    }
+    .filter(|&kelvin| kelvin.is_finite() && kelvin > 0.0)?;
+    let (px, py) = planckian(kelvin);
+    let (pu, pv) = xy_to_uv(px, py);
+    let duv = (u - pu).hypot(v - pv).copysign(v - pv);
+    Some(Cct {
+        kelvin: kelvin as f32,
+        duv: duv as f32,
+    })
+}
+
+impl Color {
+    /// The correlated colour temperature and `Duv` of the colour, see `temperature::cct`.
+    ///
+    /// Example
+    /// ```
+    /// # use colors::clr::WHITE;
+    /// # use colors::temperature::CctMethod;
+    /// # fn main() {
+    /// let white = WHITE.cct(CctMethod::Robertson).unwrap();
+    /// assert_eq!(white.kelvin.round(), 6504.0);
+    /// assert_eq!(format!("{:.4}", white.duv), "0.0032");
+    /// # }
+    /// ```
+    pub fn cct(&self, method: CctMethod) -> Option<Cct> {
+        let xyz = Xyz::from(*self);
+        let sum = xyz.x + xyz.y + xyz.z;
+        if sum <= 0.0 {
+            return None;
+        }
+        cct(xyz.x / sum, xyz.y / sum, method)
+    }
+}